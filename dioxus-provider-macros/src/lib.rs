@@ -2,61 +2,193 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use std::time::Duration;
 use syn::{
-    FnArg, ItemFn, LitStr, Pat, PatType, Result, ReturnType, Token, Type, parse::Parse,
-    parse::ParseStream, parse_macro_input,
+    FnArg, ImplItem, ItemFn, ItemImpl, LitStr, Pat, PatType, Result, ReturnType, Token, Type,
+    parse::Parse, parse::ParseStream, parse_macro_input, spanned::Spanned, visit_mut::VisitMut,
 };
 
 /// Attribute arguments for the provider macro
 #[derive(Default)]
 struct ProviderArgs {
     interval: Option<Duration>,
+    interval_when_visible_only: bool, // Skip interval ticks while the document is hidden
     cache_expiration: Option<Duration>,
     stale_time: Option<Duration>,
+    error_ttl: Option<Duration>,
+    check_interval: Option<Duration>, // How often the provider's entries are checked for expiration
+    error_fallback: Option<syn::Expr>, // Output shown in place of an error
+    dispose_delay: Option<Duration>, // How long an entry is kept once no component uses it
+    concurrency: Option<usize>, // Maximum number of concurrent fetches of this provider type
     compose: Vec<syn::Ident>, // List of provider functions to compose
+    cache: Option<LitStr>,    // Name of the cache to store entries in
+    transform: Option<TransformArg>,
+    dedupe: bool, // Share one allocation between equal cached values
+    no_persist: bool, // Keep entries out of persistence and snapshots
+    no_cache: bool,   // Fetch on every mount without reading or writing the cache
+    client_only: bool, // Never fetch while rendering on the server (`ssr = false`)
+    sized: bool,      // Record the SizedCacheValue estimate of cached results
+    expiration_jitter: Option<f64>, // Maximum jitter as a fraction of expiration/stale time
+    error_context: bool, // Annotate errors with the provider key and parameter
+    redact_params: bool, // Describe parameters as "<redacted>" in logs, errors and devtools
+    namespaced: bool,    // Build readable cache keys from the module path and provider name
+    stringify_errors: bool, // Convert errors propagated with `?` into the String error type
+    error: Option<Type>, // Error type the body's `anyhow`-style result is converted into
+    map_err: Option<syn::Path>, // Converts the body's error into `error` (default: via to_string)
+    server_fn: bool,     // Convert the body's ServerFnError into ProviderError
+    tags: Vec<LitStr>,   // Free-form tags reported in the provider's metadata
+    with_cancel: bool,   // Pass a CancellationToken as the trailing function parameter
+    conditional: bool,   // The function returns a FetchOutcome to keep unchanged cached data
+    progressive: bool,   // Pass an Emitter for partial values as the trailing function parameter
+    blocking: bool,      // Run the synchronous body on a blocking thread pool
+    should_refetch: Option<syn::Path>, // Predicate forcing a background refetch of fresh data
+    entity_key: Option<syn::Ident>, // Parameter identifying the entity an entry belongs to
+    normalize: Option<syn::Path>, // Maps a parameter to the canonical form its cache key is built from
+    batch_window: Option<Duration>, // Coalesce fetches starting within this window into one run_batch
+    accumulate: Option<syn::Path>, // Identifies the items merged into the cached list by each fetch
+    max_items: Option<usize>,      // Maximum length of an accumulated list
 }
 
+/// Post-processing function applied to a provider's `Ok` value: `transform = path -> Output`
+struct TransformArg {
+    func: syn::Path,
+    output_type: Type,
+}
+
+/// A provider invalidated by a mutation: `fetch_users` for `()` or `fetch_user(id)` for the
+/// param built from the arguments
+struct InvalidateTarget {
+    func: syn::Expr,
+    args: Vec<syn::Expr>,
+}
+
+impl Parse for InvalidateTarget {
+    fn parse(input: ParseStream) -> Result<Self> {
+        match input.parse::<syn::Expr>()? {
+            syn::Expr::Call(call) => Ok(Self {
+                func: *call.func,
+                args: call.args.into_iter().collect(),
+            }),
+            func @ syn::Expr::Path(_) => Ok(Self {
+                func,
+                args: Vec::new(),
+            }),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "expected a provider function or a call picking its parameters, e.g. fetch_user(id)",
+            )),
+        }
+    }
+}
+
+/// How long `#[mutation(await_invalidations)]` waits for invalidated entries to refetch
+const DEFAULT_AWAIT_INVALIDATIONS: Duration = Duration::from_secs(10);
+
 /// Attribute arguments for the mutation macro
 #[derive(Default)]
 struct MutationArgs {
-    invalidates: Vec<syn::Ident>, // List of provider functions to invalidate
+    compose: Vec<syn::Ident>, // Providers run with the mutation's parameters before its body
+    invalidates: Vec<InvalidateTarget>, // Providers (or provider calls) to invalidate
+    invalidates_tags: Vec<LitStr>, // Provider tags whose entries are invalidated
+    updates: Vec<syn::ExprCall>,  // Provider calls whose cache receives the mutation output
+    retry: Option<u32>,           // Number of retries after a failed attempt
+    timeout: Option<Duration>,    // Maximum duration of a single attempt
+    optimistic: Option<syn::Expr>, // Output written into the `updates` entries before running
+    with_progress: bool, // Pass a ProgressReporter as the trailing function parameter
+    await_invalidations: Option<Duration>, // How long to wait for invalidated entries to refetch
+}
+
+/// Parse a duration argument such as `"30s"`, returning the literal for error spans
+fn parse_duration_lit(input: ParseStream) -> Result<(LitStr, Duration)> {
+    let lit: LitStr = input.parse()?;
+    let duration = humantime::parse_duration(&lit.value())
+        .map_err(|e| syn::Error::new_spanned(&lit, format!("Invalid duration format: {e}")))?;
+    Ok((lit, duration))
 }
 
 impl Parse for ProviderArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut args = ProviderArgs::default();
+        let mut visible_only_lit = None;
+        let mut progressive_ident = None;
+        let mut map_err_ident = None;
+        let mut batch_window_lit = None;
+        let mut accumulate_ident = None;
+        let mut max_items_ident = None;
+        let mut blocking_ident = None;
+        let mut server_fn_ident = None;
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
+
+            // Flag arguments without a value
+            let flag = match ident.to_string().as_str() {
+                "with_cancel" => Some(&mut args.with_cancel),
+                "conditional" => Some(&mut args.conditional),
+                "progressive" => Some(&mut args.progressive),
+                "redact_params" => Some(&mut args.redact_params),
+                "namespaced" => Some(&mut args.namespaced),
+                "stringify_errors" => Some(&mut args.stringify_errors),
+                "blocking" => Some(&mut args.blocking),
+                "server_fn" => Some(&mut args.server_fn),
+                _ => None,
+            };
+            if let Some(flag) = flag {
+                if ident == "progressive" {
+                    progressive_ident = Some(ident.clone());
+                }
+                if ident == "blocking" {
+                    blocking_ident = Some(ident.clone());
+                }
+                if ident == "server_fn" {
+                    server_fn_ident = Some(ident.clone());
+                }
+                *flag = true;
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+
             input.parse::<Token![=]>()?;
 
             match ident.to_string().as_str() {
                 "interval" => {
-                    let lit: LitStr = input.parse()?;
-                    let duration_str = lit.value();
-                    let duration = humantime::parse_duration(&duration_str).map_err(|e| {
-                        syn::Error::new_spanned(lit, format!("Invalid duration format: {e}"))
-                    })?;
+                    let (_, duration) = parse_duration_lit(input)?;
                     args.interval = Some(duration);
                 }
                 "cache_expiration" => {
-                    let lit: LitStr = input.parse()?;
-                    let duration_str = lit.value();
-                    let duration = humantime::parse_duration(&duration_str).map_err(|e| {
-                        syn::Error::new_spanned(lit, format!("Invalid duration format: {e}"))
-                    })?;
+                    let (_, duration) = parse_duration_lit(input)?;
                     args.cache_expiration = Some(duration);
                 }
                 "stale_time" => {
-                    let lit: LitStr = input.parse()?;
-                    let duration_str = lit.value();
-                    let duration = humantime::parse_duration(&duration_str).map_err(|e| {
-                        syn::Error::new_spanned(lit, format!("Invalid duration format: {e}"))
-                    })?;
+                    let (_, duration) = parse_duration_lit(input)?;
                     args.stale_time = Some(duration);
                 }
+                "error_ttl" => {
+                    let (_, duration) = parse_duration_lit(input)?;
+                    args.error_ttl = Some(duration);
+                }
+                "check_interval" => {
+                    let (lit, duration) = parse_duration_lit(input)?;
+                    if duration.is_zero() {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "check_interval must be greater than zero",
+                        ));
+                    }
+                    args.check_interval = Some(duration);
+                }
+                "error_fallback" => {
+                    let expr: syn::Expr = input.parse()?;
+                    args.error_fallback = Some(expr);
+                }
+                // `gc_time` is accepted as an alias, matching other query libraries
+                "dispose_delay" | "gc_time" => {
+                    let (_, duration) = parse_duration_lit(input)?;
+                    args.dispose_delay = Some(duration);
+                }
                 "compose" => {
                     // Parse compose list: compose = [provider1, provider2, ...]
                     let content;
@@ -64,6 +196,122 @@ impl Parse for ProviderArgs {
                     let providers = content.parse_terminated(syn::Ident::parse, Token![,])?;
                     args.compose = providers.into_iter().collect();
                 }
+                "cache" => {
+                    args.cache = Some(input.parse()?);
+                }
+                "transform" => {
+                    // Parse transform function and its output type: transform = normalize -> User
+                    let func: syn::Path = input.parse()?;
+                    if !input.peek(Token![->]) {
+                        return Err(syn::Error::new_spanned(
+                            func,
+                            "transform requires the transformed output type: transform = my_fn -> OutputType",
+                        ));
+                    }
+                    input.parse::<Token![->]>()?;
+                    let output_type: Type = input.parse()?;
+                    args.transform = Some(TransformArg { func, output_type });
+                }
+                "interval_when_visible_only" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.interval_when_visible_only = lit.value;
+                    visible_only_lit = Some(lit);
+                }
+                "dedupe" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.dedupe = lit.value;
+                }
+                "no_persist" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.no_persist = lit.value;
+                }
+                "no_cache" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.no_cache = lit.value;
+                }
+                "batch_window" => {
+                    let (lit, duration) = parse_duration_lit(input)?;
+                    args.batch_window = Some(duration);
+                    batch_window_lit = Some(lit);
+                }
+                "ssr" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.client_only = !lit.value;
+                }
+                "sized" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.sized = lit.value;
+                }
+                "should_refetch" => {
+                    args.should_refetch = Some(input.parse()?);
+                }
+                "normalize" => {
+                    args.normalize = Some(input.parse()?);
+                }
+                "accumulate" => {
+                    args.accumulate = Some(input.parse()?);
+                    accumulate_ident = Some(ident.clone());
+                }
+                "max_items" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    let max_items: usize = lit.base10_parse()?;
+                    if max_items == 0 {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "max_items must keep at least one item",
+                        ));
+                    }
+                    args.max_items = Some(max_items);
+                    max_items_ident = Some(ident.clone());
+                }
+                "error" => {
+                    args.error = Some(input.parse()?);
+                }
+                "map_err" => {
+                    args.map_err = Some(input.parse()?);
+                    map_err_ident = Some(ident.clone());
+                }
+                "entity_key" => {
+                    args.entity_key = Some(input.parse()?);
+                }
+                "tags" => {
+                    // Parse tag list: tags = ["users", "profile"]
+                    let content;
+                    syn::bracketed!(content in input);
+                    let tags = content.parse_terminated(|input| input.parse::<LitStr>(), Token![,])?;
+                    args.tags = tags.into_iter().collect();
+                }
+                "error_context" => {
+                    let lit: syn::LitBool = input.parse()?;
+                    args.error_context = lit.value;
+                }
+                "concurrency" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    let limit: usize = lit.base10_parse()?;
+                    if limit == 0 {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "concurrency must allow at least one fetch",
+                        ));
+                    }
+                    args.concurrency = Some(limit);
+                }
+                "expiration_jitter" => {
+                    // Parse a percentage: expiration_jitter = "20%"
+                    let lit: LitStr = input.parse()?;
+                    let percent = lit
+                        .value()
+                        .strip_suffix('%')
+                        .and_then(|percent| percent.trim().parse::<f64>().ok())
+                        .filter(|percent| (0.0..=100.0).contains(percent))
+                        .ok_or_else(|| {
+                            syn::Error::new_spanned(
+                                &lit,
+                                "Invalid jitter format: expected a percentage between 0% and 100%, e.g. \"20%\"",
+                            )
+                        })?;
+                    args.expiration_jitter = Some(percent / 100.0);
+                }
                 _ => return Err(syn::Error::new_spanned(ident, "Unknown argument")),
             }
 
@@ -72,6 +320,97 @@ impl Parse for ProviderArgs {
             }
         }
 
+        if let Some(lit) = visible_only_lit
+            && lit.value
+            && args.interval.is_none()
+        {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "interval_when_visible_only requires an interval, e.g. interval = \"30s\"",
+            ));
+        }
+
+        // server_fn picks the error type and its conversion itself
+        if let Some(ident) = &server_fn_ident
+            && (args.error.is_some() || map_err_ident.is_some() || args.stringify_errors)
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "server_fn converts errors into ProviderError and can't be combined with error, map_err or stringify_errors",
+            ));
+        }
+
+        if let Some(ident) = map_err_ident
+            && args.error.is_none()
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "map_err requires the error type it converts into, e.g. error = MyError",
+            ));
+        }
+
+        if args.stringify_errors
+            && let Some(error) = &args.error
+        {
+            return Err(syn::Error::new_spanned(
+                error,
+                "error = ... already converts the body's errors and can't be combined with stringify_errors",
+            ));
+        }
+
+        // Partial values are shown as the provider's output, so they can't be transformed
+        if let Some(ident) = progressive_ident
+            && (args.conditional || args.transform.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "progressive can't be combined with conditional or transform",
+            ));
+        }
+
+        if let Some(ident) = max_items_ident
+            && args.accumulate.is_none()
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "max_items bounds an accumulated list and requires accumulate, e.g. accumulate = event_id",
+            ));
+        }
+
+        // Partial values and uncached results are shown as fetched, without the cached list
+        if let Some(ident) = accumulate_ident
+            && (args.progressive || args.no_cache)
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "accumulate can't be combined with progressive or no_cache",
+            ));
+        }
+
+        // The body runs on another thread, where it can't await composed providers or
+        // claim the emitter, and a bridged body is async
+        if let Some(ident) = blocking_ident
+            && (!args.compose.is_empty()
+                || args.progressive
+                || args.error.is_some()
+                || args.server_fn)
+        {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "blocking can't be combined with compose, progressive, error or server_fn",
+            ));
+        }
+
+        // Batched fetches get their result from `run_batch`, not from the function body
+        if let Some(lit) = batch_window_lit
+            && (args.with_cancel || args.conditional || args.progressive)
+        {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "batch_window can't be combined with with_cancel, conditional or progressive",
+            ));
+        }
+
         Ok(args)
     }
 }
@@ -79,18 +418,74 @@ impl Parse for ProviderArgs {
 impl Parse for MutationArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut args = MutationArgs::default();
+        let mut optimistic_span = None;
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
+
+            // Flag arguments without a value
+            if ident == "with_progress" {
+                args.with_progress = true;
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+            if ident == "await_invalidations" && !input.peek(Token![=]) {
+                args.await_invalidations = Some(DEFAULT_AWAIT_INVALIDATIONS);
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+
             input.parse::<Token![=]>()?;
 
             match ident.to_string().as_str() {
-                "invalidates" => {
-                    // Parse invalidation list: invalidates = [provider1, provider2, ...]
+                "compose" => {
+                    // Parse compose list: compose = [provider1, provider2, ...]
                     let content;
                     syn::bracketed!(content in input);
                     let providers = content.parse_terminated(syn::Ident::parse, Token![,])?;
-                    args.invalidates = providers.into_iter().collect();
+                    args.compose = providers.into_iter().collect();
+                }
+                "invalidates" => {
+                    // Parse invalidation list: invalidates = [provider1, provider2(param), ...]
+                    let content;
+                    syn::bracketed!(content in input);
+                    let targets = content.parse_terminated(InvalidateTarget::parse, Token![,])?;
+                    args.invalidates = targets.into_iter().collect();
+                }
+                "invalidates_tags" => {
+                    // Parse tag list: invalidates_tags = ["users", "profile"]
+                    let content;
+                    syn::bracketed!(content in input);
+                    let tags = content.parse_terminated(|input| input.parse::<LitStr>(), Token![,])?;
+                    args.invalidates_tags = tags.into_iter().collect();
+                }
+                "updates" => {
+                    // Parse update list: updates = [provider1(param), provider2(a, b), ...]
+                    let content;
+                    syn::bracketed!(content in input);
+                    let calls = content.parse_terminated(syn::ExprCall::parse, Token![,])?;
+                    args.updates = calls.into_iter().collect();
+                }
+                "retry" => {
+                    let lit: syn::LitInt = input.parse()?;
+                    args.retry = Some(lit.base10_parse()?);
+                }
+                "timeout" => {
+                    let (_, duration) = parse_duration_lit(input)?;
+                    args.timeout = Some(duration);
+                }
+                "await_invalidations" => {
+                    let (_, duration) = parse_duration_lit(input)?;
+                    args.await_invalidations = Some(duration);
+                }
+                "optimistic" => {
+                    let expr: syn::Expr = input.parse()?;
+                    optimistic_span = Some(ident.span());
+                    args.optimistic = Some(expr);
                 }
                 _ => return Err(syn::Error::new_spanned(ident, "Unknown argument")),
             }
@@ -100,6 +495,15 @@ impl Parse for MutationArgs {
             }
         }
 
+        if let Some(span) = optimistic_span
+            && args.updates.is_empty()
+        {
+            return Err(syn::Error::new(
+                span,
+                "optimistic requires updates naming the entries to write, e.g. updates = [fetch_user(user.id)]",
+            ));
+        }
+
         Ok(args)
     }
 }
@@ -110,10 +514,82 @@ impl Parse for MutationArgs {
 /// automatic caching, composition, and other advanced features.
 ///
 /// # Supported Arguments
-/// - `interval = "30s"` - Background refresh interval
+/// - `interval = "30s"` - Background refresh interval, on desktop and on the web (`wasm32`
+///   timers come from `wasmtimer`)
+/// - `interval_when_visible_only = true` - Skip interval refreshes while the document is
+///   hidden, resuming once it is visible again
 /// - `cache_expiration = "5min"` - Cache expiration time  
 /// - `stale_time = "1min"` - Time before data is considered stale
+/// - `error_ttl = "5s"` - Time before a cached error expires, so failures refetch sooner
+/// - `check_interval = "100ms"` - How often expiration and cleanup check the provider's
+///   entries, overriding the cache's `CacheConfig::check_interval` and the default of a
+///   sixteenth of the expiration (between 1s and 30s)
+/// - `error_fallback = expr` - Output shown as `Success` instead of a failed fetch's error,
+///   e.g. `error_fallback = Vec::new()`; the error stays cached and expires as usual
+/// - `dispose_delay = "30s"` (or `gc_time`) - Remove an entry once no component has used it
+///   for this long, independently of `cache_expiration`
+/// - `concurrency = 6` - Run at most this many fetches of the provider at once, across all
+///   params; further fetches wait for a running one to finish
 /// - `compose = [provider1, provider2, ...]` - Compose multiple providers in parallel
+/// - `cache = "auth"` - Store entries in a named cache created with `init_named_cache`
+/// - `transform = normalize -> Output` - Apply `normalize` to the `Ok` value before caching;
+///   the provider's `Output` becomes `Output` and errors pass through untransformed
+/// - `with_cancel` - Pass a `CancellationToken` as the last function parameter; it is
+///   cancelled when the fetch is dropped (e.g. the component unmounts)
+/// - `conditional` - The function returns `Result<FetchOutcome<T>, E>` and may return
+///   `FetchOutcome::NotModified` after a conditional request built from `cache_meta()`; the
+///   provider's `Output` is `T` and `E` must implement `From<ProviderError>`
+/// - `progressive` - Pass an `Emitter<Output>` as the last function parameter (after the
+///   `CancellationToken` of `with_cancel`); each emitted value is cached and shown as
+///   `Success` until the function returns its final value
+/// - `redact_params` - Describe parameters as `"<redacted>"` in logs, error context and
+///   devtools instead of their `Debug` output, e.g. for auth tokens
+/// - `namespaced` - Key entries as `module::path::StructName(param_key)` instead of a hash
+///   of the provider type, so keys are readable, name the module they come from and stay
+///   the same across builds, e.g. for disk persistence
+/// - `stringify_errors` - Let `?` propagate any error implementing `Display` by converting
+///   it with `to_string()`; the error type must be `String`
+/// - `error = String` - The body returns an `anyhow::Result<T>` (or any `Result` whose error
+///   isn't `Clone + PartialEq`, e.g. `eyre::Result<T>`), and its error is converted into the
+///   provider's error type with `to_string()` and `From<String>`; add `map_err = convert` to
+///   convert with `convert(error)` instead
+/// - `server_fn` - The body calls Dioxus server functions and returns their
+///   `Result<T, ServerFnError>`, which is converted into a `Result<T, ProviderError>` so it
+///   can be cached (requires the `fullstack` feature of `dioxus-provider`)
+/// - `tags = ["users", ...]` - Tags reported by `Provider::metadata` for tooling
+/// - `error_context = true` - Annotate errors with the provider key, parameter and (with the
+///   `backtrace` feature) a backtrace; the error type must implement `WithProviderContext`
+/// - `entity_key = param_name` - Index entries under that parameter's value so
+///   `invalidate_entity(&value)` drops them together with other providers' entries
+/// - `should_refetch = predicate` - Call `predicate(entry_age, &output)` on fresh cached
+///   successes and revalidate in the background when it returns `true`
+/// - `normalize = canonical` - Build cache keys from `canonical(&param)` (the tuple of all
+///   parameters for several), so equivalent parameters such as the same set of ids in
+///   another order share an entry; the provider still receives the original parameter
+/// - `expiration_jitter = "20%"` - Randomly extend each entry's expiration and stale times
+///   by up to this percentage to avoid synchronized refetches
+/// - `no_persist = true` - Never persist or export the provider's entries, e.g. for auth
+///   tokens or personal data
+/// - `batch_window = "10ms"` - Hold each fetch for this long and fetch every parameter
+///   requested meanwhile with one `BatchProvider::run_batch` call, which the provider type
+///   must implement; a window holding a single fetch runs the function body
+/// - `no_cache = true` - Never read or write the cache: every component using the provider
+///   fetches on mount and when its parameter changes, e.g. for one-time tokens or nonces
+/// - `ssr = false` - Never fetch while rendering on the server, e.g. for geolocation or
+///   other browser-only data; the provider stays `Loading` until the client fetches it
+/// - `blocking` - Run the body, a synchronous `fn`, on tokio's blocking thread pool so
+///   CPU-bound work such as parsing or image processing doesn't block the UI (on WASM it
+///   runs on the main thread)
+/// - `dedupe = true` - Share one allocation between equal cached values (requires
+///   `Output: Hash` and `Error: Hash`)
+/// - `accumulate = item_key` - Merge each fetched `Vec` into the cached list instead of
+///   replacing it, for feeds whose fetches return only new items; `item_key(&item)`
+///   identifies an item, so a refetched one replaces its cached copy
+/// - `max_items = 500` - Keep at most this many items of an accumulated list, dropping the
+///   oldest (requires `accumulate`)
+/// - `sized = true` - Record each cached result's `SizedCacheValue::approximate_size` for
+///   byte-based eviction with `CacheConfig::max_bytes` (requires `Output: SizedCacheValue`
+///   and `Error: SizedCacheValue`)
 ///
 /// # Composition Requirements
 /// When using `compose = [...]`, the following requirements must be met:
@@ -190,7 +666,60 @@ pub fn provider(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let input_fn = parse_macro_input!(input as ItemFn);
 
-    let result = generate_provider(input_fn, provider_args);
+    let result = generate_provider(input_fn, provider_args, None);
+
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Providers macro for declaring providers as associated functions of a type
+///
+/// Apply it to an inherent `impl` block to group related providers under one type. Every
+/// associated function marked with `#[provider(...)]` accepts the same arguments as the
+/// free-function form and becomes a provider struct named after the type and the function
+/// (`Api::fetch_user` generates `ApiFetchUser`). The associated function itself returns the
+/// provider, so it is used as `use_provider(Api::fetch_user(), id)`. Other items of the
+/// block are kept as they are.
+///
+/// `Self` in a provider's signature and body refers to the impl type, so providers can
+/// call helpers and read constants of the type. Providers can't take `self`; shared
+/// configuration belongs in associated constants or injected dependencies.
+///
+/// # Example
+/// ```rust
+/// struct Api;
+///
+/// #[providers]
+/// impl Api {
+///     const BASE_URL: &'static str = "https://example.com";
+///
+///     #[provider(cache_expiration = "5min")]
+///     async fn fetch_user(id: u32) -> Result<User, String> {
+///         http_get(&format!("{}/users/{id}", Self::BASE_URL)).await
+///     }
+///
+///     #[provider]
+///     async fn fetch_settings() -> Result<Settings, String> {
+///         http_get(&format!("{}/settings", Self::BASE_URL)).await
+///     }
+/// }
+///
+/// let user = use_provider(Api::fetch_user(), 1);
+/// ```
+#[proc_macro_attribute]
+pub fn providers(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        let args = TokenStream2::from(args);
+        return syn::Error::new_spanned(args, "#[providers] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let input_impl = parse_macro_input!(input as ItemImpl);
+
+    let result = generate_associated_providers(input_impl);
 
     match result {
         Ok(tokens) => tokens.into(),
@@ -204,7 +733,25 @@ pub fn provider(args: TokenStream, input: TokenStream) -> TokenStream {
 /// invalidate related provider caches when executed.
 ///
 /// # Supported Arguments
-/// - `invalidates = [provider1, provider2, ...]` - Providers to invalidate after mutation
+/// - `invalidates = [provider1, provider2(param_expr), ...]` - Providers to invalidate after
+///   the mutation succeeds. Providers listed by name are invalidated for `()`; calls pick the
+///   provider's parameters from the mutation's parameters (by reference).
+/// - `compose = [provider1, ...]` - Run providers taking the mutation's parameters in parallel
+///   before its body, like `#[provider(compose = [...])]`
+/// - `invalidates_tags = ["users", ...]` - Invalidate every entry of providers declared with
+///   one of these `tags` after the mutation succeeds
+/// - `updates = [provider(param_expr), ...]` - Provider entries to overwrite with the
+///   mutation's output, skipping the refetch. Parameter expressions may use the mutation's
+///   parameters (by reference) and `output`, the mutation's result.
+/// - `retry = 3` - Retry a failed mutation up to this many times
+/// - `timeout = "5s"` - Fail an attempt that takes longer than this with a
+///   `ProviderError::Timeout` converted into the mutation's error type
+/// - `optimistic = expr` - Output written into the `updates` entries before the mutation
+///   runs and rolled back if it fails. The expression may use the mutation's parameters
+///   (by reference). Requires `updates`.
+/// - `await_invalidations` (or `await_invalidations = "5s"`) - Only report `Success` once the
+///   shown entries the mutation invalidated were refetched, waiting at most 10s (or the given
+///   duration)
 ///
 /// # Example
 /// ```rust
@@ -213,6 +760,28 @@ pub fn provider(args: TokenStream, input: TokenStream) -> TokenStream {
 ///     // Update user implementation
 ///     // Will automatically invalidate fetch_user and fetch_user_list caches
 /// }
+///
+/// #[mutation(updates = [fetch_user(user.id)])]
+/// async fn rename_user(user: User) -> Result<User, String> {
+///     // The returned user is written straight into fetch_user's cache entry
+/// }
+///
+/// #[mutation(compose = [fetch_account], invalidates = [fetch_account(id), fetch_ledger(id, month)])]
+/// async fn withdraw(id: u32, month: u32) -> Result<Account, String> {
+///     let account = __dioxus_composed_fetch_account_result?;
+///     // Only the entries of this account and month are refetched
+/// }
+///
+/// #[mutation(updates = [fetch_user(user.id)], optimistic = user.clone(), retry = 2, timeout = "10s")]
+/// async fn save_user(user: User) -> Result<User, String> {
+///     // fetch_user shows `user` immediately; flaky or slow saves are retried
+/// }
+///
+/// #[mutation(with_progress)]
+/// async fn upload(file: Vec<u8>, progress: ProgressReporter) -> Result<(), String> {
+///     // `progress.set(0.5)` and `progress.step("Uploading")` are shown by
+///     // use_mutation_with_progress; the reporter is not part of the mutation's input
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn mutation(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -235,8 +804,141 @@ pub fn mutation(args: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
-fn generate_provider(input_fn: ItemFn, provider_args: ProviderArgs) -> Result<TokenStream2> {
-    let info = extract_provider_info(&input_fn)?;
+/// Generate the providers of an impl block, leaving a constructor for each in the block
+fn generate_associated_providers(mut input_impl: ItemImpl) -> Result<TokenStream2> {
+    if let Some((_, path, _)) = &input_impl.trait_ {
+        return Err(syn::Error::new_spanned(
+            path,
+            "#[providers] only supports inherent impl blocks",
+        ));
+    }
+    if !input_impl.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input_impl.generics,
+            "#[providers] does not support generic impl blocks",
+        ));
+    }
+    let self_ty = input_impl.self_ty.clone();
+    let namespace = match &*self_ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.clone()),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        syn::Error::new_spanned(
+            &self_ty,
+            "#[providers] requires a named type, e.g. `impl Api`",
+        )
+    })?;
+
+    let mut generated = Vec::new();
+    for item in &mut input_impl.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+        let Some(index) = method
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("provider"))
+        else {
+            continue;
+        };
+        let attr = method.attrs.remove(index);
+        let provider_args = match &attr.meta {
+            syn::Meta::Path(_) => ProviderArgs::default(),
+            syn::Meta::List(list) if list.tokens.is_empty() => ProviderArgs::default(),
+            syn::Meta::List(list) => syn::parse2(list.tokens.clone())?,
+            syn::Meta::NameValue(_) => {
+                return Err(syn::Error::new_spanned(attr, "Expected #[provider(...)]"));
+            }
+        };
+
+        // The provider's code moves out of the impl block, where `Self` means the impl type
+        let input_fn = ItemFn {
+            attrs: method.attrs.clone(),
+            vis: method.vis.clone(),
+            sig: method.sig.clone(),
+            block: Box::new(method.block.clone()),
+        };
+        let input_fn: ItemFn = syn::parse2(replace_self_type(quote! { #input_fn }, &self_ty))?;
+        let struct_name = associated_struct_name(&namespace, &input_fn.sig.ident);
+        generated.push(generate_provider(
+            input_fn,
+            provider_args,
+            Some(&namespace),
+        )?);
+
+        let attrs = &method.attrs;
+        let vis = &method.vis;
+        let fn_name = &method.sig.ident;
+        *item = syn::parse_quote! {
+            #(#attrs)*
+            #vis fn #fn_name() -> #struct_name {
+                #struct_name
+            }
+        };
+    }
+
+    Ok(quote! {
+        #input_impl
+        #(#generated)*
+    })
+}
+
+/// Name of the provider struct generated for an associated function of `namespace`
+fn associated_struct_name(namespace: &syn::Ident, fn_name: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(
+        &format!("{}{}", namespace, to_pascal_case(&fn_name.to_string())),
+        fn_name.span(),
+    )
+}
+
+/// Replace every `Self` in `tokens` with `self_ty`
+fn replace_self_type(tokens: TokenStream2, self_ty: &Type) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "Self" => {
+                quote_spanned! { ident.span()=> #self_ty }
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_self_type(group.stream(), self_ty),
+                );
+                replaced.set_span(group.span());
+                quote! { #replaced }
+            }
+            other => quote! { #other },
+        })
+        .collect()
+}
+
+fn generate_provider(
+    input_fn: ItemFn,
+    provider_args: ProviderArgs,
+    namespace: Option<&syn::Ident>,
+) -> Result<TokenStream2> {
+    // Convert the errors of an `anyhow`-style body into the declared error type
+    let input_fn = match &provider_args.error {
+        Some(error) => bridge_errors(
+            input_fn,
+            error,
+            provider_args.map_err.as_ref(),
+            "error = ... requires the function to return a Result, e.g. anyhow::Result<T>",
+        )?,
+        None if provider_args.server_fn => bridge_errors(
+            input_fn,
+            &syn::parse_quote! { ::dioxus_provider::errors::ProviderError },
+            Some(&syn::parse_quote! { ::dioxus_provider::server_fn::into_provider_error }),
+            "server_fn requires the function to return a Result, e.g. Result<T, ServerFnError>",
+        )?,
+        None => input_fn,
+    };
+    let info = extract_provider_info(&input_fn, namespace)?;
 
     let ProviderInfo {
         fn_vis,
@@ -248,224 +950,781 @@ fn generate_provider(input_fn: ItemFn, provider_args: ProviderArgs) -> Result<To
     } = &info;
 
     // Extract parameters once
-    let params = extract_all_params(&input_fn)?;
+    let mut params = extract_all_params(&input_fn)?;
+
+    // The cancellation token is passed by the hooks, so it is not part of the provider's param
+    // The emitter of partial values is also passed by the hooks, after the cancellation token
+    let emit_param = if provider_args.progressive {
+        Some(extract_emit_param(&input_fn, &mut params)?)
+    } else {
+        None
+    };
+    let emit_decl = match &emit_param {
+        Some(ParamInfo { name, ty }) => quote! { #name: #ty, },
+        None => quote! {},
+    };
+    let emit_arg = match &emit_param {
+        // Naming the output type reports an emitter of another type as a mismatch
+        Some(_) => quote! { ::dioxus_provider::progressive::Emitter::<#output_type>::claim(), },
+        None => quote! {},
+    };
+
+    let cancel_param = if provider_args.with_cancel {
+        Some(extract_cancel_param(&input_fn, &mut params)?)
+    } else {
+        None
+    };
+    let cancel_decl = match &cancel_param {
+        Some(ParamInfo { name, ty }) => quote! { #name: #ty, },
+        None => quote! {},
+    };
+    let new_cancel_token = match &cancel_param {
+        Some(_) => quote! { ::dioxus_provider::cancel::CancellationToken::new(), },
+        None => quote! {},
+    };
+    let cancel_arg = match &cancel_param {
+        Some(_) => quote! { cancel, },
+        None => quote! {},
+    };
 
     // Validate composition requirements if compose is used
     if !provider_args.compose.is_empty() {
         validate_composition_requirements(&provider_args.compose, &params)?;
     }
 
+    // Let `?` propagate foreign errors out of a String-error provider
+    let stringified_block;
+    let fn_block = if provider_args.stringify_errors {
+        stringified_block = stringify_errors(fn_block, error_type)?;
+        &stringified_block
+    } else {
+        fn_block
+    };
+
+    // Offload a blocking body to the thread pool, awaiting its result in `call`
+    let blocking_block;
+    let fn_block = if provider_args.blocking {
+        if let Some(asyncness) = &input_fn.sig.asyncness {
+            return Err(syn::Error::new_spanned(
+                asyncness,
+                "blocking providers run a synchronous body on a thread pool, remove `async`",
+            ));
+        }
+        blocking_block = syn::parse_quote! {{
+            ::dioxus_provider::platform::task::run_blocking(
+                move || -> Result<#output_type, #error_type> #fn_block
+            )
+            .await
+        }};
+        &blocking_block
+    } else {
+        fn_block
+    };
+
     // Generate enhanced function body with dependency injection and composition
     let enhanced_fn_block =
-        generate_enhanced_function_body(&provider_args.compose, &params, fn_block);
+        generate_enhanced_function_body(&provider_args.compose, &params, error_type, fn_block);
 
     // Generate interval and cache expiration implementations
     let interval_impl = generate_interval_impl(&provider_args);
     let cache_expiration_impl = generate_cache_expiration_impl(&provider_args);
     let stale_time_impl = generate_stale_time_impl(&provider_args);
+    let error_ttl_impl = generate_error_ttl_impl(&provider_args);
+    let check_interval_impl = generate_check_interval_impl(&provider_args);
+    let error_fallback_impl = generate_error_fallback_impl(&provider_args);
+    let dispose_delay_impl = generate_dispose_delay_impl(&provider_args);
+    let max_concurrency_impl = generate_max_concurrency_impl(&provider_args);
+    let cache_name_impl = generate_cache_name_impl(&provider_args);
+    let dedupe_impl = generate_dedupe_impl(&provider_args);
+    let accumulate_impl = generate_accumulate_impl(&provider_args);
+    let persistable_impl = generate_persistable_impl(&provider_args);
+    let ssr_impl = generate_ssr_impl(&provider_args);
+    let cacheable_impl = generate_cacheable_impl(&provider_args);
+    let approximate_size_impl = generate_approximate_size_impl(&provider_args);
+    let expiration_jitter_impl = generate_expiration_jitter_impl(&provider_args);
+    let should_refetch_impl = generate_should_refetch_impl(&provider_args);
+    let entity_key_impl = generate_entity_key_impl(&provider_args, &params)?;
+    let metadata_impl = generate_metadata_impl(&provider_args, struct_name);
+
+    // Generate common struct and const
+    let common_struct = generate_common_struct_and_const(&info);
+
+    // Conditional providers return a `FetchOutcome` wrapping the value they produce
+    let fetched_type = if provider_args.conditional {
+        extract_fetch_outcome_type(output_type)?
+    } else {
+        output_type.clone()
+    };
+
+    // The cached output type, and how `run` maps the raw result of `call` into it
+    let provider_output_type = match &provider_args.transform {
+        Some(transform) => &transform.output_type,
+        None => &fetched_type,
+    };
+    let transform_call = |call: TokenStream2| {
+        let call = if provider_args.progressive {
+            // Claim the emitter once the fetch runs, inside the hook's scope
+            quote! { async move { #call.await } }
+        } else {
+            call
+        };
+        match &provider_args.transform {
+            Some(TransformArg { func, .. }) => quote! { async move { #call.await.map(#func) } },
+            None => call,
+        }
+    };
+    // How `run_conditional` maps the raw result of `call`, keeping `NotModified` as is
+    let transform_outcome = |call: TokenStream2| match &provider_args.transform {
+        Some(TransformArg { func, .. }) => {
+            quote! { async move { #call.await.map(|outcome| outcome.map(#func)) } }
+        }
+        None => call,
+    };
+    // The `run` body calling the function: conditional providers run it without metadata,
+    // keeping the cached value should it still answer `NotModified`. `param_type` and
+    // `param` name the provider's parameter, `rebind` clones the function's arguments
+    // for each call.
+    let run_call = |param_type: TokenStream2,
+                    param: TokenStream2,
+                    rebind: TokenStream2,
+                    call: TokenStream2| {
+        if provider_args.conditional {
+            let call = transform_outcome(call);
+            quote! {
+                ::dioxus_provider::conditional::run_unconditional(
+                    <Self as ::dioxus_provider::hooks::Provider<#param_type>>::cache_name(self),
+                    <Self as ::dioxus_provider::hooks::Provider<#param_type>>::id(self, &#param),
+                    move || {
+                        #rebind
+                        #call
+                    },
+                )
+            }
+        } else {
+            transform_call(call)
+        }
+    };
+
+    // A batched fetch awaits `run_batch`, whose future isn't required to be `Send`
+    let run_send = if provider_args.batch_window.is_some() {
+        quote! {}
+    } else {
+        quote! { + Send }
+    };
+
+    // Report output and error types that cannot be cached at the signature
+    let bounds_check = generate_bounds_check(
+        provider_output_type,
+        error_type,
+        provider_args.conditional,
+    );
+
+    let prefetch_doc = quote! {
+        /// Fetches the provider's result and stores it in the cache, e.g. before navigating
+        /// to a page using it; a prefetch or fetch of the same parameters already running is
+        /// joined
+        ///
+        /// # Errors
+        ///
+        /// Returns `GlobalProviderError::NamedCacheNotFound` if the provider's named cache
+        /// has not been initialized.
+    };
+
+    // The provider's param is `()`, the function's only parameter or a tuple of them
+    let param_names: Vec<_> = params.iter().map(|p| &p.name).collect();
+    let param_types: Vec<_> = params.iter().map(|p| &p.ty).collect();
+    let param_value = |each: fn(&syn::Ident) -> TokenStream2| match param_names.as_slice() {
+        [] => quote! { () },
+        [name] => each(name),
+        names => {
+            let values = names.iter().map(|name| each(name));
+            quote! { (#(#values,)*) }
+        }
+    };
+    // The param type, the pattern the `Provider` methods take it with and the statement
+    // binding the function's parameters from it
+    let (param_type, param_pattern, destructure) = match params.as_slice() {
+        [] => (quote! { () }, quote! { _param }, quote! {}),
+        [ParamInfo { name, ty }] => (quote! { #ty }, quote! { #name }, quote! {}),
+        _ => (
+            quote! { (#(#param_types,)*) },
+            quote! { params },
+            quote! { let (#(#param_names,)*) = params; },
+        ),
+    };
+
+    let run_body = generate_batch_window_call(
+        &provider_args,
+        param_value(|name| quote! { #name.clone() }),
+        run_call(
+            param_type.clone(),
+            param_value(|name| quote! { ::std::clone::Clone::clone(&#name) }),
+            quote! { #(let #param_names = ::std::clone::Clone::clone(&#param_names);)* },
+            quote! { Self::call(#(#param_names,)* #new_cancel_token #emit_arg) },
+        ),
+    );
+    let prefetch_param = param_value(|name| quote! { #name });
+    let error_context_impl = generate_error_context_impl(&provider_args, &param_type);
+    let describe_param_impl = generate_describe_param_impl(&provider_args, &param_type);
+    let id_impl = generate_id_impl(&provider_args, struct_name, &param_type);
+    let run_with_cancel_impl = generate_run_with_cancel_impl(
+        &provider_args,
+        quote! { #param_pattern: #param_type },
+        destructure.clone(),
+        transform_call(quote! { Self::call(#(#param_names,)* cancel, #emit_arg) }),
+    );
+    let run_conditional_impl = generate_run_conditional_impl(
+        &provider_args,
+        quote! { #param_pattern: #param_type },
+        destructure.clone(),
+        transform_outcome(quote! { Self::call(#(#param_names,)* #cancel_arg #emit_arg) }),
+    );
+
+    // The `Provider` methods overridden from the provider's arguments
+    let provider_methods = quote! {
+        #interval_impl
+        #cache_expiration_impl
+        #stale_time_impl
+        #error_ttl_impl
+        #check_interval_impl
+        #error_fallback_impl
+        #dispose_delay_impl
+        #max_concurrency_impl
+        #cache_name_impl
+        #dedupe_impl
+        #accumulate_impl
+        #persistable_impl
+        #ssr_impl
+        #cacheable_impl
+        #approximate_size_impl
+        #expiration_jitter_impl
+        #should_refetch_impl
+        #entity_key_impl
+        #error_context_impl
+        #describe_param_impl
+        #id_impl
+        #metadata_impl
+        #run_with_cancel_impl
+        #run_conditional_impl
+    };
+
+    Ok(quote! {
+        #bounds_check
+        #common_struct
+
+        impl #struct_name {
+            #fn_vis async fn call(#(#param_names: #param_types,)* #cancel_decl #emit_decl) -> Result<#output_type, #error_type> {
+                #enhanced_fn_block
+            }
+
+            #prefetch_doc
+            #fn_vis async fn prefetch(#(#param_names: #param_types,)*) -> Result<Result<#provider_output_type, #error_type>, ::dioxus_provider::global::GlobalProviderError> {
+                ::dioxus_provider::global::prefetch_provider(Self, #prefetch_param)
+                    .await
+            }
+        }
+
+        impl ::dioxus_provider::hooks::Provider<#param_type> for #struct_name {
+            type Output = #provider_output_type;
+            type Error = #error_type;
+
+            fn run(&self, #param_pattern: #param_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> #run_send {
+                #destructure
+                #run_body
+            }
+
+            #provider_methods
+        }
+    })
+}
+
+fn generate_mutation(input_fn: ItemFn, mutation_args: MutationArgs) -> Result<TokenStream2> {
+    let info = extract_provider_info(&input_fn, None)?;
+
+    let ProviderInfo {
+        fn_vis,
+        fn_block,
+        output_type,
+        error_type,
+        struct_name,
+        ..
+    } = &info;
+
+    let mut params = extract_all_params(&input_fn)?;
+
+    // The progress reporter is passed by the hooks, so it is not part of the mutation's input
+    let progress_param = if mutation_args.with_progress {
+        Some(extract_progress_param(&input_fn, &mut params)?)
+    } else {
+        None
+    };
+    let progress_decl = match &progress_param {
+        Some(ParamInfo { name, ty }) => quote! { #name: #ty },
+        None => quote! {},
+    };
+    let progress_arg = match &progress_param {
+        Some(_) => quote! { ::dioxus_provider::progress::ProgressReporter::claim() },
+        None => quote! {},
+    };
+
+    // Validate composition requirements if compose is used
+    if !mutation_args.compose.is_empty() {
+        validate_composition_requirements(&mutation_args.compose, &params)?;
+    }
+
+    // Generate enhanced function body with dependency injection and composition
+    let enhanced_fn_block =
+        generate_enhanced_function_body(&mutation_args.compose, &params, error_type, fn_block);
+
+    // Generate invalidation and cache update implementations
+    let invalidation_impl = generate_invalidation_impl(&mutation_args, &params);
+    let updates_impl = generate_updates_impl(&mutation_args, &params);
+    let options_impl = generate_mutation_options_impl(&mutation_args, &params);
 
     // Generate common struct and const
     let common_struct = generate_common_struct_and_const(&info);
 
     // Determine parameter type and implementation based on function parameters
     if params.is_empty() {
-        // No parameters - Provider<()>
+        // No parameters - Mutation<()>
         Ok(quote! {
             #common_struct
 
             impl #struct_name {
-                #fn_vis async fn call() -> Result<#output_type, #error_type> {
+                #fn_vis async fn call(#progress_decl) -> Result<#output_type, #error_type> {
                     #enhanced_fn_block
                 }
             }
 
-            impl ::dioxus_provider::hooks::Provider<()> for #struct_name {
+            impl ::dioxus_provider::mutation::Mutation<()> for #struct_name {
                 type Output = #output_type;
                 type Error = #error_type;
 
-                fn run(&self, _param: ()) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
-                    Self::call()
+                fn mutate(&self, _input: ()) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
+                    Self::call(#progress_arg)
                 }
 
-                #interval_impl
-                #cache_expiration_impl
-                #stale_time_impl
+                #invalidation_impl
+                #updates_impl
+                #options_impl
             }
         })
-    } else if params.len() == 1 {
-        // Single parameter - Provider<ParamType>
-        let param = &params[0];
-        let param_name = &param.name;
-        let param_type = &param.ty;
+    } else {
+        // Has parameters - extract and handle them
+        if params.len() == 1 {
+            // Single parameter - Mutation<ParamType>
+            let param = &params[0];
+            let param_name = &param.name;
+            let param_type = &param.ty;
 
-        Ok(quote! {
-            #common_struct
+            Ok(quote! {
+                #common_struct
 
-            impl #struct_name {
-                #fn_vis async fn call(#param_name: #param_type) -> Result<#output_type, #error_type> {
-                    #enhanced_fn_block
+                impl #struct_name {
+                    #fn_vis async fn call(#param_name: #param_type, #progress_decl) -> Result<#output_type, #error_type> {
+                        #enhanced_fn_block
+                    }
                 }
-            }
 
-            impl ::dioxus_provider::hooks::Provider<#param_type> for #struct_name {
-                type Output = #output_type;
-                type Error = #error_type;
+                impl ::dioxus_provider::mutation::Mutation<#param_type> for #struct_name {
+                    type Output = #output_type;
+                    type Error = #error_type;
 
-                fn run(&self, #param_name: #param_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
-                    Self::call(#param_name)
+                    fn mutate(&self, #param_name: #param_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
+                        Self::call(#param_name, #progress_arg)
+                    }
+
+                    #invalidation_impl
+                    #updates_impl
+                    #options_impl
+                }
+            })
+        } else {
+            // Multiple parameters - Mutation<(Param1, Param2, ...)>
+            let param_names: Vec<_> = params.iter().map(|p| &p.name).collect();
+            let param_types: Vec<_> = params.iter().map(|p| &p.ty).collect();
+            let tuple_type = quote! { (#(#param_types,)*) };
+
+            Ok(quote! {
+                #common_struct
+
+                impl #struct_name {
+                    #fn_vis async fn call(#(#param_names: #param_types,)* #progress_decl) -> Result<#output_type, #error_type> {
+                        #enhanced_fn_block
+                    }
                 }
 
-                #interval_impl
-                #cache_expiration_impl
-                #stale_time_impl
+                impl ::dioxus_provider::mutation::Mutation<#tuple_type> for #struct_name {
+                    type Output = #output_type;
+                    type Error = #error_type;
+
+                    fn mutate(&self, input: #tuple_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
+                        let (#(#param_names,)*) = input;
+                        Self::call(#(#param_names,)* #progress_arg)
+                    }
+
+                    #invalidation_impl
+                    #updates_impl
+                    #options_impl
+                }
+            })
+        }
+    }
+}
+
+/// Generate compile-time checks that the output and error types can be cached
+///
+/// The bounds sit on an item emitted before the `Provider` impl, so the missing bound is
+/// reported first, at the types in the function's signature, with a readable message.
+fn generate_bounds_check(output_type: &Type, error_type: &Type, conditional: bool) -> TokenStream2 {
+    let output_bound = quote_spanned! { output_type.span()=>
+        #output_type: ::dioxus_provider::types::ProviderOutputType
+    };
+    let error_bound = quote_spanned! { error_type.span()=>
+        #error_type: ::dioxus_provider::types::ProviderErrorType
+    };
+    // Conditional providers report a `NotModified` with nothing cached as a `ProviderError`
+    let conversion_bound = conditional.then(|| {
+        quote_spanned! { error_type.span()=>
+            #error_type: ::std::convert::From<::dioxus_provider::errors::ProviderError>,
+        }
+    });
+    quote! {
+        const _: () = {
+            struct __DioxusProviderBoundsCheck
+            where
+                #output_bound,
+                #error_bound,
+                #conversion_bound;
+        };
+    }
+}
+
+/// Generate duration implementation for provider methods
+fn generate_duration_impl(method_name: &str, duration: Option<Duration>) -> TokenStream2 {
+    if let Some(duration) = duration {
+        // Keep sub-second precision so durations like "100ms" aren't truncated to zero
+        let duration_secs = duration.as_secs();
+        let duration_nanos = duration.subsec_nanos();
+        let method_ident = syn::Ident::new(method_name, proc_macro2::Span::call_site());
+
+        quote! {
+            fn #method_ident(&self) -> Option<::std::time::Duration> {
+                Some(::std::time::Duration::new(#duration_secs, #duration_nanos))
             }
-        })
+        }
     } else {
-        // Multiple parameters - Provider<(Param1, Param2, ...)>
-        let param_names: Vec<_> = params.iter().map(|p| &p.name).collect();
-        let param_types: Vec<_> = params.iter().map(|p| &p.ty).collect();
-        let tuple_type = quote! { (#(#param_types,)*) };
+        quote! {}
+    }
+}
 
-        Ok(quote! {
-            #common_struct
+/// Generate interval implementation
+fn generate_interval_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    let duration_impl = generate_duration_impl("interval", provider_args.interval);
+    if provider_args.interval_when_visible_only {
+        quote! {
+            #duration_impl
 
-            impl #struct_name {
-                #fn_vis async fn call(#(#param_names: #param_types,)*) -> Result<#output_type, #error_type> {
-                    #enhanced_fn_block
-                }
+            fn interval_when_visible_only(&self) -> bool {
+                true
             }
+        }
+    } else {
+        duration_impl
+    }
+}
 
-            impl ::dioxus_provider::hooks::Provider<#tuple_type> for #struct_name {
-                type Output = #output_type;
-                type Error = #error_type;
+/// Generate cache expiration implementation
+fn generate_cache_expiration_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    generate_duration_impl("cache_expiration", provider_args.cache_expiration)
+}
 
-                fn run(&self, params: #tuple_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
-                    let (#(#param_names,)*) = params;
-                    Self::call(#(#param_names,)*)
-                }
+/// Generate stale time implementation
+fn generate_stale_time_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    generate_duration_impl("stale_time", provider_args.stale_time)
+}
+
+/// Generate error TTL implementation
+fn generate_error_ttl_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    generate_duration_impl("error_cache_expiration", provider_args.error_ttl)
+}
 
-                #interval_impl
-                #cache_expiration_impl
-                #stale_time_impl
+/// Generate expiration check interval implementation
+fn generate_check_interval_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    generate_duration_impl("check_interval", provider_args.check_interval)
+}
+
+/// Generate error fallback implementation
+fn generate_error_fallback_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    match &provider_args.error_fallback {
+        Some(fallback) => quote! {
+            fn error_fallback(&self) -> Option<Self::Output> {
+                Some(#fallback)
             }
-        })
+        },
+        None => quote! {},
     }
 }
 
-fn generate_mutation(input_fn: ItemFn, mutation_args: MutationArgs) -> Result<TokenStream2> {
-    let info = extract_provider_info(&input_fn)?;
+/// Generate dispose delay implementation
+fn generate_dispose_delay_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    generate_duration_impl("dispose_delay", provider_args.dispose_delay)
+}
 
-    let ProviderInfo {
-        fn_vis,
-        fn_block,
-        output_type,
-        error_type,
-        struct_name,
-        ..
-    } = &info;
+/// Generate concurrency limit implementation
+fn generate_max_concurrency_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if let Some(limit) = provider_args.concurrency {
+        quote! {
+            fn max_concurrency(&self) -> Option<usize> {
+                Some(#limit)
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-    // Generate enhanced function body with dependency injection and composition
-    let enhanced_fn_block = generate_enhanced_function_body(&[], &[], fn_block);
+/// Generate cache name implementation
+fn generate_cache_name_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if let Some(name) = &provider_args.cache {
+        quote! {
+            fn cache_name(&self) -> Option<&'static str> {
+                Some(#name)
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-    // Generate invalidation implementation
-    let invalidation_impl = generate_invalidation_impl(&mutation_args);
+/// Generate a `run_with_cancel` implementation that forwards the token to the function
+fn generate_run_with_cancel_impl(
+    provider_args: &ProviderArgs,
+    param_decl: TokenStream2,
+    destructure: TokenStream2,
+    run_body: TokenStream2,
+) -> TokenStream2 {
+    if provider_args.with_cancel {
+        quote! {
+            fn run_with_cancel(
+                &self,
+                #param_decl,
+                cancel: ::dioxus_provider::cancel::CancellationToken,
+            ) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
+                #destructure
+                #run_body
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-    // Generate common struct and const
-    let common_struct = generate_common_struct_and_const(&info);
+/// Generate a `run_conditional` implementation returning the function's `FetchOutcome`
+fn generate_run_conditional_impl(
+    provider_args: &ProviderArgs,
+    param_decl: TokenStream2,
+    destructure: TokenStream2,
+    run_body: TokenStream2,
+) -> TokenStream2 {
+    if provider_args.conditional {
+        quote! {
+            fn run_conditional(
+                &self,
+                #param_decl,
+                cancel: ::dioxus_provider::cancel::CancellationToken,
+            ) -> impl ::std::future::Future<
+                Output = Result<::dioxus_provider::conditional::FetchOutcome<Self::Output>, Self::Error>,
+            > + Send {
+                let _ = &cancel;
+                #destructure
+                #run_body
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-    // Determine parameter type and implementation based on function parameters
-    if input_fn.sig.inputs.is_empty() {
-        // No parameters - Mutation<()>
-        Ok(quote! {
-            #common_struct
+/// Generate metadata implementation reporting the provider's name and tags
+fn generate_metadata_impl(provider_args: &ProviderArgs, struct_name: &syn::Ident) -> TokenStream2 {
+    let name = struct_name.to_string();
+    let tags = &provider_args.tags;
+    let tags_impl = if tags.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn tags(&self) -> &'static [&'static str] {
+                &[#(#tags),*]
+            }
+        }
+    };
+    quote! {
+        #tags_impl
+
+        fn metadata(&self) -> ::dioxus_provider::hooks::ProviderMetadata {
+            ::dioxus_provider::hooks::ProviderMetadata {
+                name: Some(#name),
+                interval: self.interval(),
+                stale_time: self.stale_time(),
+                cache_expiration: self.cache_expiration(),
+                tags: self.tags().to_vec(),
+            }
+        }
+    }
+}
+
+/// Generate error context implementation
+fn generate_error_context_impl(provider_args: &ProviderArgs, param_type: &TokenStream2) -> TokenStream2 {
+    if provider_args.error_context {
+        quote! {
+            fn contextualize_error(&self, key: &str, param: &#param_type, error: Self::Error) -> Self::Error {
+                ::dioxus_provider::errors::WithProviderContext::with_provider_context(
+                    error,
+                    key,
+                    ::dioxus_provider::hooks::Provider::describe_param(self, param),
+                )
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Generate a `describe_param` implementation redacting the parameter
+fn generate_describe_param_impl(
+    provider_args: &ProviderArgs,
+    param_type: &TokenStream2,
+) -> TokenStream2 {
+    if provider_args.redact_params {
+        quote! {
+            fn describe_param(&self, _param: &#param_type) -> String {
+                "<redacted>".to_string()
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Generate an `id` implementation for namespaced keys, or a `param_key` one for normalized parameters
+fn generate_id_impl(
+    provider_args: &ProviderArgs,
+    struct_name: &syn::Ident,
+    param_type: &TokenStream2,
+) -> TokenStream2 {
+    let param_key = match &provider_args.normalize {
+        Some(normalize) => quote! {
+            ::dioxus_provider::types::ProviderKey::provider_key(&#normalize(param))
+        },
+        None => quote! { ::dioxus_provider::types::ProviderKey::provider_key(param) },
+    };
+    if provider_args.namespaced {
+        let name = struct_name.to_string();
+        quote! {
+            fn id(&self, param: &#param_type) -> String {
+                format!("{}::{}({})", module_path!(), #name, #param_key)
+            }
+        }
+    } else if provider_args.normalize.is_some() {
+        quote! {
+            fn param_key(&self, param: &#param_type) -> String {
+                #param_key
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-            impl #struct_name {
-                #fn_vis async fn call() -> Result<#output_type, #error_type> {
-                    #enhanced_fn_block
-                }
+/// Generate expiration jitter implementation
+fn generate_expiration_jitter_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if let Some(jitter) = provider_args.expiration_jitter {
+        quote! {
+            fn expiration_jitter(&self) -> Option<f64> {
+                Some(#jitter)
             }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-            impl ::dioxus_provider::mutation::Mutation<()> for #struct_name {
-                type Output = #output_type;
-                type Error = #error_type;
+/// Generate should_refetch implementation calling the user's predicate
+fn generate_should_refetch_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if let Some(predicate) = &provider_args.should_refetch {
+        quote! {
+            fn should_refetch(&self, entry_age: ::std::time::Duration, cached: &Self::Output) -> bool {
+                #predicate(entry_age, cached)
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
-                fn mutate(&self, _input: ()) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
-                    Self::call()
-                }
+/// Generate entity key implementation reporting the named parameter's `Debug` formatting
+fn generate_entity_key_impl(provider_args: &ProviderArgs, params: &[ParamInfo]) -> Result<TokenStream2> {
+    let Some(entity) = &provider_args.entity_key else {
+        return Ok(quote! {});
+    };
+    let Some(position) = params.iter().position(|param| param.name == *entity) else {
+        return Err(syn::Error::new_spanned(
+            entity,
+            "entity_key must name one of the provider's parameters",
+        ));
+    };
 
-                #invalidation_impl
+    if params.len() == 1 {
+        let param_type = &params[0].ty;
+        Ok(quote! {
+            fn entity_key(&self, param: &#param_type) -> Option<String> {
+                Some(format!("{:?}", param))
             }
         })
     } else {
-        // Has parameters - extract and handle them
-        let params = extract_all_params(&input_fn)?;
-
-        if params.len() == 1 {
-            // Single parameter - Mutation<ParamType>
-            let param = &params[0];
-            let param_name = &param.name;
-            let param_type = &param.ty;
-
-            Ok(quote! {
-                #common_struct
-
-                impl #struct_name {
-                    #fn_vis async fn call(#param_name: #param_type) -> Result<#output_type, #error_type> {
-                        #enhanced_fn_block
-                    }
-                }
-
-                impl ::dioxus_provider::mutation::Mutation<#param_type> for #struct_name {
-                    type Output = #output_type;
-                    type Error = #error_type;
-
-                    fn mutate(&self, #param_name: #param_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
-                        Self::call(#param_name)
-                    }
-
-                    #invalidation_impl
-                }
-            })
-        } else {
-            // Multiple parameters - Mutation<(Param1, Param2, ...)>
-            let param_names: Vec<_> = params.iter().map(|p| &p.name).collect();
-            let param_types: Vec<_> = params.iter().map(|p| &p.ty).collect();
-            let tuple_type = quote! { (#(#param_types,)*) };
-
-            Ok(quote! {
-                #common_struct
-
-                impl #struct_name {
-                    #fn_vis async fn call(#(#param_names: #param_types,)*) -> Result<#output_type, #error_type> {
-                        #enhanced_fn_block
-                    }
-                }
-
-                impl ::dioxus_provider::mutation::Mutation<#tuple_type> for #struct_name {
-                    type Output = #output_type;
-                    type Error = #error_type;
-
-                    fn mutate(&self, input: #tuple_type) -> impl ::std::future::Future<Output = Result<Self::Output, Self::Error>> + Send {
-                        let (#(#param_names,)*) = input;
-                        Self::call(#(#param_names,)*)
-                    }
+        let param_types = params.iter().map(|param| &param.ty);
+        let index = syn::Index::from(position);
+        Ok(quote! {
+            fn entity_key(&self, params: &(#(#param_types,)*)) -> Option<String> {
+                Some(format!("{:?}", params.#index))
+            }
+        })
+    }
+}
 
-                    #invalidation_impl
-                }
-            })
+/// Generate a `persistable` implementation for providers opting out of persistence
+fn generate_persistable_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if provider_args.no_persist {
+        quote! {
+            fn persistable(&self) -> bool {
+                false
+            }
         }
+    } else {
+        quote! {}
     }
 }
 
-/// Generate duration implementation for provider methods
-fn generate_duration_impl(method_name: &str, duration: Option<Duration>) -> TokenStream2 {
-    if let Some(duration) = duration {
-        let duration_secs = duration.as_secs();
-        let method_ident = syn::Ident::new(method_name, proc_macro2::Span::call_site());
+/// Generate an `ssr` implementation for client-only providers
+fn generate_ssr_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if provider_args.client_only {
+        quote! {
+            fn ssr(&self) -> bool {
+                false
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
 
+/// Generate a `cacheable` implementation for providers that bypass the cache
+fn generate_cacheable_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if provider_args.no_cache {
         quote! {
-            fn #method_ident(&self) -> Option<::std::time::Duration> {
-                Some(::std::time::Duration::from_secs(#duration_secs))
+            fn cacheable(&self) -> bool {
+                false
             }
         }
     } else {
@@ -473,41 +1732,263 @@ fn generate_duration_impl(method_name: &str, duration: Option<Duration>) -> Toke
     }
 }
 
-/// Generate interval implementation
-fn generate_interval_impl(provider_args: &ProviderArgs) -> TokenStream2 {
-    generate_duration_impl("interval", provider_args.interval)
+/// Route a `run` body through the provider's batch window for `batch_window = "..."`
+fn generate_batch_window_call(
+    provider_args: &ProviderArgs,
+    param: TokenStream2,
+    run_body: TokenStream2,
+) -> TokenStream2 {
+    match provider_args.batch_window {
+        Some(window) => {
+            let window_secs = window.as_secs();
+            let window_nanos = window.subsec_nanos();
+            quote! {
+                ::dioxus_provider::batch::run_in_window(
+                    self.clone(),
+                    #param,
+                    ::std::time::Duration::new(#window_secs, #window_nanos),
+                    move || #run_body,
+                )
+            }
+        }
+        None => run_body,
+    }
 }
 
-/// Generate cache expiration implementation
-fn generate_cache_expiration_impl(provider_args: &ProviderArgs) -> TokenStream2 {
-    generate_duration_impl("cache_expiration", provider_args.cache_expiration)
+/// Generate deduplicating cache storage implementation
+fn generate_dedupe_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if provider_args.dedupe {
+        quote! {
+            fn cache_result(
+                &self,
+                cache: &::dioxus_provider::cache::ProviderCache,
+                key: String,
+                result: Result<Self::Output, Self::Error>,
+            ) -> bool {
+                cache.set_deduped(key, result)
+            }
+
+            fn cache_missed_result(
+                &self,
+                cache: &::dioxus_provider::cache::ProviderCache,
+                key: String,
+                result: Result<Self::Output, Self::Error>,
+            ) -> bool {
+                cache.set_deduped(key, result)
+            }
+        }
+    } else {
+        quote! {}
+    }
 }
 
-/// Generate stale time implementation
-fn generate_stale_time_impl(provider_args: &ProviderArgs) -> TokenStream2 {
-    generate_duration_impl("stale_time", provider_args.stale_time)
+/// Generate a `merge_fetched` implementation for providers with `accumulate = item_key`
+fn generate_accumulate_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    let Some(item_key) = &provider_args.accumulate else {
+        return quote! {};
+    };
+    let max_items = match provider_args.max_items {
+        Some(max_items) => quote! { Some(#max_items) },
+        None => quote! { None },
+    };
+    quote! {
+        fn merge_fetched(
+            &self,
+            cache: &::dioxus_provider::cache::ProviderCache,
+            key: &str,
+            result: Result<Self::Output, Self::Error>,
+        ) -> Result<Self::Output, Self::Error> {
+            cache.merge_accumulated(key, result, #item_key, #max_items)
+        }
+    }
+}
+
+/// Generate an `approximate_size` implementation for providers with `sized = true`
+fn generate_approximate_size_impl(provider_args: &ProviderArgs) -> TokenStream2 {
+    if provider_args.sized {
+        quote! {
+            fn approximate_size(&self, result: &Result<Self::Output, Self::Error>) -> Option<usize> {
+                Some(::dioxus_provider::cache::SizedCacheValue::approximate_size(result))
+            }
+        }
+    } else {
+        quote! {}
+    }
 }
 
 /// Generate invalidation implementation for mutations
-fn generate_invalidation_impl(mutation_args: &MutationArgs) -> TokenStream2 {
-    if mutation_args.invalidates.is_empty() {
+fn generate_invalidation_impl(mutation_args: &MutationArgs, params: &[ParamInfo]) -> TokenStream2 {
+    let tags = &mutation_args.invalidates_tags;
+    let tags_impl = if tags.is_empty() {
         quote! {}
     } else {
-        let provider_calls: Vec<_> = mutation_args
-            .invalidates
-            .iter()
-            .map(|provider_fn| {
-                quote! {
-                    ::dioxus_provider::mutation::provider_cache_key_simple(#provider_fn())
-                }
-            })
-            .collect();
+        quote! {
+            fn invalidates_tags(&self) -> &'static [&'static str] {
+                &[#(#tags),*]
+            }
+        }
+    };
 
+    // Providers listed without arguments are invalidated for `()`, the others for the
+    // param built from the mutation's input
+    let (fixed, per_input): (Vec<_>, Vec<_>) = mutation_args
+        .invalidates
+        .iter()
+        .partition(|target| target.args.is_empty());
+
+    let invalidates_impl = (!fixed.is_empty()).then(|| {
+        let keys = fixed.iter().map(|target| {
+            // Point a parameter mismatch at the provider's name in the attribute
+            let func = &target.func;
+            let provider_call = quote_spanned! { func.span()=> (#func)() };
+            quote! {
+                ::dioxus_provider::mutation::invalidated_cache_key::<_, ()>(#provider_call, ())
+            }
+        });
         quote! {
             fn invalidates(&self) -> Vec<String> {
-                vec![#(#provider_calls,)*]
+                vec![#(#keys,)*]
+            }
+        }
+    });
+
+    let invalidates_for_impl = (!per_input.is_empty()).then(|| {
+        let (input_type, input_binding) = generate_mutation_input_binding(params);
+        let keys = per_input.iter().map(|target| {
+            let func = &target.func;
+            let args = target.args.iter().map(|arg| quote! { (#arg).clone() });
+            let param = match target.args.len() {
+                1 => quote! { #(#args)* },
+                _ => quote! { (#(#args,)*) },
+            };
+            let provider_call = quote_spanned! { func.span()=> (#func)() };
+            quote! {
+                ::dioxus_provider::mutation::invalidated_cache_key(#provider_call, #param)
+            }
+        });
+        quote! {
+            #[allow(unused_variables)]
+            fn invalidates_for(&self, input: &#input_type) -> Vec<String> {
+                #input_binding
+                let mut keys = ::dioxus_provider::mutation::Mutation::invalidates(self);
+                keys.extend([#(#keys,)*]);
+                keys
+            }
+        }
+    });
+
+    quote! {
+        #invalidates_impl
+        #invalidates_for_impl
+        #tags_impl
+    }
+}
+
+/// Generate cache update implementation for mutations
+fn generate_updates_impl(mutation_args: &MutationArgs, params: &[ParamInfo]) -> TokenStream2 {
+    if mutation_args.updates.is_empty() {
+        return quote! {};
+    }
+
+    let (input_type, input_binding) = generate_mutation_input_binding(params);
+
+    let updates: Vec<_> = mutation_args
+        .updates
+        .iter()
+        .map(|call| {
+            let provider_fn = &call.func;
+            let args: Vec<_> = call.args.iter().collect();
+            let param = match args.as_slice() {
+                [] => quote! { () },
+                [arg] => quote! { #arg },
+                _ => quote! { (#(#args,)*) },
+            };
+            quote! {
+                ::dioxus_provider::mutation::CacheUpdate::new(#provider_fn(), #param, output.clone())
+            }
+        })
+        .collect();
+
+    quote! {
+        #[allow(unused_variables)]
+        fn updates(
+            &self,
+            input: &#input_type,
+            output: &Self::Output,
+        ) -> Vec<::dioxus_provider::mutation::CacheUpdate> {
+            #input_binding
+            vec![#(#updates,)*]
+        }
+    }
+}
+
+/// Generate retry, timeout and optimistic value implementations for mutations
+fn generate_mutation_options_impl(
+    mutation_args: &MutationArgs,
+    params: &[ParamInfo],
+) -> TokenStream2 {
+    let retry_impl = mutation_args.retry.map(|retries| {
+        quote! {
+            fn retry_policy(&self) -> Option<::dioxus_provider::mutation::RetryPolicy> {
+                Some(::dioxus_provider::mutation::RetryPolicy::new(#retries))
+            }
+        }
+    });
+
+    let timeout_impl = mutation_args.timeout.map(|timeout| {
+        let duration_impl = generate_duration_impl("timeout", Some(timeout));
+        quote! {
+            #duration_impl
+
+            fn timeout_error(&self, timeout: ::std::time::Duration) -> Option<Self::Error> {
+                Some(::std::convert::From::from(
+                    ::dioxus_provider::errors::ProviderError::Timeout(format!(
+                        "mutation did not finish within {:?}",
+                        timeout
+                    )),
+                ))
+            }
+        }
+    });
+
+    let optimistic_impl = mutation_args.optimistic.as_ref().map(|value| {
+        let (input_type, input_binding) = generate_mutation_input_binding(params);
+        quote! {
+            #[allow(unused_variables)]
+            fn optimistic_value(&self, input: &#input_type) -> Option<Self::Output> {
+                #input_binding
+                Some(#value)
             }
         }
+    });
+
+    let await_invalidations_impl =
+        generate_duration_impl("await_invalidations", mutation_args.await_invalidations);
+
+    quote! {
+        #retry_impl
+        #timeout_impl
+        #optimistic_impl
+        #await_invalidations_impl
+    }
+}
+
+/// Bind a mutation's `input` reference to its parameter names so argument expressions can use them
+///
+/// Returns the mutation's input type and the `let` binding.
+fn generate_mutation_input_binding(params: &[ParamInfo]) -> (TokenStream2, TokenStream2) {
+    let param_names: Vec<_> = params.iter().map(|p| &p.name).collect();
+    let param_types: Vec<_> = params.iter().map(|p| &p.ty).collect();
+    match params {
+        [] => (quote! { () }, quote! {}),
+        [param] => {
+            let (name, ty) = (&param.name, &param.ty);
+            (quote! { #ty }, quote! { let #name = input; })
+        }
+        _ => (
+            quote! { (#(#param_types,)*) },
+            quote! { let (#(#param_names,)*) = input; },
+        ),
     }
 }
 
@@ -520,6 +2001,9 @@ struct ProviderInfo {
     error_type: Type,
     struct_name: syn::Ident,
     fn_name: syn::Ident,
+    // Whether a free function named after the provider returns it; associated providers
+    // are returned by a function of their impl block instead
+    free_constructor: bool,
 }
 
 /// Information about a function parameter
@@ -528,18 +2012,100 @@ struct ParamInfo {
     ty: Type,
 }
 
+/// Remove the trailing `CancellationToken` parameter required by `with_cancel`
+fn extract_cancel_param(input_fn: &ItemFn, params: &mut Vec<ParamInfo>) -> Result<ParamInfo> {
+    let is_token = |param: &ParamInfo| match &param.ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "CancellationToken"),
+        _ => false,
+    };
+
+    match params.pop() {
+        Some(param) if is_token(&param) => Ok(param),
+        _ => Err(syn::Error::new_spanned(
+            &input_fn.sig,
+            "with_cancel requires a trailing `cancel: CancellationToken` parameter",
+        )),
+    }
+}
+
+/// Remove the trailing `Emitter` parameter required by `progressive`
+fn extract_emit_param(input_fn: &ItemFn, params: &mut Vec<ParamInfo>) -> Result<ParamInfo> {
+    let is_emitter = |param: &ParamInfo| match &param.ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Emitter"),
+        _ => false,
+    };
+
+    match params.pop() {
+        Some(param) if is_emitter(&param) => Ok(param),
+        _ => Err(syn::Error::new_spanned(
+            &input_fn.sig,
+            "progressive requires a trailing `emit: Emitter<T>` parameter",
+        )),
+    }
+}
+
+/// Remove the trailing `ProgressReporter` parameter required by `with_progress`
+fn extract_progress_param(input_fn: &ItemFn, params: &mut Vec<ParamInfo>) -> Result<ParamInfo> {
+    let is_reporter = |param: &ParamInfo| match &param.ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "ProgressReporter"),
+        _ => false,
+    };
+
+    match params.pop() {
+        Some(param) if is_reporter(&param) => Ok(param),
+        _ => Err(syn::Error::new_spanned(
+            &input_fn.sig,
+            "with_progress requires a trailing `progress: ProgressReporter` parameter",
+        )),
+    }
+}
+
+/// Extract `T` from the `FetchOutcome<T>` output of a `conditional` provider
+fn extract_fetch_outcome_type(output_type: &Type) -> Result<Type> {
+    if let Type::Path(type_path) = output_type
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "FetchOutcome"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(ty)) = args.args.first()
+    {
+        return Ok(ty.clone());
+    }
+    Err(syn::Error::new_spanned(
+        output_type,
+        "conditional providers must return Result<FetchOutcome<T>, E>",
+    ))
+}
+
 /// Extract provider information from the input function
-fn extract_provider_info(input_fn: &ItemFn) -> Result<ProviderInfo> {
+fn extract_provider_info(
+    input_fn: &ItemFn,
+    namespace: Option<&syn::Ident>,
+) -> Result<ProviderInfo> {
     let fn_name = input_fn.sig.ident.clone();
     let fn_vis = input_fn.vis.clone();
     let fn_attrs = input_fn.attrs.clone();
     let fn_block = input_fn.block.clone();
 
     let (output_type, error_type) = extract_result_types(&input_fn.sig.output)?;
-    let struct_name = syn::Ident::new(
-        &to_pascal_case(&fn_name.to_string()),
-        proc_macro2::Span::call_site(),
-    );
+    let struct_name = match namespace {
+        Some(namespace) => associated_struct_name(namespace, &fn_name),
+        None => syn::Ident::new(
+            &to_pascal_case(&fn_name.to_string()),
+            proc_macro2::Span::call_site(),
+        ),
+    };
 
     Ok(ProviderInfo {
         fn_vis,
@@ -549,6 +2115,7 @@ fn extract_provider_info(input_fn: &ItemFn) -> Result<ProviderInfo> {
         error_type,
         struct_name,
         fn_name,
+        free_constructor: namespace.is_none(),
     })
 }
 
@@ -558,6 +2125,17 @@ fn generate_common_struct_and_const(info: &ProviderInfo) -> TokenStream2 {
     let fn_attrs = &info.fn_attrs;
     let fn_name = &info.fn_name;
 
+    // Generate a function that returns an instance of the struct
+    let constructor = if info.free_constructor {
+        quote! {
+            pub fn #fn_name() -> #struct_name {
+                #struct_name
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     quote! {
         #[derive(Clone, PartialEq)]
         #(#fn_attrs)*
@@ -569,10 +2147,7 @@ fn generate_common_struct_and_const(info: &ProviderInfo) -> TokenStream2 {
             }
         }
 
-        // Generate a function that returns an instance of the struct
-        pub fn #fn_name() -> #struct_name {
-            #struct_name
-        }
+        #constructor
     }
 }
 
@@ -734,17 +2309,109 @@ fn validate_provider_existence(compose_providers: &[syn::Ident]) -> Result<()> {
     Ok(())
 }
 
+/// Make a function whose body returns `anyhow::Result<T>` return `Result<T, error>`
+///
+/// The body runs in an async closure returning the original type, so `?` and `return` keep
+/// working with the body's error type, and its final error is converted afterwards.
+fn bridge_errors(
+    mut input_fn: ItemFn,
+    error: &Type,
+    map_err: Option<&syn::Path>,
+    not_a_result: &str,
+) -> Result<ItemFn> {
+    let ReturnType::Type(_, body_type) = &input_fn.sig.output else {
+        return Err(syn::Error::new_spanned(&input_fn.sig, not_a_result));
+    };
+    let output_type = match &**body_type {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == "Result" || segment.ident == "ServerFnResult")
+            .and_then(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.first(),
+                _ => None,
+            })
+            .and_then(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            }),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new_spanned(body_type, not_a_result))?;
+
+    let convert = match map_err {
+        Some(map_err) => quote! { #map_err },
+        None => quote! {
+            |error| ::std::convert::From::from(::std::string::ToString::to_string(&error))
+        },
+    };
+    let block = &input_fn.block;
+    input_fn.block = syn::parse_quote! {{
+        let __dioxus_body = async move || -> #body_type #block;
+        __dioxus_body().await.map_err(#convert)
+    }};
+    input_fn.sig.output = syn::parse_quote! { -> ::std::result::Result<#output_type, #error> };
+    Ok(input_fn)
+}
+
+/// Convert the errors propagated with `?` in a provider body into its `String` error type
+fn stringify_errors(block: &syn::Block, error_type: &Type) -> Result<syn::Block> {
+    let is_string = matches!(
+        error_type,
+        Type::Path(type_path) if type_path.qself.is_none()
+            && type_path.path.segments.last().is_some_and(|segment| {
+                segment.ident == "String" && segment.arguments.is_none()
+            })
+    );
+    if !is_string {
+        return Err(syn::Error::new_spanned(
+            error_type,
+            "stringify_errors requires the provider's error type to be String",
+        ));
+    }
+
+    let mut block = block.clone();
+    StringifyErrors.visit_block_mut(&mut block);
+    Ok(block)
+}
+
+/// Rewrites `expr?` into `expr.map_err(|error| error.to_string())?`
+///
+/// Closures, async blocks and nested items return their own results, so the `?` operators
+/// inside them are left alone.
+struct StringifyErrors;
+
+impl VisitMut for StringifyErrors {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        if matches!(expr, syn::Expr::Closure(_) | syn::Expr::Async(_)) {
+            return;
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+        if let syn::Expr::Try(expr_try) = expr {
+            let inner = &expr_try.expr;
+            *expr_try.expr = syn::parse_quote_spanned! { inner.span()=>
+                (#inner).map_err(|error| ::std::string::ToString::to_string(&error))
+            };
+        }
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut syn::Item) {}
+}
+
 /// Generate enhanced function body with composition
 fn generate_enhanced_function_body(
     compose_providers: &[syn::Ident],
     params: &[ParamInfo],
+    error_type: &Type,
     original_block: &syn::Block,
 ) -> syn::Block {
     let mut statements = Vec::new();
 
     // Add composition statements
     if !compose_providers.is_empty() {
-        let composition_statements = generate_composition_statements(compose_providers, params);
+        let composition_statements =
+            generate_composition_statements(compose_providers, params, error_type);
         statements.extend(composition_statements);
     }
 
@@ -761,6 +2428,7 @@ fn generate_enhanced_function_body(
 fn generate_composition_statements(
     compose_providers: &[syn::Ident],
     params: &[ParamInfo],
+    error_type: &Type,
 ) -> Vec<syn::Stmt> {
     if compose_providers.is_empty() {
         return vec![];
@@ -769,7 +2437,11 @@ fn generate_composition_statements(
     let mut statements = Vec::new();
 
     // Add compile-time validation checks for better error messages
-    statements.extend(generate_validation_statements(compose_providers, params));
+    statements.extend(generate_validation_statements(
+        compose_providers,
+        params,
+        error_type,
+    ));
 
     // Generate variable names for composed results with unique prefix to avoid collisions
     let result_vars: Vec<_> = compose_providers
@@ -857,6 +2529,7 @@ fn generate_composition_statements(
 fn generate_validation_statements(
     compose_providers: &[syn::Ident],
     params: &[ParamInfo],
+    error_type: &Type,
 ) -> Vec<syn::Stmt> {
     let mut statements = Vec::new();
 
@@ -893,5 +2566,45 @@ fn generate_validation_statements(
         statements.push(existence_check);
     }
 
+    // Add error conversion validation so `?` works on composed results
+    let param_types: Vec<_> = params.iter().map(|param| &param.ty).collect();
+    let param_type = match param_types.as_slice() {
+        [] => quote! { () },
+        [param_type] => quote! { #param_type },
+        param_types => quote! { (#(#param_types,)*) },
+    };
+    for provider in compose_providers {
+        // Point the errors at the composed provider's name in the attribute
+        let provider_call = quote_spanned! { provider.span()=> #provider() };
+        let param_check: syn::Stmt = syn::parse_quote! {
+            const _: () = {
+                fn __dioxus_provider_assert_param<P, Param>(_provider: P)
+                where
+                    P: ::dioxus_provider::types::ComposedProviderParam<Param>,
+                {
+                }
+                fn __dioxus_provider_validate_param() {
+                    __dioxus_provider_assert_param::<_, #param_type>(#provider_call);
+                }
+            };
+        };
+        statements.push(param_check);
+        let error_conversion_check: syn::Stmt = syn::parse_quote! {
+            const _: () = {
+                fn __dioxus_provider_assert_error_conversion<P, Param, E>(_provider: P)
+                where
+                    P: ::dioxus_provider::types::ComposedProviderError<Param, E>,
+                {
+                }
+                fn __dioxus_provider_validate_error_conversion() {
+                    __dioxus_provider_assert_error_conversion::<_, #param_type, #error_type>(
+                        #provider_call,
+                    );
+                }
+            };
+        };
+        statements.push(error_conversion_check);
+    }
+
     statements
 }
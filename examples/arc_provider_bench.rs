@@ -0,0 +1,52 @@
+//! Arc Provider Read Benchmark
+//!
+//! Compares reading a large cached `Vec` the way `use_provider` does, cloning the
+//! output on every read, against `ArcProvider`, which caches the output behind an
+//! `Arc` so each read is a pointer clone. Every consumer re-render performs one read.
+//!
+//! Run with `cargo run --release --example arc_provider_bench`.
+
+use dioxus_provider::{cache::ProviderCache, hooks::ArcProvider, prelude::*};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+const ROWS: u32 = 100_000;
+const READS: u32 = 1_000;
+
+#[provider]
+async fn fetch_rows() -> Result<Vec<String>, String> {
+    Ok((0..ROWS).map(|id| format!("row {id}")).collect())
+}
+
+fn rows() -> Vec<String> {
+    (0..ROWS).map(|id| format!("row {id}")).collect()
+}
+
+/// Stores the rows with `provider`, then reads them `READS` times, returning the average
+/// time of a read
+fn measure<P>(provider: P, output: impl FnOnce(Vec<String>) -> P::Output) -> Duration
+where
+    P: Provider<(), Error = String>,
+{
+    let cache = ProviderCache::new();
+    let key = provider.id(&());
+    provider.cache_result(&cache, key.clone(), Ok(output(rows())));
+
+    let start = Instant::now();
+    for _ in 0..READS {
+        let read = cache.get::<Result<P::Output, String>>(&key);
+        assert!(matches!(read, Some(Ok(_))));
+    }
+    start.elapsed() / READS
+}
+
+fn main() {
+    let cloned = measure(fetch_rows(), |rows| rows);
+    let shared = measure(ArcProvider::new(fetch_rows()), Arc::new);
+
+    println!("Reading {ROWS} cached rows (average of {READS} reads):");
+    println!("  use_provider (clone per read): {cloned:?}");
+    println!("  use_provider_arc (Arc clone):  {shared:?}");
+}
@@ -0,0 +1,51 @@
+//! Cache Batch Write Benchmark
+//!
+//! Compares seeding the cache one `set` at a time against a single `set_many`
+//! batch, which takes the cache lock once and refreshes subscribers in one pass.
+//!
+//! Run with `cargo run --release --example cache_batch_bench`.
+
+use dioxus_provider::{cache::ProviderCache, refresh::RefreshRegistry};
+use std::time::{Duration, Instant};
+
+const ENTRIES: usize = 10_000;
+const ROUNDS: u32 = 20;
+
+fn entries() -> Vec<(String, Result<String, String>)> {
+    (0..ENTRIES)
+        .map(|id| (format!("fetch_user:{id}"), Ok(format!("user {id}"))))
+        .collect()
+}
+
+/// Runs `write` on a fresh cache and registry `ROUNDS` times, returning the average time
+fn measure(write: impl Fn(&ProviderCache, &RefreshRegistry)) -> Duration {
+    let mut total = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let cache = ProviderCache::new();
+        let registry = RefreshRegistry::new();
+        let start = Instant::now();
+        write(&cache, &registry);
+        total += start.elapsed();
+        assert_eq!(cache.size(), ENTRIES);
+    }
+    total / ROUNDS
+}
+
+fn main() {
+    let one_by_one = measure(|cache, registry| {
+        for (key, value) in entries() {
+            if cache.set(key.clone(), value) {
+                registry.trigger_refresh(&key);
+            }
+        }
+    });
+
+    let batched = measure(|cache, registry| {
+        let updated = cache.set_many(entries());
+        registry.trigger_refresh_many(&updated);
+    });
+
+    println!("Seeding {ENTRIES} entries (average of {ROUNDS} rounds):");
+    println!("  set + trigger_refresh:           {one_by_one:?}");
+    println!("  set_many + trigger_refresh_many: {batched:?}");
+}
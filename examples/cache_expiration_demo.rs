@@ -401,7 +401,7 @@ fn CacheCard<T: 'static + Clone + PartialEq, E: 'static + Clone + PartialEq + st
     render_success: fn(&T) -> Element,
 ) -> Element {
     let cache_status = match &*data.read() {
-        ProviderState::Loading { .. } => "cache-miss",
+        ProviderState::Idle | ProviderState::Loading { .. } => "cache-miss",
         ProviderState::Success(_) => "cache-hit",
         ProviderState::Error(_) => "cache-error",
     };
@@ -421,7 +421,7 @@ fn CacheCard<T: 'static + Clone + PartialEq, E: 'static + Clone + PartialEq + st
             }
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-container",
                             div { class: "loading-spinner" }
                             span { "Cache expired - fetching fresh data..." }
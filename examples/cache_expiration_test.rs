@@ -48,7 +48,7 @@ fn App() -> Element {
             div { style: "margin: 20px 0; padding: 20px; border: 1px solid #ccc;",
                 h3 { "Test Data (expires in 5s):" }
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { style: "color: orange;",
                             "🔄 Loading data..."
                         }
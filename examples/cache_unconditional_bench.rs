@@ -0,0 +1,61 @@
+//! Cache Unconditional Write Benchmark
+//!
+//! Compares storing a large provider result with `set`, which clones and compares the
+//! previous entry to detect changes, against `set_unconditional`, which `use_provider`
+//! uses for fetches that missed the cache and always replaces the entry.
+//!
+//! Run with `cargo run --release --example cache_unconditional_bench`.
+
+use dioxus_provider::cache::ProviderCache;
+use std::time::{Duration, Instant};
+
+const ITEMS: usize = 100_000;
+const ROUNDS: u32 = 50;
+const KEY: &str = "fetch_feed:()";
+
+type Feed = Result<Vec<String>, String>;
+
+fn feed(offset: usize) -> Feed {
+    Ok((0..ITEMS)
+        .map(|id| format!("post {}", id + offset))
+        .collect())
+}
+
+/// Stores a fresh feed `ROUNDS` times, optionally over an earlier one, returning the
+/// average time of the store alone
+fn measure(over_earlier_entry: bool, store: impl Fn(&ProviderCache, Feed)) -> Duration {
+    let mut total = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let cache = ProviderCache::new();
+        if over_earlier_entry {
+            cache.set(KEY.to_string(), feed(0));
+        }
+        let value = feed(1);
+        let start = Instant::now();
+        store(&cache, value);
+        total += start.elapsed();
+        assert_eq!(cache.size(), 1);
+    }
+    total / ROUNDS
+}
+
+fn main() {
+    let set = |cache: &ProviderCache, value: Feed| {
+        cache.set(KEY.to_string(), value);
+    };
+    let set_unconditional = |cache: &ProviderCache, value: Feed| {
+        cache.set_unconditional(KEY.to_string(), value);
+    };
+
+    println!("Storing a feed of {ITEMS} posts (average of {ROUNDS} rounds):");
+    println!(
+        "  empty key:           set {:?}, set_unconditional {:?}",
+        measure(false, set),
+        measure(false, set_unconditional)
+    );
+    println!(
+        "  over earlier entry:  set {:?}, set_unconditional {:?}",
+        measure(true, set),
+        measure(true, set_unconditional)
+    );
+}
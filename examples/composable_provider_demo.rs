@@ -293,7 +293,7 @@ fn IndividualProvidersDemo(user_id: u32) -> Element {
             div { class: "card user-section",
                 h4 { "👤 User Data" }
                 match &*user_data.read() {
-                    ProviderState::Loading { .. } => rsx! { p { class: "loading", "Loading user..." } },
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! { p { class: "loading", "Loading user..." } },
                     ProviderState::Success(user) => rsx! {
                         div {
                             p { strong { "Name: " } {user.name.clone()} }
@@ -309,7 +309,7 @@ fn IndividualProvidersDemo(user_id: u32) -> Element {
             div { class: "card permissions-section",
                 h4 { "🔐 Permissions" }
                 match &*permissions_data.read() {
-                    ProviderState::Loading { .. } => rsx! { p { class: "loading", "Loading permissions..." } },
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! { p { class: "loading", "Loading permissions..." } },
                     ProviderState::Success(perms) => rsx! {
                         div {
                             p { strong { "Role: " } {perms.role.clone()} }
@@ -324,7 +324,7 @@ fn IndividualProvidersDemo(user_id: u32) -> Element {
             div { class: "card settings-section",
                 h4 { "⚙️ Settings" }
                 match &*settings_data.read() {
-                    ProviderState::Loading { .. } => rsx! { p { class: "loading", "Loading settings..." } },
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! { p { class: "loading", "Loading settings..." } },
                     ProviderState::Success(settings) => rsx! {
                         div {
                             p { strong { "Theme: " } {settings.theme.clone()} }
@@ -346,7 +346,7 @@ fn ComposableProviderDemo(user_id: u32) -> Element {
     rsx! {
         div { class: "grid",
             match &*profile_data.read() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     div { class: "loading",
                         p { "⚡ Loading full profile in parallel..." }
                     }
@@ -407,7 +407,7 @@ fn PartialCompositionDemo(user_id: u32) -> Element {
     rsx! {
         div { class: "grid",
             match &*user_with_permissions.read() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     p { class: "loading", "Loading user with permissions..." }
                 },
                 ProviderState::Success(user_perms) => rsx! {
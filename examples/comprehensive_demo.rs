@@ -399,10 +399,7 @@ fn ComprehensiveCacheTest() -> Element {
                             }
                             button {
                                 class: "control-btn clear-all",
-                                onclick: move |_| {
-                                    let clear_cache = use_clear_provider_cache();
-                                    clear_cache();
-                                },
+                                onclick: move |_| clear_provider_cache(),
                                 "🗑️ Clear Global Cache"
                             }
                         }
@@ -569,7 +566,7 @@ fn ComprehensiveCacheTest() -> Element {
 fn LiveMetricsCard(data: Signal<ProviderState<LiveMetrics, String>>) -> Element {
     let refresh_metrics = use_invalidate_provider(fetch_live_metrics(), ());
     let status_class = match &*data.read() {
-        ProviderState::Loading { .. } => "loading",
+        ProviderState::Idle | ProviderState::Loading { .. } => "loading",
         ProviderState::Success(_) => "success",
         ProviderState::Error(_) => "error",
     };
@@ -599,20 +596,21 @@ fn LiveMetricsCard(data: Signal<ProviderState<LiveMetrics, String>>) -> Element
                 }
             }
             div { class: "card-content",
-                match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                ProviderView {
+                    state: data,
+                    loading: rsx! {
                         div { class: "loading-state",
                             div { class: "loading-spinner" }
                             span { "Loading data..." }
                         }
                     },
-                    ProviderState::Error(e) => rsx! {
+                    error: |e: String| rsx! {
                         div { class: "error-state",
                             span { class: "error-icon", "❌" }
                             span { class: "error-message", "Error: {e}" }
                         }
                     },
-                    ProviderState::Success(metrics) => rsx! {
+                    success: |metrics: LiveMetrics| rsx! {
                         div { class: "metrics-content",
                             div { class: "metric-item",
                                 span { class: "metric-label", "CPU Usage:" }
@@ -653,7 +651,7 @@ fn LiveMetricsCard(data: Signal<ProviderState<LiveMetrics, String>>) -> Element
 fn UserDashboardCard(data: Signal<ProviderState<UserDashboard, String>>, user_id: u32) -> Element {
     let refresh_dashboard = use_invalidate_provider(fetch_user_dashboard(), user_id);
     let status_class = match &*data.read() {
-        ProviderState::Loading { .. } => "loading",
+        ProviderState::Idle | ProviderState::Loading { .. } => "loading",
         ProviderState::Success(_) => "success",
         ProviderState::Error(_) => "error",
     };
@@ -684,7 +682,7 @@ fn UserDashboardCard(data: Signal<ProviderState<UserDashboard, String>>, user_id
             }
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "loading-spinner" }
                             span { "Loading data..." }
@@ -727,7 +725,7 @@ fn UserDashboardCard(data: Signal<ProviderState<UserDashboard, String>>, user_id
 fn AnalyticsCard(data: Signal<ProviderState<AnalyticsReport, String>>) -> Element {
     let refresh_analytics = use_invalidate_provider(fetch_analytics_report(), ());
     let status_class = match &*data.read() {
-        ProviderState::Loading { .. } => "loading",
+        ProviderState::Idle | ProviderState::Loading { .. } => "loading",
         ProviderState::Success(_) => "success",
         ProviderState::Error(_) => "error",
     };
@@ -758,7 +756,7 @@ fn AnalyticsCard(data: Signal<ProviderState<AnalyticsReport, String>>) -> Elemen
             }
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "loading-spinner" }
                             span { "Loading data..." }
@@ -813,7 +811,7 @@ fn TempDataCard(
 ) -> Element {
     let refresh_temp = use_invalidate_provider(fetch_temporary_data(), session_id);
     let status_class = match &*data.read() {
-        ProviderState::Loading { .. } => "loading",
+        ProviderState::Idle | ProviderState::Loading { .. } => "loading",
         ProviderState::Success(_) => "success",
         ProviderState::Error(_) => "error",
     };
@@ -844,7 +842,7 @@ fn TempDataCard(
             }
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "loading-spinner" }
                             span { "Loading data..." }
@@ -884,7 +882,7 @@ fn TempDataCard(
 fn ChatCard(data: Signal<ProviderState<ChatData, String>>, chat_id: u32) -> Element {
     let refresh_chat = use_invalidate_provider(fetch_chat_messages(), chat_id);
     let status_class = match &*data.read() {
-        ProviderState::Loading { .. } => "loading",
+        ProviderState::Idle | ProviderState::Loading { .. } => "loading",
         ProviderState::Success(_) => "success",
         ProviderState::Error(_) => "error",
     };
@@ -915,7 +913,7 @@ fn ChatCard(data: Signal<ProviderState<ChatData, String>>, chat_id: u32) -> Elem
             }
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "loading-spinner" }
                             span { "Loading data..." }
@@ -47,7 +47,7 @@ fn CounterApp() -> Element {
             },
             h2 { class: "text-lg font-semibold mt-4", "Provider State:" },
             match &*counter.read() {
-                ProviderState::Loading { .. } => rsx! { p { "Loading counter..." } },
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! { p { "Loading counter..." } },
                 ProviderState::Success(val) => rsx! { p { "Counter (from provider): {val}" } },
                 ProviderState::Error(err) => rsx! { p { "Error: {err}" } },
             },
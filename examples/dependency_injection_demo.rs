@@ -168,7 +168,7 @@ fn UserProfile(user_id: u32) -> Element {
             }
 
             match user() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     div { class: "loading", "Loading user..." }
                 },
                 ProviderState::Success(user) => rsx! {
@@ -184,7 +184,7 @@ fn UserProfile(user_id: u32) -> Element {
             }
 
             match posts() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     div { class: "loading", "Loading posts..." }
                 },
                 ProviderState::Success(posts) => rsx! {
@@ -224,7 +224,7 @@ fn CachedUserProfile(user_id: u32) -> Element {
             p { style: "color: #666; font-size: 0.9em;", "User ID: {user_id}" }
 
             match cached_user() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     div { class: "loading", "Loading cached user..." }
                 },
                 ProviderState::Success(user) => rsx! {
@@ -241,7 +241,7 @@ fn CachedUserProfile(user_id: u32) -> Element {
 
             h4 { "Fresh Posts (10s stale time)" }
             match fresh_posts() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     div { class: "loading", "Loading fresh posts..." }
                 },
                 ProviderState::Success(posts) => rsx! {
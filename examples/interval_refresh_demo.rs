@@ -190,7 +190,7 @@ fn SystemMetricsCard() -> Element {
             div { class: "card-header",
                 h3 { "System Metrics (5s interval)" }
                 div { class: match &*data.read() {
-                    ProviderState::Loading { .. } => "status loading",
+                    ProviderState::Idle | ProviderState::Loading { .. } => "status loading",
                     ProviderState::Error(_) => "status error",
                     ProviderState::Success(_) => "status success",
                 }}
@@ -200,7 +200,7 @@ fn SystemMetricsCard() -> Element {
 
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "spinner" }
                             span { "Loading..." }
@@ -250,7 +250,7 @@ fn BusinessMetricsCard() -> Element {
             div { class: "card-header",
                 h3 { "Business Metrics (10s interval)" }
                 div { class: match &*data.read() {
-                    ProviderState::Loading { .. } => "status loading",
+                    ProviderState::Idle | ProviderState::Loading { .. } => "status loading",
                     ProviderState::Error(_) => "status error",
                     ProviderState::Success(_) => "status success",
                 }}
@@ -260,7 +260,7 @@ fn BusinessMetricsCard() -> Element {
 
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "spinner" }
                             span { "Loading..." }
@@ -309,7 +309,7 @@ fn MetricsCard(
     color_class: String,
 ) -> Element {
     let status_class = match &*data.read() {
-        ProviderState::Loading { .. } => "status loading",
+        ProviderState::Idle | ProviderState::Loading { .. } => "status loading",
         ProviderState::Error(_) => "status error",
         ProviderState::Success(_) => "status success",
     };
@@ -325,7 +325,7 @@ fn MetricsCard(
 
             div { class: "card-content",
                 match &*data.read() {
-                    ProviderState::Loading { .. } => rsx! {
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                         div { class: "loading-state",
                             div { class: "spinner" }
                             span { "Loading..." }
@@ -0,0 +1,67 @@
+//! Lazy Provider Demo
+//!
+//! Shows a provider that only fetches when the user asks for it. The state starts
+//! as `ProviderState::Idle`, moves to `Loading` when the button is clicked and ends
+//! in `Success` or `Error`. `ProviderView` renders its `idle` view until then.
+
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::time::Duration;
+
+#[derive(Clone, PartialEq)]
+pub struct Report {
+    title: String,
+    rows: u32,
+}
+
+// An expensive report we don't want to fetch until it is requested
+#[provider(cache_expiration = "5m")]
+async fn fetch_report() -> Result<Report, String> {
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    Ok(Report {
+        title: "Quarterly usage".to_string(),
+        rows: 1_250,
+    })
+}
+
+#[component]
+fn App() -> Element {
+    let mut report = use_signal(|| ProviderState::<Report, String>::Idle);
+
+    let load_report = move |_| {
+        let task = spawn(async move {
+            let state = match fetch_report().run(()).await {
+                Ok(data) => ProviderState::Success(data),
+                Err(error) => ProviderState::Error(error),
+            };
+            report.set(state);
+        });
+        report.set(ProviderState::Loading { task });
+    };
+
+    rsx! {
+        div { class: "container",
+            h1 { "Lazy Provider Demo" }
+            button {
+                disabled: !report.read().is_idle(),
+                onclick: load_report,
+                "Load report"
+            }
+            ProviderView {
+                state: report,
+                idle: rsx! { p { "The report is fetched when you ask for it." } },
+                loading: rsx! { p { "Building report..." } },
+                error: |e: String| rsx! { p { "Error: {e}" } },
+                success: |report: Report| rsx! {
+                    h2 { "{report.title}" }
+                    p { "{report.rows} rows" }
+                },
+            }
+        }
+    }
+}
+
+fn main() {
+    init_global_providers().unwrap();
+    dioxus::launch(App);
+}
@@ -37,7 +37,7 @@ fn UserAgeCard(user_id: u32) -> Element {
 
     rsx! {
         match &message {
-            ProviderState::Loading { .. } => rsx!(div { "Loading age..." }),
+            ProviderState::Idle | ProviderState::Loading { .. } => rsx!(div { "Loading age..." }),
             ProviderState::Success(msg) => rsx!(div { "{msg}" }),
             ProviderState::Error(err) => rsx!(div { style: "color: red;", "{err}" }),
         }
@@ -105,7 +105,7 @@ async fn fetch_user_basic(user_id: u32) -> Result<User, ProviderError> {
 // Provider demonstrating UserError with dependency injection
 #[provider]
 async fn fetch_user_with_validation(user_id: u32) -> Result<User, UserError> {
-    let api_client = inject::<ApiClient>().map_err(UserError::Provider)?;
+    let api_client = inject::<ApiClient>()?;
 
     if user_id == 0 {
         return Err(UserError::ValidationFailed {
@@ -145,7 +145,7 @@ fn UserProfile(user_id: u32) -> Element {
             }
 
             match &*user_data.read() {
-                ProviderState::Loading { .. } => rsx! {
+                ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
                     div { class: "text-blue-500", "Loading user..." }
                 },
                 ProviderState::Success(user) => rsx! {
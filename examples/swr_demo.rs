@@ -243,7 +243,7 @@ fn SWRDataDisplay<
     render_success: fn(&T) -> Element,
 ) -> Element {
     match &*data.read() {
-        ProviderState::Loading { .. } => rsx! {
+        ProviderState::Idle | ProviderState::Loading { .. } => rsx! {
             div { class: "loading-container",
                 div { class: "loading-spinner" }
                 span { "Fetching fresh data..." }
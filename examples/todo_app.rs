@@ -353,7 +353,7 @@ pub fn TodoList(filter: Filter) -> Element {
             ul {
                 class: "divide-y divide-gray-200",
                 match &*todos.read() {
-                    ProviderState::Loading { .. } => rsx!(li { class: "text-blue-500", "Loading todos..." }),
+                    ProviderState::Idle | ProviderState::Loading { .. } => rsx!(li { class: "text-blue-500", "Loading todos..." }),
                     ProviderState::Error(err) => rsx!(li { class: "text-red-500", "Error: {err}" }),
                     ProviderState::Success(_) => {
                         if let Some(list) = filtered_todos {
@@ -79,10 +79,17 @@ async fn greeting_provider(name: String) -> Result<String, String> {
     Ok(format!("Hello, {name}!"))
 }
 
+// The server function's ServerFnError is converted into a cacheable ProviderError
+#[provider(server_fn)]
+async fn server_echo_provider(input: String) -> Result<String, ServerFnError> {
+    echo_server(input).await
+}
+
 #[component]
 fn GreetingDemo() -> Element {
     let mut name = use_signal(|| "World".to_string());
     let greeting = use_provider(greeting_provider(), name().clone());
+    let server_echo = use_provider(server_echo_provider(), name().clone());
     let mut echo_response = use_signal(|| String::new());
 
     rsx! {
@@ -110,6 +117,11 @@ fn GreetingDemo() -> Element {
             if !echo_response().is_empty() {
                 p { "Server echoed: {echo_response}" }
             }
+            match &*server_echo.read() {
+                ProviderState::Success(echo) => rsx!(p { "Server provider echoed: {echo}" }),
+                ProviderState::Error(err) => rsx!(p { "Server provider error: {err}" }),
+                _ => rsx!(),
+            }
         }
     }
 }
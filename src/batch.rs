@@ -0,0 +1,199 @@
+//! # Batch Windows
+//!
+//! This module coalesces the fetches of a [`BatchProvider`] that start close together into a
+//! single [`BatchProvider::run_batch`] call. A provider declared with
+//! `#[provider(batch_window = "10ms")]` holds every fetch for the window opened by the first
+//! one, then fetches all their parameters at once and hands each fetch its own result. Many
+//! components calling `use_provider(fetch_item(), id)` on the same render thus make one
+//! request, without switching to [`use_batch_provider`](crate::hooks::use_batch_provider).
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus_provider::prelude::*;
+//!
+//! #[provider(batch_window = "10ms")]
+//! async fn fetch_item(id: u32) -> Result<String, String> {
+//!     Ok(format!("Item {id}"))
+//! }
+//!
+//! impl BatchProvider<u32> for FetchItem {
+//!     async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+//!         // One request for all ids, e.g. GET /items?ids=1,2,3
+//!         ids.iter().map(|id| Ok(format!("Item {id}"))).collect()
+//!     }
+//! }
+//! ```
+//!
+//! A window that collected a single fetch runs the provider's own body instead, and so does
+//! a fetch whose batch was dropped before it answered, so a window never leaves a fetch
+//! hanging.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    future::Future,
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use futures::{
+    channel::oneshot,
+    future::{self, Either},
+};
+use tracing::debug;
+
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+#[cfg(target_family = "wasm")]
+use web_time::Instant;
+
+use crate::{hooks::BatchProvider, platform::time::sleep, types::ProviderParamBounds};
+
+/// The open window of each provider type, type-erased to a [`BatchWindow`]
+static OPEN_WINDOWS: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>> = OnceLock::new();
+
+/// Source of the ids telling windows of the same provider apart
+static NEXT_WINDOW_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Sends a fetch the result its window's batch got for its parameter
+type ResultSender<T, E> = oneshot::Sender<Result<T, E>>;
+
+/// A fetch waiting for its window's batch, with the sender of its result
+type PendingFetch<Param, T, E> = (Param, ResultSender<T, E>);
+
+/// A fetch that joined a window: the window's id, when it closes and the fetch's result
+type JoinedWindow<T, E> = (u64, Instant, oneshot::Receiver<Result<T, E>>);
+
+/// The fetches waiting for a window to close
+struct BatchWindow<Param, T, E> {
+    id: u64,
+    closes_at: Instant,
+    fetches: Vec<PendingFetch<Param, T, E>>,
+}
+
+/// Runs a fetch of a batch-window provider, joining the provider's open window
+///
+/// `single` fetches `param` alone and runs when the window holds no other fetch or its
+/// batch never answered. Used by `#[provider(batch_window = ...)]`.
+#[doc(hidden)]
+pub async fn run_in_window<P, Param, F>(
+    provider: P,
+    param: Param,
+    window: Duration,
+    single: impl FnOnce() -> F,
+) -> Result<P::Output, P::Error>
+where
+    P: BatchProvider<Param> + Clone + 'static,
+    Param: ProviderParamBounds,
+    F: Future<Output = Result<P::Output, P::Error>>,
+{
+    let slot = TypeId::of::<(P, Param)>();
+    let Some((id, closes_at, answer)) = join_window(slot, param.clone(), window) else {
+        return single().await;
+    };
+
+    // Whoever wakes first once the window closes fetches the whole batch
+    let wait = sleep(closes_at.saturating_duration_since(Instant::now()));
+    let answer = match future::select(answer, std::pin::pin!(wait)).await {
+        Either::Left((answer, _)) => answer,
+        Either::Right((_, answer)) => {
+            if let Some(fetches) = close_window::<Param, P::Output, P::Error>(slot, id) {
+                if fetches.len() == 1 {
+                    return single().await;
+                }
+                run_batch(&provider, fetches).await;
+            }
+            answer.await
+        }
+    };
+    match answer {
+        Ok(result) => result,
+        Err(oneshot::Canceled) => single().await,
+    }
+}
+
+/// Adds a fetch to the provider's open window, opening one if there is none
+///
+/// Returns the window's id, when it closes and the receiver of the fetch's result.
+fn join_window<Param, T, E>(
+    slot: TypeId,
+    param: Param,
+    window: Duration,
+) -> Option<JoinedWindow<T, E>>
+where
+    Param: Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    let mut windows = OPEN_WINDOWS.get_or_init(Default::default).lock().ok()?;
+    let open = windows.entry(slot).or_insert_with(|| {
+        Box::new(BatchWindow::<Param, T, E> {
+            id: NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed),
+            closes_at: Instant::now() + window,
+            fetches: Vec::new(),
+        })
+    });
+    let open = open.downcast_mut::<BatchWindow<Param, T, E>>()?;
+    let (sender, receiver) = oneshot::channel();
+    open.fetches.push((param, sender));
+    Some((open.id, open.closes_at, receiver))
+}
+
+/// Takes the fetches of a window, unless another fetch already took them
+fn close_window<Param, T, E>(slot: TypeId, id: u64) -> Option<Vec<PendingFetch<Param, T, E>>>
+where
+    Param: Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    let mut windows = OPEN_WINDOWS.get()?.lock().ok()?;
+    let open = windows
+        .get(&slot)?
+        .downcast_ref::<BatchWindow<Param, T, E>>()?;
+    if open.id != id {
+        return None;
+    }
+    let open = windows.remove(&slot)?;
+    let open = open.downcast::<BatchWindow<Param, T, E>>().ok()?;
+    Some(open.fetches)
+}
+
+/// Fetches the parameters of a closed window at once and answers each fetch
+///
+/// Equal parameters are fetched once and their result is shared. A fetch left without a
+/// result, e.g. because `run_batch` returned too few, falls back to fetching alone.
+async fn run_batch<P, Param>(provider: &P, fetches: Vec<PendingFetch<Param, P::Output, P::Error>>)
+where
+    P: BatchProvider<Param>,
+    Param: ProviderParamBounds,
+{
+    let mut params: Vec<Param> = Vec::new();
+    let mut waiting = Vec::new();
+    for (param, sender) in fetches {
+        let index = match params.iter().position(|known| *known == param) {
+            Some(index) => index,
+            None => {
+                params.push(param);
+                params.len() - 1
+            }
+        };
+        waiting.push((index, sender));
+    }
+    debug!(
+        "📦 [BATCH-WINDOW] Fetching {} parameters for {} fetches",
+        params.len(),
+        waiting.len()
+    );
+
+    let results = provider.run_batch(&params).await;
+    // Fetches left without a result see their sender dropped and fetch on their own
+    for (index, sender) in waiting {
+        if let Some(result) = results.get(index) {
+            let _ = sender.send(result.clone());
+        }
+    }
+}
@@ -3,9 +3,12 @@
 //! This module implements a global, type-erased cache for provider results, supporting:
 //! - **Expiration**: Entries are removed after a configurable TTL.
 //! - **Staleness (SWR)**: Entries can be marked stale and revalidated in the background.
-//! - **LRU Eviction**: Least-recently-used entries are evicted to maintain a size limit.
+//! - **LRU Eviction**: Least-recently-used entries are evicted to maintain a size limit,
+//!   counted in entries and optionally in estimated bytes (see [`SizedCacheValue`]).
 //! - **Reference Counting**: Tracks active users of each entry for safe cleanup.
 //! - **Access/Usage Stats**: Provides statistics for cache introspection and tuning.
+//! - **Change Notification**: Every write or removal is reported to a listener, which the
+//!   global caches use to refresh the components showing the changed keys.
 //!
 //! ## Example
 //! ```rust,no_run
@@ -16,18 +19,31 @@
 //! ```
 //! Cache management and async state types for dioxus-provider
 
+use futures::{
+    Stream, StreamExt,
+    channel::mpsc::{UnboundedSender, unbounded},
+    future, stream,
+};
 use std::{
-    any::Any,
-    collections::HashMap,
+    any::{Any, TypeId},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    hash::{DefaultHasher, Hash, Hasher},
     sync::{
-        Arc, Mutex,
-        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, Weak,
+        atomic::{AtomicU32, AtomicU64, Ordering},
     },
     time::Duration,
 };
+use thiserror::Error;
 use tracing::debug;
 
-use crate::platform::{DEFAULT_MAX_CACHE_SIZE, DEFAULT_UNUSED_THRESHOLD};
+use crate::{
+    platform::{DEFAULT_ENTRY_SIZE, DEFAULT_MAX_CACHE_SIZE, DEFAULT_UNUSED_THRESHOLD},
+    refresh::RefreshRegistry,
+};
+
+#[cfg(all(feature = "disk-persistence", not(target_family = "wasm")))]
+pub mod disk;
 
 // Platform-specific time imports
 #[cfg(not(target_family = "wasm"))]
@@ -39,10 +55,18 @@ use web_time::Instant;
 #[derive(Clone)]
 pub struct CacheEntry {
     data: Arc<dyn Any + Send + Sync>,
+    /// Name of the type the data was stored as
+    type_name: &'static str,
     cached_at: Arc<Mutex<Instant>>,
     reference_count: Arc<AtomicU32>,
     last_accessed: Arc<Mutex<Instant>>,
     access_count: Arc<AtomicU32>,
+    /// Per-entry multiplier applied to expiration and stale times (None means no jitter)
+    expiration_scale: Option<f64>,
+    /// Whether the entry holds a failed provider result
+    is_error: bool,
+    /// Estimated size of the data in bytes (None if its type has no estimate)
+    size: Option<usize>,
 }
 
 impl CacheEntry {
@@ -56,16 +80,51 @@ impl CacheEntry {
     ///
     /// A new `CacheEntry` instance.
     pub fn new<T: Clone + Send + Sync + 'static>(data: T) -> Self {
+        Self::from_shared(Arc::new(data), std::any::type_name::<T>())
+    }
+
+    /// Creates a new cache entry around data that may be shared with other entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The shared data to cache.
+    /// * `type_name` - The name of the data's type.
+    ///
+    /// # Returns
+    ///
+    /// A new `CacheEntry` instance.
+    fn from_shared(data: Arc<dyn Any + Send + Sync>, type_name: &'static str) -> Self {
         let now = Instant::now();
         Self {
-            data: Arc::new(data),
+            data,
+            type_name,
             cached_at: Arc::new(Mutex::new(now)),
             reference_count: Arc::new(AtomicU32::new(0)),
             last_accessed: Arc::new(Mutex::new(now)),
             access_count: Arc::new(AtomicU32::new(0)),
+            expiration_scale: None,
+            is_error: false,
+            size: None,
         }
     }
 
+    /// Replaces the cached data, keeping the entry's references, access statistics and jitter.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The new data.
+    /// * `type_name` - The name of the new data's type.
+    ///
+    /// # Side Effects
+    ///
+    /// Resets the `cached_at` timestamp and drops the size estimate of the old data.
+    fn replace_data(&mut self, data: Arc<dyn Any + Send + Sync>, type_name: &'static str) {
+        self.data = data;
+        self.type_name = type_name;
+        self.size = None;
+        self.refresh_timestamp();
+    }
+
     /// Retrieves the cached data of type `T`.
     ///
     /// # Arguments
@@ -88,6 +147,16 @@ impl CacheEntry {
         self.data.downcast_ref::<T>().cloned()
     }
 
+    /// Gets the name of the type the data was stored as.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Borrows the cached data as type `T` without recording an access.
+    pub(crate) fn peek<T: 'static>(&self) -> Option<&T> {
+        self.data.downcast_ref::<T>()
+    }
+
     /// Refreshes the cached_at timestamp to the current time.
     ///
     /// # Arguments
@@ -103,6 +172,59 @@ impl CacheEntry {
         }
     }
 
+    /// Moves the cached_at timestamp back so the entry is stale for the given stale time.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `CacheEntry`.
+    /// * `stale_time` - The stale time of the provider owning the entry.
+    ///
+    /// # Side Effects
+    ///
+    /// Sets the `cached_at` timestamp to just over `stale_time` (after jitter) ago.
+    pub fn mark_stale(&self, stale_time: Duration) {
+        let age = self.effective_duration(stale_time) + Duration::from_millis(1);
+        if let Ok(mut cached_at) = self.cached_at.lock()
+            && let Some(stale_at) = Instant::now().checked_sub(age)
+        {
+            *cached_at = stale_at;
+        }
+    }
+
+    /// Applies this entry's jitter to an expiration or stale duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `CacheEntry`.
+    /// * `duration` - The configured expiration or stale duration.
+    ///
+    /// # Returns
+    ///
+    /// The duration after jitter, or `duration` unchanged if the entry has no jitter.
+    pub fn effective_duration(&self, duration: Duration) -> Duration {
+        match self.expiration_scale {
+            Some(scale) => duration.mul_f64(scale),
+            None => duration,
+        }
+    }
+
+    /// Assigns a random jitter of up to `max_jitter` (a fraction, e.g. `0.2` for 20%)
+    /// to this entry's expiration and stale times, unless it already has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - A mutable reference to the `CacheEntry`.
+    /// * `max_jitter` - The maximum fraction added to expiration and stale durations.
+    ///
+    /// # Side Effects
+    ///
+    /// Sets the entry's expiration scale to a value in `[1.0, 1.0 + max_jitter]`.
+    pub fn apply_jitter(&mut self, max_jitter: f64) {
+        if self.expiration_scale.is_none() {
+            self.expiration_scale = Some(1.0 + random_unit() * max_jitter.max(0.0));
+        }
+    }
+
     /// Checks if the cache entry has expired based on the given expiration duration.
     ///
     /// # Arguments
@@ -115,7 +237,7 @@ impl CacheEntry {
     /// A boolean indicating whether the entry has expired.
     pub fn is_expired(&self, expiration: Duration) -> bool {
         if let Ok(cached_at) = self.cached_at.lock() {
-            cached_at.elapsed() > expiration
+            cached_at.elapsed() > self.effective_duration(expiration)
         } else {
             false
         }
@@ -133,7 +255,7 @@ impl CacheEntry {
     /// A boolean indicating whether the entry is stale.
     pub fn is_stale(&self, stale_time: Duration) -> bool {
         if let Ok(cached_at) = self.cached_at.lock() {
-            cached_at.elapsed() > stale_time
+            cached_at.elapsed() > self.effective_duration(stale_time)
         } else {
             false
         }
@@ -226,6 +348,56 @@ impl CacheEntry {
         }
     }
 
+    /// Gets a snapshot of this entry's metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `CacheEntry`.
+    ///
+    /// # Returns
+    ///
+    /// A `CacheEntryInfo` describing the entry's age, usage and references.
+    ///
+    /// # Side Effects
+    ///
+    /// None. Unlike `get`, this does not count as an access.
+    pub fn info(&self) -> CacheEntryInfo {
+        CacheEntryInfo {
+            age: self.age(),
+            time_since_last_access: self.time_since_last_access(),
+            access_count: self.access_count(),
+            reference_count: self.reference_count(),
+            is_error: self.is_error,
+        }
+    }
+
+    /// Checks if this entry holds a failed provider result.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `CacheEntry`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the entry was stored from a provider fetch that returned `Err`.
+    pub fn is_error(&self) -> bool {
+        self.is_error
+    }
+
+    /// Gets the estimated size of this entry's data.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `CacheEntry`.
+    ///
+    /// # Returns
+    ///
+    /// The size in bytes recorded from [`SizedCacheValue::approximate_size`], or `None` if the
+    /// entry was stored without an estimate.
+    pub fn approximate_size(&self) -> Option<usize> {
+        self.size
+    }
+
     /// Gets the age of this cache entry.
     ///
     /// # Arguments
@@ -242,12 +414,432 @@ impl CacheEntry {
             Duration::from_secs(0)
         }
     }
+
+    /// Moves the cached_at timestamp back so the entry is `age` old, e.g. when restoring
+    /// an entry saved by an earlier run.
+    ///
+    /// Returns `false` if the platform's clock can't represent a timestamp that far back.
+    #[cfg(all(feature = "disk-persistence", not(target_family = "wasm")))]
+    pub(crate) fn backdate(&self, age: Duration) -> bool {
+        let Some(cached_at) = Instant::now().checked_sub(age) else {
+            return false;
+        };
+        if let Ok(mut timestamp) = self.cached_at.lock() {
+            *timestamp = cached_at;
+        }
+        true
+    }
+}
+
+/// Stores a value in a locked cache map unless an equal value is already cached
+///
+/// Returns whether the value was updated. An unchanged entry only has its timestamp
+/// refreshed.
+fn store_value<T, F>(cache: &mut HashMap<String, CacheEntry>, key: &str, value: T, share: F) -> bool
+where
+    T: Clone + Send + Sync + PartialEq + 'static,
+    F: FnOnce(T) -> Arc<dyn Any + Send + Sync>,
+{
+    if let Some(existing_entry) = cache.get_mut(key)
+        && existing_entry.get::<T>().is_some_and(|existing| existing == value)
+    {
+        existing_entry.refresh_timestamp();
+        debug!(
+            "⏸️ [CACHE-STORE] Value unchanged for key: {}, refreshing timestamp",
+            key
+        );
+        return false;
+    }
+    cache.insert(
+        key.to_string(),
+        CacheEntry::from_shared(share(value), std::any::type_name::<T>()),
+    );
+    debug!("📊 [CACHE-STORE] Stored data for key: {}", key);
+    true
+}
+
+/// Returns a pseudo-random number in `[0.0, 1.0)`
+///
+/// Uses the randomly seeded std hasher, which is plenty for spreading expirations.
+fn random_unit() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::BuildHasher;
+
+    let value = RandomState::new().hash_one(Instant::now());
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// A snapshot of a cache entry's metadata, used by predicates such as [`ProviderCache::retain`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheEntryInfo {
+    /// Time since the entry was stored or last refreshed
+    pub age: Duration,
+    /// Time since the entry was last read
+    pub time_since_last_access: Duration,
+    /// Number of times the entry has been read
+    pub access_count: u32,
+    /// Number of active users of the entry
+    pub reference_count: u32,
+    /// Whether the entry holds a failed provider result
+    pub is_error: bool,
+}
+
+/// Error returned by [`ProviderCache::get_checked`] when an entry holds another type
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("cache entry '{key}' holds a `{found}`, not the requested `{expected}`")]
+pub struct CacheTypeError {
+    /// The key of the entry
+    pub key: String,
+    /// The requested type
+    pub expected: &'static str,
+    /// The type the entry was stored as
+    pub found: &'static str,
+}
+
+/// Type alias for a predicate over a cache key and its entry metadata
+pub type CacheEntryPredicate = dyn Fn(&str, &CacheEntryInfo) -> bool;
+
+/// Eviction and maintenance settings for a [`ProviderCache`]
+///
+/// Each cache carries its own configuration so that logically separated caches
+/// (see [`crate::global::init_named_cache`]) can use independent eviction policies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheConfig {
+    /// Maximum number of entries kept before LRU eviction kicks in
+    pub max_size: usize,
+    /// Entries not accessed for this long are removed during maintenance
+    pub unused_threshold: Duration,
+    /// Maximum estimated size of all entries in bytes (None means only `max_size` applies)
+    pub max_bytes: Option<usize>,
+    /// Size in bytes assumed for entries stored without a [`SizedCacheValue`] estimate
+    pub default_entry_size: usize,
+    /// How often the entries of providers without their own `check_interval` are checked for
+    /// expiration and cleanup (None derives the interval from each provider's expiration, as
+    /// does a zero interval, which would check in a busy loop)
+    pub check_interval: Option<Duration>,
+    /// How entries are picked for eviction once the cache is over its limits
+    pub eviction_policy: EvictionPolicy,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_CACHE_SIZE,
+            unused_threshold: DEFAULT_UNUSED_THRESHOLD,
+            max_bytes: None,
+            default_entry_size: DEFAULT_ENTRY_SIZE,
+            check_interval: None,
+            eviction_policy: EvictionPolicy::default(),
+        }
+    }
+}
+
+/// How a [`ProviderCache`] picks the entries to evict once it is over its limits
+///
+/// Pure LRU evicts an entry read all the time over one touched once a moment ago. The
+/// frequency-aware policies keep such hot entries, which helps when a few popular providers
+/// are read among many rarely used ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evict the least recently accessed entries first
+    #[default]
+    Lru,
+    /// Evict the least frequently accessed entries first, the least recent among equals
+    Lfu,
+    /// Evict entries accessed at most once before those accessed again, each group least
+    /// recently accessed first, like a simplified 2Q
+    TwoQueue,
+}
+
+impl EvictionPolicy {
+    /// Orders two entries by how much they are worth keeping, the most valuable first
+    fn compare(self, a: &CacheEntry, b: &CacheEntry) -> std::cmp::Ordering {
+        let by_recency = a.time_since_last_access().cmp(&b.time_since_last_access());
+        match self {
+            EvictionPolicy::Lru => by_recency,
+            EvictionPolicy::Lfu => b.access_count().cmp(&a.access_count()).then(by_recency),
+            EvictionPolicy::TwoQueue => (b.access_count() > 1)
+                .cmp(&(a.access_count() > 1))
+                .then(by_recency),
+        }
+    }
+}
+
+/// A value whose memory footprint can be estimated, for byte-based cache eviction
+///
+/// The estimate includes the value itself and the heap memory it owns. The default
+/// implementation only counts the value's inline size, which is exact for types without
+/// heap data, so those can implement the trait with an empty `impl` block. Providers
+/// declared with `#[provider(sized = true)]` record the estimate of every result they cache.
+///
+/// ```rust
+/// use dioxus_provider::cache::SizedCacheValue;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Message {
+///     id: u64,
+///     body: String,
+/// }
+///
+/// impl SizedCacheValue for Message {
+///     fn approximate_size(&self) -> usize {
+///         std::mem::size_of::<Self>() + self.body.capacity()
+///     }
+/// }
+/// ```
+pub trait SizedCacheValue {
+    /// Gets the estimated size of the value in bytes
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
+
+macro_rules! impl_inline_sized {
+    ($($ty:ty),* $(,)?) => {
+        $(impl SizedCacheValue for $ty {})*
+    };
+}
+
+impl_inline_sized!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    &'static str,
+    Duration,
+);
+
+impl SizedCacheValue for String {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.capacity()
+    }
+}
+
+impl<T: SizedCacheValue> SizedCacheValue for Option<T> {
+    fn approximate_size(&self) -> usize {
+        let inline = std::mem::size_of::<Self>();
+        match self {
+            Some(value) => inline - std::mem::size_of::<T>() + value.approximate_size(),
+            None => inline,
+        }
+    }
+}
+
+impl<T: SizedCacheValue, E: SizedCacheValue> SizedCacheValue for Result<T, E> {
+    fn approximate_size(&self) -> usize {
+        let inline = std::mem::size_of::<Self>();
+        match self {
+            Ok(value) => inline - std::mem::size_of::<T>() + value.approximate_size(),
+            Err(error) => inline - std::mem::size_of::<E>() + error.approximate_size(),
+        }
+    }
+}
+
+impl<T: SizedCacheValue + ?Sized> SizedCacheValue for Box<T> {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + (**self).approximate_size()
+    }
+}
+
+impl<T: SizedCacheValue + ?Sized> SizedCacheValue for Arc<T> {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + (**self).approximate_size()
+    }
+}
+
+impl<A: SizedCacheValue, B: SizedCacheValue> SizedCacheValue for (A, B) {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>() - std::mem::size_of::<A>() - std::mem::size_of::<B>()
+            + self.0.approximate_size()
+            + self.1.approximate_size()
+    }
+}
+
+/// Sums the inline size of a collection and the estimates of its items
+fn collection_size<'a, T: SizedCacheValue + 'a>(
+    inline: usize,
+    items: impl IntoIterator<Item = &'a T>,
+) -> usize {
+    inline + items.into_iter().map(T::approximate_size).sum::<usize>()
+}
+
+impl<T: SizedCacheValue> SizedCacheValue for Vec<T> {
+    fn approximate_size(&self) -> usize {
+        collection_size(std::mem::size_of::<Self>(), self)
+    }
+}
+
+impl<T: SizedCacheValue> SizedCacheValue for VecDeque<T> {
+    fn approximate_size(&self) -> usize {
+        collection_size(std::mem::size_of::<Self>(), self)
+    }
+}
+
+impl<T: SizedCacheValue> SizedCacheValue for HashSet<T> {
+    fn approximate_size(&self) -> usize {
+        collection_size(std::mem::size_of::<Self>(), self)
+    }
+}
+
+impl<T: SizedCacheValue> SizedCacheValue for BTreeSet<T> {
+    fn approximate_size(&self) -> usize {
+        collection_size(std::mem::size_of::<Self>(), self)
+    }
+}
+
+impl<K: SizedCacheValue, V: SizedCacheValue> SizedCacheValue for HashMap<K, V> {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self
+                .iter()
+                .map(|(key, value)| key.approximate_size() + value.approximate_size())
+                .sum::<usize>()
+    }
+}
+
+impl<K: SizedCacheValue, V: SizedCacheValue> SizedCacheValue for BTreeMap<K, V> {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self
+                .iter()
+                .map(|(key, value)| key.approximate_size() + value.approximate_size())
+                .sum::<usize>()
+    }
+}
+
+/// Type alias for the table of interned values, keyed by a hash of their type and content
+type InternTable = Arc<Mutex<HashMap<u64, Vec<Weak<dyn Any + Send + Sync>>>>>;
+
+/// Type alias for the index of cache keys, keyed by the entity their entries belong to
+type EntityIndex = Arc<Mutex<HashMap<String, HashSet<String>>>>;
+
+/// Type alias for the index of cache keys, keyed by the provider tags of their entries
+type TagIndex = Arc<Mutex<HashMap<&'static str, HashSet<String>>>>;
+
+/// Type alias for the opaque metadata of each cache key, such as the `ETag` of its response
+type EntryMeta = Arc<Mutex<HashMap<String, HashMap<String, String>>>>;
+
+/// Type alias for the listener told about the keys whose entries were written or removed
+type ChangeListener = Arc<Mutex<Option<Arc<dyn Fn(&[String]) + Send + Sync>>>>;
+
+/// Type alias for the channels of the streams watching each key, created on first watch
+type KeyWatchers = Arc<Mutex<HashMap<String, Vec<UnboundedSender<()>>>>>;
+
+/// Type alias for the channels of the streams receiving every change to the cache
+type ChangeStreams = Arc<Mutex<Vec<UnboundedSender<Vec<String>>>>>;
+
+/// Type alias for the channels of the streams receiving the keys whose fetches start or finish
+type FetchStreams = Arc<Mutex<Vec<UnboundedSender<String>>>>;
+
+/// Type alias for the provider type that owns each cache key, tracked in debug builds only
+#[cfg(debug_assertions)]
+type KeyOwners = Arc<Mutex<HashMap<String, &'static str>>>;
+
+/// Type alias for the described parameter of each cache key, tracked in debug builds only
+#[cfg(debug_assertions)]
+type KeyParams = Arc<Mutex<HashMap<String, String>>>;
+
+/// Type alias for the keys whose entries must never be persisted or exported
+type NoPersistKeys = Arc<Mutex<HashSet<String>>>;
+
+/// Type alias for the provider type name and parameter key each provider entry is persisted
+/// under, or `None` for entries persisted under their cache key
+#[cfg(feature = "disk-persistence")]
+type PersistedKeys = Arc<Mutex<HashMap<String, Option<(&'static str, String)>>>>;
+
+/// Type alias for a number of running fetches or pending mutations held for each key
+type KeyCounts = Arc<Mutex<HashMap<String, usize>>>;
+
+/// Running totals of the entries a cache dropped to stay within its limits
+#[derive(Default)]
+struct RemovalCounters {
+    evictions: AtomicU64,
+    expirations: AtomicU64,
+}
+
+/// A count held for a key by [`ProviderCache::begin_fetch`] or
+/// [`ProviderCache::hold_optimistic`], released when dropped
+pub(crate) struct KeyGuard {
+    counts: KeyCounts,
+    key: String,
+    streams: Option<FetchStreams>,
+}
+
+impl KeyGuard {
+    /// Count one more holder of `key`, reporting it to `streams` if given
+    fn hold(counts: &KeyCounts, key: &str, streams: Option<&FetchStreams>) -> Self {
+        if let Ok(mut counts) = counts.lock() {
+            *counts.entry(key.to_string()).or_insert(0) += 1;
+        }
+        let guard = Self {
+            counts: counts.clone(),
+            key: key.to_string(),
+            streams: streams.cloned(),
+        };
+        guard.report();
+        guard
+    }
+
+    /// Send the key to the streams watching its holders
+    fn report(&self) {
+        if let Some(streams) = &self.streams
+            && let Ok(mut streams) = streams.lock()
+        {
+            streams.retain(|sender| sender.unbounded_send(self.key.clone()).is_ok());
+        }
+    }
+}
+
+impl Drop for KeyGuard {
+    fn drop(&mut self) {
+        if let Ok(mut counts) = self.counts.lock()
+            && let Some(count) = counts.get_mut(&self.key)
+        {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&self.key);
+            }
+        }
+        self.report();
+    }
 }
 
 /// Global cache for provider results with automatic cleanup
 #[derive(Clone, Default)]
 pub struct ProviderCache {
     pub cache: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    config: CacheConfig,
+    interned: InternTable,
+    entities: EntityIndex,
+    tags: TagIndex,
+    meta: EntryMeta,
+    change_listener: ChangeListener,
+    watchers: KeyWatchers,
+    change_streams: ChangeStreams,
+    removals: Arc<RemovalCounters>,
+    fetching: KeyCounts,
+    fetch_streams: FetchStreams,
+    optimistic: KeyCounts,
+    no_persist: NoPersistKeys,
+    #[cfg(feature = "disk-persistence")]
+    persisted_keys: PersistedKeys,
+    #[cfg(debug_assertions)]
+    key_owners: KeyOwners,
+    #[cfg(debug_assertions)]
+    key_params: KeyParams,
 }
 
 impl ProviderCache {
@@ -260,86 +852,492 @@ impl ProviderCache {
         Self::default()
     }
 
-    /// Retrieves a cached result by key.
+    /// Creates a new provider cache with the given eviction settings.
     ///
     /// # Arguments
     ///
-    /// * `&self` - A reference to the `ProviderCache`.
-    /// * `key` - The key to retrieve.
+    /// * `config` - The eviction settings used by [`ProviderCache::maintain`] and the
+    ///   background cleanup tasks.
     ///
     /// # Returns
     ///
-    /// An `Option<T>` containing the cached data if available, or `None` if not found.
-    ///
-    /// # Side Effects
-    ///
-    /// None.
-    pub fn get<T: Clone + Send + Sync + 'static>(&self, key: &str) -> Option<T> {
-        self.cache.lock().ok()?.get(key)?.get::<T>()
+    /// A new `ProviderCache` instance.
+    pub fn with_config(mut config: CacheConfig) -> Self {
+        if config.check_interval == Some(Duration::ZERO) {
+            tracing::warn!(
+                "⚠️ [CACHE] Ignoring a zero check_interval - deriving it from each provider's expiration"
+            );
+            config.check_interval = None;
+        }
+        Self {
+            cache: Arc::default(),
+            config,
+            interned: InternTable::default(),
+            entities: EntityIndex::default(),
+            tags: TagIndex::default(),
+            meta: EntryMeta::default(),
+            change_listener: ChangeListener::default(),
+            watchers: KeyWatchers::default(),
+            change_streams: ChangeStreams::default(),
+            removals: Arc::default(),
+            fetching: KeyCounts::default(),
+            fetch_streams: FetchStreams::default(),
+            optimistic: KeyCounts::default(),
+            no_persist: NoPersistKeys::default(),
+            #[cfg(feature = "disk-persistence")]
+            persisted_keys: PersistedKeys::default(),
+            #[cfg(debug_assertions)]
+            key_owners: KeyOwners::default(),
+            #[cfg(debug_assertions)]
+            key_params: KeyParams::default(),
+        }
     }
 
-    /// Retrieves a cached result by key, checking for expiration with a specific expiration duration.
+    /// Gets the eviction settings of this cache.
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// Sets the listener told about every change to the cache, replacing the previous one.
+    ///
+    /// The listener receives the keys whose entries were written with a different value or
+    /// removed, including removals by expiration, eviction and cleanup. It runs after the
+    /// cache lock is released, so it may read the cache. The global caches use it to refresh
+    /// the components showing the changed keys, so writes made outside of components don't
+    /// need a separate `trigger_refresh`.
     ///
     /// # Arguments
     ///
     /// * `&self` - A reference to the `ProviderCache`.
-    /// * `key` - The key to retrieve.
-    /// * `expiration` - An optional duration after which the entry is considered expired.
+    /// * `listener` - Called with the changed keys.
+    pub fn on_change(&self, listener: impl Fn(&[String]) + Send + Sync + 'static) {
+        if let Ok(mut change_listener) = self.change_listener.lock() {
+            *change_listener = Some(Arc::new(listener));
+        }
+    }
+
+    /// Refreshes the subscribers of `registry` on every change to the cache.
     ///
-    /// # Returns
+    /// Sets the change listener (see [`ProviderCache::on_change`]) so that writes and
+    /// removals, including `clear` and `invalidate`, mark the components showing the changed
+    /// keys dirty no matter who calls them. The global and named caches are connected to
+    /// their registries when they are initialized; call this for caches created by hand.
     ///
-    /// An `Option<T>` containing the cached data if available and not expired, or `None` if expired.
+    /// The listener holds the registry weakly, so the registry's tasks holding this cache
+    /// don't keep both alive; changes made after the registry was dropped refresh nothing.
     ///
-    /// # Side Effects
+    /// # Arguments
     ///
-    /// If expired, the entry is removed from the cache.
-    pub fn get_with_expiration<T: Clone + Send + Sync + 'static>(
-        &self,
-        key: &str,
-        expiration: Option<Duration>,
-    ) -> Option<T> {
-        // First, check if the entry exists and is expired
-        let is_expired = {
-            let cache_guard = self.cache.lock().ok()?;
-            let entry = cache_guard.get(key)?;
-
-            if let Some(exp_duration) = expiration {
-                entry.is_expired(exp_duration)
-            } else {
-                false
-            }
-        };
-
-        // If expired, remove the entry
-        if is_expired {
-            if let Ok(mut cache) = self.cache.lock() {
-                cache.remove(key);
-                debug!(
-                    "🗑️ [CACHE-EXPIRATION] Removing expired cache entry for key: {}",
-                    key
-                );
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `registry` - The registry whose subscribers are refreshed.
+    pub fn connect_refresh_registry(&self, registry: &RefreshRegistry) {
+        let registry = registry.downgrade();
+        self.on_change(move |keys| {
+            if let Some(registry) = registry.upgrade() {
+                registry.trigger_refresh_many(keys);
             }
-            return None;
-        }
-
-        // Entry is not expired, return the data
-        let cache_guard = self.cache.lock().ok()?;
-        let entry = cache_guard.get(key)?;
-        entry.get::<T>()
+        });
     }
 
-    /// Retrieves cached data with staleness information for SWR behavior.
+    /// Watches a single key, yielding its value as type `T` whenever the entry changes.
+    ///
+    /// The stream starts with the current value, then yields the new value after every
+    /// write with a different value and `None` once the entry is removed, e.g. by
+    /// `invalidate`, expiration, eviction or cleanup. An entry holding another type reads
+    /// as `None`. Reading the value doesn't count as an access. Useful for bridging a
+    /// provider's entry into another reactive system; dropping the stream stops watching,
+    /// and its channel is forgotten by the next change or `watch` call.
     ///
     /// # Arguments
     ///
     /// * `&self` - A reference to the `ProviderCache`.
-    /// * `key` - The key to retrieve.
-    /// * `stale_time` - An optional duration after which the entry is considered stale.
-    /// * `expiration` - An optional duration after which the entry is considered expired.
+    /// * `key` - The key to watch.
     ///
     /// # Returns
     ///
-    /// An `Option<(T, bool)>` containing the cached data and a boolean indicating staleness.
+    /// A stream of the entry's values.
+    pub fn watch<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: &str,
+    ) -> impl Stream<Item = Option<T>> + Send + 'static {
+        let (sender, receiver) = unbounded();
+        if let Ok(mut watchers) = self.watchers.lock() {
+            // Keys that never change again would otherwise hold dropped streams forever
+            watchers.retain(|_, senders| {
+                senders.retain(|sender| !sender.is_closed());
+                !senders.is_empty()
+            });
+            watchers.entry(key.to_string()).or_default().push(sender);
+        }
+        let current = self.peek_value::<T>(key);
+        let cache = self.clone();
+        let key = key.to_string();
+        stream::once(future::ready(current))
+            .chain(receiver.map(move |()| cache.peek_value::<T>(&key)))
+    }
+
+    /// Streams the keys of every change to the cache.
+    ///
+    /// Each item holds the keys of one write or removal, the same keys the change listener
+    /// (see [`ProviderCache::on_change`]) receives. Unlike the listener, any number of
+    /// streams can be open, so they suit background work such as persisting the cache
+    /// after changes. Dropping the stream stops it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    ///
+    /// # Returns
+    ///
+    /// A stream of changed keys.
+    pub fn changes(&self) -> impl Stream<Item = Vec<String>> + Send + 'static {
+        let (sender, receiver) = unbounded();
+        if let Ok(mut streams) = self.change_streams.lock() {
+            streams.retain(|sender| !sender.is_closed());
+            streams.push(sender);
+        }
+        receiver
+    }
+
+    /// Checks whether a fetch of a key is running.
+    ///
+    /// Covers every fetch storing its result in this cache: first loads, background
+    /// revalidations, interval refreshes and `fetch_provider` calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to check.
+    ///
+    /// # Returns
+    ///
+    /// True while at least one fetch of the key runs.
+    pub fn is_fetching(&self, key: &str) -> bool {
+        self.fetching
+            .lock()
+            .is_ok_and(|fetching| fetching.contains_key(key))
+    }
+
+    /// Streams the keys whose fetches start or finish.
+    ///
+    /// Yields a key whenever a fetch of it starts or completes, so [`ProviderCache::is_fetching`]
+    /// may have changed. Fetches don't change entries until they store their result, so unlike
+    /// [`ProviderCache::changes`] this reports background revalidations as they begin. Dropping
+    /// the stream stops it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    ///
+    /// # Returns
+    ///
+    /// A stream of keys whose fetches started or finished.
+    pub fn fetch_activity(&self) -> impl Stream<Item = String> + Send + 'static {
+        let (sender, receiver) = unbounded();
+        if let Ok(mut streams) = self.fetch_streams.lock() {
+            streams.retain(|sender| !sender.is_closed());
+            streams.push(sender);
+        }
+        receiver
+    }
+
+    /// Records a fetch of a key, running until the returned guard is dropped
+    pub(crate) fn begin_fetch(&self, key: &str) -> KeyGuard {
+        KeyGuard::hold(&self.fetching, key, Some(&self.fetch_streams))
+    }
+
+    /// Checks whether a key holds an optimistic value of a mutation that is still running
+    ///
+    /// Fetched results for the key are not stored meanwhile, so a fetch that started before
+    /// the mutation can't overwrite the optimistic value with data from before the mutation.
+    pub fn has_pending_optimistic(&self, key: &str) -> bool {
+        self.optimistic
+            .lock()
+            .is_ok_and(|optimistic| optimistic.contains_key(key))
+    }
+
+    /// Marks a key as holding an optimistic value until the returned guard is dropped
+    pub(crate) fn hold_optimistic(&self, key: &str) -> KeyGuard {
+        KeyGuard::hold(&self.optimistic, key, None)
+    }
+
+    /// Reads the value of a key as type `T` without recording an access
+    fn peek_value<T: Clone + Send + Sync + 'static>(&self, key: &str) -> Option<T> {
+        self.cache.lock().ok()?.get(key)?.peek::<T>().cloned()
+    }
+
+    /// Reports changed keys to the change listener, if any, and to the streams watching them
+    /// or the whole cache
+    fn notify_changed(&self, keys: &[String]) {
+        if keys.is_empty() {
+            return;
+        }
+        if let Ok(mut watchers) = self.watchers.lock() {
+            for key in keys {
+                let Some(senders) = watchers.get_mut(key) else {
+                    continue;
+                };
+                senders.retain(|sender| sender.unbounded_send(()).is_ok());
+                if senders.is_empty() {
+                    watchers.remove(key);
+                }
+            }
+        }
+        if let Ok(mut streams) = self.change_streams.lock() {
+            streams.retain(|sender| sender.unbounded_send(keys.to_vec()).is_ok());
+        }
+        let listener = self
+            .change_listener
+            .lock()
+            .ok()
+            .and_then(|listener| listener.clone());
+        if let Some(listener) = listener {
+            listener(keys);
+        }
+    }
+
+    /// Records `owner` as the provider type using `key` and panics if another provider
+    /// type already used it.
+    ///
+    /// Two providers sharing a key (for example through a custom [`crate::hooks::Provider::id`])
+    /// would silently overwrite each other's entries. The hooks call this before reading or
+    /// writing a provider's entry so the collision surfaces during development. An owner is
+    /// forgotten once its entry is removed, so another provider may reuse the key afterwards.
+    /// In release builds this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `key` is already owned by a different provider type.
+    pub fn assert_key_owner(&self, key: &str, owner: &'static str) {
+        #[cfg(debug_assertions)]
+        {
+            let existing = match self.key_owners.lock() {
+                Ok(mut owners) => *owners.entry(key.to_string()).or_insert(owner),
+                Err(_) => return,
+            };
+            // Panic after the lock guard is dropped so the mutex isn't poisoned
+            assert!(
+                existing == owner,
+                "cache key '{key}' is used by two different providers: '{existing}' and '{owner}'; \
+                 give one of them a distinct `Provider::id`"
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = (key, owner);
+    }
+
+    /// Gets the provider type that used `key`, as recorded by [`ProviderCache::assert_key_owner`].
+    ///
+    /// Only tracked in debug builds; always `None` in release builds.
+    pub fn key_owner(&self, key: &str) -> Option<&'static str> {
+        #[cfg(debug_assertions)]
+        {
+            self.key_owners.lock().ok()?.get(key).copied()
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = key;
+            None
+        }
+    }
+
+    /// Records the parameter of the provider using `key`, as described by
+    /// [`crate::hooks::Provider::describe_param`].
+    ///
+    /// The hooks call this when storing a provider's result, so devtools can label entries
+    /// without the parameter's `Debug` output. In release builds this does nothing and
+    /// `describe` is never called.
+    pub fn record_key_param(&self, key: &str, describe: impl FnOnce() -> String) {
+        #[cfg(debug_assertions)]
+        if let Ok(mut params) = self.key_params.lock()
+            && !params.contains_key(key)
+        {
+            params.insert(key.to_string(), describe());
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = (key, describe);
+    }
+
+    /// Gets the described parameter of `key`, as recorded by [`ProviderCache::record_key_param`].
+    ///
+    /// Only tracked in debug builds; always `None` in release builds.
+    pub fn key_param(&self, key: &str) -> Option<String> {
+        #[cfg(debug_assertions)]
+        {
+            self.key_params.lock().ok()?.get(key).cloned()
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = key;
+            None
+        }
+    }
+
+    /// Forgets the owner, parameter, persisted key, tags and entity recorded for removed keys
+    fn forget_keys<S: AsRef<str>>(&self, keys: &[S]) {
+        #[cfg(feature = "disk-persistence")]
+        if let Ok(mut persisted) = self.persisted_keys.lock() {
+            for key in keys {
+                persisted.remove(key.as_ref());
+            }
+        }
+        if let Ok(mut tags) = self.tags.lock() {
+            tags.retain(|_, tagged| {
+                for key in keys {
+                    tagged.remove(key.as_ref());
+                }
+                !tagged.is_empty()
+            });
+        }
+        if let Ok(mut entities) = self.entities.lock() {
+            entities.retain(|_, members| {
+                for key in keys {
+                    members.remove(key.as_ref());
+                }
+                !members.is_empty()
+            });
+        }
+        #[cfg(debug_assertions)]
+        {
+            if let Ok(mut owners) = self.key_owners.lock() {
+                for key in keys {
+                    owners.remove(key.as_ref());
+                }
+            }
+            if let Ok(mut params) = self.key_params.lock() {
+                for key in keys {
+                    params.remove(key.as_ref());
+                }
+            }
+        }
+    }
+
+    /// Retrieves a cached result by key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<T>` containing the cached data if available, or `None` if not found.
+    ///
+    /// # Side Effects
+    ///
+    /// None.
+    pub fn get<T: Clone + Send + Sync + 'static>(&self, key: &str) -> Option<T> {
+        self.cache.lock().ok()?.get(key)?.get::<T>()
+    }
+
+    /// Retrieves a cached result by key, reporting an entry stored as another type as an error.
+    ///
+    /// [`ProviderCache::get`] returns `None` both for a missing key and for a key holding a
+    /// different type, which hides mistakes such as reading `User` from an entry holding
+    /// `Result<User, String>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if no entry is cached for the key, `Ok(Some(value))` if it holds a `T`, or
+    /// a `CacheTypeError` naming the stored type otherwise.
+    ///
+    /// # Side Effects
+    ///
+    /// Reading a `T` counts as an access, like `get`.
+    pub fn get_checked<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: &str,
+    ) -> Result<Option<T>, CacheTypeError> {
+        let Ok(cache) = self.cache.lock() else {
+            return Ok(None);
+        };
+        let Some(entry) = cache.get(key) else {
+            return Ok(None);
+        };
+        match entry.peek::<T>() {
+            Some(_) => Ok(entry.get::<T>()),
+            None => Err(CacheTypeError {
+                key: key.to_string(),
+                expected: std::any::type_name::<T>(),
+                found: entry.type_name,
+            }),
+        }
+    }
+
+    /// Retrieves a cached result by key, checking for expiration with a specific expiration duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to retrieve.
+    /// * `expiration` - An optional duration after which the entry is considered expired.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<T>` containing the cached data if available and not expired, or `None` if expired.
+    ///
+    /// # Side Effects
+    ///
+    /// If expired, the entry is removed from the cache.
+    pub fn get_with_expiration<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: &str,
+        expiration: Option<Duration>,
+    ) -> Option<T> {
+        // First, check if the entry exists and is expired
+        let is_expired = {
+            let cache_guard = self.cache.lock().ok()?;
+            let entry = cache_guard.get(key)?;
+
+            if let Some(exp_duration) = expiration {
+                entry.is_expired(exp_duration)
+            } else {
+                false
+            }
+        };
+
+        // If expired, remove the entry
+        if is_expired {
+            let removed = match self.cache.lock() {
+                Ok(mut cache) => cache.remove(key).is_some(),
+                Err(_) => false,
+            };
+            if removed {
+                debug!(
+                    "🗑️ [CACHE-EXPIRATION] Removing expired cache entry for key: {}",
+                    key
+                );
+                self.record_expiration();
+                self.forget_keys(&[key]);
+                self.prune_interned();
+                self.notify_changed(&[key.to_string()]);
+            }
+            return None;
+        }
+
+        // Entry is not expired, return the data
+        let cache_guard = self.cache.lock().ok()?;
+        let entry = cache_guard.get(key)?;
+        entry.get::<T>()
+    }
+
+    /// Retrieves cached data with staleness information for SWR behavior.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to retrieve.
+    /// * `stale_time` - An optional duration after which the entry is considered stale.
+    /// * `expiration` - An optional duration after which the entry is considered expired.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<(T, bool)>` containing the cached data and a boolean indicating staleness.
     ///
     /// # Side Effects
     ///
@@ -370,83 +1368,951 @@ impl ProviderCache {
             false
         };
 
-        Some((data, is_stale))
+        Some((data, is_stale))
+    }
+
+    /// Sets a value for a given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to set.
+    /// * `value` - The value to set.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the value was updated (true) or unchanged (false).
+    ///
+    /// # Side Effects
+    ///
+    /// Updates the `cached_at` timestamp if the value was updated.
+    pub fn set<T: Clone + Send + Sync + PartialEq + 'static>(&self, key: String, value: T) -> bool {
+        self.set_with(key, value, |value| Arc::new(value))
+    }
+
+    /// Sets a value for a given key, overwriting any existing value without comparing.
+    ///
+    /// Unlike [`ProviderCache::set`], the new value is not compared against the cached one,
+    /// which saves cloning and comparing a large value when the caller knows it changed,
+    /// e.g. a fetch that missed the cache. Subscribers are always notified.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to set.
+    /// * `value` - The value to set.
+    ///
+    /// # Returns
+    ///
+    /// Always true, as the value is always treated as updated.
+    ///
+    /// # Side Effects
+    ///
+    /// Replaces the data of an existing entry, keeping its references, access statistics and
+    /// expiration jitter, and resets its `cached_at` timestamp.
+    pub fn set_unconditional<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: String,
+        value: T,
+    ) -> bool {
+        if let Ok(mut cache) = self.cache.lock() {
+            match cache.get_mut(&key) {
+                Some(entry) => entry.replace_data(Arc::new(value), std::any::type_name::<T>()),
+                None => {
+                    cache.insert(key.clone(), CacheEntry::new(value));
+                }
+            }
+            debug!("📊 [CACHE-STORE] Stored data for key: {}", key);
+        }
+        self.notify_changed(&[key]);
+        true
+    }
+
+    /// Sets a value for a given key, sharing one allocation between equal values.
+    ///
+    /// Family providers often produce identical outputs for many parameters. Values
+    /// stored with this method are interned by content hash, so every key holding an
+    /// equal value points at the same allocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to set.
+    /// * `value` - The value to set.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the value was updated (true) or unchanged (false).
+    ///
+    /// # Side Effects
+    ///
+    /// Updates the `cached_at` timestamp if the value was updated.
+    pub fn set_deduped<T: Clone + Send + Sync + PartialEq + Hash + 'static>(
+        &self,
+        key: String,
+        value: T,
+    ) -> bool {
+        self.set_with(key, value, |value| self.intern(value))
+    }
+
+    /// Sets a value for a given key, recording its estimated size for byte-based eviction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to set.
+    /// * `value` - The value to set.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the value was updated (true) or unchanged (false).
+    ///
+    /// # Side Effects
+    ///
+    /// Updates the `cached_at` timestamp if the value was updated.
+    pub fn set_sized<T: Clone + Send + Sync + PartialEq + SizedCacheValue + 'static>(
+        &self,
+        key: String,
+        value: T,
+    ) -> bool {
+        let size = value.approximate_size();
+        let updated = self.set(key.clone(), value);
+        self.record_size(&key, size);
+        updated
+    }
+
+    /// Merges a fetched list into the list cached for a key, without storing it.
+    ///
+    /// Fetched items whose `item_key` matches a cached item replace it in place, the others
+    /// are appended. Once the list holds more than `max_items`, the oldest items are dropped
+    /// from its front. Used by `#[provider(accumulate = ...)]` to grow a feed from fetches
+    /// that return only new items.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the cached list.
+    /// * `fetched` - The fetched result; errors are returned only if no list is cached.
+    /// * `item_key` - Identifies an item, so a refetched item replaces its cached copy.
+    /// * `max_items` - The maximum length of the merged list (None keeps every item).
+    ///
+    /// # Returns
+    ///
+    /// The merged list, or the fetched list if no successful list is cached for the key. A
+    /// failed fetch returns the cached list unchanged, so a poll error doesn't drop the items
+    /// accumulated so far.
+    ///
+    /// # Side Effects
+    ///
+    /// None. Reading the cached list does not count as an access.
+    pub fn merge_accumulated<Item, K, E>(
+        &self,
+        key: &str,
+        fetched: Result<Vec<Item>, E>,
+        item_key: impl Fn(&Item) -> K,
+        max_items: Option<usize>,
+    ) -> Result<Vec<Item>, E>
+    where
+        Item: Clone + Send + Sync + 'static,
+        K: Eq + Hash,
+        E: Clone + Send + Sync + 'static,
+    {
+        let cached: Option<Vec<Item>> = self.cache.lock().ok().and_then(|cache| {
+            let entry = cache.get(key)?;
+            // Lists of an `ArcProvider` are cached behind an `Arc`
+            match entry.peek::<Result<Vec<Item>, E>>() {
+                Some(cached) => cached.as_ref().ok().cloned(),
+                None => entry
+                    .peek::<Result<Arc<Vec<Item>>, E>>()?
+                    .as_ref()
+                    .ok()
+                    .map(|items| items.to_vec()),
+            }
+        });
+        let (fetched, mut items) = match (fetched, cached) {
+            (Ok(fetched), cached) => (fetched, cached.unwrap_or_default()),
+            (Err(_), Some(cached)) => {
+                debug!(
+                    "⚠️ [CACHE-ACCUMULATE] Keeping the cached list after a failed fetch for key: {}",
+                    key
+                );
+                return Ok(cached);
+            }
+            (Err(error), None) => return Err(error),
+        };
+
+        let mut positions: HashMap<K, usize> = items
+            .iter()
+            .enumerate()
+            .map(|(position, item)| (item_key(item), position))
+            .collect();
+        for item in fetched {
+            let id = item_key(&item);
+            match positions.get(&id) {
+                Some(&position) => items[position] = item,
+                None => {
+                    positions.insert(id, items.len());
+                    items.push(item);
+                }
+            }
+        }
+
+        if let Some(max_items) = max_items
+            && items.len() > max_items
+        {
+            let dropped = items.len() - max_items;
+            items.drain(..dropped);
+            debug!(
+                "✂️ [CACHE-ACCUMULATE] Dropped {} oldest items for key: {}",
+                dropped, key
+            );
+        }
+        Ok(items)
+    }
+
+    /// Sets many values of the same type while taking the cache lock once.
+    ///
+    /// Each value is stored like [`ProviderCache::set`] would store it, but the whole batch
+    /// becomes visible to readers at once. Pair it with
+    /// [`RefreshRegistry::trigger_refresh_many`](crate::refresh::RefreshRegistry::trigger_refresh_many)
+    /// to refresh the subscribers of the updated keys in one pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `entries` - The keys and values to set.
+    ///
+    /// # Returns
+    ///
+    /// The keys whose value was updated. Keys holding an equal value are left out.
+    ///
+    /// # Side Effects
+    ///
+    /// Updates the `cached_at` timestamp of every key in the batch.
+    pub fn set_many<T, I>(&self, entries: I) -> Vec<String>
+    where
+        T: Clone + Send + Sync + PartialEq + 'static,
+        I: IntoIterator<Item = (String, T)>,
+    {
+        let updated: Vec<String> = {
+            let Ok(mut cache) = self.cache.lock() else {
+                return Vec::new();
+            };
+            entries
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    store_value(&mut cache, &key, value, |value| Arc::new(value)).then_some(key)
+                })
+                .collect()
+        };
+        self.notify_changed(&updated);
+        updated
+    }
+
+    /// Inserts many prepared entries while taking the cache lock once.
+    ///
+    /// Unlike [`ProviderCache::set_many`], the entries may hold values of different types.
+    /// Existing entries for the same keys are replaced without comparing values.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `entries` - The keys and entries to insert, e.g. built with [`CacheEntry::new`].
+    ///
+    /// # Returns
+    ///
+    /// The number of entries inserted.
+    ///
+    /// # Side Effects
+    ///
+    /// The entries are inserted into the cache.
+    pub fn insert_many<I>(&self, entries: I) -> usize
+    where
+        I: IntoIterator<Item = (String, CacheEntry)>,
+    {
+        let mut keys = Vec::new();
+        {
+            let Ok(mut cache) = self.cache.lock() else {
+                return 0;
+            };
+            let len_before = cache.len();
+            for (key, entry) in entries {
+                keys.push(key.clone());
+                cache.insert(key, entry);
+            }
+            debug!(
+                "📊 [CACHE-STORE] Inserted {} entries ({} new)",
+                keys.len(),
+                cache.len() - len_before
+            );
+        }
+        self.notify_changed(&keys);
+        keys.len()
+    }
+
+    /// Shared implementation of `set` and `set_deduped`
+    fn set_with<T, F>(&self, key: String, value: T, share: F) -> bool
+    where
+        T: Clone + Send + Sync + PartialEq + 'static,
+        F: FnOnce(T) -> Arc<dyn Any + Send + Sync>,
+    {
+        let updated = match self.cache.lock() {
+            Ok(mut cache) => store_value(&mut cache, &key, value, share),
+            Err(_) => false,
+        };
+        if updated {
+            self.notify_changed(&[key]);
+        }
+        updated
+    }
+
+    /// Returns a shared allocation holding a value equal to `value`, creating it if needed
+    fn intern<T: Clone + Send + Sync + PartialEq + Hash + 'static>(
+        &self,
+        value: T,
+    ) -> Arc<dyn Any + Send + Sync> {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<T>().hash(&mut hasher);
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let Ok(mut interned) = self.interned.lock() else {
+            return Arc::new(value);
+        };
+        let bucket = interned.entry(hash).or_default();
+        // Drop values no longer held by any cache entry
+        bucket.retain(|weak| weak.strong_count() > 0);
+
+        let existing = bucket.iter().filter_map(Weak::upgrade).find(|shared| {
+            shared
+                .downcast_ref::<T>()
+                .is_some_and(|existing| *existing == value)
+        });
+        if let Some(shared) = existing {
+            debug!("🔗 [CACHE-DEDUPE] Sharing existing allocation for equal value");
+            return shared;
+        }
+
+        let shared: Arc<dyn Any + Send + Sync> = Arc::new(value);
+        bucket.push(Arc::downgrade(&shared));
+        shared
+    }
+
+    /// Drops interned values no longer held by any cache entry, and their empty buckets
+    fn prune_interned(&self) {
+        if let Ok(mut interned) = self.interned.lock() {
+            interned.retain(|_, bucket| {
+                bucket.retain(|weak| weak.strong_count() > 0);
+                !bucket.is_empty()
+            });
+        }
+    }
+
+    /// Spreads the expiration of an entry by a random jitter of up to `max_jitter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the entry.
+    /// * `max_jitter` - The maximum fraction (e.g. `0.2` for 20%) added to the entry's
+    ///   expiration and stale times.
+    ///
+    /// # Side Effects
+    ///
+    /// Entries created in the same frame expire at different times instead of all at once.
+    /// An entry keeps its jitter until it is replaced with a new value.
+    pub fn apply_expiration_jitter(&self, key: &str, max_jitter: f64) {
+        if let Ok(mut cache) = self.cache.lock()
+            && let Some(entry) = cache.get_mut(key)
+        {
+            entry.apply_jitter(max_jitter);
+        }
+    }
+
+    /// Records the estimated size of the entry for a key.
+    ///
+    /// The cache stores type-erased values, so the hooks record the estimate of providers
+    /// that report one after storing their result. Entries without an estimate count as
+    /// [`CacheConfig::default_entry_size`].
+    pub(crate) fn record_size(&self, key: &str, size: usize) {
+        if let Ok(mut cache) = self.cache.lock()
+            && let Some(entry) = cache.get_mut(key)
+        {
+            entry.size = Some(size);
+        }
+    }
+
+    /// Refreshes the timestamp of the entry for a key if it holds a value equal to `value`.
+    ///
+    /// Returns whether it did, i.e. whether storing `value` would leave the entry unchanged.
+    pub(crate) fn refresh_if_unchanged<T: PartialEq + 'static>(
+        &self,
+        key: &str,
+        value: &T,
+    ) -> bool {
+        let Ok(cache) = self.cache.lock() else {
+            return false;
+        };
+        match cache.get(key) {
+            Some(entry) if entry.peek::<T>() == Some(value) => {
+                entry.refresh_timestamp();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves the output of the `Result<T, E>` stored for a key behind an `Arc`.
+    ///
+    /// `ArcProvider` lets its inner provider store a result, then shares the output so every
+    /// read of the entry clones the `Arc` instead of the output. The output is only cloned
+    /// if the stored allocation is shared, e.g. interned by `set_deduped`.
+    pub(crate) fn share_output<T, E>(&self, key: &str)
+    where
+        T: Clone + Send + Sync + 'static,
+        E: Clone + Send + Sync + 'static,
+    {
+        if let Ok(mut cache) = self.cache.lock()
+            && let Some(entry) = cache.get_mut(key)
+        {
+            let data = std::mem::replace(&mut entry.data, Arc::new(()));
+            entry.data = match data.downcast::<Result<T, E>>() {
+                Ok(result) => {
+                    entry.type_name = std::any::type_name::<Result<Arc<T>, E>>();
+                    Arc::new(Arc::unwrap_or_clone(result).map(Arc::new))
+                }
+                Err(data) => data,
+            };
+        }
+    }
+
+    /// Records whether the entry for a key holds a failed provider result.
+    ///
+    /// The cache stores type-erased values, so the hooks record the outcome of each fetch
+    /// after storing it. Entries written directly with `set` are treated as successes.
+    pub(crate) fn mark_error(&self, key: &str, is_error: bool) {
+        if let Ok(mut cache) = self.cache.lock()
+            && let Some(entry) = cache.get_mut(key)
+        {
+            entry.is_error = is_error;
+        }
+    }
+
+    /// Records whether the entries for a key may be persisted or exported.
+    ///
+    /// The hooks record this from `Provider::persistable` before storing a provider's
+    /// result, so a serializer never sees the entry before it is marked. The mark is kept
+    /// for the lifetime of the cache, also for entries stored later. Keys never marked,
+    /// e.g. written directly with `set`, are persistable.
+    pub(crate) fn mark_persistable(&self, key: &str, persistable: bool) {
+        if let Ok(mut no_persist) = self.no_persist.lock() {
+            if persistable {
+                no_persist.remove(key);
+            } else {
+                no_persist.insert(key.to_string());
+            }
+        }
+    }
+
+    /// Checks whether the entries for a key may be persisted or exported.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to check.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the key belongs to a provider whose `persistable` returns `false`.
+    pub fn is_persistable(&self, key: &str) -> bool {
+        self.no_persist
+            .lock()
+            .is_ok_and(|no_persist| !no_persist.contains(key))
+    }
+
+    /// Records the provider type name and parameter key an entry is persisted under.
+    ///
+    /// Cache keys hash the provider's `TypeId`, which can change whenever the app is rebuilt,
+    /// so persistence writes provider entries under this identity and rebuilds their keys on
+    /// load. The hooks record it before storing a provider's result; `identify` is only
+    /// called for keys not recorded yet and returns `None` for keys that are persisted as is,
+    /// e.g. those of providers with a custom [`crate::hooks::Provider::id`]. Without the
+    /// `disk-persistence` feature this does nothing and `identify` is never called.
+    pub(crate) fn record_persisted_key(
+        &self,
+        key: &str,
+        identify: impl FnOnce() -> Option<(&'static str, String)>,
+    ) {
+        #[cfg(feature = "disk-persistence")]
+        if let Ok(mut persisted) = self.persisted_keys.lock()
+            && !persisted.contains_key(key)
+        {
+            persisted.insert(key.to_string(), identify());
+        }
+        #[cfg(not(feature = "disk-persistence"))]
+        let _ = (key, identify);
+    }
+
+    /// Gets the provider type name and parameter key recorded for a key by
+    /// `record_persisted_key`, or `None` if the entry is persisted under its cache key.
+    #[cfg(feature = "disk-persistence")]
+    pub(crate) fn persisted_key(&self, key: &str) -> Option<(&'static str, String)> {
+        self.persisted_keys.lock().ok()?.get(key).cloned().flatten()
+    }
+
+    /// Gets the keys of the entries that may be persisted or exported, sorted.
+    ///
+    /// Persistence and snapshot serializers should only write these entries, so data of
+    /// providers declared with `#[provider(no_persist = true)]`, such as auth tokens, never
+    /// leaves memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    ///
+    /// # Returns
+    ///
+    /// The keys of all persistable entries.
+    pub fn persistable_keys(&self) -> Vec<String> {
+        // Keys are marked before their entry is stored, so checking the marks after listing
+        // the entries sees the mark of every listed entry
+        let mut keys: Vec<String> = match self.cache.lock() {
+            Ok(cache) => cache.keys().cloned().collect(),
+            Err(_) => return Vec::new(),
+        };
+        let Ok(no_persist) = self.no_persist.lock() else {
+            return Vec::new();
+        };
+        keys.retain(|key| !no_persist.contains(key));
+        keys.sort();
+        keys
+    }
+
+    /// Removes every entry holding a failed provider result.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries removed.
+    ///
+    /// # Side Effects
+    ///
+    /// Successful entries are kept and the change listener is notified of the removed keys.
+    pub fn clear_errors(&self) -> usize {
+        self.retain(|_, info| !info.is_error).len()
+    }
+
+    /// Takes a snapshot of the entry for a key, for restoring it later.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the entry.
+    ///
+    /// # Returns
+    ///
+    /// A copy of the entry, or `None` if the key is not cached.
+    ///
+    /// # Side Effects
+    ///
+    /// None. Unlike `get`, taking a snapshot does not count as an access.
+    pub fn snapshot(&self, key: &str) -> Option<CacheEntry> {
+        self.cache.lock().ok()?.get(key).cloned()
+    }
+
+    /// Restores an entry from a snapshot taken with `snapshot`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the entry.
+    /// * `snapshot` - The snapshot to restore; `None` removes the entry, matching a key
+    ///   that was not cached when the snapshot was taken.
+    ///
+    /// # Side Effects
+    ///
+    /// Replaces whatever is currently cached for the key. Removing the entry also clears its
+    /// metadata and tag and entity indexes like `remove`; a restored entry keeps whatever is
+    /// indexed for the key, so callers re-index it with `index_tag` and `index_entity` if it
+    /// may have been removed since the snapshot was taken.
+    pub fn restore(&self, key: &str, snapshot: Option<CacheEntry>) {
+        match snapshot {
+            Some(entry) => {
+                if let Ok(mut cache) = self.cache.lock() {
+                    cache.insert(key.to_string(), entry);
+                }
+            }
+            None => {
+                self.remove_entry(key);
+            }
+        }
+        debug!("⏪ [CACHE-RESTORE] Restored snapshot for key: {}", key);
+        self.notify_changed(&[key.to_string()]);
+    }
+
+    /// Removes a cached result by key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to remove.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the entry was removed.
+    ///
+    /// # Side Effects
+    ///
+    /// The entry's metadata is cleared and the change listener is notified.
+    pub fn remove(&self, key: &str) -> bool {
+        let removed = self.remove_entry(key);
+        if removed {
+            self.notify_changed(&[key.to_string()]);
+        }
+        removed
+    }
+
+    /// Removes an entry and its metadata without notifying the change listener
+    fn remove_entry(&self, key: &str) -> bool {
+        self.clear_entry_meta(key);
+        let removed = match self.cache.lock() {
+            Ok(mut cache) => cache.remove(key).is_some(),
+            Err(_) => false,
+        };
+        if removed {
+            self.forget_keys(&[key]);
+            self.prune_interned();
+        }
+        removed
+    }
+
+    /// Invalidates a cached result by key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to invalidate.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether an entry was present and removed.
+    ///
+    /// # Side Effects
+    ///
+    /// The entry is removed from the cache. Unlike `remove`, the change listener is notified
+    /// even if nothing was cached, so components showing the key refetch it.
+    pub fn invalidate(&self, key: &str) -> bool {
+        let removed = self.remove_entry(key);
+        self.notify_changed(&[key.to_string()]);
+        debug!(
+            "🗑️ [CACHE-INVALIDATE] Invalidated cache entry for key: {} (removed: {})",
+            key, removed
+        );
+        removed
+    }
+
+    /// Marks the entry for a key as freshly fetched.
+    ///
+    /// Use this after writing a value by hand that should count as up to date, so it is
+    /// neither revalidated nor expired before a fetched value would be.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to touch.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether an entry was found.
+    ///
+    /// # Side Effects
+    ///
+    /// Sets the entry's `cached_at` timestamp to now.
+    pub fn touch(&self, key: &str) -> bool {
+        let Ok(cache) = self.cache.lock() else {
+            return false;
+        };
+        let Some(entry) = cache.get(key) else {
+            return false;
+        };
+        entry.refresh_timestamp();
+        debug!("👆 [CACHE-TOUCH] Marked entry as fresh for key: {}", key);
+        true
+    }
+
+    /// Marks the entry for a key as stale, so the next read revalidates it in the background.
+    ///
+    /// Unlike [`ProviderCache::invalidate`], the entry keeps being served while it is
+    /// revalidated. See [`crate::global::mark_provider_stale`] to use a provider's own
+    /// stale time and refresh its subscribers.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to mark stale.
+    /// * `stale_time` - The stale time of the provider owning the entry.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether an entry was found.
+    ///
+    /// # Side Effects
+    ///
+    /// Moves the entry's `cached_at` timestamp back by just over `stale_time`.
+    pub fn mark_stale(&self, key: &str, stale_time: Duration) -> bool {
+        let Ok(cache) = self.cache.lock() else {
+            return false;
+        };
+        let Some(entry) = cache.get(key) else {
+            return false;
+        };
+        entry.mark_stale(stale_time);
+        debug!("⏳ [CACHE-STALE] Marked entry as stale for key: {}", key);
+        true
+    }
+
+    /// Gets a metadata value stored next to the entry for a key.
+    ///
+    /// Metadata is only visible while an entry is cached, so a provider never sends a
+    /// validator for data that is no longer there.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the entry.
+    /// * `name` - The name of the metadata value, e.g. `"etag"`.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if it is unset or no entry is cached for the key.
+    pub fn entry_meta(&self, key: &str, name: &str) -> Option<String> {
+        if !self.cache.lock().ok()?.contains_key(key) {
+            return None;
+        }
+        self.meta.lock().ok()?.get(key)?.get(name).cloned()
+    }
+
+    /// Sets a metadata value for the entry for a key.
+    ///
+    /// The value may be set before the entry is stored, while its fetch is running.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the entry.
+    /// * `name` - The name of the metadata value.
+    /// * `value` - The opaque value, e.g. a response's `ETag`.
+    ///
+    /// # Side Effects
+    ///
+    /// The value is dropped when the entry is removed.
+    pub fn set_entry_meta(&self, key: &str, name: String, value: String) {
+        if let Ok(mut meta) = self.meta.lock() {
+            meta.entry(key.to_string()).or_default().insert(name, value);
+        }
+    }
+
+    /// Removes all metadata values for a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key of the entry.
+    pub fn clear_entry_meta(&self, key: &str) {
+        if let Ok(mut meta) = self.meta.lock() {
+            meta.remove(key);
+        }
+    }
+
+    /// Records that the entry for a key belongs to an entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `entity` - The entity key, as reported by `Provider::entity_key`.
+    /// * `key` - The cache key of the entry.
+    ///
+    /// # Side Effects
+    ///
+    /// The key is dropped by a later `invalidate_entity` for the same entity.
+    pub fn index_entity(&self, entity: String, key: &str) {
+        if let Ok(mut entities) = self.entities.lock() {
+            entities.entry(entity).or_default().insert(key.to_string());
+        }
+    }
+
+    /// Removes every cached entry belonging to an entity, across all providers.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `entity` - The entity key, as reported by `Provider::entity_key`.
+    ///
+    /// # Returns
+    ///
+    /// The keys of the removed entries.
+    ///
+    /// # Side Effects
+    ///
+    /// The entity is removed from the index and the change listener is notified of the
+    /// removed keys.
+    pub fn invalidate_entity(&self, entity: &str) -> Vec<String> {
+        let keys = self
+            .entities
+            .lock()
+            .ok()
+            .and_then(|mut entities| entities.remove(entity))
+            .unwrap_or_default();
+        let removed: Vec<String> = keys
+            .into_iter()
+            .filter(|key| self.remove_entry(key))
+            .collect();
+        debug!(
+            "🗑️ [CACHE-INVALIDATE] Invalidated {} entries for entity: {}",
+            removed.len(),
+            entity
+        );
+        self.notify_changed(&removed);
+        removed
+    }
+
+    /// Records that the entry for a key was stored by a provider with a tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `tag` - The tag, as reported by `Provider::tags`.
+    /// * `key` - The cache key of the entry.
+    ///
+    /// # Side Effects
+    ///
+    /// The key is dropped by a later `invalidate_tag` for the same tag.
+    pub fn index_tag(&self, tag: &'static str, key: &str) {
+        if let Ok(mut tags) = self.tags.lock() {
+            tags.entry(tag).or_default().insert(key.to_string());
+        }
     }
 
-    /// Sets a value for a given key.
+    /// Removes every cached entry stored by providers with a tag.
     ///
     /// # Arguments
     ///
     /// * `&self` - A reference to the `ProviderCache`.
-    /// * `key` - The key to set.
-    /// * `value` - The value to set.
+    /// * `tag` - The tag, as reported by `Provider::tags`.
     ///
     /// # Returns
     ///
-    /// A boolean indicating whether the value was updated (true) or unchanged (false).
+    /// The keys of the removed entries.
     ///
     /// # Side Effects
     ///
-    /// Updates the `cached_at` timestamp if the value was updated.
-    pub fn set<T: Clone + Send + Sync + PartialEq + 'static>(&self, key: String, value: T) -> bool {
-        if let Ok(mut cache) = self.cache.lock() {
-            if let Some(existing_entry) = cache.get_mut(&key) {
-                if let Some(existing_value) = existing_entry.get::<T>() {
-                    if existing_value == value {
-                        existing_entry.refresh_timestamp();
-                        debug!(
-                            "⏸️ [CACHE-STORE] Value unchanged for key: {}, refreshing timestamp",
-                            key
-                        );
-                        return false;
-                    }
-                }
-            }
-            cache.insert(key.clone(), CacheEntry::new(value));
-            debug!("📊 [CACHE-STORE] Stored data for key: {}", key);
-            return true;
-        }
-        false
+    /// The tag is removed from the index and the change listener is notified of the
+    /// removed keys.
+    pub fn invalidate_tag(&self, tag: &str) -> Vec<String> {
+        let keys = self
+            .tags
+            .lock()
+            .ok()
+            .and_then(|mut tags| tags.remove(tag))
+            .unwrap_or_default();
+        let removed: Vec<String> = keys
+            .into_iter()
+            .filter(|key| self.remove_entry(key))
+            .collect();
+        debug!(
+            "🗑️ [CACHE-INVALIDATE] Invalidated {} entries for tag: {}",
+            removed.len(),
+            tag
+        );
+        self.notify_changed(&removed);
+        removed
     }
 
-    /// Removes a cached result by key.
+    /// Removes every cached entry whose key starts with a prefix.
     ///
     /// # Arguments
     ///
     /// * `&self` - A reference to the `ProviderCache`.
-    /// * `key` - The key to remove.
+    /// * `prefix` - The start of the keys to remove, e.g. the key prefix of a scope.
     ///
     /// # Returns
     ///
-    /// A boolean indicating whether the entry was removed.
+    /// The keys of the removed entries.
     ///
     /// # Side Effects
     ///
-    /// None.
-    pub fn remove(&self, key: &str) -> bool {
-        if let Ok(mut cache) = self.cache.lock() {
-            cache.remove(key).is_some()
-        } else {
-            false
-        }
+    /// The change listener is notified of the removed keys.
+    pub fn invalidate_prefix(&self, prefix: &str) -> Vec<String> {
+        let keys: Vec<String> = self
+            .cache
+            .lock()
+            .map(|cache| {
+                cache
+                    .keys()
+                    .filter(|key| key.starts_with(prefix))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let removed: Vec<String> = keys
+            .into_iter()
+            .filter(|key| self.remove_entry(key))
+            .collect();
+        debug!(
+            "🗑️ [CACHE-INVALIDATE] Invalidated {} entries with prefix: {}",
+            removed.len(),
+            prefix
+        );
+        self.notify_changed(&removed);
+        removed
     }
 
-    /// Invalidates a cached result by key (alias for remove).
+    /// Removes every cached entry of type `T` whose value matches a predicate.
+    ///
+    /// Provider entries are stored as `Result<Output, Error>`, so e.g. cached users are
+    /// matched with `invalidate_where(|user: &Result<User, String>| ...)`. Entries of other
+    /// types are skipped.
     ///
     /// # Arguments
     ///
     /// * `&self` - A reference to the `ProviderCache`.
-    /// * `key` - The key to invalidate.
+    /// * `predicate` - Called with the value of each entry of type `T`. Entries for which it
+    ///   returns `true` are removed.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed entries.
     ///
     /// # Side Effects
     ///
-    /// The entry is removed from the cache.
-    pub fn invalidate(&self, key: &str) {
-        self.remove(key);
+    /// The change listener is notified of the removed keys once the cache is unlocked, so
+    /// components showing them refetch. Reading the values does not count as an access.
+    pub fn invalidate_where<T: 'static>(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        let keys: Vec<String> = self
+            .cache
+            .lock()
+            .map(|cache| {
+                cache
+                    .iter()
+                    .filter(|(_, entry)| entry.peek::<T>().is_some_and(&predicate))
+                    .map(|(key, _)| key.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let removed: Vec<String> = keys
+            .into_iter()
+            .filter(|key| self.remove_entry(key))
+            .collect();
         debug!(
-            "🗑️ [CACHE-INVALIDATE] Invalidated cache entry for key: {}",
-            key
+            "🗑️ [CACHE-INVALIDATE] Invalidated {} entries of type {} matching a predicate",
+            removed.len(),
+            std::any::type_name::<T>()
         );
+        self.notify_changed(&removed);
+        removed.len()
     }
 
     /// Clears all cached results.
@@ -457,13 +2323,30 @@ impl ProviderCache {
     ///
     /// # Side Effects
     ///
-    /// All entries are removed from the cache.
+    /// All entries, their metadata, the entity and tag indexes and the interned values are
+    /// removed from the cache.
+    /// The change listener is notified of every removed key, so a cache connected with
+    /// [`ProviderCache::connect_refresh_registry`] refreshes the components showing them.
     pub fn clear(&self) {
+        let mut removed = Vec::new();
         if let Ok(mut cache) = self.cache.lock() {
-            let count = cache.len();
-            cache.clear();
-            debug!("🗑️ [CACHE-CLEAR] Cleared {} cache entries", count);
+            removed.extend(cache.drain().map(|(key, _)| key));
+            debug!("🗑️ [CACHE-CLEAR] Cleared {} cache entries", removed.len());
+        }
+        if let Ok(mut entities) = self.entities.lock() {
+            entities.clear();
         }
+        if let Ok(mut tags) = self.tags.lock() {
+            tags.clear();
+        }
+        if let Ok(mut meta) = self.meta.lock() {
+            meta.clear();
+        }
+        if let Ok(mut interned) = self.interned.lock() {
+            interned.clear();
+        }
+        self.forget_keys(&removed);
+        self.notify_changed(&removed);
     }
 
     /// Gets the number of cached entries.
@@ -483,6 +2366,70 @@ impl ProviderCache {
         self.cache.lock().map(|cache| cache.len()).unwrap_or(0)
     }
 
+    /// Gets the estimated size of all entries in bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the entries' recorded sizes, counting entries without an estimate as
+    /// [`CacheConfig::default_entry_size`].
+    pub fn total_size(&self) -> usize {
+        self.cache
+            .lock()
+            .map(|cache| cache.values().map(|entry| self.entry_size(entry)).sum())
+            .unwrap_or(0)
+    }
+
+    /// Gets the estimated memory held by the cache in bytes.
+    ///
+    /// Same as [`ProviderCache::total_size`]; compare it with
+    /// [`CacheConfig::max_bytes`] when sizing the cache.
+    pub fn total_memory_estimate(&self) -> usize {
+        self.total_size()
+    }
+
+    /// Gets the number of entries evicted to stay within the size limits so far.
+    ///
+    /// The count keeps growing across `clear` and is shared by the clones of this cache.
+    pub fn eviction_count(&self) -> u64 {
+        self.removals.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Gets the number of entries removed because they expired so far.
+    ///
+    /// The count keeps growing across `clear` and is shared by the clones of this cache.
+    pub fn expiration_count(&self) -> u64 {
+        self.removals.expirations.load(Ordering::Relaxed)
+    }
+
+    /// Counts an entry removed because it expired
+    pub(crate) fn record_expiration(&self) {
+        self.removals.expirations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Gets the numbers needed to tell whether the cache limits fit the workload.
+    ///
+    /// A steadily growing eviction count means entries are dropped before they can be
+    /// reused, so `max_size` or `max_bytes` is too small for the data being shown.
+    pub fn capacity_stats(&self) -> CacheCapacityStats {
+        CacheCapacityStats {
+            entry_count: self.size(),
+            max_size: self.config.max_size,
+            memory_estimate: self.total_memory_estimate(),
+            max_bytes: self.config.max_bytes,
+            evictions: self.eviction_count(),
+            expirations: self.expiration_count(),
+        }
+    }
+
+    /// Gets the estimated size of an entry, falling back to the configured default
+    fn entry_size(&self, entry: &CacheEntry) -> usize {
+        entry.size.unwrap_or(self.config.default_entry_size)
+    }
+
     /// Cleans up unused entries based on access time.
     ///
     /// # Arguments
@@ -498,28 +2445,156 @@ impl ProviderCache {
     ///
     /// Unused entries are removed from the cache.
     pub fn cleanup_unused_entries(&self, unused_threshold: Duration) -> usize {
+        let removed = self
+            .retain(|_, info| {
+                info.time_since_last_access <= unused_threshold || info.reference_count > 0
+            })
+            .len();
+        if removed > 0 {
+            debug!("🧹 [CACHE-CLEANUP] Removed {} unused entries", removed);
+        }
+        removed
+    }
+
+    /// Removes the entry for a key if the predicate returns true for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to check.
+    /// * `predicate` - Called with the entry while the cache is locked.
+    ///
+    /// # Returns
+    ///
+    /// Whether the entry was removed.
+    ///
+    /// # Side Effects
+    ///
+    /// A removed entry's metadata is cleared and the change listener is notified.
+    pub fn remove_if(&self, key: &str, predicate: impl FnOnce(&CacheEntry) -> bool) -> bool {
+        let removed = match self.cache.lock() {
+            Ok(mut cache) => {
+                let matches = cache.get(key).is_some_and(predicate);
+                matches && cache.remove(key).is_some()
+            }
+            Err(_) => false,
+        };
+        if removed {
+            self.clear_entry_meta(key);
+            self.forget_keys(&[key]);
+            self.prune_interned();
+            self.notify_changed(&[key.to_string()]);
+        }
+        removed
+    }
+
+    /// Removes an entry that hasn't been read for `unused_for` and has no references.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `key` - The key to dispose.
+    /// * `unused_for` - How long the entry must have gone unread.
+    ///
+    /// # Returns
+    ///
+    /// Whether no entry is left for the key, because it was removed now or before.
+    ///
+    /// # Side Effects
+    ///
+    /// A removed entry's metadata is cleared, as with `remove`.
+    pub fn dispose_if_unused(&self, key: &str, unused_for: Duration) -> bool {
+        let removed = match self.cache.lock() {
+            Ok(mut cache) => match cache.get(key) {
+                None => return true,
+                Some(entry) if entry.is_unused_for(unused_for) && entry.reference_count() == 0 => {
+                    cache.remove(key);
+                    true
+                }
+                Some(_) => false,
+            },
+            Err(_) => false,
+        };
+        if removed {
+            self.clear_entry_meta(key);
+            self.forget_keys(&[key]);
+            self.prune_interned();
+            debug!("🗑️ [CACHE-DISPOSE] Disposed unused entry: {}", key);
+            self.notify_changed(&[key.to_string()]);
+        }
+        removed
+    }
+
+    /// Gets a snapshot of every entry's metadata, sorted by key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    ///
+    /// # Returns
+    ///
+    /// Each key with its `CacheEntryInfo`.
+    ///
+    /// # Side Effects
+    ///
+    /// None. Reading the metadata does not count as an access.
+    pub fn entries(&self) -> Vec<(String, CacheEntryInfo)> {
+        let mut entries: Vec<_> = match self.cache.lock() {
+            Ok(cache) => cache
+                .iter()
+                .map(|(key, entry)| (key.clone(), entry.info()))
+                .collect(),
+            Err(_) => return Vec::new(),
+        };
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+    }
+
+    /// Keeps only the entries for which the predicate returns true.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - A reference to the `ProviderCache`.
+    /// * `predicate` - Called with each key and a snapshot of its metadata. Entries for
+    ///   which it returns `false` are removed. Reference counts are exposed through
+    ///   `CacheEntryInfo::reference_count` so the predicate can keep entries still in use.
+    ///
+    /// # Returns
+    ///
+    /// The keys of the removed entries, so callers can trigger a refresh for them.
+    ///
+    /// # Side Effects
+    ///
+    /// Entries rejected by the predicate are removed from the cache.
+    pub fn retain<F>(&self, predicate: F) -> Vec<String>
+    where
+        F: Fn(&str, &CacheEntryInfo) -> bool,
+    {
+        let mut removed = Vec::new();
         if let Ok(mut cache) = self.cache.lock() {
-            let initial_size = cache.len();
             cache.retain(|key, entry| {
-                let should_keep =
-                    !entry.is_unused_for(unused_threshold) || entry.reference_count() > 0;
+                let should_keep = predicate(key, &entry.info());
                 if !should_keep {
-                    debug!("🧹 [CACHE-CLEANUP] Removing unused entry: {}", key);
+                    debug!("🧹 [CACHE-RETAIN] Removing entry: {}", key);
+                    removed.push(key.clone());
                 }
                 should_keep
             });
-            let removed = initial_size - cache.len();
-            if removed > 0 {
-                debug!("🧹 [CACHE-CLEANUP] Removed {} unused entries", removed);
-            }
-            removed
-        } else {
-            0
         }
+        if !removed.is_empty() {
+            self.forget_keys(&removed);
+            self.prune_interned();
+        }
+        self.notify_changed(&removed);
+        removed
     }
 
     /// Evicts least recently used entries to maintain cache size limit.
     ///
+    /// Entries are picked by the cache's [`CacheConfig::eviction_policy`], least recently
+    /// used first by default. When [`CacheConfig::max_bytes`] is set, entries are also evicted until their
+    /// estimated total size (see [`ProviderCache::total_size`]) fits the byte budget.
+    ///
     /// # Arguments
     ///
     /// * `&self` - A reference to the `ProviderCache`.
@@ -531,38 +2606,54 @@ impl ProviderCache {
     ///
     /// # Side Effects
     ///
-    /// Least recently used entries are removed from the cache.
+    /// The entries the eviction policy values least are removed from the cache.
     pub fn evict_lru_entries(&self, max_size: usize) -> usize {
-        if let Ok(mut cache) = self.cache.lock() {
-            if cache.len() <= max_size {
+        let evicted: Vec<String> = {
+            let Ok(mut cache) = self.cache.lock() else {
+                return 0;
+            };
+            let mut total_size: usize = cache.values().map(|entry| self.entry_size(entry)).sum();
+            let over_budget = |count: usize, total_size: usize| {
+                count > max_size || self.config.max_bytes.is_some_and(|max| total_size > max)
+            };
+            if !over_budget(cache.len(), total_size) {
                 return 0;
             }
 
             // Convert to vector for sorting
             let mut entries: Vec<_> = cache.drain().collect();
 
-            // Sort by last access time (oldest first)
-            entries.sort_by(|(_, a), (_, b)| {
-                a.time_since_last_access().cmp(&b.time_since_last_access())
-            });
+            // Sort by the eviction policy (entries worth keeping first)
+            let policy = self.config.eviction_policy;
+            entries.sort_by(|(_, a), (_, b)| policy.compare(a, b));
 
-            // Keep the most recently used entries
-            let to_keep = entries.split_off(entries.len().saturating_sub(max_size));
-            let evicted = entries.len();
+            // Drop the least valuable entries until both limits are met
+            let mut evicted = Vec::new();
+            while over_budget(entries.len(), total_size)
+                && let Some((key, entry)) = entries.pop()
+            {
+                total_size -= self.entry_size(&entry);
+                evicted.push(key);
+            }
 
             // Rebuild cache with kept entries
-            cache.extend(to_keep);
-
-            if evicted > 0 {
-                debug!(
-                    "🗑️ [LRU-EVICT] Evicted {} entries due to cache size limit",
-                    evicted
-                );
-            }
+            cache.extend(entries);
             evicted
-        } else {
-            0
+        };
+
+        if !evicted.is_empty() {
+            debug!(
+                "🗑️ [LRU-EVICT] Evicted {} entries due to cache size limit",
+                evicted.len()
+            );
+            self.removals
+                .evictions
+                .fetch_add(evicted.len() as u64, Ordering::Relaxed);
+            self.forget_keys(&evicted);
+            self.prune_interned();
         }
+        self.notify_changed(&evicted);
+        evicted.len()
     }
 
     /// Performs comprehensive cache maintenance.
@@ -577,12 +2668,26 @@ impl ProviderCache {
     ///
     /// # Side Effects
     ///
-    /// Unused entries are removed and LRU entries are evicted.
+    /// Unused entries are removed, LRU entries are evicted and metadata of removed
+    /// entries is dropped.
     pub fn maintain(&self) -> CacheMaintenanceStats {
-        CacheMaintenanceStats {
-            unused_removed: self.cleanup_unused_entries(DEFAULT_UNUSED_THRESHOLD),
-            lru_evicted: self.evict_lru_entries(DEFAULT_MAX_CACHE_SIZE),
+        let stats = CacheMaintenanceStats {
+            unused_removed: self.cleanup_unused_entries(self.config.unused_threshold),
+            lru_evicted: self.evict_lru_entries(self.config.max_size),
             final_size: self.size(),
+        };
+        self.prune_entry_meta();
+        stats
+    }
+
+    /// Drops metadata of keys that no longer have a cached entry
+    fn prune_entry_meta(&self) {
+        let keys: HashSet<String> = match self.cache.lock() {
+            Ok(cache) => cache.keys().cloned().collect(),
+            Err(_) => return,
+        };
+        if let Ok(mut meta) = self.meta.lock() {
+            meta.retain(|key, _| keys.contains(key));
         }
     }
 
@@ -639,6 +2744,20 @@ pub struct CacheMaintenanceStats {
     pub final_size: usize,
 }
 
+/// Cache usage against its limits, with running removal counts
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheCapacityStats {
+    pub entry_count: usize,
+    pub max_size: usize,
+    /// Estimated memory held by the entries in bytes
+    pub memory_estimate: usize,
+    pub max_bytes: Option<usize>,
+    /// Entries evicted by the size limits since the cache was created
+    pub evictions: u64,
+    /// Entries removed by expiration since the cache was created
+    pub expirations: u64,
+}
+
 /// General cache statistics
 #[derive(Debug, Clone, Default)]
 pub struct CacheStats {
@@ -666,3 +2785,405 @@ impl CacheStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moves an entry's `cached_at` timestamp into the past
+    fn age_entry(cache: &ProviderCache, key: &str, age: Duration) {
+        let guard = cache.cache.lock().unwrap();
+        let entry = guard.get(key).unwrap();
+        *entry.cached_at.lock().unwrap() = Instant::now() - age;
+    }
+
+    #[test]
+    fn test_get_checked_reports_type_mismatch() {
+        let cache = ProviderCache::new();
+        cache.set("user".to_string(), Ok::<u32, String>(1));
+
+        assert_eq!(
+            cache.get_checked::<Result<u32, String>>("user"),
+            Ok(Some(Ok(1)))
+        );
+        assert_eq!(cache.get_checked::<u32>("missing"), Ok(None));
+        // `get` can't tell a mismatch from a missing key
+        assert_eq!(cache.get::<u32>("user"), None);
+        let error = cache.get_checked::<u32>("user").unwrap_err();
+        assert_eq!(error.key, "user");
+        assert_eq!(error.expected, "u32");
+        assert_eq!(
+            error.found,
+            "core::result::Result<u32, alloc::string::String>"
+        );
+    }
+
+    #[test]
+    fn test_zero_check_interval_is_ignored() {
+        let cache = ProviderCache::with_config(CacheConfig {
+            check_interval: Some(Duration::ZERO),
+            ..CacheConfig::default()
+        });
+        assert_eq!(cache.config().check_interval, None);
+
+        let cache = ProviderCache::with_config(CacheConfig {
+            check_interval: Some(Duration::from_millis(10)),
+            ..CacheConfig::default()
+        });
+        assert_eq!(
+            cache.config().check_interval,
+            Some(Duration::from_millis(10))
+        );
+    }
+
+    #[test]
+    fn test_touch_makes_old_entry_fresh() {
+        let cache = ProviderCache::new();
+        cache.set("user".to_string(), 1u32);
+        age_entry(&cache, "user", Duration::from_secs(120));
+        let is_stale = |cache: &ProviderCache| {
+            cache.cache.lock().unwrap()["user"].is_stale(Duration::from_secs(60))
+        };
+        assert!(is_stale(&cache));
+
+        assert!(cache.touch("user"));
+        assert!(!is_stale(&cache));
+        assert!(!cache.touch("missing"));
+    }
+
+    #[test]
+    fn test_mark_stale_keeps_entry_but_makes_it_stale() {
+        let cache = ProviderCache::new();
+        cache.set("user".to_string(), 1u32);
+        let stale_time = Duration::from_secs(60);
+
+        assert!(cache.mark_stale("user", stale_time));
+        let guard = cache.cache.lock().unwrap();
+        assert!(guard["user"].is_stale(stale_time));
+        assert!(!guard["user"].is_expired(Duration::from_secs(300)));
+        drop(guard);
+        assert_eq!(cache.get::<u32>("user"), Some(1));
+        assert!(!cache.mark_stale("missing", stale_time));
+    }
+
+    #[test]
+    fn test_retain_keeps_only_fresh_entries() {
+        let cache = ProviderCache::new();
+        cache.set("fresh".to_string(), 1u32);
+        cache.set("old".to_string(), 2u32);
+        cache.set("older".to_string(), 3u32);
+        age_entry(&cache, "old", Duration::from_secs(120));
+        age_entry(&cache, "older", Duration::from_secs(600));
+
+        let mut removed = cache.retain(|_, info| info.age < Duration::from_secs(60));
+        removed.sort();
+
+        assert_eq!(removed, vec!["old".to_string(), "older".to_string()]);
+        assert_eq!(cache.get::<u32>("fresh"), Some(1));
+        assert_eq!(cache.size(), 1);
+    }
+
+    #[test]
+    fn test_invalidate_where_matches_values_of_one_type() {
+        #[derive(Clone, PartialEq)]
+        struct User {
+            org_id: u32,
+        }
+
+        let cache = ProviderCache::new();
+        for (user_id, org_id) in [(1, 5), (2, 7), (3, 5), (4, 9)] {
+            cache.set(format!("user_{user_id}"), Ok::<_, String>(User { org_id }));
+        }
+        cache.set(
+            "user_5".to_string(),
+            Err::<User, String>("offline".to_string()),
+        );
+        cache.set("org_5".to_string(), 5u32);
+        let mut changes = Box::pin(cache.changes());
+
+        let removed = cache.invalidate_where(|user: &Result<User, String>| {
+            user.as_ref().is_ok_and(|user| user.org_id == 5)
+        });
+
+        assert_eq!(removed, 2);
+        assert!(cache.get::<Result<User, String>>("user_1").is_none());
+        assert!(cache.get::<Result<User, String>>("user_3").is_none());
+        assert!(cache.get::<Result<User, String>>("user_2").is_some());
+        assert!(cache.get::<Result<User, String>>("user_5").is_some());
+        // Entries of other types are skipped even if they hold a matching value
+        assert_eq!(cache.get::<u32>("org_5"), Some(5));
+
+        let mut notified = futures::executor::block_on(changes.next()).unwrap();
+        notified.sort();
+        assert_eq!(notified, ["user_1", "user_3"]);
+    }
+
+    #[test]
+    fn test_clear_errors_keeps_successes() {
+        let cache = ProviderCache::new();
+        cache.set("ok".to_string(), Ok::<u32, String>(1));
+        cache.set("failed".to_string(), Err::<u32, String>("offline".to_string()));
+        cache.mark_error("failed", true);
+
+        assert_eq!(cache.clear_errors(), 1);
+        assert_eq!(cache.get::<Result<u32, String>>("ok"), Some(Ok(1)));
+        assert_eq!(cache.get::<Result<u32, String>>("failed"), None);
+    }
+
+    #[test]
+    fn test_restore_snapshot_undoes_overwrite() {
+        let cache = ProviderCache::new();
+        cache.set("user".to_string(), "Alice".to_string());
+
+        let snapshot = cache.snapshot("user");
+        let missing = cache.snapshot("other");
+        cache.set("user".to_string(), "Bob".to_string());
+        cache.set("other".to_string(), "Carol".to_string());
+
+        cache.restore("user", snapshot);
+        cache.restore("other", missing);
+        assert_eq!(cache.get::<String>("user"), Some("Alice".to_string()));
+        assert_eq!(cache.get::<String>("other"), None);
+    }
+
+    #[test]
+    fn test_restore_of_uncached_key_clears_metadata_and_index() {
+        let cache = ProviderCache::new();
+        let missing = cache.snapshot("user");
+        cache.set("user".to_string(), "Bob".to_string());
+        cache.set_entry_meta("user", "etag".to_string(), "v2".to_string());
+        cache.index_tag("users", "user");
+
+        cache.restore("user", missing);
+        assert_eq!(cache.entry_meta("user", "etag"), None);
+        assert!(cache.tags.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_set_deduped_shares_one_allocation() {
+        let cache = ProviderCache::new();
+        let role = Ok::<_, String>("admin".to_string());
+        for user_id in 0..10 {
+            cache.set_deduped(format!("role_{user_id}"), role.clone());
+        }
+        cache.set_deduped("role_other".to_string(), Ok::<_, String>("guest".to_string()));
+
+        let guard = cache.cache.lock().unwrap();
+        let first = &guard["role_0"].data;
+        for user_id in 1..10 {
+            assert!(Arc::ptr_eq(first, &guard[&format!("role_{user_id}")].data));
+        }
+        assert!(!Arc::ptr_eq(first, &guard["role_other"].data));
+        // One allocation held by ten entries
+        assert_eq!(Arc::strong_count(first), 10);
+    }
+
+    #[test]
+    fn test_interned_values_are_pruned_on_remove() {
+        let cache = ProviderCache::new();
+        cache.set_deduped("a".to_string(), 1u32);
+        cache.set_deduped("b".to_string(), 1u32);
+        cache.set_deduped("c".to_string(), 2u32);
+
+        cache.remove("a");
+        assert_eq!(cache.interned.lock().unwrap().len(), 2);
+        cache.remove("b");
+        assert_eq!(cache.interned.lock().unwrap().len(), 1);
+        cache.clear();
+        assert!(cache.interned.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dropped_streams_are_pruned_on_subscribe() {
+        let cache = ProviderCache::new();
+        for key in ["a", "b", "c"] {
+            drop(cache.watch::<u32>(key));
+        }
+        drop(cache.changes());
+        drop(cache.fetch_activity());
+
+        let _watching = cache.watch::<u32>("d");
+        let _changes = cache.changes();
+        let _activity = cache.fetch_activity();
+        let watchers = cache.watchers.lock().unwrap();
+        assert_eq!(watchers.keys().collect::<Vec<_>>(), ["d"]);
+        assert_eq!(cache.change_streams.lock().unwrap().len(), 1);
+        assert_eq!(cache.fetch_streams.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_tags_are_pruned_on_remove_and_eviction() {
+        let cache = ProviderCache::new();
+        for key in ["a", "b", "c"] {
+            cache.set(key.to_string(), 1u32);
+            cache.index_tag("stats", key);
+        }
+
+        cache.remove("a");
+        assert_eq!(cache.evict_lru_entries(1), 1);
+        let tagged = cache.tags.lock().unwrap()["stats"].clone();
+        assert_eq!(tagged.len(), 1);
+
+        // A key stored again without the tag must not be dropped with it
+        cache.set("a".to_string(), 2u32);
+        cache.invalidate_tag("stats");
+        assert_eq!(cache.get::<u32>("a"), Some(2));
+        assert!(cache.tags.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_expiration_jitter_spreads_entries() {
+        let cache = ProviderCache::new();
+        let expiration = Duration::from_secs(100);
+        for item_id in 0..100 {
+            let key = format!("item_{item_id}");
+            cache.set(key.clone(), item_id);
+            cache.apply_expiration_jitter(&key, 0.2);
+        }
+
+        let guard = cache.cache.lock().unwrap();
+        let mut effective: Vec<_> = guard
+            .values()
+            .map(|entry| entry.effective_duration(expiration))
+            .collect();
+        effective.sort();
+        effective.dedup();
+
+        assert!(effective.len() > 50, "expirations were not spread");
+        assert!(effective.first().unwrap() >= &expiration);
+        assert!(effective.last().unwrap() <= &expiration.mul_f64(1.2));
+    }
+
+    #[test]
+    fn test_eviction_policies_pick_different_victims() {
+        let evicted_by = |policy| {
+            let cache = ProviderCache::with_config(CacheConfig {
+                eviction_policy: policy,
+                ..CacheConfig::default()
+            });
+            // "hot" is read often but not lately, "recent" once a moment ago
+            cache.set("hot".to_string(), 1u32);
+            cache.set("warm".to_string(), 2u32);
+            cache.set("recent".to_string(), 3u32);
+            for _ in 0..5 {
+                cache.get::<u32>("hot");
+            }
+            cache.get::<u32>("warm");
+            cache.get::<u32>("warm");
+            cache.get::<u32>("recent");
+            {
+                let guard = cache.cache.lock().unwrap();
+                for (key, ago) in [("hot", 30), ("warm", 20), ("recent", 10)] {
+                    *guard[key].last_accessed.lock().unwrap() =
+                        Instant::now() - Duration::from_secs(ago);
+                }
+            }
+
+            assert_eq!(cache.evict_lru_entries(1), 2);
+            ["hot", "warm", "recent"]
+                .into_iter()
+                .filter(|key| cache.get::<u32>(key).is_none())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(evicted_by(EvictionPolicy::Lru), ["hot", "warm"]);
+        assert_eq!(evicted_by(EvictionPolicy::Lfu), ["warm", "recent"]);
+        // Read once, "recent" is still on probation and goes before the entries read again
+        assert_eq!(evicted_by(EvictionPolicy::TwoQueue), ["hot", "recent"]);
+    }
+
+    #[test]
+    fn test_set_unconditional_overwrites_equal_value() {
+        let cache = ProviderCache::new();
+        assert!(cache.set_unconditional("user_1".to_string(), "Ada".to_string()));
+        assert!(!cache.set("user_1".to_string(), "Ada".to_string()));
+
+        // Equal values are overwritten too, unlike with `set`
+        assert!(cache.set_unconditional("user_1".to_string(), "Ada".to_string()));
+        assert!(cache.set_unconditional("user_1".to_string(), "Grace".to_string()));
+        assert_eq!(cache.get::<String>("user_1"), Some("Grace".to_string()));
+        assert_eq!(cache.size(), 1);
+    }
+
+    #[test]
+    fn test_set_unconditional_keeps_entry_metadata() {
+        let cache = ProviderCache::new();
+        cache.set_unconditional("user_1".to_string(), "Ada".to_string());
+        cache.cache.lock().unwrap()["user_1"].add_reference();
+        assert_eq!(cache.get::<String>("user_1"), Some("Ada".to_string()));
+        age_entry(&cache, "user_1", Duration::from_secs(60));
+
+        cache.set_unconditional("user_1".to_string(), "Grace".to_string());
+        let info = cache.entries().remove(0).1;
+        assert_eq!(info.reference_count, 1);
+        assert_eq!(info.access_count, 1);
+        assert!(info.age < Duration::from_secs(1));
+        assert_eq!(cache.get::<String>("user_1"), Some("Grace".to_string()));
+    }
+
+    #[test]
+    fn test_fetch_guards_track_running_fetches() {
+        let cache = ProviderCache::new();
+        let first = cache.begin_fetch("user_1");
+        let second = cache.begin_fetch("user_1");
+        assert!(cache.is_fetching("user_1"));
+        assert!(!cache.is_fetching("user_2"));
+
+        drop(first);
+        assert!(cache.is_fetching("user_1"));
+        drop(second);
+        assert!(!cache.is_fetching("user_1"));
+    }
+
+    #[test]
+    fn test_fetch_activity_reports_started_and_finished_fetches() {
+        let cache = ProviderCache::new();
+        let activity = cache.fetch_activity();
+
+        let fetch = cache.begin_fetch("user_1");
+        let _optimistic = cache.hold_optimistic("user_2");
+        drop(fetch);
+        drop(cache);
+
+        let keys = futures::executor::block_on(activity.collect::<Vec<_>>());
+        assert_eq!(keys, ["user_1", "user_1"]);
+    }
+
+    #[test]
+    fn test_set_many_reports_updated_keys() {
+        let cache = ProviderCache::new();
+        cache.set("user_1".to_string(), "Ada".to_string());
+
+        let mut updated = cache.set_many([
+            ("user_1".to_string(), "Ada".to_string()),
+            ("user_2".to_string(), "Grace".to_string()),
+            ("user_3".to_string(), "Edsger".to_string()),
+        ]);
+        updated.sort();
+
+        assert_eq!(updated, vec!["user_2".to_string(), "user_3".to_string()]);
+        assert_eq!(cache.get::<String>("user_3"), Some("Edsger".to_string()));
+        assert_eq!(cache.size(), 3);
+    }
+
+    #[test]
+    fn test_batch_becomes_visible_at_once() {
+        const BATCH: usize = 500;
+        let cache = ProviderCache::new();
+        let reader_cache = cache.clone();
+        let reader = std::thread::spawn(move || {
+            loop {
+                let present = reader_cache.cache.lock().unwrap().len();
+                // Every read sees all of the batch or none of it
+                assert!(present == 0 || present == BATCH, "saw {present} entries");
+                if present == BATCH {
+                    break;
+                }
+            }
+        });
+
+        cache.insert_many((0..BATCH).map(|id| (format!("item_{id}"), CacheEntry::new(id))));
+        reader.join().unwrap();
+        assert_eq!(cache.get::<usize>("item_42"), Some(42));
+    }
+}
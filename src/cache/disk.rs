@@ -0,0 +1,485 @@
+//! # Disk Persistence
+//!
+//! Saves cached provider results to a JSON file and loads them on the next start, enabled
+//! with the `disk-persistence` feature on desktop and server targets. A restarted app then
+//! shows the data of its previous run right away and revalidates it like any other cached
+//! entry, instead of starting from empty loading states.
+//!
+//! Cache entries are type-erased, so the types to persist are registered on the
+//! [`DiskCache`]: entries of other types, failed results and entries of providers declared
+//! with `#[provider(no_persist = true)]` are never written.
+//!
+//! Cache keys hash the provider's `TypeId`, which isn't guaranteed to stay the same when the
+//! app is rebuilt, so entries of providers registered with
+//! [`DiskCache::register_provider`] are saved under the provider's type name and parameter
+//! key instead, and get their cache key rebuilt on load. Entries of providers with a custom
+//! `Provider::id` are saved under that ID, which must then be the same across builds.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus::prelude::*;
+//! use dioxus_provider::{
+//!     cache::disk::{DiskCache, use_disk_persistence},
+//!     prelude::*,
+//! };
+//!
+//! #[provider(stale_time = "5min")]
+//! async fn fetch_feed() -> Result<Vec<String>, String> {
+//!     Ok(vec!["Hello".to_string()])
+//! }
+//!
+//! #[component]
+//! fn App() -> Element {
+//!     use_disk_persistence(|| {
+//!         DiskCache::new("/home/me/.local/share/my-app/providers.json")
+//!             .with_version(2)
+//!             .register_provider(fetch_feed())
+//!     });
+//!     rsx! {}
+//! }
+//! ```
+//!
+//! Bump the version whenever a persisted type changes shape: files written with another
+//! version are ignored instead of being decoded into the new types.
+
+use dioxus::prelude::*;
+use futures::{
+    Stream, StreamExt,
+    channel::oneshot,
+    future::{self, Either},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    fs, io,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    pin::pin,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, warn};
+
+use crate::{
+    cache::{CacheEntry, ProviderCache},
+    global::{ensure_global_providers, get_global_cache},
+    hooks::Provider,
+    platform::time::sleep,
+    types::ProviderParamBounds,
+};
+
+/// How long the cache has to stay unchanged before `use_disk_persistence` writes it
+const DEFAULT_WRITE_DELAY: Duration = Duration::from_secs(1);
+
+/// Error type for saving and loading a [`DiskCache`]
+#[derive(Debug, thiserror::Error)]
+pub enum DiskCacheError {
+    #[error("Failed to access the cache file: {0}")]
+    Io(#[from] io::Error),
+    #[error("The cache file is corrupt: {0}")]
+    Corrupt(#[from] serde_json::Error),
+}
+
+/// A cache file on disk together with the types of the entries it stores
+pub struct DiskCache {
+    path: PathBuf,
+    version: u32,
+    write_delay: Duration,
+    codecs: Vec<(String, Box<dyn EntryCodec>)>,
+}
+
+impl DiskCache {
+    /// Creates a disk cache stored at `path`, with version 0 and no registered types
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            version: 0,
+            write_delay: DEFAULT_WRITE_DELAY,
+            codecs: Vec::new(),
+        }
+    }
+
+    /// Sets the version written to the file; files with another version are not loaded
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets how long `use_disk_persistence` waits for the cache to settle before writing
+    pub fn with_write_delay(mut self, write_delay: Duration) -> Self {
+        self.write_delay = write_delay;
+        self
+    }
+
+    /// Persists entries holding values of type `T`, stored under `tag` in the file
+    ///
+    /// Provider entries hold the provider's `Result<Output, Error>`, but are only restored
+    /// by a new build of the app if registered with `register_provider`. Types registered
+    /// first take precedence.
+    pub fn register<T>(mut self, tag: impl Into<String>) -> Self
+    where
+        T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        self.codecs
+            .push((tag.into(), Box::new(TypedCodec::<T>(PhantomData))));
+        self
+    }
+
+    /// Persists the successful entries of a provider, tagged with the provider's type name
+    pub fn register_provider<P, Param>(mut self, provider: P) -> Self
+    where
+        P: Provider<Param>,
+        Param: ProviderParamBounds,
+        P::Output: Serialize + DeserializeOwned,
+    {
+        let _ = provider;
+        let codec = SuccessCodec::<P::Output, P::Error> {
+            cache_key: crate::types::provider_type_key::<P>,
+            marker: PhantomData,
+        };
+        self.codecs
+            .push((std::any::type_name::<P>().to_string(), Box::new(codec)));
+        self
+    }
+
+    /// Gets the path of the cache file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the successful, persistable entries of registered types to the file
+    ///
+    /// The file is replaced atomically, so a crash while saving leaves the previous file
+    /// intact. Returns the number of entries written.
+    pub fn save(&self, cache: &ProviderCache) -> Result<usize, DiskCacheError> {
+        let (count, contents) = self.export_snapshot(cache)?;
+        write_file(&self.path, &contents)?;
+        debug!(
+            "💾 [DISK-SAVE] Saved {} entries to {}",
+            count,
+            self.path.display()
+        );
+        Ok(count)
+    }
+
+    /// Saves the cache like `save`, writing the file on a background thread
+    ///
+    /// The entries are encoded on the calling thread, which owns the cache, so only the file
+    /// IO leaves it and a slow disk doesn't stall rendering.
+    async fn save_in_background(&self, cache: &ProviderCache) -> Result<usize, DiskCacheError> {
+        let (count, contents) = self.export_snapshot(cache)?;
+        let path = self.path.clone();
+        let (sender, receiver) = oneshot::channel();
+        thread::Builder::new()
+            .name("dioxus-provider-disk".to_string())
+            .spawn(move || {
+                let _ = sender.send(write_file(&path, &contents));
+            })?;
+        receiver
+            .await
+            .unwrap_or_else(|_| Err(io::Error::other("the disk writer thread panicked")))?;
+        debug!(
+            "💾 [DISK-SAVE] Saved {} entries to {}",
+            count,
+            self.path.display()
+        );
+        Ok(count)
+    }
+
+    /// Encodes the entries `save` would write, without touching the file
+    ///
+    /// Returns the number of entries and the JSON contents of the cache file. Failed
+    /// results, entries of unregistered types and entries of `no_persist` providers are
+    /// left out, as are entries keyed by their provider type whose provider isn't
+    /// registered with `register_provider`.
+    pub fn export_snapshot(
+        &self,
+        cache: &ProviderCache,
+    ) -> Result<(usize, Vec<u8>), DiskCacheError> {
+        let mut entries = Vec::new();
+        for key in cache.persistable_keys() {
+            let Some(entry) = cache.snapshot(&key) else {
+                continue;
+            };
+            if entry.is_error() {
+                continue;
+            }
+            // Entries keyed by their provider type are saved under the provider's type name and
+            // parameter key, which only that provider's codec can turn back into the key
+            let persisted = cache.persisted_key(&key);
+            let encoded = match &persisted {
+                Some((provider, _)) => self
+                    .codecs
+                    .iter()
+                    .filter(|(tag, _)| tag == provider)
+                    .find_map(|(tag, codec)| Some((tag, codec.encode(&entry)?))),
+                None => self
+                    .codecs
+                    .iter()
+                    .find_map(|(tag, codec)| Some((tag, codec.encode(&entry)?))),
+            };
+            match encoded {
+                Some((tag, Ok(value))) => entries.push(DiskEntry {
+                    param_key: persisted.is_some(),
+                    key: persisted.map_or(key, |(_, param_key)| param_key),
+                    tag: tag.clone(),
+                    age_ms: duration_millis(entry.age()),
+                    value,
+                }),
+                Some((_, Err(error))) => {
+                    debug!(
+                        "💾 [DISK-SAVE] Skipping {} that doesn't encode: {}",
+                        key, error
+                    );
+                }
+                None => {}
+            }
+        }
+
+        let count = entries.len();
+        let file = DiskFile {
+            version: self.version,
+            saved_at_ms: duration_millis(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default(),
+            ),
+            entries,
+        };
+        Ok((count, serde_json::to_vec(&file)?))
+    }
+
+    /// Loads the entries of the file into `cache`, keeping the age they had when saved
+    ///
+    /// A missing file or one written with another version loads nothing. Entries whose
+    /// type is no longer registered or no longer decodes are skipped, and keys that are
+    /// already cached keep their current entry. Provider entries get their cache key
+    /// rebuilt from the provider's type name and parameter key, so they are restored by
+    /// a rebuilt app too. Returns the number of entries loaded.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `DiskCacheError::Corrupt` if the file isn't a cache file, and
+    /// `DiskCacheError::Io` if it can't be read.
+    pub fn load(&self, cache: &ProviderCache) -> Result<usize, DiskCacheError> {
+        let contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error.into()),
+        };
+        let file: DiskFile = serde_json::from_slice(&contents)?;
+        if file.version != self.version {
+            debug!(
+                "💾 [DISK-LOAD] Ignoring {} written with version {} (expected {})",
+                self.path.display(),
+                file.version,
+                self.version
+            );
+            return Ok(0);
+        }
+
+        let now_ms = duration_millis(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default(),
+        );
+        let since_save = Duration::from_millis(now_ms.saturating_sub(file.saved_at_ms));
+        let mut loaded = 0;
+        for DiskEntry {
+            key,
+            param_key,
+            tag,
+            age_ms,
+            value,
+        } in file.entries
+        {
+            let Some((_, codec)) = self.codecs.iter().find(|(known, _)| *known == tag) else {
+                debug!(
+                    "💾 [DISK-LOAD] Skipping {} of unregistered type {}",
+                    key, tag
+                );
+                continue;
+            };
+            let key = if param_key {
+                let Some(cache_key) = codec.cache_key(&key) else {
+                    debug!(
+                        "💾 [DISK-LOAD] Skipping {} of {} that isn't a registered provider",
+                        key, tag
+                    );
+                    continue;
+                };
+                cache_key
+            } else {
+                key
+            };
+            if cache.snapshot(&key).is_some() {
+                continue;
+            }
+            let entry = match codec.decode(value) {
+                Ok(entry) => entry,
+                Err(error) => {
+                    debug!(
+                        "💾 [DISK-LOAD] Skipping {} that no longer decodes: {}",
+                        key, error
+                    );
+                    continue;
+                }
+            };
+            if entry.backdate(Duration::from_millis(age_ms) + since_save) {
+                cache.restore(&key, Some(entry));
+                loaded += 1;
+            }
+        }
+        debug!(
+            "💾 [DISK-LOAD] Loaded {} entries from {}",
+            loaded,
+            self.path.display()
+        );
+        Ok(loaded)
+    }
+
+    /// Saves the cache once it has been unchanged for the write delay after each change
+    async fn write_behind(self, cache: ProviderCache, changes: impl Stream<Item = Vec<String>>) {
+        let mut changes = pin!(changes);
+        while changes.next().await.is_some() {
+            // Restart the delay on every change, so a burst of writes is saved once
+            while let Either::Left((Some(_), _)) =
+                future::select(changes.next(), pin!(sleep(self.write_delay))).await
+            {}
+            if let Err(error) = self.save_in_background(&cache).await {
+                warn!(
+                    "💾 [DISK-SAVE] Failed to save {}: {}",
+                    self.path.display(),
+                    error
+                );
+            }
+        }
+    }
+}
+
+/// Hook that persists the default global cache to disk
+///
+/// On first render, loads the file of the disk cache created by `init` into the cache, so
+/// call it high in the tree, before the components whose providers it restores. Afterwards
+/// the cache is saved whenever it has been unchanged for the disk cache's write delay, until
+/// the component unmounts. A corrupt file is logged and replaced by the next save.
+pub fn use_disk_persistence(init: impl FnOnce() -> DiskCache) {
+    use_hook(|| {
+        ensure_global_providers();
+        let Ok(cache) = get_global_cache().cloned() else {
+            return;
+        };
+        let disk = init();
+        if let Err(error) = disk.load(&cache) {
+            warn!(
+                "💾 [DISK-LOAD] Failed to load {}: {}",
+                disk.path.display(),
+                error
+            );
+        }
+        // Subscribe before the task first runs, so no change is missed
+        let changes = cache.changes();
+        spawn(disk.write_behind(cache, changes));
+    });
+}
+
+/// Writes the contents of a cache file, replacing the file at `path` atomically
+fn write_file(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, contents)?;
+    fs::rename(&temporary, path)
+}
+
+/// Encodes and decodes the entries holding one type
+trait EntryCodec: Send + Sync {
+    /// Encodes the entry's value, or returns None if it holds another type
+    fn encode(&self, entry: &CacheEntry) -> Option<Result<serde_json::Value, serde_json::Error>>;
+
+    /// Decodes a value into a new entry
+    fn decode(&self, value: serde_json::Value) -> Result<CacheEntry, serde_json::Error>;
+
+    /// Builds the cache key of a provider entry from its parameter key, or returns None if
+    /// the codec doesn't belong to a provider
+    fn cache_key(&self, param_key: &str) -> Option<String> {
+        let _ = param_key;
+        None
+    }
+}
+
+struct TypedCodec<T>(PhantomData<fn() -> T>);
+
+impl<T> EntryCodec for TypedCodec<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    fn encode(&self, entry: &CacheEntry) -> Option<Result<serde_json::Value, serde_json::Error>> {
+        entry.peek::<T>().map(serde_json::to_value)
+    }
+
+    fn decode(&self, value: serde_json::Value) -> Result<CacheEntry, serde_json::Error> {
+        serde_json::from_value::<T>(value).map(CacheEntry::new)
+    }
+}
+
+/// Encodes the `Ok` values of provider entries, skipping cached errors
+struct SuccessCodec<T, E> {
+    /// Builds the provider's cache key from a parameter key
+    cache_key: fn(&str) -> String,
+    marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T, E> EntryCodec for SuccessCodec<T, E>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    fn encode(&self, entry: &CacheEntry) -> Option<Result<serde_json::Value, serde_json::Error>> {
+        match entry.peek::<Result<T, E>>()? {
+            Ok(value) => Some(serde_json::to_value(value)),
+            Err(_) => None,
+        }
+    }
+
+    fn decode(&self, value: serde_json::Value) -> Result<CacheEntry, serde_json::Error> {
+        serde_json::from_value::<T>(value).map(|value| CacheEntry::new(Ok::<T, E>(value)))
+    }
+
+    fn cache_key(&self, param_key: &str) -> Option<String> {
+        Some((self.cache_key)(param_key))
+    }
+}
+
+/// The contents of a cache file
+#[derive(Serialize, Deserialize)]
+struct DiskFile {
+    version: u32,
+    /// When the file was written, in milliseconds since the Unix epoch
+    saved_at_ms: u64,
+    entries: Vec<DiskEntry>,
+}
+
+/// A cache entry in a cache file
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    /// The entry's cache key, or its parameter key if `param_key` is set
+    key: String,
+    /// Whether `key` is a parameter key, turned into the cache key by the provider in `tag`
+    #[serde(default, skip_serializing_if = "is_false")]
+    param_key: bool,
+    /// The tag the entry's type was registered with
+    tag: String,
+    /// The entry's age when the file was written
+    age_ms: u64,
+    value: serde_json::Value,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+fn duration_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,186 @@
+//! # Cancellation
+//!
+//! This module provides the [`CancellationToken`] passed to providers declared with
+//! `#[provider(with_cancel)]`. The hooks cancel the token when a fetch is dropped before it
+//! completes (for example when its component unmounts), so long-running providers can stop
+//! work early and release resources such as connections, background tasks or file handles.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus_provider::{cancel::CancellationToken, prelude::*};
+//!
+//! #[provider(with_cancel)]
+//! async fn fetch_export(job_id: u32, cancel: CancellationToken) -> Result<String, String> {
+//!     for chunk in 0..100 {
+//!         if cancel.is_cancelled() {
+//!             return Err(format!("export {} cancelled at chunk {}", job_id, chunk));
+//!         }
+//!         // ... download the next chunk
+//!     }
+//!     Ok(format!("export {}", job_id))
+//! }
+//! ```
+//!
+//! ## Cross-Platform Compatibility
+//!
+//! The token only uses `std` synchronization and wakers, so it works on both web and desktop
+//! without depending on a particular async runtime.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Token signalling that a provider fetch has been cancelled
+///
+/// Clones share the same state, so cancelling any clone cancels them all.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Arc<CancellationState>,
+}
+
+impl CancellationToken {
+    /// Create a new token that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the token and wake every task waiting on [`CancellationToken::cancelled`]
+    pub fn cancel(&self) {
+        if self.state.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        if let Ok(mut wakers) = self.state.wakers.lock() {
+            for waker in wakers.drain(..) {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Check if the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Wait until the token is cancelled
+    ///
+    /// Useful for racing work against cancellation, e.g. with `futures::select!`.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+        }
+    }
+
+    /// Get a guard that cancels the token when dropped
+    pub fn drop_guard(self) -> CancelOnDrop {
+        CancelOnDrop { token: Some(self) }
+    }
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`]
+pub struct Cancelled {
+    token: CancellationToken,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        if let Ok(mut wakers) = self.token.state.wakers.lock()
+            && !wakers.iter().any(|waker| waker.will_wake(cx.waker()))
+        {
+            wakers.push(cx.waker().clone());
+        }
+        // Re-check in case the token was cancelled while registering the waker
+        if self.token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Guard that cancels its token when dropped
+///
+/// The hooks hold one inside every fetch task, so dropping the task (on unmount or when it
+/// is cancelled) trips the token observed by the provider.
+pub struct CancelOnDrop {
+    token: Option<CancellationToken>,
+}
+
+impl CancelOnDrop {
+    /// Drop the guard without cancelling its token, e.g. once the fetch has completed
+    pub fn disarm(mut self) -> CancellationToken {
+        self.token.take().unwrap_or_default()
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(token) = &self.token {
+            token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_wakes_waiting_task() {
+        let token = CancellationToken::new();
+        let waiter = tokio::spawn(token.cancelled());
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!waiter.is_finished());
+
+        token.clone().cancel();
+        tokio::time::timeout(Duration::from_millis(100), waiter)
+            .await
+            .expect("waiter should be woken")
+            .unwrap();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_drop_guard_cancels_token() {
+        let token = CancellationToken::new();
+        let guard = token.clone().drop_guard();
+        assert!(!token.is_cancelled());
+
+        drop(guard);
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_disarmed_guard_leaves_token_untouched() {
+        let token = CancellationToken::new();
+        token.clone().drop_guard().disarm();
+        assert!(!token.is_cancelled());
+    }
+}
@@ -0,0 +1,277 @@
+//! # Components for rendering provider state
+//!
+//! Nearly every component that reads a provider matches on its [`ProviderState`] to pick
+//! a loading, error, or success view. [`ProviderView`] does that match once so components
+//! only describe what each state looks like. [`ProviderSuspenseGroup`] coordinates when
+//! suspending providers in separate suspense boundaries are revealed.
+
+use dioxus::prelude::*;
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    future::Future,
+    rc::Rc,
+};
+use tokio::sync::Notify;
+
+use crate::provider_state::ProviderState;
+
+/// Renders a provider's state with a view per state
+///
+/// `success` receives a clone of the data and `error` a clone of the error. `loading` and
+/// `error` are optional and render nothing when omitted. `idle` is rendered before a lazy
+/// provider has started and defaults to the `loading` view. `empty` replaces `success` for
+/// data that `is_empty` returns true for, e.g. an empty list, so it only shows once the
+/// provider has loaded.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_greeting() -> Result<String, String> {
+///     Ok("Hello".to_string())
+/// }
+///
+/// #[component]
+/// fn Greeting() -> Element {
+///     let greeting = use_provider(fetch_greeting(), ());
+///     rsx! {
+///         ProviderView {
+///             state: greeting,
+///             loading: rsx! { "Loading..." },
+///             error: |e: String| rsx! { "Error: {e}" },
+///             success: |text: String| rsx! { h1 { "{text}" } },
+///         }
+///     }
+/// }
+/// ```
+///
+/// For collections, pass `empty` and `is_empty` to show a placeholder instead of an empty list:
+///
+/// ```rust,no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_provider::prelude::*;
+/// # #[provider]
+/// # async fn fetch_todos() -> Result<Vec<String>, String> { Ok(Vec::new()) }
+/// #[component]
+/// fn Todos() -> Element {
+///     let todos = use_provider(fetch_todos(), ());
+///     rsx! {
+///         ProviderView {
+///             state: todos,
+///             loading: rsx! { "Loading..." },
+///             empty: rsx! { "No todos yet" },
+///             is_empty: |todos: Vec<String>| todos.is_empty(),
+///             success: |todos: Vec<String>| rsx! {
+///                 for todo in todos {
+///                     li { "{todo}" }
+///                 }
+///             },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ProviderView<T: Clone + PartialEq + 'static, E: Clone + PartialEq + 'static>(
+    /// The provider state to render, as returned by `use_provider`
+    state: Signal<ProviderState<T, E>>,
+    /// Renders the data once the provider has succeeded
+    success: Callback<T, Element>,
+    /// Rendered while the provider is loading
+    loading: Option<Element>,
+    /// Rendered while the provider has not started, instead of `loading`
+    idle: Option<Element>,
+    /// Renders the error if the provider failed
+    error: Option<Callback<E, Element>>,
+    /// Rendered instead of `success` when `is_empty` returns true for the data
+    empty: Option<Element>,
+    /// Decides whether the data is empty and should render `empty`
+    is_empty: Option<Callback<T, bool>>,
+) -> Element {
+    let current = state.read().clone();
+    match current {
+        ProviderState::Idle => idle.or(loading).unwrap_or(VNode::empty()),
+        ProviderState::Loading { .. } => loading.unwrap_or(VNode::empty()),
+        ProviderState::Success(data) => match (empty, is_empty) {
+            (Some(empty), Some(is_empty)) if is_empty.call(data.clone()) => empty,
+            _ => success.call(data),
+        },
+        ProviderState::Error(e) => match error {
+            Some(render_error) => render_error.call(e),
+            None => VNode::empty(),
+        },
+    }
+}
+
+/// The order in which the members of a [`ProviderSuspenseGroup`] are revealed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RevealOrder {
+    /// Every member stays suspended until all of them have loaded
+    #[default]
+    Together,
+    /// Members are revealed in the order they first rendered, each once it and every
+    /// member before it have loaded
+    Forwards,
+}
+
+/// Reveals the suspending providers inside it together instead of one by one
+///
+/// Components that read a provider with
+/// [`use_provider_suspense`](crate::hooks::use_provider_suspense) join the closest group
+/// above them. Each can sit in its own `SuspenseBoundary` with its own fallback, but a
+/// member that has loaded keeps its boundary suspended until the group reveals it: with
+/// [`RevealOrder::Together`] once every member has loaded (or failed), with
+/// [`RevealOrder::Forwards`] once every member before it has. This avoids the staggered
+/// reveal of a page whose sections load at different speeds.
+///
+/// Members waiting on a provider that has not started (`Idle`) hold back the group until
+/// it loads.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_section(id: u32) -> Result<String, String> {
+///     Ok(format!("Section {id}"))
+/// }
+///
+/// #[component]
+/// fn Section(id: u32) -> Element {
+///     let section = use_provider_suspense(fetch_section(), id)?;
+///     rsx! { "{section:?}" }
+/// }
+///
+/// #[component]
+/// fn Page() -> Element {
+///     rsx! {
+///         ProviderSuspenseGroup {
+///             for id in 0..3 {
+///                 SuspenseBoundary {
+///                     key: "{id}",
+///                     fallback: |_| rsx! { "Loading section..." },
+///                     Section { id }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ProviderSuspenseGroup(
+    /// The order members are revealed in, all at once by default
+    #[props(default)]
+    reveal_order: RevealOrder,
+    children: Element,
+) -> Element {
+    let group = use_context_provider(|| SuspenseGroup::new(reveal_order));
+    group.set_reveal_order(reveal_order);
+    children
+}
+
+/// The members of a [`ProviderSuspenseGroup`], shared with them through context
+#[derive(Clone)]
+pub(crate) struct SuspenseGroup {
+    inner: Rc<SuspenseGroupInner>,
+}
+
+struct SuspenseGroupInner {
+    reveal_order: Cell<RevealOrder>,
+    /// Whether each member has loaded, by member id in the order the members joined
+    members: RefCell<BTreeMap<u64, bool>>,
+    next_id: Cell<u64>,
+    /// Woken whenever a member loads or leaves, or the reveal order changes
+    changed: Notify,
+}
+
+impl SuspenseGroup {
+    fn new(reveal_order: RevealOrder) -> Self {
+        Self {
+            inner: Rc::new(SuspenseGroupInner {
+                reveal_order: Cell::new(reveal_order),
+                members: RefCell::default(),
+                next_id: Cell::new(0),
+                changed: Notify::new(),
+            }),
+        }
+    }
+
+    fn set_reveal_order(&self, reveal_order: RevealOrder) {
+        if self.inner.reveal_order.replace(reveal_order) != reveal_order {
+            self.inner.changed.notify_waiters();
+        }
+    }
+
+    /// Adds a member that has not loaded yet, it leaves the group when dropped
+    pub(crate) fn join(&self) -> SuspenseGroupMember {
+        let id = self.inner.next_id.get();
+        self.inner.next_id.set(id + 1);
+        self.inner.members.borrow_mut().insert(id, false);
+        SuspenseGroupMember {
+            group: self.clone(),
+            id,
+        }
+    }
+}
+
+/// A component's membership in a [`ProviderSuspenseGroup`]
+pub(crate) struct SuspenseGroupMember {
+    group: SuspenseGroup,
+    id: u64,
+}
+
+impl SuspenseGroupMember {
+    /// Records whether the member's provider has loaded
+    pub(crate) fn set_loaded(&self, loaded: bool) {
+        let previous = self
+            .group
+            .inner
+            .members
+            .borrow_mut()
+            .insert(self.id, loaded);
+        if previous != Some(loaded) {
+            self.group.inner.changed.notify_waiters();
+        }
+    }
+
+    /// Checks whether the group shows the member
+    pub(crate) fn is_revealed(&self) -> bool {
+        is_revealed(&self.group.inner, self.id)
+    }
+
+    /// Waits until the group shows the member
+    pub(crate) fn revealed(&self) -> impl Future<Output = ()> + 'static {
+        let inner = self.group.inner.clone();
+        let id = self.id;
+        async move {
+            loop {
+                // Register before checking, so a change in between is not missed
+                let changed = inner.changed.notified();
+                if is_revealed(&inner, id) {
+                    return;
+                }
+                changed.await;
+            }
+        }
+    }
+}
+
+impl Drop for SuspenseGroupMember {
+    fn drop(&mut self) {
+        self.group.inner.members.borrow_mut().remove(&self.id);
+        self.group.inner.changed.notify_waiters();
+    }
+}
+
+fn is_revealed(group: &SuspenseGroupInner, id: u64) -> bool {
+    let members = group.members.borrow();
+    match group.reveal_order.get() {
+        RevealOrder::Together => members.values().all(|loaded| *loaded),
+        RevealOrder::Forwards => members.range(..=id).all(|(_, loaded)| *loaded),
+    }
+}
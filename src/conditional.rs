@@ -0,0 +1,160 @@
+//! # Conditional Requests
+//!
+//! This module lets providers revalidate cached data with conditional requests such as
+//! HTTP `If-None-Match`. While the hooks fetch a provider, [`cache_meta`] gives its body
+//! access to opaque string metadata stored next to the cache entry, for example the `ETag`
+//! of the cached response. When the server answers that nothing changed, the provider
+//! returns [`FetchOutcome::NotModified`] and the hooks keep the cached value, only marking
+//! it as fresh again instead of storing and re-rendering a new one.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus_provider::{conditional::cache_meta, prelude::*};
+//!
+//! # struct Response { status: u16, etag: Option<String>, body: String }
+//! # async fn get(url: &str, if_none_match: Option<String>) -> Result<Response, String> { todo!() }
+//! #[provider(stale_time = "30s", conditional)]
+//! async fn fetch_article(id: u32) -> Result<FetchOutcome<String>, String> {
+//!     let meta = cache_meta();
+//!     let etag = meta.as_ref().and_then(|meta| meta.get("etag"));
+//!     let response = get(&format!("/articles/{}", id), etag).await?;
+//!     if response.status == 304 {
+//!         return Ok(FetchOutcome::NotModified);
+//!     }
+//!     if let (Some(meta), Some(etag)) = (meta, response.etag) {
+//!         meta.set("etag", etag);
+//!     }
+//!     Ok(FetchOutcome::Modified(response.body))
+//! }
+//! ```
+//!
+//! Only return `NotModified` after sending a validator read from [`cache_meta`]: metadata is
+//! only visible while a cached entry exists, so there is always a value to keep. The error
+//! type of a conditional provider must implement `From<ProviderError>`, so a `NotModified`
+//! answered when nothing is cached can be reported as an error.
+
+use std::future::Future;
+
+use tracing::debug;
+
+use crate::{cache::ProviderCache, errors::ProviderError, global::get_cache_and_registry};
+
+tokio::task_local! {
+    static CACHE_META: Option<CacheMeta>;
+}
+
+/// The result of a conditional fetch
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchOutcome<T> {
+    /// The data changed (or was fetched unconditionally) and should replace the cached value
+    Modified(T),
+    /// The cached value is still current and only needs to be marked as fresh
+    NotModified,
+}
+
+impl<T> FetchOutcome<T> {
+    /// Maps the modified value, leaving `NotModified` unchanged
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> FetchOutcome<U> {
+        match self {
+            FetchOutcome::Modified(value) => FetchOutcome::Modified(f(value)),
+            FetchOutcome::NotModified => FetchOutcome::NotModified,
+        }
+    }
+}
+
+/// Metadata of the cache entry a provider is currently fetching
+///
+/// Obtained with [`cache_meta`]. Values are opaque strings owned by the provider, such as
+/// an `ETag` or `Last-Modified` header, and are dropped together with the entry.
+#[derive(Clone)]
+pub struct CacheMeta {
+    cache: ProviderCache,
+    key: String,
+}
+
+impl CacheMeta {
+    pub(crate) fn new(cache: ProviderCache, key: &str) -> Self {
+        Self {
+            cache,
+            key: key.to_string(),
+        }
+    }
+
+    /// Gets the cache key of the entry being fetched
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Gets a metadata value of the cached entry (None if unset or nothing is cached yet)
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.cache.entry_meta(&self.key, name)
+    }
+
+    /// Sets a metadata value, kept for the entry stored from this fetch
+    pub fn set(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.cache
+            .set_entry_meta(&self.key, name.into(), value.into());
+    }
+}
+
+/// Gets the metadata of the cache entry being fetched (None outside a fetch run by the hooks)
+///
+/// Calling [`Provider::run`](crate::hooks::Provider::run) directly never exposes metadata, so
+/// conditional providers fall back to a plain request there.
+pub fn cache_meta() -> Option<CacheMeta> {
+    CACHE_META.try_with(Clone::clone).ok().flatten()
+}
+
+/// Runs a future with `meta` returned by [`cache_meta`]
+pub(crate) async fn with_cache_meta<F: Future>(meta: CacheMeta, future: F) -> F::Output {
+    CACHE_META.scope(Some(meta), future).await
+}
+
+/// Runs a conditional fetch without metadata, so it resolves to a value
+///
+/// Used by `#[provider(conditional)]` to implement `Provider::run`, including when the
+/// provider is composed into another provider's fetch. Should `fetch` answer `NotModified`
+/// anyway, the result cached for `key` in the named cache is returned, or the fetch is run
+/// once more if nothing is cached.
+///
+/// # Errors
+///
+/// Returns the fetch's error, or a [`ProviderError::Cache`] converted into `E` if the fetch
+/// answers `NotModified` twice while nothing is cached for `key`.
+#[doc(hidden)]
+pub async fn run_unconditional<T, E, F, Fut>(
+    cache_name: Option<&'static str>,
+    key: String,
+    mut fetch: F,
+) -> Result<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + From<ProviderError> + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<FetchOutcome<T>, E>>,
+{
+    if let FetchOutcome::Modified(value) = CACHE_META.scope(None, fetch()).await? {
+        return Ok(value);
+    }
+    if let Ok((cache, _)) = get_cache_and_registry(cache_name)
+        && let Some(cached) = cache.get::<Result<T, E>>(&key)
+    {
+        debug!(
+            "📦 [CONDITIONAL] Not modified, keeping cached result for: {}",
+            key
+        );
+        return cached;
+    }
+    debug!(
+        "🔄 [CONDITIONAL] Not modified without a cached result, fetching again: {}",
+        key
+    );
+    match CACHE_META.scope(None, fetch()).await? {
+        FetchOutcome::Modified(value) => Ok(value),
+        FetchOutcome::NotModified => Err(ProviderError::Cache(format!(
+            "provider returned FetchOutcome::NotModified for '{key}' without a cached entry to keep"
+        ))
+        .into()),
+    }
+}
@@ -0,0 +1,215 @@
+//! # Provider Devtools
+//!
+//! A floating panel for inspecting a provider cache while developing, enabled with the
+//! `devtools` feature. Drop [`ProviderDevtools`] into the root of the app to list every
+//! cached entry with its age, error state and subscribers, see which keys poll on an
+//! interval or are being revalidated, and invalidate entries by hand.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus::prelude::*;
+//! use dioxus_provider::prelude::*;
+//!
+//! #[component]
+//! fn App() -> Element {
+//!     rsx! {
+//!         // ... the app
+//!         if cfg!(debug_assertions) {
+//!             ProviderDevtools {}
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The panel polls the cache, once a second by default, so it also shows changes made
+//! outside of components.
+
+use dioxus::prelude::*;
+use std::time::Duration;
+
+use crate::{
+    cache::{CacheEntryInfo, ProviderCache},
+    global::{ensure_global_providers, get_cache_and_registry},
+    platform::time::sleep,
+    refresh::RefreshRegistry,
+};
+
+/// How often the panel re-reads the cache unless `poll_interval` is set
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A cache entry as shown by [`ProviderDevtools`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DevtoolsEntry {
+    /// The entry's cache key
+    pub key: String,
+    /// The provider type that stored the entry (debug builds only)
+    pub provider: Option<&'static str>,
+    /// The described parameter of the entry, see `Provider::describe_param` (debug builds only)
+    pub param: Option<String>,
+    /// A snapshot of the entry's metadata
+    pub info: CacheEntryInfo,
+    /// Number of components subscribed to refreshes of the key
+    pub subscribers: usize,
+    /// The key's refresh interval, if it is polled
+    pub interval: Option<Duration>,
+    /// Whether a background revalidation of the key is running
+    pub revalidating: bool,
+}
+
+/// Collects the entries of a cache together with their refresh state, sorted by key
+pub fn devtools_entries(cache: &ProviderCache, registry: &RefreshRegistry) -> Vec<DevtoolsEntry> {
+    let intervals = registry.active_interval_keys();
+    let revalidating = registry.revalidating_keys();
+    cache
+        .entries()
+        .into_iter()
+        .map(|(key, info)| DevtoolsEntry {
+            provider: cache.key_owner(&key),
+            param: cache.key_param(&key),
+            subscribers: registry.subscriber_count(&key),
+            interval: intervals
+                .iter()
+                .find(|(interval_key, _)| *interval_key == key)
+                .map(|(_, interval)| *interval),
+            revalidating: revalidating.contains(&key),
+            info,
+            key,
+        })
+        .collect()
+}
+
+/// Floating development panel listing the entries of a provider cache
+///
+/// Each entry can be invalidated, which removes it and refetches it in every component
+/// using it. Unless `start_open` is set, the panel starts collapsed to a small button in the
+/// bottom right corner.
+#[component]
+pub fn ProviderDevtools(
+    /// Name of the cache to inspect (None means the default global cache)
+    cache: Option<&'static str>,
+    /// How often the panel re-reads the cache (defaults to one second)
+    poll_interval: Option<Duration>,
+    /// Whether the panel starts expanded instead of collapsed to its button
+    #[props(default)]
+    start_open: bool,
+) -> Element {
+    let mut open = use_signal(|| start_open);
+    let mut tick = use_signal(|| 0u64);
+    let poll_interval = poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+    use_future(move || async move {
+        loop {
+            sleep(poll_interval).await;
+            tick += 1;
+        }
+    });
+    // Re-render on every poll
+    let _ = tick();
+
+    ensure_global_providers();
+    let (provider_cache, registry) = match get_cache_and_registry(cache) {
+        Ok(pair) => pair,
+        Err(error) => return rsx! { div { style: PANEL_STYLE, "{error}" } },
+    };
+    let entries = devtools_entries(&provider_cache, &registry);
+    let entry_count = entries.len();
+
+    if !open() {
+        return rsx! {
+            button { style: TOGGLE_STYLE, onclick: move |_| open.set(true), "Providers ({entry_count})" }
+        };
+    }
+
+    let clear_cache = provider_cache.clone();
+
+    rsx! {
+        div { style: PANEL_STYLE,
+            div { style: "display: flex; gap: 8px; align-items: center; margin-bottom: 8px;",
+                strong { style: "flex: 1;", "Providers ({entry_count})" }
+                button {
+                    onclick: move |_| {
+                        clear_cache.clear();
+                        tick += 1;
+                    },
+                    "Clear all"
+                }
+                button { onclick: move |_| open.set(false), "Close" }
+            }
+            table { style: "width: 100%; border-collapse: collapse;",
+                thead {
+                    tr {
+                        th { "Key" }
+                        th { "Status" }
+                        th { "Age" }
+                        th { "Reads" }
+                        th { "Subs" }
+                        th { "Interval" }
+                        th {}
+                    }
+                }
+                tbody {
+                    for entry in entries {
+                        DevtoolsRow {
+                            key: "{entry.key}",
+                            entry,
+                            cache,
+                            on_change: move |_| tick += 1,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One row of [`ProviderDevtools`]
+#[component]
+fn DevtoolsRow(
+    entry: DevtoolsEntry,
+    cache: Option<&'static str>,
+    on_change: EventHandler,
+) -> Element {
+    let status = match (entry.info.is_error, entry.revalidating) {
+        (_, true) => "revalidating",
+        (true, false) => "error",
+        (false, false) => "ok",
+    };
+    let interval = entry
+        .interval
+        .map(|interval| format!("{interval:?}"))
+        .unwrap_or_default();
+    let title = match &entry.param {
+        Some(param) => format!("{}({param})", entry.provider.unwrap_or_default()),
+        None => entry.provider.unwrap_or_default().to_string(),
+    };
+    let key = entry.key.clone();
+
+    rsx! {
+        tr {
+            td { title: "{title}", style: "font-family: monospace;", "{entry.key}" }
+            td { "{status}" }
+            td { "{entry.info.age:.1?}" }
+            td { "{entry.info.access_count}" }
+            td { "{entry.subscribers}" }
+            td { "{interval}" }
+            td {
+                button {
+                    onclick: move |_| {
+                        if let Ok((cache, _)) = get_cache_and_registry(cache) {
+                            cache.invalidate(&key);
+                        }
+                        on_change.call(());
+                    },
+                    "Invalidate"
+                }
+            }
+        }
+    }
+}
+
+const TOGGLE_STYLE: &str = "position: fixed; right: 12px; bottom: 12px; z-index: 10000; \
+    padding: 6px 10px; font: 12px sans-serif; border-radius: 4px;";
+
+const PANEL_STYLE: &str = "position: fixed; right: 12px; bottom: 12px; z-index: 10000; \
+    width: 640px; max-height: 50vh; overflow: auto; padding: 10px; font: 12px sans-serif; \
+    background: #1e1e1e; color: #eee; border-radius: 6px; box-shadow: 0 2px 12px rgba(0,0,0,.4);";
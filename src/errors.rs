@@ -49,6 +49,10 @@
 //! }
 //! ```
 
+use crate::injection::InjectionError;
+use std::fmt;
+#[cfg(feature = "backtrace")]
+use std::{backtrace::Backtrace, sync::Arc};
 use thiserror::Error;
 
 /// Common error types for provider operations
@@ -98,9 +102,123 @@ pub enum ProviderError {
     #[error("Cache error: {0}")]
     Cache(String),
 
+    /// Errors returned by a server function, with the HTTP status of its response
+    #[error("Server function failed with status {status}: {message}")]
+    ServerFn { status: u16, message: String },
+
     /// Generic provider errors for cases not covered above
     #[error("Provider error: {0}")]
     Generic(String),
+
+    /// An error annotated with the provider key and parameter that produced it
+    #[error("{source} (provider: {key}, param: {param})")]
+    WithContext {
+        key: String,
+        param: String,
+        source: Box<ProviderError>,
+        trace: ProviderBacktrace,
+    },
+}
+
+impl ProviderError {
+    /// Wrap an error with the cache key and described parameter of the failing provider
+    ///
+    /// With the `backtrace` feature enabled, a backtrace is captured at this point.
+    pub fn with_context(
+        key: impl Into<String>,
+        param_debug: impl Into<String>,
+        source: ProviderError,
+    ) -> Self {
+        ProviderError::WithContext {
+            key: key.into(),
+            param: param_debug.into(),
+            source: Box::new(source),
+            trace: ProviderBacktrace::capture(),
+        }
+    }
+
+    /// Get the key of the provider that produced this error, if it carries context
+    pub fn provider_key(&self) -> Option<&str> {
+        match self {
+            ProviderError::WithContext { key, .. } => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Get the error without any provider context
+    pub fn root_cause(&self) -> &ProviderError {
+        match self {
+            ProviderError::WithContext { source, .. } => source.root_cause(),
+            error => error,
+        }
+    }
+
+    /// Get the backtrace captured when context was attached
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            ProviderError::WithContext { trace, .. } => trace.get(),
+            _ => None,
+        }
+    }
+}
+
+/// Backtrace captured when provider context is attached to an error
+///
+/// Only captured with the `backtrace` feature, otherwise it is always empty. It is
+/// ignored when comparing errors, so the same failure still compares equal.
+#[derive(Clone, Default)]
+pub struct ProviderBacktrace {
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<Arc<Backtrace>>,
+}
+
+impl ProviderBacktrace {
+    /// Capture a backtrace if the `backtrace` feature is enabled
+    pub fn capture() -> Self {
+        Self {
+            #[cfg(feature = "backtrace")]
+            backtrace: Some(Arc::new(Backtrace::force_capture())),
+        }
+    }
+
+    /// Get the captured backtrace
+    #[cfg(feature = "backtrace")]
+    pub fn get(&self) -> Option<&Backtrace> {
+        self.backtrace.as_deref()
+    }
+}
+
+impl fmt::Debug for ProviderBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = &self.backtrace {
+            return write!(f, "\n{backtrace}");
+        }
+        f.write_str("<no backtrace>")
+    }
+}
+
+impl PartialEq for ProviderBacktrace {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Errors that can be annotated with the key and parameter of the provider that failed
+///
+/// Providers declared with `#[provider(error_context = true)]` call this on every error
+/// before it is cached. Implement it for custom error types to opt them in, e.g. by
+/// wrapping the `ProviderError` variant.
+pub trait WithProviderContext {
+    /// Attach the provider's cache key and described parameter to the error
+    fn with_provider_context(self, key: &str, param_debug: String) -> Self;
+}
+
+impl WithProviderContext for ProviderError {
+    fn with_provider_context(self, key: &str, param_debug: String) -> Self {
+        ProviderError::with_context(key, param_debug, self)
+    }
 }
 
 /// Errors specific to user operations
@@ -225,6 +343,36 @@ impl From<ProviderError> for String {
     }
 }
 
+impl From<InjectionError> for ProviderError {
+    fn from(error: InjectionError) -> Self {
+        ProviderError::DependencyInjection(error.to_string())
+    }
+}
+
+impl From<InjectionError> for String {
+    fn from(error: InjectionError) -> Self {
+        ProviderError::from(error).to_string()
+    }
+}
+
+impl From<InjectionError> for UserError {
+    fn from(error: InjectionError) -> Self {
+        UserError::Provider(error.into())
+    }
+}
+
+impl From<InjectionError> for ApiError {
+    fn from(error: InjectionError) -> Self {
+        ApiError::Provider(error.into())
+    }
+}
+
+impl From<InjectionError> for DatabaseError {
+    fn from(error: InjectionError) -> Self {
+        DatabaseError::Provider(error.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +383,22 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid input: test input");
     }
 
+    #[test]
+    fn test_provider_error_context_display() {
+        let error = ProviderError::Network("connection failed".to_string())
+            .with_provider_context("fetch_user(1)", "1".to_string());
+
+        assert_eq!(error.provider_key(), Some("fetch_user(1)"));
+        assert_eq!(
+            error.root_cause(),
+            &ProviderError::Network("connection failed".to_string())
+        );
+        assert_eq!(
+            error.to_string(),
+            "Network error: connection failed (provider: fetch_user(1), param: 1)"
+        );
+    }
+
     #[test]
     fn test_user_error_with_provider_error() {
         let provider_error = ProviderError::Network("connection failed".to_string());
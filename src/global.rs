@@ -3,9 +3,28 @@
 //! This module provides global singletons for cache, disposal, and refresh management
 //! that operate at application scale rather than component lifecycle scale.
 
-use std::sync::OnceLock;
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+    time::Duration,
+};
 
-use crate::{cache::ProviderCache, refresh::RefreshRegistry};
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+#[cfg(target_family = "wasm")]
+use web_time::Instant;
+
+use futures::{FutureExt, StreamExt, channel::oneshot, future::Shared};
+use tokio::sync::Semaphore;
+
+use crate::{
+    cache::{CacheConfig, ProviderCache},
+    hooks::{Provider, revalidate_mounted_entry, run_provider, store_provider_result},
+    provider_state::ProviderState,
+    refresh::RefreshRegistry,
+    types::ProviderParamBounds,
+};
 
 /// Error type for global provider operations
 #[derive(Debug, thiserror::Error)]
@@ -14,6 +33,8 @@ pub enum GlobalProviderError {
     NotInitialized,
     #[error("Failed to initialize global providers: {0}")]
     InitializationFailed(String),
+    #[error("Named cache '{0}' not initialized. Call init_named_cache(\"{0}\", ..) first.")]
+    NamedCacheNotFound(String),
 }
 
 /// Global singleton instance of the provider cache
@@ -22,10 +43,46 @@ static GLOBAL_CACHE: OnceLock<ProviderCache> = OnceLock::new();
 /// Global singleton instance of the refresh registry
 static GLOBAL_REFRESH_REGISTRY: OnceLock<RefreshRegistry> = OnceLock::new();
 
+/// Type alias for the registry of named caches and their refresh registries
+type NamedCacheRegistry = Mutex<HashMap<String, (ProviderCache, RefreshRegistry)>>;
+
+/// Global map of named caches, each with its own refresh registry
+static NAMED_CACHES: OnceLock<NamedCacheRegistry> = OnceLock::new();
+
+/// Type alias for the fetch permits of each provider type with a concurrency limit, keyed by
+/// `Provider::type_name` and the limit
+type ConcurrencyLimiters = Mutex<HashMap<(&'static str, usize), Arc<Semaphore>>>;
+
+/// Global map of the fetch permits of provider types declaring `max_concurrency`
+static CONCURRENCY_LIMITERS: OnceLock<ConcurrencyLimiters> = OnceLock::new();
+
+/// Type alias for the cache name and key of a prefetch
+type PrefetchSlot = (Option<&'static str>, String);
+
+/// Type alias for the prefetches running for each cache and key, resolved once they finish
+type InFlightPrefetches = Mutex<HashMap<PrefetchSlot, Shared<oneshot::Receiver<()>>>>;
+
+/// Global map of running prefetches, so callers prefetching the same key share one fetch
+static IN_FLIGHT_PREFETCHES: OnceLock<InFlightPrefetches> = OnceLock::new();
+
+/// Handles to the global provider cache and refresh registry
+///
+/// Returned by [`init_global_providers`] so an application can seed, inspect or wire up the
+/// cache at startup, before any component mounts. Both handles share their state with the
+/// global instances, so cloning is cheap.
+#[derive(Clone)]
+pub struct GlobalProviders {
+    /// The default cache every provider without a named cache stores its results in
+    pub cache: ProviderCache,
+    /// The refresh registry tracking subscribers, intervals and mounted hooks of the cache
+    pub refresh: RefreshRegistry,
+}
+
 /// Initialize the global provider management system
 ///
 /// This should be called once at the start of your application,
-/// typically in your main function or app initialization.
+/// typically in your main function or app initialization. Calling it again keeps the
+/// existing cache and registry, and returns handles to them.
 ///
 /// ## Example
 ///
@@ -35,8 +92,11 @@ static GLOBAL_REFRESH_REGISTRY: OnceLock<RefreshRegistry> = OnceLock::new();
 ///
 /// fn main() {
 ///     // Initialize global provider system
-///     init_global_providers();
-///     
+///     let providers = init_global_providers().unwrap();
+///
+///     // Hydrate the cache before any component mounts
+///     providers.cache.set("app:config".to_string(), Ok::<_, String>("dark".to_string()));
+///
 ///     // Launch your app
 ///     dioxus::launch(app);
 /// }
@@ -48,14 +108,48 @@ static GLOBAL_REFRESH_REGISTRY: OnceLock<RefreshRegistry> = OnceLock::new();
 ///     }
 /// }
 /// ```
-pub fn init_global_providers() -> Result<(), GlobalProviderError> {
-    // Initialize cache first
-    GLOBAL_CACHE.get_or_init(ProviderCache::new);
+pub fn init_global_providers() -> Result<GlobalProviders, GlobalProviderError> {
+    init_global_providers_with_config(CacheConfig::default())
+}
+
+/// Initialize the global provider system with settings for the default cache
+///
+/// Works like [`init_global_providers`]. `config` only applies if the global cache isn't
+/// initialized yet.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::{cache::CacheConfig, global::init_global_providers_with_config};
+/// use std::time::Duration;
+///
+/// // Check expiration every 100ms, so expired entries are removed right away
+/// init_global_providers_with_config(CacheConfig {
+///     check_interval: Some(Duration::from_millis(100)),
+///     ..CacheConfig::default()
+/// })
+/// .unwrap();
+/// ```
+pub fn init_global_providers_with_config(
+    config: CacheConfig,
+) -> Result<GlobalProviders, GlobalProviderError> {
+    // Initialize refresh registry first, so the cache can refresh its subscribers
+    let refresh_registry = GLOBAL_REFRESH_REGISTRY.get_or_init(RefreshRegistry::new);
 
-    // Initialize refresh registry
-    let _refresh_registry = GLOBAL_REFRESH_REGISTRY.get_or_init(RefreshRegistry::new);
+    // Initialize cache
+    let cache = GLOBAL_CACHE
+        .get_or_init(|| connected_cache(ProviderCache::with_config(config), refresh_registry));
 
-    Ok(())
+    Ok(GlobalProviders {
+        cache: cache.clone(),
+        refresh: refresh_registry.clone(),
+    })
+}
+
+/// Makes every change to `cache` refresh the components subscribed to the changed keys
+fn connected_cache(cache: ProviderCache, refresh_registry: &RefreshRegistry) -> ProviderCache {
+    cache.connect_refresh_registry(refresh_registry);
+    cache
 }
 
 /// Get the global provider cache instance
@@ -86,6 +180,688 @@ pub fn get_global_refresh_registry() -> Result<&'static RefreshRegistry, GlobalP
         .ok_or(GlobalProviderError::NotInitialized)
 }
 
+/// Initialize a named cache with its own refresh registry
+///
+/// Named caches keep logically separated data apart from the default global cache,
+/// each with independent eviction settings and clear operations. Providers opt in
+/// with `#[provider(cache = "name")]` (or by overriding `Provider::cache_name`).
+///
+/// Calling this again for an existing name keeps the existing cache.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::{cache::CacheConfig, global::init_named_cache};
+///
+/// init_named_cache("auth", CacheConfig::default());
+/// ```
+pub fn init_named_cache(name: &str, config: CacheConfig) {
+    let registry = NAMED_CACHES.get_or_init(Default::default);
+    if let Ok(mut caches) = registry.lock() {
+        caches.entry(name.to_string()).or_insert_with(|| {
+            let refresh_registry = RefreshRegistry::new();
+            let cache = connected_cache(ProviderCache::with_config(config), &refresh_registry);
+            (cache, refresh_registry)
+        });
+    }
+}
+
+/// Get a named cache instance
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if `init_named_cache()` has not been
+/// called for this name.
+pub fn get_named_cache(name: &str) -> Result<ProviderCache, GlobalProviderError> {
+    get_named_entry(name).map(|(cache, _)| cache)
+}
+
+/// Get the refresh registry belonging to a named cache
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if `init_named_cache()` has not been
+/// called for this name.
+pub fn get_named_refresh_registry(name: &str) -> Result<RefreshRegistry, GlobalProviderError> {
+    get_named_entry(name).map(|(_, registry)| registry)
+}
+
+fn get_named_entry(name: &str) -> Result<(ProviderCache, RefreshRegistry), GlobalProviderError> {
+    NAMED_CACHES
+        .get()
+        .and_then(|registry| registry.lock().ok()?.get(name).cloned())
+        .ok_or_else(|| GlobalProviderError::NamedCacheNotFound(name.to_string()))
+}
+
+/// Get the cache and refresh registry for an optional cache name
+///
+/// `None` resolves to the default global cache and registry.
+pub fn get_cache_and_registry(
+    name: Option<&str>,
+) -> Result<(ProviderCache, RefreshRegistry), GlobalProviderError> {
+    match name {
+        Some(name) => get_named_entry(name),
+        None => Ok((
+            get_global_cache()?.clone(),
+            get_global_refresh_registry()?.clone(),
+        )),
+    }
+}
+
+/// Get the cache and refresh registry for an optional cache name, creating a named cache
+/// that hasn't been initialized with the default settings
+///
+/// Used by the hooks, so a component using a provider declared with
+/// `#[provider(cache = "name")]` still renders if `init_named_cache` wasn't called. The
+/// missing call is logged as a warning.
+pub(crate) fn get_or_init_cache_and_registry(
+    name: Option<&str>,
+) -> (ProviderCache, RefreshRegistry) {
+    ensure_global_providers();
+    if let Some(name) = name
+        && get_named_entry(name).is_err()
+    {
+        tracing::warn!(
+            "⚠️ [CACHE] Named cache '{}' used before init_named_cache() - creating it with the default settings",
+            name
+        );
+        init_named_cache(name, CacheConfig::default());
+    }
+    get_cache_and_registry(name).expect("Global providers not initialized")
+}
+
+/// Get every initialized cache with its refresh registry, default cache first
+///
+/// Useful for operations keyed only by cache key (such as mutation invalidation),
+/// where the owning cache is not known.
+pub fn all_caches() -> Vec<(ProviderCache, RefreshRegistry)> {
+    let mut caches = Vec::new();
+    if let Ok(default) = get_cache_and_registry(None) {
+        caches.push(default);
+    }
+    if let Some(registry) = NAMED_CACHES.get()
+        && let Ok(named) = registry.lock()
+    {
+        caches.extend(named.values().cloned());
+    }
+    caches
+}
+
+/// Clear the default provider cache and refresh every provider using it
+///
+/// Unlike [`use_clear_provider_cache`](crate::hooks::use_clear_provider_cache), this is not
+/// a hook, so it is safe to call from event handlers, async tasks or non-component code.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[component]
+/// fn ClearButton() -> Element {
+///     rsx! {
+///         button {
+///             onclick: move |_| clear_provider_cache(),
+///             "Clear Cache"
+///         }
+///     }
+/// }
+/// ```
+pub fn clear_provider_cache() {
+    ensure_global_providers();
+    if let Ok((cache, refresh_registry)) = get_cache_and_registry(None) {
+        cache.clear();
+        refresh_registry.clear_all();
+    }
+}
+
+/// Invalidate a provider's cached result for `param` and refetch it where it is in use
+///
+/// Unlike [`use_invalidate_provider`](crate::hooks::use_invalidate_provider), this is not a
+/// hook, so it is safe to call from event handlers, async tasks or non-component code.
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if the provider stores its entries in
+/// a named cache that has not been initialized.
+pub fn invalidate_provider<P, Param>(provider: P, param: Param) -> Result<(), GlobalProviderError>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    ensure_global_providers();
+    let (cache, _) = get_cache_and_registry(provider.cache_name())?;
+    let cache_key = provider.id(&param);
+
+    cache.invalidate(&cache_key);
+    Ok(())
+}
+
+/// Mark a provider's cached result for `param` as stale so it revalidates where it is in use
+///
+/// The cached value keeps being served while it is refetched in the background, unlike
+/// [`invalidate_provider`] which drops it. Use this after writing data by hand that should
+/// be confirmed against the source. Providers without a `stale_time` are left alone.
+///
+/// Returns whether an entry was marked stale.
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if the provider stores its entries in
+/// a named cache that has not been initialized.
+pub fn mark_provider_stale<P, Param>(provider: P, param: Param) -> Result<bool, GlobalProviderError>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    ensure_global_providers();
+    let (cache, refresh_registry) = get_cache_and_registry(provider.cache_name())?;
+    let Some(stale_time) = provider.stale_time() else {
+        return Ok(false);
+    };
+    let cache_key = provider.id(&param);
+
+    let marked = cache.mark_stale(&cache_key, stale_time);
+    if marked {
+        revalidate_mounted_entry(&provider, &param, &cache_key, &cache, &refresh_registry);
+        refresh_registry.trigger_refresh(&cache_key);
+    }
+    Ok(marked)
+}
+
+/// Seed a provider's cache entry for `param` with a value it would otherwise have to fetch
+///
+/// Use this to prime a detail provider from data another provider already loaded, such as
+/// the items of a list, so opening the detail view is instant. The entry is only written if
+/// nothing is cached for `param` yet, so fresher fetched data is never overwritten. Like
+/// [`invalidate_provider`], this is safe to call outside of hooks.
+///
+/// Returns whether the entry was seeded. Providers that bypass the cache are never seeded.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// pub struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// #[provider]
+/// async fn fetch_users() -> Result<Vec<User>, String> {
+///     Ok(vec![])
+/// }
+///
+/// #[provider]
+/// async fn fetch_user(id: u32) -> Result<User, String> {
+///     Err(format!("user {} not found", id))
+/// }
+///
+/// fn seed_user_details(users: &[User]) {
+///     for user in users {
+///         seed_provider(fetch_user(), user.id, user.clone()).ok();
+///     }
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if the provider stores its entries in
+/// a named cache that has not been initialized.
+pub fn seed_provider<P, Param>(
+    provider: P,
+    param: Param,
+    value: P::Output,
+) -> Result<bool, GlobalProviderError>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    ensure_global_providers();
+    let (cache, refresh_registry) = get_cache_and_registry(provider.cache_name())?;
+    if !provider.cacheable() {
+        return Ok(false);
+    }
+    let cache_key = provider.id(&param);
+
+    cache.assert_key_owner(&cache_key, std::any::type_name::<P>());
+    if cache.snapshot(&cache_key).is_some() {
+        return Ok(false);
+    }
+    store_provider_result(
+        &provider,
+        &param,
+        &cache,
+        &refresh_registry,
+        &cache_key,
+        Ok(value),
+    );
+    Ok(true)
+}
+
+/// Fetch a provider's result for `param` and store it in the cache
+///
+/// Runs the provider on the caller's executor instead of spawning a Dioxus task, so it works
+/// outside components and without a Dioxus runtime, e.g. to warm the cache from a
+/// background job, a plain tokio task or a test. The provider always runs, even if a result
+/// is cached; components showing the key are refreshed if the stored value changed. A
+/// result is not stored if a newer fetch of the key started meanwhile, and never for
+/// providers that bypass the cache, which are just run.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_feed() -> Result<Vec<String>, String> {
+///     Ok(vec![])
+/// }
+///
+/// async fn warm_cache() {
+///     if let Ok(Err(error)) = fetch_provider(fetch_feed(), ()).await {
+///         eprintln!("couldn't warm the feed: {}", error);
+///     }
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if the provider stores its entries in
+/// a named cache that has not been initialized.
+pub async fn fetch_provider<P, Param>(
+    provider: P,
+    param: Param,
+) -> Result<Result<P::Output, P::Error>, GlobalProviderError>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    ensure_global_providers();
+    let (cache, refresh_registry) = get_cache_and_registry(provider.cache_name())?;
+    let cache_key = provider.id(&param);
+    if !provider.cacheable() {
+        return Ok(run_provider(&provider, &param, &cache, &cache_key).await);
+    }
+
+    let generation = refresh_registry.start_fetch(&cache_key);
+    let result = run_provider(&provider, &param, &cache, &cache_key).await;
+    if generation.claim_store() {
+        store_provider_result(
+            &provider,
+            &param,
+            &cache,
+            &refresh_registry,
+            &cache_key,
+            result.clone(),
+        );
+    } else {
+        tracing::debug!(
+            "⏭️ [FETCH] Not storing superseded fetch for key: {}",
+            cache_key
+        );
+    }
+    Ok(result)
+}
+
+/// Fetch a provider's result for `param` like [`fetch_provider`], sharing a running prefetch
+///
+/// If a prefetch of the same key is already running, or a fetch a component started, waits
+/// for it and returns its result instead of fetching again, so prefetching on every hover or
+/// route change doesn't pile up requests. `#[provider]` generates a `prefetch` method on the provider type calling this,
+/// e.g. `FetchUser::prefetch(id).await` before navigating to a user's page. Providers that
+/// bypass the cache have nothing to share, so every call runs them.
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if the provider stores its entries in
+/// a named cache that has not been initialized.
+pub async fn prefetch_provider<P, Param>(
+    provider: P,
+    param: Param,
+) -> Result<Result<P::Output, P::Error>, GlobalProviderError>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    ensure_global_providers();
+    let (cache, _) = get_cache_and_registry(provider.cache_name())?;
+    if !provider.cacheable() {
+        return fetch_provider(provider, param).await;
+    }
+    let slot = (provider.cache_name(), provider.id(&param));
+
+    let running = {
+        let Ok(mut prefetches) = IN_FLIGHT_PREFETCHES.get_or_init(Default::default).lock() else {
+            return fetch_provider(provider, param).await;
+        };
+        match prefetches.get(&slot) {
+            Some(running) => Err(running.clone()),
+            None => {
+                let (done, finished) = oneshot::channel();
+                let finished = finished.shared();
+                prefetches.insert(slot.clone(), finished.clone());
+                let slot = slot.clone();
+                Ok((done, RunningPrefetch { slot, finished }))
+            }
+        }
+    };
+
+    match running {
+        Ok((done, running)) => {
+            let result = match join_running_fetch(&cache, &slot.1).await {
+                Some(result) => Ok(result),
+                None => fetch_provider(provider, param).await,
+            };
+            drop(running);
+            let _ = done.send(());
+            result
+        }
+        Err(finished) => {
+            tracing::debug!("🔗 [PREFETCH] Joining running prefetch for key: {}", slot.1);
+            // A prefetch whose caller gave up, or whose result wasn't stored, is fetched again
+            if finished.await.is_ok()
+                && let Some(result) = cache.get::<Result<P::Output, P::Error>>(&slot.1)
+            {
+                return Ok(result);
+            }
+            fetch_provider(provider, param).await
+        }
+    }
+}
+
+/// Waits for a running fetch of `key`, e.g. one a component started, and returns the result
+/// it stored
+///
+/// Returns None if no fetch of the key is running, or if it finished without storing.
+async fn join_running_fetch<T>(cache: &ProviderCache, key: &str) -> Option<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    // Subscribed before checking, so the fetch can't finish unnoticed in between
+    let mut activity = cache.fetch_activity();
+    if !cache.is_fetching(key) {
+        return None;
+    }
+    tracing::debug!("🔗 [PREFETCH] Joining running fetch for key: {}", key);
+    let joined_at = Instant::now();
+    while cache.is_fetching(key) {
+        activity.next().await?;
+    }
+    cache
+        .snapshot(key)
+        .is_some_and(|entry| entry.age() <= joined_at.elapsed())
+        .then(|| cache.get(key))
+        .flatten()
+}
+
+/// A prefetch other callers can join, forgotten once it finishes or its caller drops it
+struct RunningPrefetch {
+    slot: PrefetchSlot,
+    finished: Shared<oneshot::Receiver<()>>,
+}
+
+impl Drop for RunningPrefetch {
+    fn drop(&mut self) {
+        if let Some(Ok(mut prefetches)) = IN_FLIGHT_PREFETCHES
+            .get()
+            .map(|prefetches| prefetches.lock())
+            && prefetches
+                .get(&self.slot)
+                .is_some_and(|running| running.ptr_eq(&self.finished))
+        {
+            prefetches.remove(&self.slot);
+        }
+    }
+}
+
+/// Call `callback` with a provider's new state whenever a fetch for `param` changes its value
+///
+/// Bridges provider data into imperative code outside of components, such as syncing it
+/// into an external store or logging. The callback runs on the executor after each fetch,
+/// revalidation or refetch that changes the cached value, never while the cache is locked.
+/// It is not called for the value cached at subscription time.
+///
+/// The subscription lasts until the returned guard is dropped.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_balance(account: u32) -> Result<u64, String> {
+///     Ok(100)
+/// }
+///
+/// let _subscription = subscribe_provider(fetch_balance(), 7, |state| {
+///     if let ProviderState::Success(balance) = state {
+///         println!("balance is now {}", balance);
+///     }
+/// });
+/// ```
+///
+/// ## Errors
+///
+/// Returns `GlobalProviderError::NamedCacheNotFound` if the provider stores its entries in
+/// a named cache that has not been initialized.
+pub fn subscribe_provider<P, Param, F>(
+    provider: P,
+    param: Param,
+    callback: F,
+) -> Result<ProviderSubscription, GlobalProviderError>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+    F: Fn(&ProviderState<P::Output, P::Error>) + Send + Sync + 'static,
+{
+    ensure_global_providers();
+    let (_, refresh_registry) = get_cache_and_registry(provider.cache_name())?;
+    let cache_key = provider.id(&param);
+
+    let listener_id = refresh_registry.add_state_listener(
+        &cache_key,
+        Arc::new(move |state: &dyn Any| {
+            if let Some(state) = state.downcast_ref::<ProviderState<P::Output, P::Error>>() {
+                callback(state);
+            }
+        }),
+    );
+    Ok(ProviderSubscription {
+        refresh_registry,
+        cache_key,
+        listener_id,
+    })
+}
+
+/// Guard returned by [`subscribe_provider`] that unsubscribes its callback when dropped
+#[must_use = "the callback is unsubscribed as soon as the subscription is dropped"]
+pub struct ProviderSubscription {
+    refresh_registry: RefreshRegistry,
+    cache_key: String,
+    listener_id: u64,
+}
+
+impl ProviderSubscription {
+    /// Get the cache key of the subscribed provider entry
+    pub fn key(&self) -> &str {
+        &self.cache_key
+    }
+}
+
+impl Drop for ProviderSubscription {
+    fn drop(&mut self) {
+        self.refresh_registry
+            .remove_state_listener(&self.cache_key, self.listener_id);
+    }
+}
+
+/// Invalidate every cached entry belonging to an entity and refetch them where they are in use
+///
+/// Drops the entries of all providers that report `entity` (by its `Debug` formatting)
+/// through `#[provider(entity_key = ...)]`, across the default and named caches. Like
+/// [`invalidate_provider`], this is safe to call outside of hooks. Returns the number of
+/// entries invalidated.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider(entity_key = user_id)]
+/// async fn fetch_user_posts(user_id: u32) -> Result<Vec<String>, String> {
+///     Ok(vec![])
+/// }
+///
+/// fn log_out(user_id: u32) {
+///     invalidate_entity(&user_id);
+/// }
+/// ```
+pub fn invalidate_entity<E: std::fmt::Debug + ?Sized>(entity: &E) -> usize {
+    ensure_global_providers();
+    let entity = format!("{:?}", entity);
+    let mut invalidated = 0;
+    for (cache, _) in all_caches() {
+        invalidated += cache.invalidate_entity(&entity).len();
+    }
+    invalidated
+}
+
+/// Invalidate every cached entry of providers with a tag and refetch them where they are in use
+///
+/// Drops the entries stored by all providers declared with the tag, e.g.
+/// `#[provider(tags = ["users"])]`, across the default and named caches. Mutations declared
+/// with `#[mutation(invalidates_tags = [...])]` call this after succeeding. Returns the number
+/// of entries invalidated.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider(tags = ["users"])]
+/// async fn fetch_user_names() -> Result<Vec<String>, String> {
+///     Ok(vec![])
+/// }
+///
+/// fn on_users_imported() {
+///     invalidate_tag("users");
+/// }
+/// ```
+pub fn invalidate_tag(tag: &str) -> usize {
+    ensure_global_providers();
+    let mut invalidated = 0;
+    for (cache, _) in all_caches() {
+        invalidated += cache.invalidate_tag(tag).len();
+    }
+    invalidated
+}
+
+/// Drop every cached entry that is not referenced, across the default and named caches
+///
+/// Meant as a response to memory pressure: unlike the periodic cleanup, entries are removed
+/// no matter how recently they were read. Entries with a non-zero reference count are kept.
+/// Components reading a dropped entry keep their current state and refetch on their next
+/// refresh. Returns the number of entries removed.
+///
+/// See [`register_memory_pressure_handler`](crate::platform::register_memory_pressure_handler)
+/// to run cache cleanup automatically when the platform signals it.
+pub fn handle_memory_pressure() -> usize {
+    let removed = all_caches()
+        .iter()
+        .map(|(cache, _)| cache.cleanup_unused_entries(Duration::ZERO))
+        .sum();
+    tracing::debug!(
+        "🧹 [GLOBAL] Memory pressure: removed {} unused entries",
+        removed
+    );
+    removed
+}
+
+/// Get the semaphore limiting concurrent fetches of the provider type `type_name` to `limit`
+///
+/// The semaphore is shared by every fetch of the type with the same limit, whatever its
+/// param or wrapper. Instances of one type declaring different limits each get their own
+/// semaphore, so every limit holds for the fetches declaring it. A limit of zero is treated
+/// as one, so fetches can't wait forever.
+pub(crate) fn concurrency_limiter(type_name: &'static str, limit: usize) -> Arc<Semaphore> {
+    let limit = limit.max(1);
+    CONCURRENCY_LIMITERS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry((type_name, limit))
+        .or_insert_with(|| Arc::new(Semaphore::new(limit)))
+        .clone()
+}
+
+/// Initialize the global providers with defaults if the application has not done so
+///
+/// Provider hooks call this on first use so that forgetting `init_global_providers()`
+/// does not crash the application.
+pub(crate) fn ensure_global_providers() {
+    if !is_initialized() {
+        tracing::debug!(
+            "⚙️ [GLOBAL] init_global_providers() was not called - initializing global providers with defaults"
+        );
+        let _ = init_global_providers();
+    }
+}
+
+/// Tear down the global providers when the application shuts down
+///
+/// Cancels every interval refresh, stale check, cache cleanup and delayed disposal task,
+/// drops pending debounced operations and subscriptions, and empties the default cache.
+/// Named caches are emptied the same way and forgotten, so they have to be initialized
+/// again. The default cache and registry are process-wide statics and cannot be dropped,
+/// but once this returns they are as empty as fresh ones, and a later
+/// [`init_global_providers`] hands them out again, e.g. when a test or an embedded app
+/// mounts a new root.
+///
+/// Components that are still mounted are not refreshed, but start new tasks if they
+/// re-render, so call it once the app stopped rendering. Does nothing if the global
+/// providers were never initialized.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::global::{init_global_providers, shutdown_global_providers};
+///
+/// # fn app() -> dioxus::prelude::Element { todo!() }
+/// init_global_providers().unwrap();
+/// dioxus::launch(app);
+/// shutdown_global_providers();
+/// ```
+pub fn shutdown_global_providers() {
+    let named = match NAMED_CACHES.get().map(|registry| registry.lock()) {
+        Some(Ok(mut named)) => named.drain().map(|(_, entry)| entry).collect(),
+        _ => Vec::new(),
+    };
+    let default = get_cache_and_registry(None).ok();
+
+    let mut cancelled = 0;
+    for (cache, refresh_registry) in default.into_iter().chain(named) {
+        // Forget the subscribers first, so emptying the cache does not refetch anything
+        cancelled += refresh_registry.shutdown();
+        cache.clear();
+    }
+    // Prefetches still running store into the emptied caches, but no new caller joins them
+    if let Some(Ok(mut prefetches)) = IN_FLIGHT_PREFETCHES
+        .get()
+        .map(|prefetches| prefetches.lock())
+    {
+        prefetches.clear();
+    }
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::global_metrics().clear();
+
+    tracing::debug!("🛑 [GLOBAL] Shut down global providers, cancelled {cancelled} periodic tasks");
+}
+
 /// Check if global providers have been initialized
 pub fn is_initialized() -> bool {
     GLOBAL_CACHE.get().is_some() && GLOBAL_REFRESH_REGISTRY.get().is_some()
@@ -93,19 +869,11 @@ pub fn is_initialized() -> bool {
 
 /// Reset global providers (mainly for testing)
 ///
-/// This is primarily intended for testing scenarios where you need
-/// to reset the global state between tests.
-///
-/// ## Warning
-///
-/// This function is not thread-safe and should only be used in single-threaded
-/// test environments. Do not use this in production code.
+/// Equivalent to [`shutdown_global_providers`], kept for tests resetting the global state
+/// between cases.
 #[cfg(test)]
 pub fn reset_global_providers() {
-    // Note: OnceLock doesn't have a public reset method, so this is mainly
-    // for documentation. In real tests, you'd typically use a different
-    // approach or restart the test process.
-    panic!("Global provider reset is not currently supported. Restart the application.");
+    shutdown_global_providers();
 }
 
 // Backward compatibility functions that panic for existing code
@@ -166,6 +934,55 @@ mod tests {
         assert!(get_global_refresh_registry().is_err());
     }
 
+    #[test]
+    fn test_named_caches_are_independent() {
+        init_named_cache("test_auth", CacheConfig::default());
+        init_named_cache("test_ui", CacheConfig::default());
+
+        let auth = get_named_cache("test_auth").unwrap();
+        let ui = get_named_cache("test_ui").unwrap();
+        auth.set("token".to_string(), 1u32);
+        ui.set("panel".to_string(), 2u32);
+
+        ui.clear();
+
+        assert_eq!(auth.get::<u32>("token"), Some(1));
+        assert_eq!(ui.get::<u32>("panel"), None);
+        assert!(get_named_cache("test_missing").is_err());
+    }
+
+    #[test]
+    fn test_concurrency_limiter_is_shared_per_limit() {
+        let limiter = concurrency_limiter("test::Limited", 2);
+        assert_eq!(limiter.available_permits(), 2);
+        assert!(Arc::ptr_eq(
+            &limiter,
+            &concurrency_limiter("test::Limited", 2)
+        ));
+        assert_eq!(
+            concurrency_limiter("test::Limited", 0).available_permits(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_concurrency_limits_hold_when_alternating() {
+        let mut held = Vec::new();
+        for _ in 0..4 {
+            for limit in [2, 3] {
+                if let Ok(permit) =
+                    concurrency_limiter("test::Alternating", limit).try_acquire_owned()
+                {
+                    held.push((limit, permit));
+                }
+            }
+        }
+
+        let held_with = |limit| held.iter().filter(|(held, _)| *held == limit).count();
+        assert_eq!(held_with(2), 2);
+        assert_eq!(held_with(3), 3);
+    }
+
     #[test]
     fn test_backward_compatibility() {
         // Test that the old panic functions still work when initialized
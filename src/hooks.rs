@@ -1,7 +1,8 @@
 //! # Provider Hooks
 //!
 //! This module provides hooks for working with providers in Dioxus applications.
-//! It requires `dioxus_provider::global::init_global_providers()` to be called at application startup.
+//! Call `dioxus_provider::global::init_global_providers()` at application startup; if it is
+//! forgotten, the hooks initialize the global providers with defaults on first use.
 //!
 //! ## Example
 //!
@@ -27,16 +28,34 @@
 //! ```
 
 use dioxus::{
-    core::{ReactiveContext, SuspendedFuture},
+    core::{ReactiveContext, SuspendedFuture, Task, needs_update, use_drop},
     prelude::*,
 };
-use std::{fmt::Debug, future::Future, time::Duration};
+use futures::{FutureExt, StreamExt};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    future::Future,
+    rc::Rc,
+    time::Duration,
+};
 use tracing::debug;
 
 use crate::{
-    cache::ProviderCache,
-    global::{get_global_cache, get_global_refresh_registry},
-    refresh::{RefreshRegistry, TaskType},
+    cache::{CacheCapacityStats, CacheEntry, CacheEntryPredicate, ProviderCache},
+    cancel::CancellationToken,
+    components::SuspenseGroup,
+    conditional::{CacheMeta, FetchOutcome, with_cache_meta},
+    global::{
+        all_caches, concurrency_limiter, ensure_global_providers, get_global_cache,
+        get_global_refresh_registry, get_or_init_cache_and_registry,
+    },
+    platform::{
+        RenderTarget, is_document_visible, is_hydrating, render_target, sleep, spawn_task,
+        ssr_prefetch,
+    },
+    progressive::with_emitter,
+    refresh::{RefreshRegistry, RefreshRegistryStats, TaskType},
 };
 
 use crate::param_utils::IntoProviderParam;
@@ -82,6 +101,10 @@ pub use crate::provider_state::ProviderState;
 ///     // ...
 /// }
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a provider of `{Param}`",
+    label = "`{Self}` doesn't take a parameter of type `{Param}`"
+)]
 pub trait Provider<Param = ()>: Clone + PartialEq + 'static
 where
     Param: ProviderParamBounds,
@@ -97,18 +120,66 @@ where
     /// from an API, reading from a database, or computing a value.
     fn run(&self, param: Param) -> impl Future<Output = Result<Self::Output, Self::Error>>;
 
+    /// Execute the async operation with a token that is cancelled if the fetch is dropped
+    ///
+    /// The hooks always fetch through this method. The default implementation ignores the
+    /// token and calls `run`; providers declared with `#[provider(with_cancel)]` pass it to
+    /// their function so it can stop work early when the fetch is abandoned.
+    fn run_with_cancel(
+        &self,
+        param: Param,
+        cancel: CancellationToken,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>> {
+        let _ = cancel;
+        self.run(param)
+    }
+
+    /// Execute the async operation, allowing it to report that the cached value is current
+    ///
+    /// The hooks fetch through this method with the entry's metadata available from
+    /// [`cache_meta`](crate::conditional::cache_meta). Returning
+    /// [`FetchOutcome::NotModified`] keeps the cached value and marks it as fresh again.
+    /// The default implementation calls `run_with_cancel` and always reports a modified
+    /// value; providers declared with `#[provider(conditional)]` return their function's
+    /// `FetchOutcome`.
+    fn run_conditional(
+        &self,
+        param: Param,
+        cancel: CancellationToken,
+    ) -> impl Future<Output = Result<FetchOutcome<Self::Output>, Self::Error>> {
+        async move {
+            self.run_with_cancel(param, cancel)
+                .await
+                .map(FetchOutcome::Modified)
+        }
+    }
+
     /// Get a unique identifier for this provider instance with the given parameters
     ///
     /// This ID is used for caching and invalidation. The default implementation
-    /// hashes the provider's type and parameters to generate a unique ID.
+    /// hashes the provider's type and the parameter's [`param_key`](Provider::param_key) to generate a unique ID.
     fn id(&self, param: &Param) -> String {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+        crate::types::provider_type_key::<Self>(&self.param_key(param))
+    }
+
+    /// Get the key of a parameter that the default [`id`](Provider::id) is built from
+    ///
+    /// The default implementation uses the parameter's [`ProviderKey`](crate::types::ProviderKey).
+    /// Disk persistence stores entries under this key and the provider's type name, since the
+    /// hashed ID can change between builds. Providers declared with
+    /// `#[provider(normalize = ...)]` return the key of the normalized parameter.
+    fn param_key(&self, param: &Param) -> String {
+        param.provider_key()
+    }
 
-        let mut hasher = DefaultHasher::new();
-        std::any::TypeId::of::<Self>().hash(&mut hasher);
-        param.hash(&mut hasher);
-        format!("{:x}", hasher.finish())
+    /// Describe a parameter for logs, error context and devtools
+    ///
+    /// The default implementation uses the parameter's `Debug` formatting. Override it to
+    /// redact sensitive parameters such as auth tokens; providers declared with
+    /// `#[provider(redact_params)]` describe every parameter as `"<redacted>"`. Cache keys
+    /// are hashes, so they never contain the parameter either way.
+    fn describe_param(&self, param: &Param) -> String {
+        format!("{:?}", param)
     }
 
     /// Get the interval duration for automatic refresh (None means no interval)
@@ -119,6 +190,15 @@ where
         None
     }
 
+    /// Whether interval refreshes are skipped while the document is hidden
+    ///
+    /// When true, each interval tick checks [`crate::platform::is_document_visible`] and does
+    /// nothing while the app is in the background, so polling pauses and resumes with the
+    /// page's visibility without a consumer-side gate such as [`use_provider_polling`].
+    fn interval_when_visible_only(&self) -> bool {
+        false
+    }
+
     /// Get the cache expiration duration (None means no expiration)
     ///
     /// When set, cached data will be considered expired after this duration and
@@ -134,20 +214,316 @@ where
     fn stale_time(&self) -> Option<Duration> {
         None
     }
+
+    /// Get how long a failed result stays cached (None means errors expire like successes)
+    ///
+    /// Transient errors such as network blips shouldn't linger as long as good data. When
+    /// set, a cached `Err` expires after this duration and the provider refetches.
+    fn error_cache_expiration(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Get the output shown in place of an error when the provider has no data to show
+    ///
+    /// When set, `use_provider` shows `Success(fallback)` instead of `Error` for a failed
+    /// fetch before it has shown any data for the key, e.g. an empty list when the first load
+    /// fails. Errors after real data was shown are reported as usual. The error itself is
+    /// still cached, but marked stale, so providers with a `stale_time` retry it like stale
+    /// data; others fetch again once it expires (see `error_cache_expiration`).
+    fn error_fallback(&self) -> Option<Self::Output> {
+        None
+    }
+
+    /// Get how often the provider's entries are checked for expiration and cleanup
+    /// (None uses the cache's `CacheConfig::check_interval`)
+    ///
+    /// Without either, expiration is checked every sixteenth of the expiration time, between
+    /// one and thirty seconds, so an entry may be served for a while past its expiration.
+    /// Providers that must never show expired data can check more often.
+    fn check_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Get how long an entry is kept once no component uses it (None means it stays until
+    /// it expires or is evicted)
+    ///
+    /// When set, an entry that no mounted `use_provider` hook shows and that hasn't been
+    /// read for this long is removed, independently of `cache_expiration`.
+    fn dispose_delay(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Get the maximum number of fetches of this provider type that run at once (None means
+    /// unlimited)
+    ///
+    /// The limit is shared by every param, so a list fetching many items doesn't overwhelm
+    /// a rate-limited backend. Fetches beyond it wait until a running fetch completes or is
+    /// cancelled.
+    fn max_concurrency(&self) -> Option<usize> {
+        None
+    }
+
+    /// Decide whether a fresh cached result should still be revalidated in the background
+    ///
+    /// Consulted on every render that serves a successful cached result, in addition to the
+    /// stale/expiration checks, for domain-specific freshness rules such as "refetch if the
+    /// cached version is older than the running app". Returning `true` serves the cached
+    /// value and starts a background revalidation. Cached errors are never passed here.
+    ///
+    /// The default implementation never forces a refetch. Providers declared with
+    /// `#[provider(should_refetch = predicate)]` call `predicate(entry_age, cached)`.
+    fn should_refetch(&self, entry_age: Duration, cached: &Self::Output) -> bool {
+        let _ = (entry_age, cached);
+        false
+    }
+
+    /// Get the maximum random jitter applied to each entry's expiration and stale times
+    /// as a fraction (None means no jitter, `Some(0.2)` means up to 20% longer)
+    ///
+    /// When many entries of the same provider are created at once, jitter spreads their
+    /// expirations over a window instead of refetching them all in one burst.
+    fn expiration_jitter(&self) -> Option<f64> {
+        None
+    }
+
+    /// Get the free-form tags of this provider (empty by default)
+    ///
+    /// Entries stored by a tagged provider can be dropped together with
+    /// [`invalidate_tag`](crate::global::invalidate_tag), e.g. by a mutation declared with
+    /// `#[mutation(invalidates_tags = ["users"])]`. Providers declared with
+    /// `#[provider(tags = [...])]` return those tags.
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Get the name of the provider's type
+    ///
+    /// Fetches are grouped by this name in the `metrics` feature's
+    /// [`ProviderMetrics`](crate::metrics::ProviderMetrics), and share the limit of
+    /// [`Provider::max_concurrency`]. Wrappers such as `ArcProvider` report the type of the
+    /// provider they wrap.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Get a description of this provider for tooling, logging and error messages
+    ///
+    /// The default implementation reports the provider's configuration and tags without a
+    /// name. Providers declared with `#[provider]` also report their name.
+    fn metadata(&self) -> ProviderMetadata {
+        ProviderMetadata {
+            name: None,
+            interval: self.interval(),
+            stale_time: self.stale_time(),
+            cache_expiration: self.cache_expiration(),
+            tags: self.tags().to_vec(),
+        }
+    }
+
+    /// Annotate an error returned by `run` before it is cached and surfaced
+    ///
+    /// The default implementation returns the error unchanged. Providers declared with
+    /// `#[provider(error_context = true)]` wrap it with their cache key, parameter and
+    /// (with the `backtrace` feature) a backtrace via `WithProviderContext`.
+    fn contextualize_error(&self, key: &str, param: &Param, error: Self::Error) -> Self::Error {
+        let _ = (key, param);
+        error
+    }
+
+    /// Store a fetched result in the cache, returning whether the cached value changed
+    ///
+    /// The default implementation uses `ProviderCache::set`. Providers declared with
+    /// `#[provider(dedupe = true)]` use `ProviderCache::set_deduped` instead, so equal
+    /// outputs across parameters share one allocation.
+    fn cache_result(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<Self::Output, Self::Error>,
+    ) -> bool {
+        cache.set(key, result)
+    }
+
+    /// Store the result of a fetch that missed the cache, returning whether the cached value
+    /// changed
+    ///
+    /// The default implementation uses `ProviderCache::set_unconditional`, skipping the
+    /// comparison with an earlier value that [`Provider::cache_result`] makes. Providers
+    /// declared with `#[provider(dedupe = true)]` keep using `ProviderCache::set_deduped`.
+    fn cache_missed_result(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<Self::Output, Self::Error>,
+    ) -> bool {
+        cache.set_unconditional(key, result)
+    }
+
+    /// Combine a fetched result with the entry cached under `key` before it is stored
+    ///
+    /// The default implementation returns the fetched result, replacing the entry. Providers
+    /// declared with `#[provider(accumulate = item_key)]` merge the fetched items into the
+    /// cached list with `ProviderCache::merge_accumulated`, so fetches returning only new
+    /// items grow one list. Components showing the entry see the merged result.
+    fn merge_fetched(
+        &self,
+        cache: &ProviderCache,
+        key: &str,
+        result: Result<Self::Output, Self::Error>,
+    ) -> Result<Self::Output, Self::Error> {
+        let _ = (cache, key);
+        result
+    }
+
+    /// Estimate the size of a fetched result in bytes (None counts it as the cache's
+    /// `CacheConfig::default_entry_size`)
+    ///
+    /// Used when the cache enforces a byte budget with `CacheConfig::max_bytes`. Providers
+    /// declared with `#[provider(sized = true)]` return the result's
+    /// [`SizedCacheValue`](crate::cache::SizedCacheValue) estimate.
+    fn approximate_size(&self, result: &Result<Self::Output, Self::Error>) -> Option<usize> {
+        let _ = result;
+        None
+    }
+
+    /// Get the entity an entry for `param` belongs to (None means the entry is not indexed)
+    ///
+    /// Entries of different providers that report the same entity key can be dropped
+    /// together with [`invalidate_entity`](crate::global::invalidate_entity), e.g. every
+    /// cached entry for a user on logout. `invalidate_entity` looks entities up by their
+    /// `Debug` formatting, so implementations should return `format!("{:?}", value)`.
+    /// Providers declared with `#[provider(entity_key = param_name)]` do this for the
+    /// named parameter.
+    fn entity_key(&self, param: &Param) -> Option<String> {
+        let _ = param;
+        None
+    }
+
+    /// Whether this provider's entries may be persisted or exported (true by default)
+    ///
+    /// Providers holding sensitive data such as auth tokens or personal information return
+    /// `false`, so serializers listing entries with `ProviderCache::persistable_keys` skip
+    /// them. Persistable entries are still only written if their type can be serialized.
+    /// Providers declared with `#[provider(no_persist = true)]` return `false`.
+    fn persistable(&self) -> bool {
+        true
+    }
+
+    /// Whether this provider fetches while rendering on the server (true by default)
+    ///
+    /// Providers reading browser-only data such as geolocation return `false`, so during
+    /// server-side rendering (see [`set_render_target`](crate::platform::set_render_target))
+    /// they stay `Loading` without fetching and the client fetches them after hydration.
+    /// Providers declared with `#[provider(ssr = false)]` return `false`.
+    fn ssr(&self) -> bool {
+        true
+    }
+
+    /// Whether `use_provider` reads and writes this provider's results in the cache (true by default)
+    ///
+    /// Providers of volatile, single-use data such as one-time tokens or nonces return
+    /// `false`: every component using them fetches on mount and whenever its parameter
+    /// changes, without sharing results or starting interval, stale or cleanup tasks.
+    /// Refreshing the provider's key, e.g. with `use_invalidate_provider_always`, fetches
+    /// again. Providers declared with `#[provider(no_cache = true)]` return `false`.
+    fn cacheable(&self) -> bool {
+        true
+    }
+
+    /// Get the name of the cache this provider stores its entries in (None means the default global cache)
+    ///
+    /// Named caches must be created with `init_named_cache()` before the provider is used.
+    fn cache_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Human-friendly description of a provider and its configuration
+///
+/// Returned by [`Provider::metadata`] for devtools, logging and clearer error messages.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProviderMetadata {
+    /// Name of the provider (None for providers not declared with `#[provider]`)
+    pub name: Option<&'static str>,
+    /// Interval for automatic refresh
+    pub interval: Option<Duration>,
+    /// Time before cached data is considered stale
+    pub stale_time: Option<Duration>,
+    /// Time before cached data expires
+    pub cache_expiration: Option<Duration>,
+    /// Free-form tags for grouping providers in tooling
+    pub tags: Vec<&'static str>,
+}
+
+/// A provider that can fetch many parameters with a single call
+///
+/// Implement this next to [`Provider`] when the backend can resolve several ids at once
+/// (the DataLoader pattern). [`use_batch_provider`] collects every parameter missing from
+/// the cache and hands them to `run_batch` in one call instead of running the provider
+/// once per parameter.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct ItemProvider;
+///
+/// impl Provider<u32> for ItemProvider {
+///     type Output = String;
+///     type Error = String;
+///
+///     async fn run(&self, id: u32) -> Result<String, String> {
+///         Ok(format!("Item {}", id))
+///     }
+/// }
+///
+/// impl BatchProvider<u32> for ItemProvider {
+///     async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+///         // One request for all ids, e.g. GET /items?ids=1,2,3
+///         ids.iter().map(|id| Ok(format!("Item {}", id))).collect()
+///     }
+/// }
+/// ```
+pub trait BatchProvider<Param = ()>: Provider<Param>
+where
+    Param: ProviderParamBounds,
+{
+    /// Fetch every parameter at once
+    ///
+    /// Must return exactly one result per parameter, in the same order as `params`.
+    fn run_batch(
+        &self,
+        params: &[Param],
+    ) -> impl Future<Output = Vec<Result<Self::Output, Self::Error>>>;
+
+    /// Result for a parameter that `run_batch` returned no result for
+    ///
+    /// Called for each parameter past the end of a short `run_batch` result. The default
+    /// fetches the parameter alone with [`Provider::run`]; override it to report an error
+    /// for the parameter instead.
+    fn missing_result(
+        &self,
+        param: &Param,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>> {
+        self.run(param.clone())
+    }
 }
 
 /// Extension trait to enable suspense support for provider signals
 ///
 /// Allows you to call `.suspend()` on a `Signal<ProviderState<T, E>>`
-/// inside a component. If the state is `Loading`, this will suspend
-/// rendering and trigger Dioxus's SuspenseBoundary fallback.
+/// inside a component. If the state is `Loading` or `Idle`, this will suspend
+/// rendering and trigger Dioxus's SuspenseBoundary fallback. An idle provider has no
+/// fetch to wait on, so the component stays suspended until the state changes.
 ///
 /// Usage:
 /// ```rust
 /// let user = use_provider(fetch_user(), (1,)).suspend()?;
 /// ```
 pub trait SuspenseSignalExt<T, E> {
-    /// Returns Ok(data) if ready, Err(RenderError::Suspended) if loading, or Ok(Err(error)) if error.
+    /// Returns Ok(data) if ready, Err(RenderError::Suspended) if loading or idle, or Ok(Err(error)) if error.
     fn suspend(&self) -> Result<Result<T, E>, RenderError>;
 }
 
@@ -173,34 +549,60 @@ impl<T: Clone, E: Clone> SuspenseSignalExt<T, E> for Signal<ProviderState<T, E>>
             ProviderState::Loading { task } => {
                 Err(RenderError::Suspended(SuspendedFuture::new(*task)))
             }
+            // Reading the signal above re-renders the component once it leaves `Idle`
+            ProviderState::Idle => Err(RenderError::Suspended(SuspendedFuture::new(parked_task()))),
             ProviderState::Success(data) => Ok(Ok(data.clone())),
             ProviderState::Error(error) => Ok(Err(error.clone())),
         }
     }
 }
 
-/// Get the provider cache - requires global providers to be initialized
+/// Task a scope suspends on while its provider is `Idle`, shared by all of its renders
+#[derive(Clone, Copy)]
+struct ParkedTask(Task);
+
+/// Get the never-finishing task the current scope suspends on, spawning it on first use
+fn parked_task() -> Task {
+    if let Some(ParkedTask(task)) = dioxus::core::has_context() {
+        return task;
+    }
+    provide_context(ParkedTask(spawn(std::future::pending()))).0
+}
+
+/// Get the provider cache, initializing the global providers with defaults if needed
 fn get_provider_cache() -> ProviderCache {
+    ensure_global_providers();
     get_global_cache()
         .expect("Global providers not initialized")
         .clone()
 }
 
-/// Get the refresh registry - requires global providers to be initialized
+/// Get the refresh registry, initializing the global providers with defaults if needed
 fn get_refresh_registry() -> RefreshRegistry {
+    ensure_global_providers();
     get_global_refresh_registry()
         .expect("Global providers not initialized")
         .clone()
 }
 
+/// Get the cache and refresh registry a provider stores its entries in
+fn get_provider_cache_and_registry<P, Param>(provider: &P) -> (ProviderCache, RefreshRegistry)
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    get_or_init_cache_and_registry(provider.cache_name())
+}
+
 /// Hook to access the provider cache for manual cache management
 ///
 /// This hook provides direct access to the global provider cache for manual
 /// invalidation, clearing, and other cache operations.
 ///
-/// ## Global Providers Required
+/// ## Global Providers
 ///
-/// You must call `init_global_providers()` at application startup before using any provider hooks.
+/// Call `init_global_providers()` at application startup. If it has not been called,
+/// the global providers are initialized with defaults on first use.
 ///
 /// ## Setup
 ///
@@ -242,11 +644,73 @@ pub fn use_provider_cache() -> ProviderCache {
     get_provider_cache()
 }
 
+/// Hook to selectively drop entries from every provider cache
+///
+/// Returns a function that keeps only the entries for which the predicate returns
+/// true in the default and named caches, and triggers a refresh of every component
+/// using a removed entry. The
+/// predicate receives each cache key and a [`crate::cache::CacheEntryInfo`] snapshot, including
+/// the entry's reference count.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+/// use std::time::Duration;
+///
+/// #[component]
+/// fn MyComponent() -> Element {
+///     let retain = use_cache_retain();
+///
+///     rsx! {
+///         button {
+///             // Drop everything older than ten minutes that nobody is using
+///             onclick: move |_| retain(&|_, info| {
+///                 info.age < Duration::from_secs(600) || info.reference_count > 0
+///             }),
+///             "Drop old data"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_cache_retain() -> impl Fn(&CacheEntryPredicate) + Clone {
+    ensure_global_providers();
+
+    move |predicate: &CacheEntryPredicate| {
+        for (cache, _) in all_caches() {
+            cache.retain(predicate);
+        }
+    }
+}
+
+/// Hook to read statistics about the global refresh registry
+///
+/// Returns a snapshot of subscriber, task and revalidation counts, useful for
+/// feeding a devtools or debugging view. For per-key details, use the
+/// introspection methods on [`RefreshRegistry`] such as
+/// [`RefreshRegistry::active_interval_keys`] and [`RefreshRegistry::subscriber_count`].
+pub fn use_refresh_registry_stats() -> RefreshRegistryStats {
+    get_refresh_registry().stats()
+}
+
+/// Hook to read how the global cache is doing against its limits
+///
+/// Returns a snapshot of the entry count, the estimated memory and the number of entries
+/// evicted or expired so far, for capacity planning or a devtools view. Evictions that keep
+/// growing while the app runs mean the cache is too small for the data it shows.
+pub fn use_cache_capacity_stats() -> CacheCapacityStats {
+    get_provider_cache().capacity_stats()
+}
+
 /// Hook to invalidate a specific provider cache entry
 ///
 /// Returns a function that, when called, will invalidate the cache entry for the
 /// specified provider and parameters, and trigger a refresh of all components
-/// using that provider.
+/// using that provider. Nothing is refreshed if no entry was cached, e.g. while the first
+/// fetch is still running; use [`use_invalidate_provider_always`] to refresh regardless.
+/// Use [`invalidate_provider`](crate::global::invalidate_provider) where a hook cannot be
+/// called.
 ///
 /// Requires global providers to be initialized with `init_global_providers()`.
 ///
@@ -278,20 +742,148 @@ where
     P: Provider<Param>,
     Param: ProviderParamBounds,
 {
-    let cache = get_provider_cache();
-    let refresh_registry = get_refresh_registry();
+    let (cache, _) = get_provider_cache_and_registry(&provider);
+    let cache_key = provider.id(&param);
+
+    move || {
+        cache.remove(&cache_key);
+    }
+}
+
+/// Hook to invalidate a provider cache entry and refresh its components even if nothing
+/// was cached
+///
+/// Works like [`use_invalidate_provider`], but subscribers are refreshed whether or not an
+/// entry was removed, so components still showing a key whose entry was already dropped,
+/// e.g. by `remove`, refetch it.
+pub fn use_invalidate_provider_always<P, Param>(provider: P, param: Param) -> impl Fn() + Clone
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    let (cache, _) = get_provider_cache_and_registry(&provider);
+    let cache_key = provider.id(&param);
+
+    move || {
+        cache.invalidate(&cache_key);
+    }
+}
+
+/// Hook to retry a provider that failed
+///
+/// Returns a function bound to the provider and param that clears the cached error and
+/// refetches, so every component using the provider shows `Loading` and then the new
+/// result. Unlike [`use_invalidate_provider`], a successful cached result is kept, so a
+/// retry button rendered next to a stale error can't discard data loaded in the meantime.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn user_provider(id: u32) -> Result<String, String> {
+///     Ok(format!("User {}", id))
+/// }
+///
+/// #[component]
+/// fn UserCard(id: u32) -> Element {
+///     let user = use_provider(user_provider(), id);
+///     let retry = use_provider_retry(user_provider(), id);
+///
+///     match &*user.read() {
+///         ProviderState::Error(error) => rsx! {
+///             p { "Failed: {error}" }
+///             button { onclick: move |_| retry(), "Retry" }
+///         },
+///         ProviderState::Success(user) => rsx! { p { "{user}" } },
+///         _ => rsx! { p { "Loading..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_retry<P, Param>(provider: P, param: Param) -> impl Fn() + Clone
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    let (cache, _) = get_provider_cache_and_registry(&provider);
     let cache_key = provider.id(&param);
 
     move || {
+        if cache
+            .snapshot(&cache_key)
+            .is_some_and(|entry| !entry.is_error())
+        {
+            return;
+        }
+        debug!("🔁 [RETRY] Retrying provider for key: {}", cache_key);
         cache.invalidate(&cache_key);
-        refresh_registry.trigger_refresh(&cache_key);
+    }
+}
+
+/// Hook to invalidate a provider cache entry with debouncing
+///
+/// Works like [`use_invalidate_provider`], but rapid calls are coalesced: the cache entry
+/// is only invalidated and refreshed once no further call has happened for `debounce`.
+/// This is useful for bursts of changes, such as search-as-you-type invalidating a
+/// results provider.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+/// use std::time::Duration;
+///
+/// #[provider]
+/// async fn search_results() -> Result<Vec<String>, String> {
+///     Ok(vec![])
+/// }
+///
+/// #[component]
+/// fn SearchBox() -> Element {
+///     let invalidate_results =
+///         use_debounced_invalidate(search_results(), (), Duration::from_millis(300));
+///
+///     rsx! {
+///         input {
+///             oninput: move |_| invalidate_results(),
+///         }
+///     }
+/// }
+/// ```
+pub fn use_debounced_invalidate<P, Param>(
+    provider: P,
+    param: Param,
+    debounce: Duration,
+) -> impl Fn() + Clone
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    let (cache, refresh_registry) = get_provider_cache_and_registry(&provider);
+    let cache_key = provider.id(&param);
+
+    move || {
+        let cache = cache.clone();
+        let cache_key_for_task = cache_key.clone();
+
+        refresh_registry.schedule_debounced(&cache_key, debounce, move || {
+            debug!(
+                "⏱️ [DEBOUNCE] Invalidating cache entry for key: {}",
+                cache_key_for_task
+            );
+            cache.invalidate(&cache_key_for_task);
+        });
     }
 }
 
 /// Hook to clear the entire provider cache
 ///
 /// Returns a function that, when called, will clear all cached provider data
-/// and trigger a refresh of all providers currently in use.
+/// and trigger a refresh of all providers currently in use. Use
+/// [`clear_provider_cache`](crate::global::clear_provider_cache) where a hook cannot be called.
 ///
 /// Requires global providers to be initialized with `init_global_providers()`.
 ///
@@ -323,32 +915,108 @@ pub fn use_clear_provider_cache() -> impl Fn() + Clone {
     }
 }
 
-/// Unified trait for using providers with any parameter format
+/// Hook to clear only the failed results from every provider cache
 ///
-/// This trait provides a single, unified interface for using providers
-/// regardless of their parameter format. It automatically handles:
-/// - No parameters `()`
-/// - Tuple parameters `(param,)`
-/// - Direct parameters `param`
-pub trait UseProvider<Args> {
-    /// The type of data returned on success
-    type Output: ProviderOutputBounds;
-    /// The type of error returned on failure
-    type Error: ProviderErrorBounds;
-
-    /// Use the provider with the given arguments
-    fn use_provider(self, args: Args) -> Signal<ProviderState<Self::Output, Self::Error>>;
-}
-
-/// Unified implementation for all providers using parameter normalization
+/// Returns a function that removes each cached `Err` from the default and named caches
+/// and refreshes the affected providers so they refetch, while successful results stay
+/// cached. Useful after connectivity returns from a network blip.
 ///
-/// This single implementation replaces all the previous repetitive implementations
-/// by using the `IntoProviderParam` trait to normalize different parameter formats.
-impl<P, Args> UseProvider<Args> for P
-where
-    P: Provider<Args::Param> + Send + Clone,
-    Args: IntoProviderParam,
-{
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[component]
+/// fn OfflineBanner() -> Element {
+///     let retry_failed = use_clear_provider_errors();
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| retry_failed(),
+///             "Retry failed requests"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_clear_provider_errors() -> impl Fn() + Clone {
+    ensure_global_providers();
+
+    move || {
+        for (cache, _) in all_caches() {
+            let cleared = cache.clear_errors();
+            debug!("🗑️ [CLEAR-ERRORS] Cleared {} failed results", cleared);
+        }
+    }
+}
+
+/// Hook to clear a named provider cache
+///
+/// Works like [`use_clear_provider_cache`], but only clears the cache created with
+/// `init_named_cache(name, ..)`, leaving the default cache and other named caches intact.
+/// A name that hasn't been initialized gets a cache with the default settings, like the
+/// hooks of providers declared with `#[provider(cache = "name")]` do.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[component]
+/// fn LogoutButton() -> Element {
+///     let clear_auth = use_clear_named_provider_cache("auth");
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| clear_auth(),
+///             "Log out"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_clear_named_provider_cache(name: &str) -> impl Fn() + Clone {
+    let (cache, refresh_registry) = get_or_init_cache_and_registry(Some(name));
+
+    move || {
+        cache.clear();
+        refresh_registry.clear_all();
+    }
+}
+
+/// Unified trait for using providers with any parameter format
+///
+/// This trait provides a single, unified interface for using providers
+/// regardless of their parameter format. It automatically handles:
+/// - No parameters `()`
+/// - Tuple parameters `(param,)`
+/// - Direct parameters `param`
+#[diagnostic::on_unimplemented(
+    message = "Provider `{Self}` can't be used with a parameter of type `{Args}`",
+    label = "`{Args}` doesn't convert into the parameter `{Self}` takes",
+    note = "parameters are converted with `IntoProviderParam`, e.g. `\"123\"` into a `String`; pass the type of the provider function's parameter, e.g. `123` for `id: u32`",
+    note = "providers taking several parameters take a tuple with one element per parameter"
+)]
+pub trait UseProvider<Args> {
+    /// The type of data returned on success
+    type Output: ProviderOutputBounds;
+    /// The type of error returned on failure
+    type Error: ProviderErrorBounds;
+
+    /// Use the provider with the given arguments
+    fn use_provider(self, args: Args) -> Signal<ProviderState<Self::Output, Self::Error>>;
+}
+
+/// Unified implementation for all providers using parameter normalization
+///
+/// This single implementation replaces all the previous repetitive implementations
+/// by using the `IntoProviderParam` trait to normalize different parameter formats.
+#[diagnostic::do_not_recommend]
+impl<P, Args> UseProvider<Args> for P
+where
+    P: Provider<Args::Param> + Send + Clone,
+    Args: IntoProviderParam,
+{
     type Output = P::Output;
     type Error = P::Error;
 
@@ -367,33 +1035,73 @@ where
     P: Provider<Param> + Send + Clone,
     Param: ProviderParamBounds,
 {
+    // Whether a provider is cacheable is fixed by its type, so the hooks called stay the same
+    if !provider.cacheable() {
+        return use_uncached_provider(provider, param);
+    }
+
     let mut state = use_signal(|| ProviderState::Loading {
         task: spawn(async {}),
     });
-    let cache = get_provider_cache();
-    let refresh_registry = get_refresh_registry();
+    let (cache, refresh_registry) = get_provider_cache_and_registry(&provider);
 
     let cache_key = provider.id(&param);
     let cache_expiration = provider.cache_expiration();
+    let error_ttl = provider.error_cache_expiration();
 
     // Setup intelligent cache management (replaces old auto-dispose system)
     setup_intelligent_cache_management(&provider, &cache_key, &cache, &refresh_registry);
 
     // Check cache expiration before the memo - this happens on every render
-    check_and_handle_cache_expiration(cache_expiration, &cache_key, &cache, &refresh_registry);
+    check_and_handle_cache_expiration(cache_expiration, error_ttl, &cache_key, &cache);
+
+    // SWR staleness checking - runs on every render to check for stale data. While the
+    // client hydrates, cached data is shown as the server rendered it instead of refetched
+    if fetches_on_render_target(&provider) && !is_hydrating() {
+        check_and_handle_swr_core(&provider, &param, &cache_key, &cache, &refresh_registry);
+    }
+
+    // The fetch this hook is running and its key, so a param change can abort it
+    let in_flight: InFlightFetch = use_hook(Rc::default);
 
-    // SWR staleness checking - runs on every render to check for stale data
-    check_and_handle_swr_core(&provider, &param, &cache_key, &cache, &refresh_registry);
+    // The key this hook shows, so an entry is only disposed once no component uses it
+    let mounted_key: MountedKey = use_hook(Rc::default);
+    // The key this hook has shown data for, so later errors aren't hidden by the fallback
+    let shown_key: ShownKey = use_hook(Rc::default);
+    {
+        let mounted_key = mounted_key.clone();
+        let refresh_registry = refresh_registry.clone();
+        use_drop(move || {
+            if let Some(key) = mounted_key.borrow_mut().take() {
+                refresh_registry.unmount_key(&key);
+            }
+        });
+    }
 
-    // Use memo with reactive dependencies to track changes automatically
-    let _execution_memo = use_memo(use_reactive!(|(provider, param)| {
-        let cache_key = provider.id(&param);
+    // The memo reruns when the cache key changes rather than whenever the provider value
+    // compares unequal, so providers holding closures or config can be built every render
+    let latest = use_latest((provider, param));
+    let _execution_memo = use_memo(use_reactive!(|cache_key| {
+        let (provider, param) = latest.borrow().clone();
 
         debug!(
-            "🔄 [USE_PROVIDER] Memo executing for key: {} with param: {:?}",
-            cache_key, param
+            "🔄 [USE_PROVIDER] Memo executing for key: {} with param: {}",
+            cache_key,
+            provider.describe_param(&param)
         );
 
+        abort_superseded_fetch(&in_flight, &cache_key, &refresh_registry);
+        track_mounted_key(&mounted_key, &cache_key, &refresh_registry);
+
+        // Client-only providers stay loading during server-side rendering
+        if !fetches_on_render_target(&provider) {
+            debug!(
+                "🖥️ [USE_PROVIDER] Skipping client-only provider on the server: {}",
+                cache_key
+            );
+            return;
+        }
+
         // Subscribe to refresh events for this cache key if we have a reactive context
         if let Some(reactive_context) = ReactiveContext::current() {
             refresh_registry.subscribe_to_refresh(&cache_key, reactive_context);
@@ -412,359 +1120,2494 @@ where
         setup_stale_check_task_core(&provider, &param, &cache_key, &cache, &refresh_registry);
 
         // Check cache for valid data
-        if let Some(cached_result) = cache.get::<Result<P::Output, P::Error>>(&cache_key) {
+        cache.assert_key_owner(&cache_key, std::any::type_name::<P>());
+        if let Some(cached_result) = read_cached_result::<P::Output, P::Error>(&cache, &cache_key) {
             // Access tracking is automatically handled by cache.get() updating last_accessed time
             debug!("📊 [CACHE-HIT] Serving cached data for: {}", cache_key);
 
-            match cached_result {
-                Ok(data) => {
-                    let _ = spawn(async move {
-                        state.set(ProviderState::Success(data));
-                    });
-                }
-                Err(error) => {
-                    let _ = spawn(async move {
-                        state.set(ProviderState::Error(error));
-                    });
-                }
-            }
+            let cached_state = result_state(
+                &provider,
+                Some(&cache),
+                &cache_key,
+                &shown_key,
+                cached_result,
+            );
+            let _ = spawn(async move {
+                state.set(cached_state);
+            });
             return;
         }
 
         // Cache miss - set loading and spawn async task
         let cache_clone = cache.clone();
+        let refresh_registry_clone = refresh_registry.clone();
         let cache_key_clone = cache_key.clone();
         let provider = provider.clone();
         let param = param.clone();
+        let in_flight_for_async = in_flight.clone();
+        let shown_key = shown_key.clone();
         let mut state_for_async = state;
+        // Completions of this fetch are discarded once a newer fetch of the key stored its
+        // result, and a fetch this hook still runs for the key, e.g. before a manual refresh,
+        // is aborted
+        let generation = refresh_registry.start_fetch(&cache_key);
+        if let Some((_, previous)) = in_flight.borrow_mut().take() {
+            previous.cancel();
+        }
 
         // Spawn the real async task and store the handle in Loading
         let task = spawn(async move {
-            let result = provider.run(param).await;
-            let updated = cache_clone.set(cache_key_clone.clone(), result.clone());
+            // Partial values of progressive providers are shown while the fetch runs
+            let (fetch, mut partials) = with_emitter::<P::Output, _>(run_provider(
+                &provider,
+                &param,
+                &cache_clone,
+                &cache_key_clone,
+            ));
+            let mut fetch = std::pin::pin!(fetch.fuse());
+            let result = loop {
+                futures::select! {
+                    result = fetch => break result,
+                    partial = partials.select_next_some() => {
+                        if !generation.claim_store() {
+                            continue;
+                        }
+                        debug!("🧩 [PROGRESSIVE] Partial value for key: {}", cache_key_clone);
+                        let updated = store_provider_result(
+                            &provider,
+                            &param,
+                            &cache_clone,
+                            &refresh_registry_clone,
+                            &cache_key_clone,
+                            Ok(partial.clone()),
+                        );
+                        if updated {
+                            *shown_key.borrow_mut() = Some(cache_key_clone.clone());
+                            state_for_async.set(ProviderState::Success(partial));
+                        }
+                    }
+                }
+            };
+            in_flight_for_async
+                .borrow_mut()
+                .take_if(|(key, _)| *key == cache_key_clone);
+            // Storing a newer fetch's result refreshed this hook, which then shows it
+            if !generation.claim_store() {
+                debug!(
+                    "⏭️ [USE_PROVIDER] Discarding superseded fetch for key: {}",
+                    cache_key_clone
+                );
+                return;
+            }
+            let result = provider.merge_fetched(&cache_clone, &cache_key_clone, result);
+            // The fetch missed the cache, so there is no earlier value worth comparing
+            let updated = store_missed_provider_result(
+                &provider,
+                &param,
+                &cache_clone,
+                &refresh_registry_clone,
+                &cache_key_clone,
+                result.clone(),
+            );
             debug!(
                 "📊 [CACHE-STORE] Attempted to store new data for: {} (updated: {})",
                 cache_key_clone, updated
             );
             if updated {
                 // Only update state and trigger rerender if value changed
-                match result {
-                    Ok(data) => state_for_async.set(ProviderState::Success(data)),
-                    Err(error) => state_for_async.set(ProviderState::Error(error)),
-                }
+                state_for_async.set(result_state(
+                    &provider,
+                    Some(&cache_clone),
+                    &cache_key_clone,
+                    &shown_key,
+                    result,
+                ));
             }
         });
+        *in_flight.borrow_mut() = Some((cache_key, task));
         state.set(ProviderState::Loading { task });
     }));
 
     state
 }
 
-/// Performs SWR staleness checking and triggers background revalidation if needed
-fn check_and_handle_swr_core<P, Param>(
-    provider: &P,
-    param: &Param,
-    cache_key: &str,
-    cache: &ProviderCache,
-    refresh_registry: &RefreshRegistry,
-) where
-    P: Provider<Param> + Clone,
+/// Runs a provider that bypasses the cache, fetching for this component alone
+///
+/// The fetch runs whenever the provider, its parameter or the refresh count of its key
+/// changes, and a fetch still running is cancelled by the next one. The fetch goes through
+/// [`run_provider`] like a cached one, only its result isn't stored.
+fn use_uncached_provider<P, Param>(
+    provider: P,
+    param: Param,
+) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: Provider<Param> + Send + Clone,
     Param: ProviderParamBounds,
 {
-    let stale_time = provider.stale_time();
-    let cache_expiration = provider.cache_expiration();
+    let mut state = use_signal(|| ProviderState::Loading {
+        task: spawn(async {}),
+    });
+    let (cache, refresh_registry) = get_provider_cache_and_registry(&provider);
+    let in_flight: Rc<RefCell<Option<dioxus::core::Task>>> = use_hook(Rc::default);
+    let shown_key: ShownKey = use_hook(Rc::default);
 
-    if let Some(stale_duration) = stale_time {
-        if let Ok(cache_lock) = cache.cache.lock() {
-            if let Some(entry) = cache_lock.get(cache_key) {
-                if entry.is_stale(stale_duration)
-                    && !entry.is_expired(cache_expiration.unwrap_or(Duration::from_secs(3600)))
-                    && !refresh_registry.is_revalidation_in_progress(cache_key)
-                {
-                    // Data is stale but not expired and no revalidation in progress - trigger background revalidation
-                    if refresh_registry.start_revalidation(cache_key) {
-                        debug!(
-                            "🔄 [SWR] Data is stale for key: {} - triggering background revalidation",
-                            cache_key
-                        );
+    let cache_key = provider.id(&param);
+    let latest = use_latest((provider, param));
+    let _execution_memo = use_memo(use_reactive!(|cache_key| {
+        let (provider, param) = latest.borrow().clone();
 
-                        let cache = cache.clone();
-                        let cache_key_clone = cache_key.to_string();
-                        let provider = provider.clone();
-                        let param = param.clone();
-                        let refresh_registry_clone = refresh_registry.clone();
-
-                        spawn(async move {
-                            let result = provider.run(param).await;
-                            let updated = cache.set(cache_key_clone.clone(), result);
-                            refresh_registry_clone.complete_revalidation(&cache_key_clone);
-                            if updated {
-                                refresh_registry_clone.trigger_refresh(&cache_key_clone);
-                                debug!(
-                                    "✅ [SWR] Background revalidation completed for key: {} (value changed)",
-                                    cache_key_clone
-                                );
-                            } else {
-                                debug!(
-                                    "✅ [SWR] Background revalidation completed for key: {} (value unchanged)",
-                                    cache_key_clone
-                                );
-                            }
-                        });
-                    }
-                }
-            }
+        if !fetches_on_render_target(&provider) {
+            return;
         }
-    }
+
+        // Refreshing the key fetches again, even though nothing is cached under it
+        if let Some(reactive_context) = ReactiveContext::current() {
+            refresh_registry.subscribe_to_refresh(&cache_key, reactive_context);
+        }
+        let _current_refresh_count = refresh_registry.get_refresh_count(&cache_key);
+
+        debug!(
+            "🚫 [NO-CACHE] Fetching uncached provider for key: {}",
+            cache_key
+        );
+        if let Some(previous) = in_flight.borrow_mut().take() {
+            previous.cancel();
+        }
+        let mut state_for_async = state;
+        let shown_key = shown_key.clone();
+        let cache = cache.clone();
+        let task = spawn(async move {
+            let result = run_provider(&provider, &param, &cache, &cache_key).await;
+            state_for_async.set(result_state(
+                &provider, None, &cache_key, &shown_key, result,
+            ));
+        });
+        *in_flight.borrow_mut() = Some(task);
+        state.set(ProviderState::Loading { task });
+    }));
+
+    state
 }
 
-/// Sets up interval refresh task for a provider
-fn setup_interval_task_core<P, Param>(
+/// Type alias for the key a `use_provider` hook has shown data for
+type ShownKey = Rc<RefCell<Option<String>>>;
+
+/// The state `use_provider` shows for a fetched or cached result
+///
+/// A failed result shows the provider's `error_fallback` instead, if it declares one and
+/// the hook hasn't shown data for the key yet. The failed entry in `cache` is then marked
+/// stale, so it is retried like stale data.
+fn result_state<P, Param>(
     provider: &P,
-    param: &Param,
+    cache: Option<&ProviderCache>,
     cache_key: &str,
-    cache: &ProviderCache,
-    refresh_registry: &RefreshRegistry,
-) where
-    P: Provider<Param> + Clone + Send,
+    shown_key: &ShownKey,
+    result: Result<P::Output, P::Error>,
+) -> ProviderState<P::Output, P::Error>
+where
+    P: Provider<Param>,
     Param: ProviderParamBounds,
 {
-    if let Some(interval) = provider.interval() {
-        let cache_clone = cache.clone();
-        let provider_clone = provider.clone();
-        let param_clone = param.clone();
-        let cache_key_clone = cache_key.to_string();
-        let refresh_registry_clone = refresh_registry.clone();
-
-        refresh_registry.start_interval_task(cache_key, interval, move || {
-            // Re-execute the provider and update cache in background
-            let cache_for_task = cache_clone.clone();
-            let provider_for_task = provider_clone.clone();
-            let param_for_task = param_clone.clone();
-            let cache_key_for_task = cache_key_clone.clone();
-            let refresh_registry_for_task = refresh_registry_clone.clone();
-
-            spawn(async move {
-                let result = provider_for_task.run(param_for_task).await;
-                let updated = cache_for_task.set(cache_key_for_task.clone(), result);
-                // Only trigger refresh if value changed
-                if updated {
-                    refresh_registry_for_task.trigger_refresh(&cache_key_for_task);
-                }
-            });
-        });
+    let error = match result {
+        Ok(data) => {
+            *shown_key.borrow_mut() = Some(cache_key.to_string());
+            return ProviderState::Success(data);
+        }
+        Err(error) => error,
+    };
+    if shown_key.borrow().as_deref() == Some(cache_key) {
+        return ProviderState::Error(error);
+    }
+    let Some(fallback) = provider.error_fallback() else {
+        return ProviderState::Error(error);
+    };
+    debug!(
+        "🛟 [ERROR-FALLBACK] Showing fallback data for: {}",
+        cache_key
+    );
+    if let (Some(cache), Some(stale_time)) = (cache, provider.stale_time()) {
+        cache.mark_stale(cache_key, stale_time);
     }
+    ProviderState::Success(fallback)
 }
 
-/// Sets up automatic cache expiration monitoring for providers
-fn setup_cache_expiration_task_core<P, Param>(
-    provider: &P,
-    _param: &Param,
-    cache_key: &str,
-    cache: &ProviderCache,
-    refresh_registry: &RefreshRegistry,
-) where
-    P: Provider<Param> + Clone + Send,
+/// Whether a provider fetches for the current render target
+///
+/// Every provider fetches on the client. On the server only providers that allow
+/// server-side rendering fetch, and only while SSR prefetching is enabled; their results
+/// land in the cache the server renders the page from.
+fn fetches_on_render_target<P, Param>(provider: &P) -> bool
+where
+    P: Provider<Param>,
     Param: ProviderParamBounds,
 {
-    if let Some(expiration) = provider.cache_expiration() {
-        let cache_clone = cache.clone();
-        let cache_key_clone = cache_key.to_string();
-        let refresh_registry_clone = refresh_registry.clone();
-
-        refresh_registry.start_periodic_task(
-            cache_key,
-            TaskType::CacheExpiration,
-            expiration / 4, // Check every quarter of the expiration time
-            move || {
-                // Check if cache entry has expired
-                if let Ok(mut cache_lock) = cache_clone.cache.lock() {
-                    if let Some(entry) = cache_lock.get(&cache_key_clone) {
-                        if entry.is_expired(expiration) {
-                            debug!(
-                                "🗑️ [AUTO-EXPIRATION] Cache expired for key: {} - triggering reactive refresh",
-                                cache_key_clone
-                            );
-                            cache_lock.remove(&cache_key_clone);
-                            drop(cache_lock); // Release lock before triggering refresh
-
-                            // Trigger refresh to mark all reactive contexts as dirty
-                            refresh_registry_clone.trigger_refresh(&cache_key_clone);
-                        }
-                    }
-                }
-            },
-        );
+    match render_target() {
+        RenderTarget::Client => true,
+        RenderTarget::Server => ssr_prefetch() && provider.ssr(),
     }
 }
 
-/// Sets up automatic stale-checking task for SWR providers
-fn setup_stale_check_task_core<P, Param>(
-    provider: &P,
-    param: &Param,
-    cache_key: &str,
-    cache: &ProviderCache,
-    refresh_registry: &RefreshRegistry,
-) where
-    P: Provider<Param> + Clone + Send,
-    Param: ProviderParamBounds,
+/// Reads a provider's cached result, counting it as an access
+///
+/// An entry holding another type means something other than the provider wrote to its key,
+/// e.g. `ProviderCache::set` with a bare value instead of a `Result`. That is logged as an
+/// error and treated as a cache miss, after panicking in debug builds so the mistake isn't
+/// hidden by a refetch.
+///
+/// # Panics
+///
+/// In debug builds, if the entry doesn't hold a `Result<T, E>`.
+fn read_cached_result<T, E>(cache: &ProviderCache, cache_key: &str) -> Option<Result<T, E>>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
 {
-    if let Some(stale_time) = provider.stale_time() {
-        let cache_clone = cache.clone();
-        let provider_clone = provider.clone();
-        let param_clone = param.clone();
-        let cache_key_clone = cache_key.to_string();
-        let refresh_registry_clone = refresh_registry.clone();
+    match cache.get_checked::<Result<T, E>>(cache_key) {
+        Ok(cached) => cached,
+        Err(error) => {
+            tracing::error!("⚠️ [CACHE-TYPE] {}", error);
+            debug_assert!(false, "{error}");
+            None
+        }
+    }
+}
 
-        refresh_registry.start_stale_check_task(cache_key, stale_time, move || {
-            // Check if data is stale and trigger revalidation if needed
-            check_and_handle_swr_core(
-                &provider_clone,
-                &param_clone,
-                &cache_key_clone,
-                &cache_clone,
-                &refresh_registry_clone,
-            );
-        });
+/// Keeps the value passed on the latest render, for memos that only rerun on key changes
+///
+/// A memo keeps the closure of its first render, so it reads the current provider and
+/// parameter from here instead of capturing them.
+pub(crate) fn use_latest<T: 'static>(value: T) -> Rc<RefCell<T>> {
+    let mut value = Some(value);
+    let latest = use_hook(|| Rc::new(RefCell::new(value.take().unwrap())));
+    if let Some(value) = value {
+        *latest.borrow_mut() = value;
     }
+    latest
 }
 
-/// Shared cache expiration logic
-fn check_and_handle_cache_expiration(
-    cache_expiration: Option<Duration>,
+/// Type alias for the fetch a `use_provider` hook is running, with the key it fetches
+type InFlightFetch = Rc<RefCell<Option<(String, dioxus::core::Task)>>>;
+
+/// Cancels the fetch a hook started for another key once its param changed
+///
+/// Dropping the fetch cancels the provider's `CancellationToken`, so slow fetches for params
+/// that are no longer shown stop instead of racing the current one. Every hook runs its own
+/// fetch, so other components using the old key are unaffected. The fetch keeps running if
+/// `subscribe_provider` listeners are waiting for the old key's result.
+fn abort_superseded_fetch(
+    in_flight: &InFlightFetch,
     cache_key: &str,
-    cache: &ProviderCache,
     refresh_registry: &RefreshRegistry,
 ) {
-    if let Some(expiration) = cache_expiration {
-        if let Ok(mut cache_lock) = cache.cache.lock() {
-            if let Some(entry) = cache_lock.get(cache_key) {
-                if entry.is_expired(expiration) {
-                    debug!(
-                        "🗑️ [CACHE EXPIRATION] Removing expired cache entry for key: {}",
-                        cache_key
-                    );
-                    cache_lock.remove(cache_key);
-                    // Trigger a refresh to re-execute the provider
-                    refresh_registry.trigger_refresh(cache_key);
-                }
-            }
-        }
+    let superseded = in_flight.borrow_mut().take_if(|(key, _)| key != cache_key);
+    if let Some((previous_key, task)) = superseded
+        && !refresh_registry.has_state_listeners(&previous_key)
+    {
+        debug!(
+            "🛑 [USE_PROVIDER] Aborting superseded fetch for key: {}",
+            previous_key
+        );
+        task.cancel();
     }
 }
 
-/// Sets up intelligent cache management for a provider
+/// Runs a provider, attaching error context to failures for providers that opt in
 ///
-/// This replaces the old component-unmount auto-dispose with a better system:
-/// 1. Access-time tracking for LRU management
-/// 2. Periodic cleanup of unused entries based on cache_expiration
-/// 3. Cache size limits with LRU eviction
-/// 4. Automatic background cleanup tasks
-fn setup_intelligent_cache_management<P, Param>(
+/// The provider's cancellation token is cancelled if this future is dropped before the
+/// fetch completes, e.g. when the task running it is cancelled or its scope unmounts.
+///
+/// With the `metrics` feature, the fetch's duration and outcome are recorded for the
+/// provider's type.
+///
+/// A `NotModified` outcome resolves to the cached value, so storing it only refreshes the
+/// entry's timestamp. If the entry was removed while the fetch was running, the provider
+/// runs again without a validator.
+pub(crate) async fn run_provider<P, Param>(
     provider: &P,
-    cache_key: &str,
+    param: &Param,
     cache: &ProviderCache,
-    refresh_registry: &RefreshRegistry,
+    cache_key: &str,
+) -> Result<P::Output, P::Error>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    let _fetching = cache.begin_fetch(cache_key);
+    // Held until the fetch completes or is dropped, so cancelled fetches free their slot
+    let _permit = match provider.max_concurrency() {
+        Some(limit) => concurrency_limiter(provider.type_name(), limit)
+            .acquire_owned()
+            .await
+            .ok(),
+        None => None,
+    };
+    let cancel = CancellationToken::new();
+    let guard = cancel.clone().drop_guard();
+    let meta = CacheMeta::new(cache.clone(), cache_key);
+    let fetch = with_cache_meta(
+        meta,
+        provider.run_conditional(param.clone(), cancel.clone()),
+    );
+    #[cfg(feature = "metrics")]
+    let fetch = crate::metrics::record_fetch(provider.type_name(), fetch);
+    let outcome = fetch.await;
+    let result = match outcome {
+        Ok(FetchOutcome::Modified(data)) => Ok(data),
+        Ok(FetchOutcome::NotModified) => {
+            match cache.get::<Result<P::Output, P::Error>>(cache_key) {
+                Some(Ok(data)) => {
+                    debug!("⏸️ [FETCH] Not modified for key: {}", cache_key);
+                    Ok(data)
+                }
+                _ => provider.run_with_cancel(param.clone(), cancel).await,
+            }
+        }
+        Err(error) => Err(error),
+    };
+    guard.disarm();
+
+    result.map_err(|error| provider.contextualize_error(cache_key, param, error))
+}
+
+/// Stores a fetched provider result, merged with the cached entry by the provider, applies
+/// the provider's expiration jitter, indexes the entry under the provider's entity key and
+/// notifies state listeners if the value changed
+pub(crate) fn store_provider_result<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+    cache_key: &str,
+    result: Result<P::Output, P::Error>,
+) -> bool
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    let result = provider.merge_fetched(cache, cache_key, result);
+    store_result_with(
+        provider,
+        param,
+        cache,
+        refresh_registry,
+        cache_key,
+        result,
+        P::cache_result,
+    )
+}
+
+/// Stores the result of a fetch that missed the cache like [`store_provider_result`], but
+/// overwrites the entry without comparing it to an earlier value
+///
+/// The result is stored as given: callers merge it with [`Provider::merge_fetched`] first,
+/// so they can show the merged result.
+fn store_missed_provider_result<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+    cache_key: &str,
+    result: Result<P::Output, P::Error>,
+) -> bool
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    store_result_with(
+        provider,
+        param,
+        cache,
+        refresh_registry,
+        cache_key,
+        result,
+        P::cache_missed_result,
+    )
+}
+
+/// Type alias for the provider method storing a result in the cache
+type StoreResult<P, Param> = fn(
+    &P,
+    &ProviderCache,
+    String,
+    Result<<P as Provider<Param>>::Output, <P as Provider<Param>>::Error>,
+) -> bool;
+
+/// Shared implementation of `store_provider_result` and `store_missed_provider_result`
+fn store_result_with<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+    cache_key: &str,
+    result: Result<P::Output, P::Error>,
+    store: StoreResult<P, Param>,
+) -> bool
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    // The entry shows a running mutation's optimistic value, which its result reconciles
+    if cache.has_pending_optimistic(cache_key) {
+        debug!(
+            "⏭️ [CACHE-STORE] Keeping optimistic value over fetched result for: {}",
+            cache_key
+        );
+        return false;
+    }
+    cache.assert_key_owner(cache_key, std::any::type_name::<P>());
+    cache.record_key_param(cache_key, || provider.describe_param(param));
+    let is_error = result.is_err();
+    // Only clone the result into a state when someone is listening for it
+    let new_state = refresh_registry
+        .has_state_listeners(cache_key)
+        .then(|| match &result {
+            Ok(data) => ProviderState::<P::Output, P::Error>::Success(data.clone()),
+            Err(error) => ProviderState::Error(error.clone()),
+        });
+    let size = provider.approximate_size(&result);
+    if provider.persistable() {
+        cache.record_persisted_key(cache_key, || {
+            let param_key = provider.param_key(param);
+            (crate::types::provider_type_key::<P>(&param_key) == cache_key)
+                .then(|| (std::any::type_name::<P>(), param_key))
+        });
+    } else {
+        cache.mark_persistable(cache_key, false);
+    }
+    let updated = store(provider, cache, cache_key.to_string(), result);
+    cache.mark_error(cache_key, is_error);
+    if let Some(size) = size {
+        cache.record_size(cache_key, size);
+    }
+    if let Some(jitter) = provider.expiration_jitter() {
+        cache.apply_expiration_jitter(cache_key, jitter);
+    }
+    if let Some(entity) = provider.entity_key(param) {
+        cache.index_entity(entity, cache_key);
+    }
+    for tag in provider.tags() {
+        cache.index_tag(tag, cache_key);
+    }
+    // The cache lock is released by now, so listeners may read the cache
+    if updated && let Some(new_state) = new_state {
+        refresh_registry.notify_state_listeners(cache_key, &new_state);
+    }
+    updated
+}
+
+/// Revalidates a stale entry in the background if a hook shows it, without waiting for
+/// the hook's component to render again
+///
+/// Used by [`mark_provider_stale`](crate::global::mark_provider_stale), whose refresh doesn't
+/// re-render components that only read derived signals, such as `use_provider_swr`'s.
+pub(crate) fn revalidate_mounted_entry<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    if refresh_registry.mounted_count(cache_key) > 0
+        && fetches_on_render_target(provider)
+        && !is_hydrating()
+    {
+        check_and_handle_swr_core(provider, param, cache_key, cache, refresh_registry);
+    }
+}
+
+/// Performs SWR staleness and `should_refetch` checks and triggers background revalidation if needed
+fn check_and_handle_swr_core<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) where
+    P: Provider<Param> + Clone,
+    Param: ProviderParamBounds,
+{
+    let stale_time = provider.stale_time();
+    let cache_expiration = provider.cache_expiration();
+
+    // Check a snapshot so the provider's `should_refetch` runs without the cache locked
+    let revalidation_reason = cache.snapshot(cache_key).and_then(|entry| {
+        if let Some(stale_duration) = stale_time
+            && entry.is_stale(stale_duration)
+            && !entry.is_expired(cache_expiration.unwrap_or(Duration::from_secs(3600)))
+        {
+            Some("Data is stale")
+        } else if should_refetch_entry(provider, &entry) {
+            Some("Provider requested a refetch")
+        } else {
+            None
+        }
+    });
+
+    // Data needs revalidation and none is in progress - trigger background revalidation
+    if let Some(reason) = revalidation_reason
+        && !refresh_registry.is_revalidation_in_progress(cache_key)
+        && refresh_registry.start_revalidation(cache_key)
+    {
+        debug!(
+            "🔄 [SWR] {} for key: {} - triggering background revalidation",
+            reason, cache_key
+        );
+
+        let cache = cache.clone();
+        let cache_key_clone = cache_key.to_string();
+        let provider = provider.clone();
+        let param = param.clone();
+        let refresh_registry_clone = refresh_registry.clone();
+
+        spawn_task(async move {
+            let result = run_provider(&provider, &param, &cache, &cache_key_clone).await;
+            let updated = store_provider_result(
+                &provider,
+                &param,
+                &cache,
+                &refresh_registry_clone,
+                &cache_key_clone,
+                result,
+            );
+            refresh_registry_clone.complete_revalidation(&cache_key_clone);
+            if updated {
+                debug!(
+                    "✅ [SWR] Background revalidation completed for key: {} (value changed)",
+                    cache_key_clone
+                );
+            } else {
+                debug!(
+                    "✅ [SWR] Background revalidation completed for key: {} (value unchanged)",
+                    cache_key_clone
+                );
+            }
+        });
+    }
+}
+
+/// Asks the provider whether a successful cached entry should be refetched despite being fresh
+fn should_refetch_entry<P, Param>(provider: &P, entry: &CacheEntry) -> bool
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    match entry.peek::<Result<P::Output, P::Error>>() {
+        Some(Ok(cached)) => provider.should_refetch(entry.age(), cached),
+        _ => false,
+    }
+}
+
+/// Sets up interval refresh task for a provider
+fn setup_interval_task_core<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) where
+    P: Provider<Param> + Clone + Send,
+    Param: ProviderParamBounds,
+{
+    if let Some(interval) = provider.interval() {
+        refresh_registry.start_interval_task(
+            cache_key,
+            interval,
+            interval_refresh_fn(provider, param, cache_key, cache, refresh_registry),
+        );
+    }
+}
+
+/// Builds the function run on each interval tick, refetching the provider in the background
+fn interval_refresh_fn<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) -> impl Fn() + Send + 'static
+where
+    P: Provider<Param> + Clone + Send,
+    Param: ProviderParamBounds,
+{
+    let cache_clone = cache.clone();
+    let provider_clone = provider.clone();
+    let param_clone = param.clone();
+    let cache_key_clone = cache_key.to_string();
+    let refresh_registry_clone = refresh_registry.clone();
+    let visible_only = provider.interval_when_visible_only();
+
+    move || {
+        // Hidden documents skip the tick, polling resumes on the first tick once visible
+        if visible_only && !is_document_visible() {
+            return;
+        }
+
+        // Re-execute the provider and update cache in background
+        let cache_for_task = cache_clone.clone();
+        let provider_for_task = provider_clone.clone();
+        let param_for_task = param_clone.clone();
+        let cache_key_for_task = cache_key_clone.clone();
+        let refresh_registry_for_task = refresh_registry_clone.clone();
+
+        spawn_task(async move {
+            let result = run_provider(
+                &provider_for_task,
+                &param_for_task,
+                &cache_for_task,
+                &cache_key_for_task,
+            )
+            .await;
+            // Storing a changed value refreshes the subscribers of the key
+            store_provider_result(
+                &provider_for_task,
+                &param_for_task,
+                &cache_for_task,
+                &refresh_registry_for_task,
+                &cache_key_for_task,
+                result,
+            );
+        });
+    }
+}
+
+/// Sets up automatic cache expiration monitoring for providers
+fn setup_cache_expiration_task_core<P, Param>(
+    provider: &P,
+    _param: &Param,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) where
+    P: Provider<Param> + Clone + Send,
+    Param: ProviderParamBounds,
+{
+    let cache_expiration = provider.cache_expiration();
+    let error_ttl = provider.error_cache_expiration();
+    // Check often enough for whichever of the two lifetimes is shorter
+    let shortest_lifetime = match (cache_expiration, error_ttl) {
+        (Some(expiration), Some(ttl)) => Some(expiration.min(ttl)),
+        (expiration, ttl) => expiration.or(ttl),
+    };
+
+    if let Some(lifetime) = shortest_lifetime {
+        let cache_clone = cache.clone();
+        let cache_key_clone = cache_key.to_string();
+        let check_interval = configured_check_interval(provider, cache).unwrap_or_else(|| {
+            (lifetime / 16).clamp(Duration::from_secs(1), Duration::from_secs(30))
+        });
+
+        refresh_registry.start_periodic_task(
+            cache_key,
+            TaskType::CacheExpiration,
+            check_interval,
+            move || {
+                if remove_expired_entry(&cache_clone, &cache_key_clone, cache_expiration, error_ttl)
+                {
+                    debug!(
+                        "🗑️ [AUTO-EXPIRATION] Cache expired for key: {} - triggering reactive refresh",
+                        cache_key_clone
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Gets the expiration check interval set for a provider, falling back to its cache's
+///
+/// A zero interval, which a hand-written provider could return, counts as unset.
+fn configured_check_interval<P, Param>(provider: &P, cache: &ProviderCache) -> Option<Duration>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    provider
+        .check_interval()
+        .filter(|interval| !interval.is_zero())
+        .or(cache.config().check_interval)
+}
+
+/// Sets up automatic stale-checking task for SWR providers
+fn setup_stale_check_task_core<P, Param>(
+    provider: &P,
+    param: &Param,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) where
+    P: Provider<Param> + Clone + Send,
+    Param: ProviderParamBounds,
+{
+    if let Some(stale_time) = provider.stale_time() {
+        let cache_clone = cache.clone();
+        let provider_clone = provider.clone();
+        let param_clone = param.clone();
+        let cache_key_clone = cache_key.to_string();
+        let refresh_registry_clone = refresh_registry.clone();
+
+        refresh_registry.start_stale_check_task(cache_key, stale_time, move || {
+            // Check if data is stale and trigger revalidation if needed
+            check_and_handle_swr_core(
+                &provider_clone,
+                &param_clone,
+                &cache_key_clone,
+                &cache_clone,
+                &refresh_registry_clone,
+            );
+        });
+    }
+}
+
+/// Shared cache expiration logic
+fn check_and_handle_cache_expiration(
+    cache_expiration: Option<Duration>,
+    error_ttl: Option<Duration>,
+    cache_key: &str,
+    cache: &ProviderCache,
+) {
+    if (cache_expiration.is_some() || error_ttl.is_some())
+        && remove_expired_entry(cache, cache_key, cache_expiration, error_ttl)
+    {
+        debug!(
+            "🗑️ [CACHE EXPIRATION] Removed expired cache entry for key: {}",
+            cache_key
+        );
+    }
+}
+
+/// Removes the entry for a key if it has expired, returning whether it was removed
+///
+/// Removing the entry refreshes the components showing the key, so they refetch it.
+fn remove_expired_entry(
+    cache: &ProviderCache,
+    cache_key: &str,
+    cache_expiration: Option<Duration>,
+    error_ttl: Option<Duration>,
+) -> bool {
+    let removed = cache.remove_if(cache_key, |entry| {
+        is_entry_expired(entry, cache_expiration, error_ttl)
+    });
+    if removed {
+        cache.record_expiration();
+    }
+    removed
+}
+
+/// Checks an entry against the provider's expiration, using the error TTL for failed results
+fn is_entry_expired(
+    entry: &CacheEntry,
+    cache_expiration: Option<Duration>,
+    error_ttl: Option<Duration>,
+) -> bool {
+    entry_expiration(entry, cache_expiration, error_ttl)
+        .is_some_and(|expiration| entry.is_expired(expiration))
+}
+
+/// Gets the expiration that applies to an entry, using the error TTL for failed results
+fn entry_expiration(
+    entry: &CacheEntry,
+    cache_expiration: Option<Duration>,
+    error_ttl: Option<Duration>,
+) -> Option<Duration> {
+    match (entry.is_error(), error_ttl) {
+        (true, Some(ttl)) => Some(cache_expiration.map_or(ttl, |expiration| expiration.min(ttl))),
+        _ => cache_expiration,
+    }
+}
+
+/// Sets up intelligent cache management for a provider
+///
+/// This replaces the old component-unmount auto-dispose with a better system:
+/// 1. Access-time tracking for LRU management
+/// 2. Periodic cleanup of unused entries based on cache_expiration
+/// 3. Cache size limits with LRU eviction
+/// 4. Automatic background cleanup tasks
+/// 5. Disposal of the provider's entry once unused for its `dispose_delay`
+fn setup_intelligent_cache_management<P, Param>(
+    provider: &P,
+    cache_key: &str,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
 ) where
     P: Provider<Param> + Clone,
     Param: ProviderParamBounds,
 {
-    // Set up periodic cleanup task for this provider if cache_expiration is configured
-    if let Some(cache_expiration) = provider.cache_expiration() {
-        let cleanup_interval = std::cmp::max(
-            cache_expiration / 4,    // Clean up 4x more frequently than expiration
-            Duration::from_secs(30), // But at least every 30 seconds
-        );
+    if let Some(dispose_delay) = provider.dispose_delay() {
+        setup_dispose_task(cache_key, dispose_delay, cache, refresh_registry);
+    }
+
+    // Set up periodic cleanup task for this provider if cache_expiration is configured
+    if let Some(cache_expiration) = provider.cache_expiration() {
+        let cleanup_interval = configured_check_interval(provider, cache).unwrap_or_else(|| {
+            std::cmp::max(
+                cache_expiration / 4,    // Clean up 4x more frequently than expiration
+                Duration::from_secs(30), // But at least every 30 seconds
+            )
+        });
+
+        let cache_clone = cache.clone();
+        let unused_threshold = cache_expiration * 2; // Remove entries unused for 2x expiration time
+        let cleanup_key = format!("{cache_key}_cleanup");
+
+        refresh_registry.start_periodic_task(
+            &cleanup_key,
+            TaskType::CacheCleanup,
+            cleanup_interval,
+            move || {
+                // Remove entries that haven't been accessed recently
+                let removed = cache_clone.cleanup_unused_entries(unused_threshold);
+                if removed > 0 {
+                    debug!(
+                        "🧹 [SMART-CLEANUP] Removed {} unused cache entries",
+                        removed
+                    );
+                }
+
+                // Enforce the size limit configured for this cache
+                let evicted = cache_clone.evict_lru_entries(cache_clone.config().max_size);
+                if evicted > 0 {
+                    debug!(
+                        "🗑️ [LRU-EVICT] Evicted {} entries due to cache size limit",
+                        evicted
+                    );
+                }
+            },
+        );
+
+        debug!(
+            "📊 [SMART-CACHE] Intelligent cache management enabled for: {} (cleanup every {:?})",
+            cache_key, cleanup_interval
+        );
+    }
+}
+
+/// Starts a task removing a provider's entry once it has been unused for `dispose_delay`
+///
+/// The task runs in the root scope so it outlives the components using the key, and stops
+/// once the entry is gone. An entry is disposed once no mounted hook has shown it and
+/// nothing has read it for `dispose_delay`, unless it has references.
+fn setup_dispose_task(
+    cache_key: &str,
+    dispose_delay: Duration,
+    cache: &ProviderCache,
+    refresh_registry: &RefreshRegistry,
+) {
+    let check_interval = Duration::max(
+        Duration::min(dispose_delay / 4, Duration::from_secs(30)),
+        Duration::from_millis(10),
+    );
+    let dispose_key = format!("{cache_key}_dispose");
+    let cache = cache.clone();
+    let registry = refresh_registry.clone();
+    let key = cache_key.to_string();
+    let task_key = dispose_key.clone();
+
+    let start = || {
+        refresh_registry.start_periodic_task(
+            &dispose_key,
+            TaskType::CacheCleanup,
+            check_interval,
+            move || {
+                let unused = registry
+                    .unmounted_for(&key)
+                    .is_some_and(|unmounted_for| unmounted_for >= dispose_delay);
+                if unused && cache.dispose_if_unused(&key, dispose_delay) {
+                    registry.stop_periodic_task(&task_key, TaskType::CacheCleanup);
+                    registry.forget_unmounted_key(&key);
+                }
+            },
+        );
+    };
+    match dioxus::core::Runtime::current() {
+        Ok(runtime) => runtime.on_scope(ScopeId::ROOT, start),
+        Err(_) => start(),
+    }
+}
+
+/// Records the key a hook shows, moving it over when a param change switches keys
+fn track_mounted_key(
+    mounted_key: &MountedKey,
+    cache_key: &str,
+    refresh_registry: &RefreshRegistry,
+) {
+    let mut mounted_key = mounted_key.borrow_mut();
+    if mounted_key.as_deref() == Some(cache_key) {
+        return;
+    }
+    if let Some(previous_key) = mounted_key.replace(cache_key.to_string()) {
+        refresh_registry.unmount_key(&previous_key);
+    }
+    refresh_registry.mount_key(cache_key);
+}
+
+/// Type alias for the key a `use_provider` hook shows, tracked for disposal
+type MountedKey = Rc<RefCell<Option<String>>>;
+
+/// Unified hook for using any provider - automatically detects parameterized vs non-parameterized providers
+///
+/// This is the main hook for consuming providers in Dioxus components. It automatically
+/// handles both simple providers (no parameters) and parameterized providers, providing
+/// a consistent interface for all provider types through the `IntoProviderParam` trait.
+///
+/// ## Supported Parameter Formats
+///
+/// - **No parameters**: `use_provider(provider, ())`
+/// - **Tuple parameters**: `use_provider(provider, (param,))`
+/// - **Direct parameters**: `use_provider(provider, param)`
+///
+/// ## Features
+///
+/// - **Automatic Caching**: Results are cached based on provider configuration
+/// - **Reactive Updates**: Components automatically re-render when data changes
+/// - **Loading States**: Provides loading, success, and error states
+/// - **Background Refresh**: Supports interval refresh and stale-while-revalidate
+/// - **Auto-Dispose**: Automatically cleans up unused providers
+/// - **Unified API**: Single function handles all parameter formats
+///
+/// ## Usage Examples
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_user() -> Result<String, String> {
+///     Ok("User data".to_string())
+/// }
+///
+/// #[provider]
+/// async fn fetch_user_by_id(user_id: u32) -> Result<String, String> {
+///     Ok(format!("User {}", user_id))
+/// }
+///
+/// #[component]
+/// fn MyComponent() -> Element {
+///     // All of these work seamlessly:
+///     let user = use_provider(fetch_user(), ());           // No parameters
+///     let user_by_id = use_provider(fetch_user_by_id(), 123);     // Direct parameter
+///     let user_by_id_tuple = use_provider(fetch_user_by_id(), (123,)); // Tuple parameter
+///
+///     rsx! {
+///         div { "Users loaded!" }
+///     }
+/// }
+/// ```
+pub fn use_provider<P, Args>(provider: P, args: Args) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: UseProvider<Args>,
+{
+    provider.use_provider(args)
+}
+
+/// Hook to use a provider through a signal that can only be read
+///
+/// Works like [`use_provider`], but the returned [`ReadSignal`] has no `set` or `write`, so
+/// a component can't override the provider's state locally and drift from the cache.
+/// Change the data through the cache instead, e.g. with [`use_invalidate_provider`],
+/// [`seed_provider`](crate::global::seed_provider) or a mutation. Prefer this hook unless
+/// the component needs the writable signal.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_user(id: u32) -> Result<String, String> {
+///     Ok(format!("User {}", id))
+/// }
+///
+/// #[component]
+/// fn UserName(id: u32) -> Element {
+///     let user = use_provider_readonly(fetch_user(), id);
+///     rsx! { "{user:?}" }
+/// }
+/// ```
+pub fn use_provider_readonly<P, Args>(
+    provider: P,
+    args: Args,
+) -> ReadSignal<ProviderState<P::Output, P::Error>>
+where
+    P: UseProvider<Args>,
+{
+    let state = provider.use_provider(args);
+    use_hook(|| ReadSignal::new(state))
+}
+
+/// Hook to use a provider's output mapped to a different type
+///
+/// The mapping runs in a memo over the provider's state, so it is recomputed whenever the
+/// source changes and components re-render only when the mapped value changes. Loading and
+/// error states pass through unchanged. This lets several components share one provider
+/// (and its cache entry) while each views the data through its own lens.
+///
+/// The mapping should be pure: it runs on the UI thread during rendering.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct UserDto { first: String, last: String }
+///
+/// #[provider]
+/// async fn fetch_user(id: u32) -> Result<UserDto, String> {
+///     Ok(UserDto { first: "Ada".into(), last: "Lovelace".into() })
+/// }
+///
+/// #[component]
+/// fn UserName(id: u32) -> Element {
+///     let name = use_provider_map(fetch_user(), id, |user| format!("{} {}", user.first, user.last));
+///
+///     match &*name.read() {
+///         ProviderState::Success(name) => rsx! { span { "{name}" } },
+///         ProviderState::Error(err) => rsx! { span { "Error: {err}" } },
+///         ProviderState::Idle | ProviderState::Loading { .. } => rsx! { span { "..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_map<P, Args, U, F>(
+    provider: P,
+    args: Args,
+    map: F,
+) -> Memo<ProviderState<U, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+    U: PartialEq + 'static,
+    F: Fn(&P::Output) -> U + 'static,
+{
+    let source = use_provider_core(provider, args.into_param());
+
+    use_memo(move || match &*source.read() {
+        ProviderState::Success(data) => ProviderState::Success(map(data)),
+        ProviderState::Error(error) => ProviderState::Error(error.clone()),
+        ProviderState::Loading { task } => ProviderState::Loading { task: *task },
+        ProviderState::Idle => ProviderState::Idle,
+    })
+}
+
+/// A provider state together with the successful value shown before it
+pub type WithPrevious<T, E> = (Option<T>, ProviderState<T, E>);
+
+/// Type alias for the latest successful value of a hook and the one shown before it
+type SuccessHistory<T> = Rc<RefCell<(Option<T>, Option<T>)>>;
+
+/// Hook to use a provider's state together with its previous successful value
+///
+/// Returns `(previous, current)`, where `previous` is the last `Success` value shown before
+/// the current state. It is kept through `Loading` and `Error`, so while a refetch runs
+/// `previous` holds the data being replaced, and once it succeeds `previous` and `current`
+/// can be compared, e.g. to animate a counter or diff a list. Unlike serving stale data
+/// while revalidating, the loading state is not hidden.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_score() -> Result<u32, String> {
+///     Ok(42)
+/// }
+///
+/// #[component]
+/// fn Score() -> Element {
+///     let score = use_provider_with_previous(fetch_score(), ());
+///
+///     match &*score.read() {
+///         (Some(previous), ProviderState::Success(current)) if previous < current => {
+///             rsx! { span { class: "ticking-up", "{current}" } }
+///         }
+///         (_, ProviderState::Success(current)) => rsx! { span { "{current}" } },
+///         (Some(previous), _) => rsx! { span { class: "refreshing", "{previous}" } },
+///         (None, _) => rsx! { span { "..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_with_previous<P, Args>(
+    provider: P,
+    args: Args,
+) -> Memo<WithPrevious<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    let source = use_provider_core(provider, args.into_param());
+    let successes: SuccessHistory<P::Output> = use_hook(Rc::default);
+
+    use_memo(move || {
+        let state = source.read().clone();
+        let mut successes = successes.borrow_mut();
+        let (previous, latest) = &mut *successes;
+        match &state {
+            ProviderState::Success(data) if latest.as_ref() != Some(data) => {
+                *previous = latest.replace(data.clone());
+                (previous.clone(), state)
+            }
+            ProviderState::Success(_) => (previous.clone(), state),
+            _ => (latest.clone(), state),
+        }
+    })
+}
+
+/// How often [`use_provider_freshness`] recomputes an entry's age while nothing else changes
+const FRESHNESS_TICK: Duration = Duration::from_secs(1);
+
+/// How fresh a provider's cached entry is, as shown by [`use_provider_freshness`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Freshness {
+    /// Time since the entry was stored or refreshed (None while nothing is cached)
+    pub age: Option<Duration>,
+    /// Time since the entry was last read (None while nothing is cached)
+    pub time_since_last_access: Option<Duration>,
+    /// Whether the entry is older than the provider's `stale_time`
+    pub is_stale: bool,
+    /// Whether the entry is older than the provider's `cache_expiration` (or its error TTL
+    /// for a failed result)
+    pub is_expired: bool,
+    /// Whether a fetch of the key is running, e.g. a background revalidation
+    pub is_fetching: bool,
+}
+
+/// Reads the freshness of entries under a provider's stale time and expiration
+#[derive(Clone)]
+struct FreshnessSource {
+    cache: ProviderCache,
+    stale_time: Option<Duration>,
+    cache_expiration: Option<Duration>,
+    error_ttl: Option<Duration>,
+}
+
+impl FreshnessSource {
+    /// Reads the freshness of a key, with how long until it next changes on its own
+    fn read(&self, cache_key: &str) -> (Freshness, Duration) {
+        let is_fetching = self.cache.is_fetching(cache_key);
+        let Some(entry) = self.cache.snapshot(cache_key) else {
+            let freshness = Freshness {
+                is_fetching,
+                ..Freshness::default()
+            };
+            return (freshness, FRESHNESS_TICK);
+        };
+
+        let age = entry.age();
+        let stale_after = self
+            .stale_time
+            .map(|stale_time| entry.effective_duration(stale_time));
+        let expires_after = entry_expiration(&entry, self.cache_expiration, self.error_ttl)
+            .map(|expiration| entry.effective_duration(expiration));
+        // Wake just after the entry turns stale or expires, and at least every tick
+        let next_change = [stale_after, expires_after]
+            .into_iter()
+            .flatten()
+            .filter(|after| *after >= age)
+            .map(|after| after - age + Duration::from_millis(1))
+            .fold(FRESHNESS_TICK, Duration::min);
+
+        let freshness = Freshness {
+            age: Some(age),
+            time_since_last_access: Some(entry.time_since_last_access()),
+            is_stale: stale_after.is_some_and(|after| age > after),
+            is_expired: expires_after.is_some_and(|after| age > after),
+            is_fetching,
+        };
+        (freshness, next_change)
+    }
+}
+
+/// Hook to show how fresh a provider's cached entry is, updating live
+///
+/// Builds staleness indicators such as "updated 2s ago": the returned signal holds the
+/// entry's age and whether it is stale or expired under the provider's `stale_time` and
+/// `cache_expiration`, and whether a fetch of it is running. It is recomputed whenever the
+/// entry changes or a fetch of it starts or finishes, right after it turns stale or
+/// expires, and otherwise every second.
+///
+/// This hook only observes the cache; pair it with [`use_provider`] to fetch the data.
+/// Providers that bypass the cache have no entry, so their freshness stays the default.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider(stale_time = "30s")]
+/// async fn fetch_prices() -> Result<Vec<u32>, String> {
+///     Ok(vec![42])
+/// }
+///
+/// #[component]
+/// fn LastUpdated() -> Element {
+///     let freshness = use_provider_freshness(fetch_prices(), ());
+///     match freshness().age {
+///         Some(age) if freshness().is_stale => rsx! { "Updated {age.as_secs()}s ago, refreshing…" },
+///         Some(age) => rsx! { "Updated {age.as_secs()}s ago" },
+///         None => rsx! { "Loading…" },
+///     }
+/// }
+/// ```
+pub fn use_provider_freshness<P, Args>(provider: P, args: Args) -> Signal<Freshness>
+where
+    P: Provider<Args::Param>,
+    Args: IntoProviderParam,
+{
+    use_freshness_core(&provider, &args.into_param())
+}
+
+/// Watches the freshness of a provider's entry for [`use_provider_freshness`] and
+/// [`use_provider_swr`]
+fn use_freshness_core<P, Param>(provider: &P, param: &Param) -> Signal<Freshness>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    // Whether a provider is cacheable is fixed by its type, so the hooks called stay the same
+    if !provider.cacheable() {
+        return use_signal(Freshness::default);
+    }
+
+    let (cache, _) = get_provider_cache_and_registry(provider);
+    let cache_key = provider.id(param);
+    let source = FreshnessSource {
+        cache,
+        stale_time: provider.stale_time(),
+        cache_expiration: provider.cache_expiration(),
+        error_ttl: provider.error_cache_expiration(),
+    };
+    let mut freshness = use_signal(|| source.read(&cache_key).0);
+    let latest = use_latest(source);
+    let watcher: Rc<RefCell<Option<dioxus::core::Task>>> = use_hook(Rc::default);
+
+    // Watch the current key, replacing the watcher of the previous one
+    let _watch_memo = use_memo(use_reactive!(|cache_key| {
+        let source = latest.borrow().clone();
+        if let Some(previous) = watcher.borrow_mut().take() {
+            previous.cancel();
+        }
+        let task = spawn(async move {
+            let mut changes = futures::stream::select(
+                source.cache.changes().map(drop),
+                source.cache.fetch_activity().map(drop),
+            );
+            loop {
+                let (current, next_change) = source.read(&cache_key);
+                if *freshness.peek() != current {
+                    freshness.set(current);
+                }
+                let next_tick = std::pin::pin!(sleep(next_change));
+                futures::future::select(changes.next(), next_tick).await;
+            }
+        });
+        *watcher.borrow_mut() = Some(task);
+    }));
+
+    freshness
+}
+
+/// A provider's data for rendering stale-while-revalidate, as returned by [`use_provider_swr`]
+///
+/// Unlike [`ProviderState`], the data doesn't disappear while a fetch runs or after it
+/// fails: it stays next to the error and the flags, so a component can keep showing it with
+/// a subtle refresh indicator or a non-blocking error message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwrState<T, E> {
+    /// The latest successful data (None until the first fetch succeeds)
+    pub data: Option<T>,
+    /// The error of the latest fetch, if it failed
+    pub error: Option<E>,
+    /// Whether the cached entry is older than the provider's `stale_time`
+    pub is_stale: bool,
+    /// Whether a fetch runs while `data` is shown, e.g. a background revalidation
+    pub is_revalidating: bool,
+}
+
+impl<T, E> Default for SwrState<T, E> {
+    fn default() -> Self {
+        Self {
+            data: None,
+            error: None,
+            is_stale: false,
+            is_revalidating: false,
+        }
+    }
+}
+
+impl<T: Clone, E: Clone> SwrState<T, E> {
+    /// Combines a provider's state and freshness, keeping `kept` data while loading or failed
+    fn new(kept: Option<T>, state: &ProviderState<T, E>, freshness: &Freshness) -> Self {
+        let (data, error) = match state {
+            ProviderState::Success(data) => (Some(data.clone()), None),
+            ProviderState::Error(error) => (kept, Some(error.clone())),
+            ProviderState::Idle | ProviderState::Loading { .. } => (kept, None),
+        };
+        // Providers that bypass the cache report no fetches, but show loading while refetching
+        let is_fetching = freshness.is_fetching || matches!(state, ProviderState::Loading { .. });
+        Self {
+            is_revalidating: is_fetching && data.is_some(),
+            is_stale: freshness.is_stale,
+            data,
+            error,
+        }
+    }
+
+    /// Returns true while there is neither data nor an error to show, i.e. on the first load
+    pub fn is_loading(&self) -> bool {
+        self.data.is_none() && self.error.is_none()
+    }
+}
+
+/// Hook to render a provider stale-while-revalidate, keeping its data through refetches and
+/// errors
+///
+/// Fetches and caches like [`use_provider`], but the returned [`SwrState`] holds the last
+/// successful data together with whether it is stale, whether a revalidation is running and
+/// the error of a failed refetch. A component can render the data whenever there is some,
+/// and show refreshing and error indicators next to it instead of replacing it. The data of
+/// a previous parameter is not kept. Providers that bypass the cache keep their data through
+/// refetches too, but are never stale.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider(stale_time = "30s")]
+/// async fn fetch_feed() -> Result<Vec<String>, String> {
+///     Ok(vec!["post".to_string()])
+/// }
+///
+/// #[component]
+/// fn Feed() -> Element {
+///     let feed = use_provider_swr(fetch_feed(), ());
+///     let feed = feed.read();
+///     rsx! {
+///         if feed.is_revalidating {
+///             span { class: "spinner" }
+///         }
+///         if let Some(error) = &feed.error {
+///             div { class: "toast", "Couldn't refresh: {error}" }
+///         }
+///         for post in feed.data.iter().flatten() {
+///             p { "{post}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_provider_swr<P, Args>(provider: P, args: Args) -> Signal<SwrState<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    let param = args.into_param();
+    let cache_key = provider.id(&param);
+    let freshness = use_freshness_core(&provider, &param);
+    let state = use_provider_core(provider, param);
+    let mut swr = use_signal(|| SwrState::new(None, &state.peek(), &freshness.peek()));
+    let shown_key: Rc<RefCell<String>> = use_hook(|| Rc::new(RefCell::new(cache_key.clone())));
+
+    // Reading both signals reruns the effect whenever the state or the freshness changes
+    use_effect(use_reactive!(|cache_key| {
+        let kept = if *shown_key.borrow() == cache_key {
+            swr.peek().data.clone()
+        } else {
+            None
+        };
+        *shown_key.borrow_mut() = cache_key;
+        let current = SwrState::new(kept, &state.read(), &freshness.read());
+        if *swr.peek() != current {
+            swr.set(current);
+        }
+    }));
+
+    swr
+}
+
+/// Hook to use a provider as a Dioxus [`Resource`]
+///
+/// Eases interop with code built around `use_resource`: the resource resolves to the
+/// provider's result and re-runs whenever its state changes, e.g. when the cache key is
+/// refreshed or invalidated. While the provider loads, the resource is pending and keeps
+/// the last value it resolved to. The data still comes from the provider's cache, so the
+/// resource shares it with every `use_provider` of the same key.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_user(id: u32) -> Result<String, String> {
+///     Ok(format!("User {id}"))
+/// }
+///
+/// #[component]
+/// fn UserName(id: u32) -> Element {
+///     let user = use_provider_as_resource(fetch_user(), id);
+///     match &*user.read() {
+///         Some(Ok(name)) => rsx! { "{name}" },
+///         Some(Err(error)) => rsx! { "Error: {error}" },
+///         None => rsx! { "..." },
+///     }
+/// }
+/// ```
+pub fn use_provider_as_resource<P, Args>(
+    provider: P,
+    args: Args,
+) -> Resource<Result<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    let source = use_provider_core(provider, args.into_param());
+
+    // Reading the state while polling subscribes the resource, so each change restarts it
+    use_resource(move || async move {
+        let state = source.read().clone();
+        match state {
+            ProviderState::Success(data) => Ok(data),
+            ProviderState::Error(error) => Err(error),
+            ProviderState::Idle | ProviderState::Loading { .. } => std::future::pending().await,
+        }
+    })
+}
+
+/// Hook to show a Dioxus [`Resource`] as a provider state
+///
+/// The converse of [`use_provider_as_resource`], for adopting providers gradually: a
+/// resource created with `use_resource` can be passed to components and helpers that work
+/// with [`ProviderState`]. The state is `Loading` until the resource first resolves, and
+/// afterwards follows its latest value, keeping it while the resource re-runs.
+pub fn use_provider_from_resource<T, E>(
+    resource: Resource<Result<T, E>>,
+) -> Memo<ProviderState<T, E>>
+where
+    T: Clone + PartialEq + 'static,
+    E: Clone + PartialEq + 'static,
+{
+    use_memo(move || match &*resource.read() {
+        Some(Ok(data)) => ProviderState::Success(data.clone()),
+        Some(Err(error)) => ProviderState::Error(error.clone()),
+        None => ProviderState::Loading {
+            task: resource.task(),
+        },
+    })
+}
+
+/// Hook to use a provider in a component that suspends until the data is ready
+///
+/// Same as `use_provider(provider, args).suspend()`, except inside a
+/// [`ProviderSuspenseGroup`](crate::components::ProviderSuspenseGroup): the component joins
+/// the group and stays suspended after its provider has loaded until the group reveals it,
+/// so siblings in separate suspense boundaries appear together.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_user(id: u32) -> Result<String, String> {
+///     Ok(format!("User {id}"))
+/// }
+///
+/// #[component]
+/// fn UserCard(id: u32) -> Element {
+///     match use_provider_suspense(fetch_user(), id)? {
+///         Ok(name) => rsx! { "{name}" },
+///         Err(error) => rsx! { "Error: {error}" },
+///     }
+/// }
+/// ```
+pub fn use_provider_suspense<P, Args>(
+    provider: P,
+    args: Args,
+) -> Result<Result<P::Output, P::Error>, RenderError>
+where
+    P: UseProvider<Args>,
+{
+    let state = use_provider(provider, args);
+    // The membership is dropped with the component, which leaves the group
+    let member =
+        use_hook(|| try_consume_context::<SuspenseGroup>().map(|group| Rc::new(group.join())));
+    // The task waiting for the reveal, spawned once however often the component renders
+    let waiting: Rc<Cell<Option<Task>>> = use_hook(Rc::default);
+
+    if let Some(member) = &member {
+        let loaded = matches!(
+            &*state.read(),
+            ProviderState::Success(_) | ProviderState::Error(_)
+        );
+        member.set_loaded(loaded);
+        if loaded && !member.is_revealed() {
+            let task = waiting.get().unwrap_or_else(|| {
+                let revealed = member.revealed();
+                let slot = waiting.clone();
+                // Nothing the component reads changes on reveal, so re-render it explicitly
+                let task = spawn(async move {
+                    revealed.await;
+                    slot.set(None);
+                    needs_update();
+                });
+                waiting.set(Some(task));
+                task
+            });
+            return Err(RenderError::Suspended(SuspendedFuture::new(task)));
+        }
+    }
+    state.suspend()
+}
+
+/// Hook to use a provider with a parameter computed from signals
+///
+/// The closure is evaluated in a memo, so every signal it reads is tracked: when any of
+/// them changes, the parameter is recomputed and the provider re-runs with the new value.
+/// This avoids forgetting to read one of the signals a derived parameter depends on.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_report(range: String) -> Result<String, String> {
+///     Ok(format!("Report for {}", range))
+/// }
+///
+/// #[component]
+/// fn Report() -> Element {
+///     let from = use_signal(|| 2023);
+///     let to = use_signal(|| 2024);
+///     let report = use_provider_computed(fetch_report(), move || format!("{}-{}", from(), to()));
+///
+///     match &*report.read() {
+///         ProviderState::Success(report) => rsx! { div { "{report}" } },
+///         ProviderState::Error(err) => rsx! { div { "Error: {err}" } },
+///         ProviderState::Idle | ProviderState::Loading { .. } => rsx! { div { "Loading..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_computed<P, Param, F>(
+    provider: P,
+    compute_param: F,
+) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: Provider<Param> + Send,
+    Param: ProviderParamBounds,
+    F: Fn() -> Param + 'static,
+{
+    let param = use_memo(compute_param);
+    // Reading the memo here re-renders the component whenever the computed param changes
+    use_provider_core(provider, param.cloned())
+}
+
+/// Hook to use an interval provider whose polling can be paused at runtime
+///
+/// Works like [`use_provider`], but the provider's interval refresh only ticks while
+/// `enabled` is true. Turning it off (e.g. when a panel is hidden or the user is idle)
+/// pauses the interval task without clearing the cached data, and turning it back on
+/// resumes polling on the existing schedule. Pauses are counted per hook: polling only
+/// stops once every mounted hook showing the provider has it disabled, and each hook
+/// releases its own pause when re-enabled or unmounted.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider(interval = "4s")]
+/// async fn fetch_live_metrics() -> Result<u32, String> {
+///     Ok(42)
+/// }
+///
+/// #[component]
+/// fn MetricsPanel(visible: ReadSignal<bool>) -> Element {
+///     let metrics = use_provider_polling(fetch_live_metrics(), (), visible);
+///
+///     match &*metrics.read() {
+///         ProviderState::Success(value) => rsx! { div { "Requests/s: {value}" } },
+///         ProviderState::Error(err) => rsx! { div { "Error: {err}" } },
+///         ProviderState::Idle | ProviderState::Loading { .. } => rsx! { div { "Loading..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_polling<P, Args>(
+    provider: P,
+    args: Args,
+    enabled: ReadSignal<bool>,
+) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    let param = args.into_param();
+    let (_, refresh_registry) = get_provider_cache_and_registry(&provider);
+    let cache_key = provider.id(&param);
+    let state = use_provider_core(provider, param);
+
+    // The key this hook holds a pause on, so it only ever releases its own pause
+    let paused_key = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+
+    let registry_for_effect = refresh_registry.clone();
+    let paused_key_for_effect = paused_key.clone();
+    use_effect(use_reactive!(|cache_key| {
+        let wanted = (!enabled()).then(|| cache_key.clone());
+        let mut held = paused_key_for_effect.borrow_mut();
+        if *held == wanted {
+            return;
+        }
+        if let Some(key) = held.take() {
+            registry_for_effect.resume_interval_task(&key);
+        }
+        if let Some(key) = wanted {
+            debug!("⏸️ [POLLING] Pausing interval refresh for: {}", key);
+            registry_for_effect.pause_interval_task(&key);
+            *held = Some(key);
+        }
+    }));
+
+    use_drop(move || {
+        if let Some(key) = paused_key.borrow_mut().take() {
+            refresh_registry.resume_interval_task(&key);
+        }
+    });
+
+    state
+}
+
+/// Hook to use a provider whose polling interval can change at runtime
+///
+/// Works like [`use_provider`], but interval refreshes run every `interval` instead of the
+/// interval the provider declares, and the provider doesn't need to declare one. Whenever
+/// the signal changes the interval task is restarted, so an app can poll fast while the
+/// user is active and slow down when idle. A zero interval stops polling. The provider's
+/// declared interval is restored when the parameter changes, and the overridden task is
+/// stopped once every component overriding the key's interval has unmounted.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+/// use std::time::Duration;
+///
+/// #[provider]
+/// async fn fetch_live_metrics() -> Result<u32, String> {
+///     Ok(42)
+/// }
+///
+/// #[component]
+/// fn MetricsPanel(user_active: ReadSignal<bool>) -> Element {
+///     let interval = use_memo(move || {
+///         if user_active() {
+///             Duration::from_secs(2)
+///         } else {
+///             Duration::from_secs(30)
+///         }
+///     });
+///     let metrics = use_provider_with_interval(fetch_live_metrics(), (), interval.into());
+///
+///     match &*metrics.read() {
+///         ProviderState::Success(value) => rsx! { div { "Requests/s: {value}" } },
+///         ProviderState::Error(err) => rsx! { div { "Error: {err}" } },
+///         ProviderState::Idle | ProviderState::Loading { .. } => rsx! { div { "Loading..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_with_interval<P, Args>(
+    provider: P,
+    args: Args,
+    interval: ReadSignal<Duration>,
+) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    let param = args.into_param();
+    let (cache, refresh_registry) = get_provider_cache_and_registry(&provider);
+    let state = use_provider_core(provider.clone(), param.clone());
+
+    // Track the polled key and its parameter, so a parameter change restores the previous
+    // key's declared interval
+    let polled = use_hook(|| Rc::new(RefCell::new(None::<(String, Args::Param)>)));
+
+    let provider_for_effect = provider.clone();
+    let cache_for_effect = cache.clone();
+    let registry_for_effect = refresh_registry.clone();
+    let polled_for_effect = polled.clone();
+    use_effect(use_reactive!(|param| {
+        let cache_key = provider_for_effect.id(&param);
+        let previous = polled_for_effect.replace(Some((cache_key.clone(), param.clone())));
+        if previous
+            .as_ref()
+            .is_none_or(|(previous_key, _)| *previous_key != cache_key)
+        {
+            registry_for_effect.retain_interval_override(&cache_key);
+        }
+        // Other hooks may still override the previous key's interval
+        if let Some((previous_key, previous_param)) = previous
+            && previous_key != cache_key
+            && registry_for_effect.release_interval_override(&previous_key)
+        {
+            setup_interval_task_core(
+                &provider_for_effect,
+                &previous_param,
+                &previous_key,
+                &cache_for_effect,
+                &registry_for_effect,
+            );
+        }
+
+        let interval = interval();
+        debug!(
+            "⏱️ [POLLING] Interval for {} set to {:?}",
+            cache_key, interval
+        );
+        registry_for_effect.restart_interval_task(
+            &cache_key,
+            interval,
+            interval_refresh_fn(
+                &provider_for_effect,
+                &param,
+                &cache_key,
+                &cache_for_effect,
+                &registry_for_effect,
+            ),
+        );
+    }));
+
+    use_drop(move || {
+        if let Some((polled_key, _)) = &*polled.borrow() {
+            refresh_registry.release_interval_override(polled_key);
+        }
+    });
+
+    state
+}
+
+/// Hook to use a provider that falls back to a secondary provider when it fails
+///
+/// Returns the primary provider's state while it is loading or has succeeded. If the
+/// primary fails, the secondary provider is fetched for the same parameter and its result
+/// is returned instead, so an error is only surfaced when both providers fail. Both
+/// providers read and write their own cache entries, unless they bypass the cache, and
+/// invalidating either one refetches through the chain.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_from_edge(id: u32) -> Result<String, String> {
+///     Err("edge cache unavailable".to_string())
+/// }
+///
+/// #[provider]
+/// async fn fetch_from_origin(id: u32) -> Result<String, String> {
+///     Ok(format!("Item {}", id))
+/// }
+///
+/// #[component]
+/// fn Item(id: u32) -> Element {
+///     let item = use_provider_with_fallback(fetch_from_edge(), fetch_from_origin(), id);
+///
+///     match &*item.read() {
+///         ProviderState::Success(item) => rsx! { div { "{item}" } },
+///         ProviderState::Error(err) => rsx! { div { "Both sources failed: {err}" } },
+///         ProviderState::Idle | ProviderState::Loading { .. } => rsx! { div { "Loading..." } },
+///     }
+/// }
+/// ```
+pub fn use_provider_with_fallback<P, S, Args>(
+    primary: P,
+    secondary: S,
+    args: Args,
+) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    S: Provider<Args::Param, Output = P::Output, Error = P::Error> + Send,
+    Args: IntoProviderParam,
+{
+    let param = args.into_param();
+    let primary_state = use_provider_core(primary, param.clone());
+
+    let mut state = use_signal(|| ProviderState::Loading {
+        task: spawn(async {}),
+    });
+    let (cache, refresh_registry) = get_provider_cache_and_registry(&secondary);
+
+    let cache_key = secondary.id(&param);
+    let latest = use_latest((secondary, param));
+    let _fallback_memo = use_memo(use_reactive!(|cache_key| {
+        let (secondary, param) = latest.borrow().clone();
+        // Reading the primary state makes the memo rerun whenever the primary changes
+        match primary_state() {
+            ProviderState::Error(_) => {}
+            settled_or_loading => {
+                let _ = spawn(async move {
+                    state.set(settled_or_loading);
+                });
+                return;
+            }
+        }
+
+        debug!("🔀 [FALLBACK] Primary failed, using secondary: {}", cache_key);
+
+        if let Some(reactive_context) = ReactiveContext::current() {
+            refresh_registry.subscribe_to_refresh(&cache_key, reactive_context);
+        }
+        let _current_refresh_count = refresh_registry.get_refresh_count(&cache_key);
+
+        // A secondary provider bypassing the cache is fetched without reading or storing
+        let cacheable = secondary.cacheable();
+        if cacheable {
+            cache.assert_key_owner(&cache_key, std::any::type_name::<S>());
+        }
+        if cacheable
+            && let Some(cached_result) = read_cached_result::<P::Output, P::Error>(&cache, &cache_key)
+        {
+            debug!("📊 [CACHE-HIT] Serving cached fallback data for: {}", cache_key);
+            let _ = spawn(async move {
+                match cached_result {
+                    Ok(data) => state.set(ProviderState::Success(data)),
+                    Err(error) => state.set(ProviderState::Error(error)),
+                }
+            });
+            return;
+        }
+
+        let cache = cache.clone();
+        let refresh_registry = refresh_registry.clone();
+        let secondary = secondary.clone();
+        let param = param.clone();
+        let mut state_for_async = state;
+
+        let task = spawn(async move {
+            let result = run_provider(&secondary, &param, &cache, &cache_key).await;
+            if cacheable {
+                store_provider_result(
+                    &secondary,
+                    &param,
+                    &cache,
+                    &refresh_registry,
+                    &cache_key,
+                    result.clone(),
+                );
+            }
+            match result {
+                Ok(data) => state_for_async.set(ProviderState::Success(data)),
+                Err(error) => state_for_async.set(ProviderState::Error(error)),
+            }
+        });
+        state.set(ProviderState::Loading { task });
+    }));
+
+    state
+}
+
+/// Provider adapter that caches and serves its inner provider's output behind an `Arc`
+///
+/// Reading a cached value clones it, which deep-copies large outputs on every render
+/// for every consumer. `ArcProvider` wraps the output in an `Arc` once, when fetched,
+/// so every read afterwards is a cheap pointer clone and all consumers share the same
+/// allocation. It is what [`use_provider_arc`] uses under the hood.
+///
+/// The adapter keeps the inner provider's configuration (interval, expiration, stale
+/// time and cache name) but stores its entries under its own cache key, derived from
+/// the inner provider's key. Invalidate it with the adapter itself, e.g.
+/// `use_invalidate_provider(ArcProvider::new(fetch_items()), ())`.
+///
+/// Results are still stored, merged and sized by the inner provider, so `dedupe`,
+/// `accumulate` and `sized` keep working. The inner provider sees the unwrapped output,
+/// which costs at most one clone per fetch rather than one per read.
+#[derive(Clone, PartialEq)]
+pub struct ArcProvider<P> {
+    inner: P,
+}
+
+impl<P> ArcProvider<P> {
+    /// Wrap a provider so its output is shared behind an `Arc`
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    /// Stores a result with the inner provider's `store`, then moves its output behind an
+    /// `Arc`
+    fn store_shared<Param>(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<std::sync::Arc<P::Output>, P::Error>,
+        store: StoreResult<P, Param>,
+    ) -> bool
+    where
+        P: Provider<Param>,
+        Param: ProviderParamBounds,
+    {
+        let result = result.map(std::sync::Arc::unwrap_or_clone);
+        let size = self.inner.approximate_size(&result);
+        let updated = store(&self.inner, cache, key.clone(), result);
+        cache.share_output::<P::Output, P::Error>(&key);
+        if let Some(size) = size {
+            cache.record_size(&key, size);
+        }
+        updated
+    }
+}
+
+impl<P, Param> Provider<Param> for ArcProvider<P>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    type Output = std::sync::Arc<P::Output>;
+    type Error = P::Error;
 
-        let cache_clone = cache.clone();
-        let unused_threshold = cache_expiration * 2; // Remove entries unused for 2x expiration time
-        let cleanup_key = format!("{cache_key}_cleanup");
+    async fn run(&self, param: Param) -> Result<Self::Output, Self::Error> {
+        self.inner.run(param).await.map(std::sync::Arc::new)
+    }
 
-        refresh_registry.start_periodic_task(
-            &cleanup_key,
-            TaskType::CacheCleanup,
-            cleanup_interval,
-            move || {
-                // Remove entries that haven't been accessed recently
-                let removed = cache_clone.cleanup_unused_entries(unused_threshold);
-                if removed > 0 {
-                    debug!(
-                        "🧹 [SMART-CLEANUP] Removed {} unused cache entries",
-                        removed
-                    );
-                }
+    async fn run_with_cancel(
+        &self,
+        param: Param,
+        cancel: CancellationToken,
+    ) -> Result<Self::Output, Self::Error> {
+        self.inner
+            .run_with_cancel(param, cancel)
+            .await
+            .map(std::sync::Arc::new)
+    }
 
-                // Enforce cache size limits (configurable - could be made dynamic)
-                const MAX_CACHE_SIZE: usize = 1000;
-                let evicted = cache_clone.evict_lru_entries(MAX_CACHE_SIZE);
-                if evicted > 0 {
-                    debug!(
-                        "🗑️ [LRU-EVICT] Evicted {} entries due to cache size limit",
-                        evicted
-                    );
-                }
-            },
-        );
+    async fn run_conditional(
+        &self,
+        param: Param,
+        cancel: CancellationToken,
+    ) -> Result<FetchOutcome<Self::Output>, Self::Error> {
+        self.inner
+            .run_conditional(param, cancel)
+            .await
+            .map(|outcome| outcome.map(std::sync::Arc::new))
+    }
 
-        debug!(
-            "📊 [SMART-CACHE] Intelligent cache management enabled for: {} (cleanup every {:?})",
-            cache_key, cleanup_interval
-        );
+    fn id(&self, param: &Param) -> String {
+        format!("{}:arc", self.inner.id(param))
+    }
+
+    fn describe_param(&self, param: &Param) -> String {
+        self.inner.describe_param(param)
+    }
+
+    fn interval(&self) -> Option<Duration> {
+        self.inner.interval()
+    }
+
+    fn interval_when_visible_only(&self) -> bool {
+        self.inner.interval_when_visible_only()
+    }
+
+    fn cache_expiration(&self) -> Option<Duration> {
+        self.inner.cache_expiration()
+    }
+
+    fn dispose_delay(&self) -> Option<Duration> {
+        self.inner.dispose_delay()
+    }
+
+    fn max_concurrency(&self) -> Option<usize> {
+        self.inner.max_concurrency()
+    }
+
+    fn stale_time(&self) -> Option<Duration> {
+        self.inner.stale_time()
+    }
+
+    fn cache_name(&self) -> Option<&'static str> {
+        self.inner.cache_name()
+    }
+
+    fn error_cache_expiration(&self) -> Option<Duration> {
+        self.inner.error_cache_expiration()
+    }
+
+    fn check_interval(&self) -> Option<Duration> {
+        self.inner.check_interval()
+    }
+
+    fn error_fallback(&self) -> Option<Self::Output> {
+        self.inner.error_fallback().map(std::sync::Arc::new)
+    }
+
+    fn expiration_jitter(&self) -> Option<f64> {
+        self.inner.expiration_jitter()
+    }
+
+    fn should_refetch(&self, entry_age: Duration, cached: &Self::Output) -> bool {
+        self.inner.should_refetch(entry_age, cached)
+    }
+
+    fn entity_key(&self, param: &Param) -> Option<String> {
+        self.inner.entity_key(param)
+    }
+
+    fn persistable(&self) -> bool {
+        self.inner.persistable()
+    }
+
+    fn ssr(&self) -> bool {
+        self.inner.ssr()
+    }
+
+    fn cacheable(&self) -> bool {
+        self.inner.cacheable()
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        self.inner.tags()
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.inner.type_name()
+    }
+
+    fn contextualize_error(&self, key: &str, param: &Param, error: Self::Error) -> Self::Error {
+        self.inner.contextualize_error(key, param, error)
+    }
+
+    fn metadata(&self) -> ProviderMetadata {
+        self.inner.metadata()
+    }
+
+    fn cache_result(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<Self::Output, Self::Error>,
+    ) -> bool {
+        // The inner provider would compare against the `Arc`, so compare here
+        if cache.refresh_if_unchanged(&key, &result) {
+            return false;
+        }
+        self.store_shared(cache, key, result, P::cache_result)
+    }
+
+    fn cache_missed_result(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<Self::Output, Self::Error>,
+    ) -> bool {
+        self.store_shared(cache, key, result, P::cache_missed_result)
+    }
+
+    fn merge_fetched(
+        &self,
+        cache: &ProviderCache,
+        key: &str,
+        result: Result<Self::Output, Self::Error>,
+    ) -> Result<Self::Output, Self::Error> {
+        self.inner
+            .merge_fetched(cache, key, result.map(std::sync::Arc::unwrap_or_clone))
+            .map(std::sync::Arc::new)
+    }
+
+    /// Always None: the size is recorded when storing, from the inner provider's estimate
+    /// of the unwrapped output
+    fn approximate_size(&self, result: &Result<Self::Output, Self::Error>) -> Option<usize> {
+        let _ = result;
+        None
     }
 }
 
-/// Unified hook for using any provider - automatically detects parameterized vs non-parameterized providers
+/// Hook for using a provider whose output is shared behind an `Arc`
 ///
-/// This is the main hook for consuming providers in Dioxus components. It automatically
-/// handles both simple providers (no parameters) and parameterized providers, providing
-/// a consistent interface for all provider types through the `IntoProviderParam` trait.
+/// Works like [`use_provider`], but the cache stores `Arc<Output>` so consumers share
+/// one allocation instead of deep-cloning the output on every read. Prefer this for
+/// providers returning large structures such as big `Vec`s. Change detection still
+/// compares values, so an unchanged refetch does not re-render consumers.
 ///
-/// ## Supported Parameter Formats
+/// ## Example
 ///
-/// - **No parameters**: `use_provider(provider, ())`
-/// - **Tuple parameters**: `use_provider(provider, (param,))`
-/// - **Direct parameters**: `use_provider(provider, param)`
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
 ///
-/// ## Features
+/// #[provider]
+/// async fn fetch_rows() -> Result<Vec<String>, String> {
+///     Ok(vec!["row".to_string(); 10_000])
+/// }
 ///
-/// - **Automatic Caching**: Results are cached based on provider configuration
-/// - **Reactive Updates**: Components automatically re-render when data changes
-/// - **Loading States**: Provides loading, success, and error states
-/// - **Background Refresh**: Supports interval refresh and stale-while-revalidate
-/// - **Auto-Dispose**: Automatically cleans up unused providers
-/// - **Unified API**: Single function handles all parameter formats
+/// #[component]
+/// fn Table() -> Element {
+///     let rows = use_provider_arc(fetch_rows(), ());
 ///
-/// ## Usage Examples
+///     match &*rows.read() {
+///         ProviderState::Success(rows) => rsx! { "{rows.len()} rows" },
+///         _ => rsx! { "Loading..." },
+///     }
+/// }
+/// ```
+pub fn use_provider_arc<P, Args>(
+    provider: P,
+    args: Args,
+) -> Signal<ProviderState<std::sync::Arc<P::Output>, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    use_provider(ArcProvider::new(provider), args)
+}
+
+/// Hook to use a provider for many parameters at once, e.g. the rows of a list
+///
+/// Returns one state per entry of `params`, in the same order. All entries share the cache
+/// with [`use_provider`], and parameters that appear several times, or are still being
+/// fetched by this hook when `params` changes, are only fetched once. Fetches are not
+/// shared between hooks, so two hooks missing the same entry both fetch it. Each missing entry is fetched with
+/// the provider's own `run`; use [`use_batch_provider`] to fetch them with one call.
+///
+/// The hook re-renders once a fetch completes and follows invalidation and cache
+/// expiration of every entry. Interval refresh and stale-while-revalidate are not set up,
+/// so use [`use_provider`] for providers that rely on them. Providers that bypass the cache
+/// fetch every parameter for this hook alone, without reading or storing entries.
+///
+/// ## Example
 ///
 /// ```rust,no_run
 /// use dioxus::prelude::*;
 /// use dioxus_provider::prelude::*;
 ///
 /// #[provider]
-/// async fn fetch_user() -> Result<String, String> {
-///     Ok("User data".to_string())
-/// }
-///
-/// #[provider]
-/// async fn fetch_user_by_id(user_id: u32) -> Result<String, String> {
-///     Ok(format!("User {}", user_id))
+/// async fn fetch_item(id: u32) -> Result<String, String> {
+///     Ok(format!("Item {}", id))
 /// }
 ///
 /// #[component]
-/// fn MyComponent() -> Element {
-///     // All of these work seamlessly:
-///     let user = use_provider(fetch_user(), ());           // No parameters
-///     let user_by_id = use_provider(fetch_user_by_id(), 123);     // Direct parameter
-///     let user_by_id_tuple = use_provider(fetch_user_by_id(), (123,)); // Tuple parameter
+/// fn ItemList(ids: Vec<u32>) -> Element {
+///     let items = use_provider_batch(fetch_item(), ids);
 ///
 ///     rsx! {
-///         div { "Users loaded!" }
+///         for item in items.read().iter() {
+///             match item {
+///                 ProviderState::Success(item) => rsx! { li { "{item}" } },
+///                 ProviderState::Error(err) => rsx! { li { "Error: {err}" } },
+///                 ProviderState::Idle | ProviderState::Loading { .. } => rsx! { li { "..." } },
+///             }
+///         }
 ///     }
 /// }
 /// ```
-pub fn use_provider<P, Args>(provider: P, args: Args) -> Signal<ProviderState<P::Output, P::Error>>
+pub fn use_provider_batch<P, Param>(
+    provider: P,
+    params: Vec<Param>,
+) -> Signal<Vec<ProviderState<P::Output, P::Error>>>
 where
-    P: UseProvider<Args>,
+    P: Provider<Param> + Send,
+    Param: ProviderParamBounds,
 {
-    provider.use_provider(args)
+    use_provider_batch_core(provider, params, |provider: P, misses| async move {
+        let (cache, _) = get_provider_cache_and_registry(&provider);
+        let fetches = misses
+            .iter()
+            .map(|(cache_key, param)| run_provider(&provider, param, &cache, cache_key));
+        futures::future::join_all(fetches).await
+    })
+}
+
+/// Hook to use a [`BatchProvider`] for many parameters, fetching all missing entries in one call
+///
+/// Behaves like [`use_provider_batch`], except that the parameters missing from the cache
+/// are passed to [`BatchProvider::run_batch`] together. Parameters already cached or being
+/// fetched by this hook are left out of the batch; another hook's batch is not joined. A
+/// parameter `run_batch` returns no result for gets [`BatchProvider::missing_result`].
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// # #[derive(Clone, PartialEq)]
+/// # struct ItemProvider;
+/// # impl Provider<u32> for ItemProvider {
+/// #     type Output = String;
+/// #     type Error = String;
+/// #     async fn run(&self, id: u32) -> Result<String, String> { Ok(id.to_string()) }
+/// # }
+/// # impl BatchProvider<u32> for ItemProvider {
+/// #     async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+/// #         ids.iter().map(|id| Ok(id.to_string())).collect()
+/// #     }
+/// # }
+/// #[component]
+/// fn ItemList(ids: Vec<u32>) -> Element {
+///     // One run_batch call for every id not cached yet
+///     let items = use_batch_provider(ItemProvider, ids);
+///     rsx! { "{items.read().len()} items" }
+/// }
+/// ```
+pub fn use_batch_provider<P, Param>(
+    provider: P,
+    params: Vec<Param>,
+) -> Signal<Vec<ProviderState<P::Output, P::Error>>>
+where
+    P: BatchProvider<Param> + Send,
+    Param: ProviderParamBounds,
+{
+    use_provider_batch_core(provider, params, |provider: P, misses| async move {
+        let params: Vec<Param> = misses.iter().map(|(_, param)| param.clone()).collect();
+        let mut results = provider.run_batch(&params).await;
+        if results.len() < params.len() {
+            debug!(
+                "📦 [USE_BATCH_PROVIDER] run_batch returned {} results for {} parameters",
+                results.len(),
+                params.len()
+            );
+        }
+        results.truncate(params.len());
+        for param in &params[results.len()..] {
+            results.push(provider.missing_result(param).await);
+        }
+        results
+            .into_iter()
+            .zip(&misses)
+            .map(|(result, (cache_key, param))| {
+                result.map_err(|error| provider.contextualize_error(cache_key, param, error))
+            })
+            .collect()
+    })
+}
+
+/// Core of the batch hooks: serves cached entries and fetches the missing ones with `fetch`
+///
+/// `fetch` receives the missing entries as `(cache key, param)` pairs, without duplicates,
+/// and returns their results in the same order. Completed fetches are stored in the cache
+/// and refresh their keys, so the memo rebuilds every state from the cache.
+fn use_provider_batch_core<P, Param, F, Fut>(
+    provider: P,
+    params: Vec<Param>,
+    fetch: F,
+) -> Signal<Vec<ProviderState<P::Output, P::Error>>>
+where
+    P: Provider<Param> + Send,
+    Param: ProviderParamBounds,
+    F: Fn(P, Vec<(String, Param)>) -> Fut + 'static,
+    Fut: Future<Output = Vec<Result<P::Output, P::Error>>> + 'static,
+{
+    // Whether a provider is cacheable is fixed by its type, so the hooks called stay the same
+    if !provider.cacheable() {
+        return use_uncached_provider_batch(provider, params, fetch);
+    }
+
+    let mut states = use_signal(|| {
+        let task = spawn(async {});
+        params
+            .iter()
+            .map(|_| ProviderState::Loading { task })
+            .collect::<Vec<_>>()
+    });
+    let (cache, refresh_registry) = get_provider_cache_and_registry(&provider);
+    // Keys being fetched by this hook, with the task fetching them
+    let in_flight = use_hook(|| Rc::new(RefCell::new(std::collections::HashMap::new())));
+
+    let cache_keys: Vec<String> = params.iter().map(|param| provider.id(param)).collect();
+    for cache_key in &cache_keys {
+        check_and_handle_cache_expiration(
+            provider.cache_expiration(),
+            provider.error_cache_expiration(),
+            cache_key,
+            &cache,
+        );
+    }
+
+    // Keyed on the cache keys like `use_provider`, so providers that never compare equal
+    // don't rerun the memo every render
+    let latest = use_latest((provider, params));
+    let _execution_memo = use_memo(use_reactive!(|cache_keys| {
+        let (provider, params) = latest.borrow().clone();
+        debug!(
+            "🔄 [USE_PROVIDER_BATCH] Memo executing for {} keys",
+            cache_keys.len()
+        );
+
+        for cache_key in &cache_keys {
+            if let Some(reactive_context) = ReactiveContext::current() {
+                refresh_registry.subscribe_to_refresh(cache_key, reactive_context);
+            }
+            let _current_refresh_count = refresh_registry.get_refresh_count(cache_key);
+        }
+
+        // Serve cached entries and collect each missing key once
+        let mut cached = Vec::with_capacity(cache_keys.len());
+        let mut misses: Vec<(String, Param)> = Vec::new();
+        let mut missed = std::collections::HashSet::new();
+        for (cache_key, param) in cache_keys.iter().zip(&params) {
+            cache.assert_key_owner(cache_key, std::any::type_name::<P>());
+            let result = cache.get::<Result<P::Output, P::Error>>(cache_key);
+            if result.is_none()
+                && !in_flight.borrow().contains_key(cache_key)
+                && missed.insert(cache_key.as_str())
+            {
+                misses.push((cache_key.clone(), param.clone()));
+            }
+            cached.push(result);
+        }
+
+        if !misses.is_empty() {
+            debug!(
+                "📦 [USE_PROVIDER_BATCH] Fetching {} missing keys",
+                misses.len()
+            );
+            let missing_keys: Vec<String> = misses.iter().map(|(key, _)| key.clone()).collect();
+            let fetched = fetch(provider.clone(), misses.clone());
+            let provider = provider.clone();
+            let cache = cache.clone();
+            let refresh_registry = refresh_registry.clone();
+            let in_flight_for_task = in_flight.clone();
+            let task = spawn(async move {
+                let results = fetched.await;
+                for ((cache_key, param), result) in misses.iter().zip(results) {
+                    store_provider_result(
+                        &provider,
+                        param,
+                        &cache,
+                        &refresh_registry,
+                        cache_key,
+                        result,
+                    );
+                }
+                let fetched_keys: Vec<&str> = misses.iter().map(|(key, _)| key.as_str()).collect();
+                let mut in_flight = in_flight_for_task.borrow_mut();
+                for cache_key in &fetched_keys {
+                    in_flight.remove(*cache_key);
+                }
+                drop(in_flight);
+                // Rerun the memo of this hook and of every other user of the keys
+                refresh_registry.trigger_refresh_many(&fetched_keys);
+            });
+            let mut in_flight = in_flight.borrow_mut();
+            for cache_key in missing_keys {
+                in_flight.insert(cache_key, task);
+            }
+        }
+
+        let in_flight = in_flight.borrow();
+        let next_states: Vec<_> = cached
+            .into_iter()
+            .zip(&cache_keys)
+            .map(|(result, cache_key)| match result {
+                Some(Ok(data)) => ProviderState::Success(data),
+                Some(Err(error)) => ProviderState::Error(error),
+                None => match in_flight.get(cache_key) {
+                    Some(task) => ProviderState::Loading { task: *task },
+                    None => ProviderState::Idle,
+                },
+            })
+            .collect();
+        let _ = spawn(async move {
+            states.set(next_states);
+        });
+    }));
+
+    states
+}
+
+/// Runs the batch hooks for a provider that bypasses the cache, fetching for this component
+/// alone
+///
+/// Every distinct key is fetched with `fetch` whenever the keys or their refresh counts
+/// change, and a fetch still running is cancelled by the next one. The results are shown
+/// without being stored.
+fn use_uncached_provider_batch<P, Param, F, Fut>(
+    provider: P,
+    params: Vec<Param>,
+    fetch: F,
+) -> Signal<Vec<ProviderState<P::Output, P::Error>>>
+where
+    P: Provider<Param> + Send,
+    Param: ProviderParamBounds,
+    F: Fn(P, Vec<(String, Param)>) -> Fut + 'static,
+    Fut: Future<Output = Vec<Result<P::Output, P::Error>>> + 'static,
+{
+    let mut states = use_signal(|| {
+        let task = spawn(async {});
+        params
+            .iter()
+            .map(|_| ProviderState::Loading { task })
+            .collect::<Vec<_>>()
+    });
+    let (_, refresh_registry) = get_provider_cache_and_registry(&provider);
+    let in_flight: Rc<RefCell<Option<dioxus::core::Task>>> = use_hook(Rc::default);
+
+    let cache_keys: Vec<String> = params.iter().map(|param| provider.id(param)).collect();
+    let latest = use_latest((provider, params));
+    let _execution_memo = use_memo(use_reactive!(|cache_keys| {
+        let (provider, params) = latest.borrow().clone();
+
+        // Refreshing a key fetches again, even though nothing is cached under it
+        for cache_key in &cache_keys {
+            if let Some(reactive_context) = ReactiveContext::current() {
+                refresh_registry.subscribe_to_refresh(cache_key, reactive_context);
+            }
+            let _current_refresh_count = refresh_registry.get_refresh_count(cache_key);
+        }
+
+        debug!(
+            "🚫 [NO-CACHE] Fetching {} uncached batch keys",
+            cache_keys.len()
+        );
+        if let Some(previous) = in_flight.borrow_mut().take() {
+            previous.cancel();
+        }
+
+        // Fetch each distinct key once
+        let mut fetched_keys = std::collections::HashSet::new();
+        let misses: Vec<(String, Param)> = cache_keys
+            .iter()
+            .zip(&params)
+            .filter(|(cache_key, _)| fetched_keys.insert(cache_key.as_str()))
+            .map(|(cache_key, param)| (cache_key.clone(), param.clone()))
+            .collect();
+        let keys: Vec<String> = misses.iter().map(|(key, _)| key.clone()).collect();
+        let fetched = fetch(provider, misses);
+        let cache_keys_for_task = cache_keys.clone();
+        let mut states_for_async = states;
+        let task = spawn(async move {
+            let results: std::collections::HashMap<_, _> =
+                keys.into_iter().zip(fetched.await).collect();
+            states_for_async.set(
+                cache_keys_for_task
+                    .iter()
+                    .map(|cache_key| match results.get(cache_key) {
+                        Some(Ok(data)) => ProviderState::Success(data.clone()),
+                        Some(Err(error)) => ProviderState::Error(error.clone()),
+                        None => ProviderState::Idle,
+                    })
+                    .collect(),
+            );
+        });
+        *in_flight.borrow_mut() = Some(task);
+        states.set(
+            cache_keys
+                .iter()
+                .map(|_| ProviderState::Loading { task })
+                .collect(),
+        );
+    }));
+
+    states
 }
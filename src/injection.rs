@@ -5,10 +5,40 @@
  * that don't fit well as provider parameters (e.g., API clients, databases).
  */
 
-use crate::errors::ProviderError;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::sync::{Arc, OnceLock, RwLock};
+use thiserror::Error;
+
+/// Errors returned by the dependency injection system
+///
+/// `ProviderError`, `String` and the structured error types convert from it, so `inject::<T>()?`
+/// works in providers returning any of them. Custom error types can implement
+/// `From<InjectionError>` to do the same.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum InjectionError {
+    /// `init_dependency_injection` has not been called
+    #[error("Dependency registry not initialized. Call init_dependency_injection() first.")]
+    NotInitialized,
+
+    /// A dependency of this type is already registered
+    #[error("Dependency of type {0} already registered")]
+    AlreadyRegistered(&'static str),
+
+    /// No dependency of this type is registered
+    #[error(
+        "Dependency of type {0} not found. Make sure to register it with register_dependency() first."
+    )]
+    NotFound(&'static str),
+
+    /// The registered dependency could not be downcast to this type
+    #[error("Failed to downcast dependency of type {0}")]
+    TypeMismatch(&'static str),
+
+    /// A thread panicked while holding the registry lock
+    #[error("Failed to acquire lock on dependencies")]
+    LockPoisoned,
+}
 
 /// Global registry for dependency injection
 static DEPENDENCY_REGISTRY: OnceLock<DependencyRegistry> = OnceLock::new();
@@ -27,19 +57,15 @@ impl DependencyRegistry {
     }
 
     /// Register a dependency of type T
-    pub fn register<T: Send + Sync + 'static>(&self, dependency: T) -> Result<(), ProviderError> {
+    pub fn register<T: Send + Sync + 'static>(&self, dependency: T) -> Result<(), InjectionError> {
         let type_id = TypeId::of::<T>();
-        let mut deps = self.dependencies.write().map_err(|_| {
-            ProviderError::DependencyInjection(
-                "Failed to acquire write lock on dependencies".to_string(),
-            )
-        })?;
+        let mut deps = self
+            .dependencies
+            .write()
+            .map_err(|_| InjectionError::LockPoisoned)?;
 
         if deps.contains_key(&type_id) {
-            return Err(ProviderError::DependencyInjection(format!(
-                "Dependency of type {} already registered",
-                std::any::type_name::<T>()
-            )));
+            return Err(InjectionError::AlreadyRegistered(std::any::type_name::<T>()));
         }
 
         deps.insert(type_id, Arc::new(dependency));
@@ -47,27 +73,21 @@ impl DependencyRegistry {
     }
 
     /// Get a dependency of type T
-    pub fn get<T: Send + Sync + 'static>(&self) -> Result<Arc<T>, ProviderError> {
+    pub fn get<T: Send + Sync + 'static>(&self) -> Result<Arc<T>, InjectionError> {
         let type_id = TypeId::of::<T>();
-        let deps = self.dependencies.read().map_err(|_| {
-            ProviderError::DependencyInjection(
-                "Failed to acquire read lock on dependencies".to_string(),
-            )
-        })?;
-
-        let dependency = deps.get(&type_id).ok_or_else(|| {
-            ProviderError::DependencyInjection(format!(
-                "Dependency of type {} not found. Make sure to register it with register_dependency() first.",
-                std::any::type_name::<T>()
-            ))
-        })?;
-
-        dependency.clone().downcast::<T>().map_err(|_| {
-            ProviderError::DependencyInjection(format!(
-                "Failed to downcast dependency of type {}",
-                std::any::type_name::<T>()
-            ))
-        })
+        let deps = self
+            .dependencies
+            .read()
+            .map_err(|_| InjectionError::LockPoisoned)?;
+
+        let dependency = deps
+            .get(&type_id)
+            .ok_or(InjectionError::NotFound(std::any::type_name::<T>()))?;
+
+        dependency
+            .clone()
+            .downcast::<T>()
+            .map_err(|_| InjectionError::TypeMismatch(std::any::type_name::<T>()))
     }
 
     /// Check if a dependency of type T is registered
@@ -80,23 +100,21 @@ impl DependencyRegistry {
     }
 
     /// Clear all dependencies (mainly for testing)
-    pub fn clear(&self) -> Result<(), ProviderError> {
-        let mut deps = self.dependencies.write().map_err(|_| {
-            ProviderError::DependencyInjection(
-                "Failed to acquire write lock on dependencies".to_string(),
-            )
-        })?;
+    pub fn clear(&self) -> Result<(), InjectionError> {
+        let mut deps = self
+            .dependencies
+            .write()
+            .map_err(|_| InjectionError::LockPoisoned)?;
         deps.clear();
         Ok(())
     }
 
     /// Get all registered dependency type names (for debugging)
-    pub fn list_types(&self) -> Result<Vec<String>, ProviderError> {
-        let deps = self.dependencies.read().map_err(|_| {
-            ProviderError::DependencyInjection(
-                "Failed to acquire read lock on dependencies".to_string(),
-            )
-        })?;
+    pub fn list_types(&self) -> Result<Vec<String>, InjectionError> {
+        let deps = self
+            .dependencies
+            .read()
+            .map_err(|_| InjectionError::LockPoisoned)?;
 
         // Note: We can't easily get type names from TypeId,
         // so this is mainly useful for debugging count
@@ -110,24 +128,18 @@ pub fn init_dependency_injection() {
 }
 
 /// Register a global dependency
-pub fn register_dependency<T: Send + Sync + 'static>(dependency: T) -> Result<(), ProviderError> {
-    let registry = DEPENDENCY_REGISTRY.get().ok_or_else(|| {
-        ProviderError::DependencyInjection(
-            "Dependency registry not initialized. Call init_dependency_injection() first."
-                .to_string(),
-        )
-    })?;
+pub fn register_dependency<T: Send + Sync + 'static>(dependency: T) -> Result<(), InjectionError> {
+    let registry = DEPENDENCY_REGISTRY
+        .get()
+        .ok_or(InjectionError::NotInitialized)?;
     registry.register(dependency)
 }
 
 /// Get a global dependency
-pub fn inject<T: Send + Sync + 'static>() -> Result<Arc<T>, ProviderError> {
-    let registry = DEPENDENCY_REGISTRY.get().ok_or_else(|| {
-        ProviderError::DependencyInjection(
-            "Dependency registry not initialized. Call init_dependency_injection() first."
-                .to_string(),
-        )
-    })?;
+pub fn inject<T: Send + Sync + 'static>() -> Result<Arc<T>, InjectionError> {
+    let registry = DEPENDENCY_REGISTRY
+        .get()
+        .ok_or(InjectionError::NotInitialized)?;
     registry.get()
 }
 
@@ -140,28 +152,32 @@ pub fn has_dependency<T: Send + Sync + 'static>() -> bool {
 }
 
 /// Clear all dependencies (mainly for testing)
-pub fn clear_dependencies() -> Result<(), ProviderError> {
-    let registry = DEPENDENCY_REGISTRY.get().ok_or_else(|| {
-        ProviderError::DependencyInjection("Dependency registry not initialized".to_string())
-    })?;
+pub fn clear_dependencies() -> Result<(), InjectionError> {
+    let registry = DEPENDENCY_REGISTRY
+        .get()
+        .ok_or(InjectionError::NotInitialized)?;
     registry.clear()
 }
 
 /// Macro for easy dependency injection in providers
+///
+/// Failures are returned with `?`, so the enclosing function's error type must implement
+/// `From<InjectionError>`.
 #[macro_export]
 macro_rules! inject {
     ($type:ty) => {
-        $crate::injection::inject::<$type>()
-            .map_err(|e| format!("Dependency injection failed: {}", e))?
+        $crate::injection::inject::<$type>()?
     };
 }
 
 /// Macro for registering dependencies with error handling
+///
+/// Failures are returned with `?`, so the enclosing function's error type must implement
+/// `From<InjectionError>`.
 #[macro_export]
 macro_rules! register {
     ($dependency:expr) => {
-        $crate::injection::register_dependency($dependency)
-            .map_err(|e| format!("Dependency registration failed: {}", e))?
+        $crate::injection::register_dependency($dependency)?
     };
 }
 
@@ -215,7 +231,10 @@ mod tests {
         assert!(register_dependency(service1).is_ok());
 
         // Second registration should fail
-        assert!(register_dependency(service2).is_err());
+        assert!(matches!(
+            register_dependency(service2),
+            Err(InjectionError::AlreadyRegistered(_))
+        ));
     }
 
     #[test]
@@ -224,7 +243,7 @@ mod tests {
         clear_dependencies().unwrap();
 
         // Try to inject non-existent dependency
-        let result: Result<Arc<TestService>, ProviderError> = inject();
-        assert!(result.is_err());
+        let result: Result<Arc<TestService>, InjectionError> = inject();
+        assert!(matches!(result, Err(InjectionError::NotFound(_))));
     }
 }
@@ -1,53 +1,114 @@
 #![doc = include_str!("../README.md")]
 
 // Core modules
+pub mod batch;
 pub mod cache;
+pub mod cancel;
+pub mod components;
+pub mod conditional;
+#[cfg(feature = "devtools")]
+pub mod devtools;
 pub mod errors;
 pub mod global;
 pub mod hooks;
 pub mod injection;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod mutation;
 mod param_utils;
 pub mod platform;
+pub mod progress;
+pub mod progressive;
 mod provider_state;
 pub mod refresh;
+pub mod scope;
+#[cfg(feature = "fullstack")]
+pub mod server_fn;
 pub mod types;
 
 pub mod prelude {
     //! The prelude exports all the most common types and functions for using dioxus-provider.
 
     // The main provider trait and the macro
+    pub use crate::hooks::BatchProvider;
     pub use crate::hooks::Provider;
-    pub use dioxus_provider_macros::{mutation, provider};
+    pub use dioxus_provider_macros::{mutation, provider, providers};
 
     // The core hook for using providers
+    pub use crate::hooks::use_batch_provider;
     pub use crate::hooks::use_provider;
+    pub use crate::hooks::use_provider_arc;
+    pub use crate::hooks::use_provider_as_resource;
+    pub use crate::hooks::use_provider_batch;
+    pub use crate::hooks::use_provider_computed;
+    pub use crate::hooks::use_provider_freshness;
+    pub use crate::hooks::use_provider_from_resource;
+    pub use crate::hooks::use_provider_map;
+    pub use crate::hooks::use_provider_polling;
+    pub use crate::hooks::use_provider_readonly;
+    pub use crate::hooks::use_provider_suspense;
+    pub use crate::hooks::use_provider_swr;
+    pub use crate::hooks::use_provider_with_fallback;
+    pub use crate::hooks::use_provider_with_interval;
+    pub use crate::hooks::use_provider_with_previous;
+    pub use crate::scope::use_scoped_provider;
 
     // Hooks for manual cache management
+    pub use crate::hooks::use_cache_capacity_stats;
+    pub use crate::hooks::use_cache_retain;
+    pub use crate::hooks::use_clear_named_provider_cache;
     pub use crate::hooks::use_clear_provider_cache;
+    pub use crate::hooks::use_debounced_invalidate;
     pub use crate::hooks::use_invalidate_provider;
+    pub use crate::hooks::use_invalidate_provider_always;
     pub use crate::hooks::use_provider_cache;
+    pub use crate::hooks::use_provider_retry;
+    pub use crate::hooks::use_refresh_registry_stats;
 
     // The async state enum, needed for matching
-    pub use crate::provider_state::ProviderState;
+    pub use crate::provider_state::{IsEmpty, ProviderState};
+
+    // Rendering provider state without matching on it
+    pub use crate::components::{ProviderSuspenseGroup, ProviderView, RevealOrder};
+
+    // Caching providers per scope, e.g. per tab or document
+    pub use crate::scope::{ScopeBoundary, invalidate_scope};
+    #[cfg(feature = "devtools")]
+    pub use crate::devtools::ProviderDevtools;
+
+    // Cache keys for parameters that can't implement Hash
+    pub use crate::types::ProviderKey;
+
+    // Size estimates for byte-based cache eviction
+    pub use crate::cache::SizedCacheValue;
+
+    // Outcome of providers revalidating with conditional requests
+    pub use crate::conditional::FetchOutcome;
 
     // Global initialization
-    pub use crate::global::init_global_providers;
+    pub use crate::global::{
+        GlobalProviders, ProviderSubscription, clear_provider_cache, fetch_provider,
+        init_global_providers, init_global_providers_with_config, init_named_cache,
+        invalidate_entity, invalidate_provider, invalidate_tag, mark_provider_stale,
+        prefetch_provider, seed_provider, shutdown_global_providers, subscribe_provider,
+    };
 
     // Dependency Injection
     pub use crate::injection::{
-        clear_dependencies, has_dependency, init_dependency_injection, inject, register_dependency,
+        InjectionError, clear_dependencies, has_dependency, init_dependency_injection, inject,
+        register_dependency,
     };
 
     // Mutation system - Manual Implementation Pattern
     pub use crate::mutation::{
-        Mutation, MutationState, provider_cache_key, provider_cache_key_simple, use_mutation,
-        use_optimistic_mutation,
+        Mutation, MutationState, RetryPolicy, provider_cache_key, provider_cache_key_simple,
+        use_mutation, use_mutation_async, use_mutation_with_progress, use_optimistic_mutation,
+        use_optimistic_mutation_for,
     };
 
     // Error types
     pub use crate::errors::{
         ApiError, ApiResult, DatabaseError, DatabaseResult, ProviderError, ProviderResult,
-        UserError, UserResult,
+        UserError, UserResult, WithProviderContext,
     };
 }
@@ -0,0 +1,204 @@
+//! # Provider Metrics
+//!
+//! Aggregate fetch metrics per provider type, enabled with the `metrics` feature. Every fetch the
+//! hooks run is recorded with its duration and outcome, so dashboards can show how often a
+//! provider fetches, how long it takes and how often it fails without parsing tracing output.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus::prelude::*;
+//! use dioxus_provider::metrics::use_provider_metrics;
+//!
+//! #[component]
+//! fn MetricsDashboard() -> Element {
+//!     let metrics = use_provider_metrics();
+//!     rsx! {
+//!         for (provider, snapshot) in metrics {
+//!             p { "{provider}: {snapshot.fetch_count} fetches, {snapshot.error_count} errors" }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Fetches cancelled before they complete, e.g. because their component unmounted, are not
+//! recorded. Calling [`Provider::run`](crate::hooks::Provider::run) directly bypasses the hooks
+//! and isn't recorded either.
+
+use dioxus::core::ReactiveContext;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{Arc, Mutex, OnceLock},
+    time::Duration,
+};
+
+use crate::{hooks::Provider, types::ProviderParamBounds};
+
+// Platform-specific time imports
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+#[cfg(target_family = "wasm")]
+use web_time::Instant;
+
+/// Metrics recorded by the hooks
+static PROVIDER_METRICS: OnceLock<ProviderMetrics> = OnceLock::new();
+
+/// Type alias for the reactive contexts re-rendered when metrics are recorded
+type SubscriberSet = Arc<Mutex<HashSet<ReactiveContext>>>;
+
+/// Aggregated fetch metrics of one provider type
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Number of completed fetches
+    pub fetch_count: u64,
+    /// Number of fetches that returned an error
+    pub error_count: u64,
+    /// Combined duration of all fetches
+    pub total_duration: Duration,
+    /// Duration of the latest fetch
+    pub last_duration: Duration,
+}
+
+impl MetricsSnapshot {
+    /// Gets the average duration of a fetch (zero before the first one)
+    pub fn average_duration(&self) -> Duration {
+        match u32::try_from(self.fetch_count) {
+            Ok(0) => Duration::ZERO,
+            Ok(count) => self.total_duration / count,
+            Err(_) => self.total_duration.div_f64(self.fetch_count as f64),
+        }
+    }
+
+    /// Gets the fraction of fetches that returned an error, from 0.0 to 1.0 (zero before the
+    /// first fetch)
+    pub fn error_rate(&self) -> f64 {
+        if self.fetch_count == 0 {
+            return 0.0;
+        }
+        self.error_count as f64 / self.fetch_count as f64
+    }
+}
+
+/// A registry of fetch metrics keyed by provider type name
+#[derive(Clone, Default)]
+pub struct ProviderMetrics {
+    records: Arc<Mutex<HashMap<&'static str, MetricsSnapshot>>>,
+    subscribers: SubscriberSet,
+}
+
+impl ProviderMetrics {
+    /// Creates an empty metrics registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a completed fetch of the provider type `provider`
+    pub fn record(&self, provider: &'static str, duration: Duration, is_error: bool) {
+        if let Ok(mut records) = self.records.lock() {
+            let snapshot = records.entry(provider).or_default();
+            snapshot.fetch_count += 1;
+            snapshot.error_count += u64::from(is_error);
+            snapshot.total_duration += duration;
+            snapshot.last_duration = duration;
+        }
+        self.notify_subscribers();
+    }
+
+    /// Gets the metrics of the provider type `provider` (None if it was never fetched)
+    pub fn get(&self, provider: &str) -> Option<MetricsSnapshot> {
+        self.records.lock().ok()?.get(provider).cloned()
+    }
+
+    /// Gets the metrics of every fetched provider type, sorted by type name
+    pub fn all(&self) -> Vec<(&'static str, MetricsSnapshot)> {
+        let Ok(records) = self.records.lock() else {
+            return Vec::new();
+        };
+        let mut all: Vec<_> = records
+            .iter()
+            .map(|(provider, snapshot)| (*provider, snapshot.clone()))
+            .collect();
+        all.sort_by_key(|(provider, _)| *provider);
+        all
+    }
+
+    /// Removes all recorded metrics
+    pub fn clear(&self) {
+        if let Ok(mut records) = self.records.lock() {
+            records.clear();
+        }
+        self.notify_subscribers();
+    }
+
+    /// Re-renders a reactive context whenever metrics are recorded or cleared
+    ///
+    /// The context is dropped from the registry once it no longer exists.
+    pub fn subscribe(&self, reactive_context: ReactiveContext) {
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.insert(reactive_context);
+        }
+    }
+
+    /// Marks every subscribed reactive context dirty, forgetting the dropped ones
+    fn notify_subscribers(&self) {
+        let subscribers: Vec<ReactiveContext> = match self.subscribers.lock() {
+            Ok(subscribers) => subscribers.iter().copied().collect(),
+            Err(_) => return,
+        };
+        // Callbacks may re-enter the registry, so no lock is held while marking
+        let dropped: Vec<ReactiveContext> = subscribers
+            .into_iter()
+            .filter(|reactive_context| !reactive_context.mark_dirty())
+            .collect();
+        if !dropped.is_empty()
+            && let Ok(mut subscribers) = self.subscribers.lock()
+        {
+            for reactive_context in &dropped {
+                subscribers.remove(reactive_context);
+            }
+        }
+    }
+}
+
+/// Gets the metrics registry the hooks record fetches in
+pub fn global_metrics() -> &'static ProviderMetrics {
+    PROVIDER_METRICS.get_or_init(ProviderMetrics::new)
+}
+
+/// Runs a fetch of the provider type `provider`, recording its duration and outcome once it
+/// completes
+pub(crate) async fn record_fetch<T, E>(
+    provider: &'static str,
+    fetch: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let started = Instant::now();
+    let result = fetch.await;
+    global_metrics().record(provider, started.elapsed(), result.is_err());
+    result
+}
+
+/// Gets the recorded metrics of a provider, combined over all of its params (None if the
+/// hooks never fetched it)
+pub fn provider_metrics<P, Param>(provider: &P) -> Option<MetricsSnapshot>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    global_metrics().get(provider.type_name())
+}
+
+/// Gets the recorded metrics of every provider the hooks fetched, sorted by type name
+pub fn all_provider_metrics() -> Vec<(&'static str, MetricsSnapshot)> {
+    global_metrics().all()
+}
+
+/// Hook to read the recorded metrics of every fetched provider, sorted by type name
+///
+/// The component re-renders whenever a fetch is recorded, so a dashboard stays current.
+pub fn use_provider_metrics() -> Vec<(&'static str, MetricsSnapshot)> {
+    if let Some(reactive_context) = ReactiveContext::current() {
+        global_metrics().subscribe(reactive_context);
+    }
+    all_provider_metrics()
+}
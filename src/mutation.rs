@@ -11,14 +11,29 @@
 //! - **Automatic Cache Invalidation**: Invalidate related providers automatically
 //! - **Mutation State**: Track loading, success, and error states
 //! - **Rollback Support**: Automatic rollback of optimistic updates on failure
+//! - **Retries and Timeouts**: Retry transient failures and bound how long an attempt may take
 
 use dioxus::prelude::*;
-use std::future::Future;
+use futures::{
+    FutureExt, StreamExt,
+    channel::oneshot,
+    future::{Either, select},
+    stream::select_all,
+};
+use std::{future::Future, pin::pin, time::Duration};
 use tracing::debug;
 
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+#[cfg(target_family = "wasm")]
+use web_time::Instant;
+
 use crate::{
-    global::{get_global_cache, get_global_refresh_registry},
-    hooks::Provider,
+    cache::{CacheEntry, KeyGuard, ProviderCache},
+    global::{all_caches, ensure_global_providers, get_cache_and_registry},
+    hooks::{Provider, use_latest},
+    platform::sleep,
+    progress::{MutationProgress, with_progress},
     types::ProviderParamBounds,
 };
 
@@ -115,11 +130,196 @@ where
         Vec::new()
     }
 
+    /// Get provider cache keys to invalidate after a successful mutation with `input`
+    ///
+    /// Defaults to [`Mutation::invalidates`]. Override this (or list provider calls such as
+    /// `#[mutation(invalidates = [fetch_user(id)])]`) when the keys depend on the input.
+    fn invalidates_for(&self, _input: &Input) -> Vec<String> {
+        self.invalidates()
+    }
+
+    /// Get provider tags whose entries should be invalidated after a successful mutation
+    ///
+    /// Every entry stored by a provider carrying one of these tags is dropped and refetched
+    /// where it is in use, see [`invalidate_tag`](crate::global::invalidate_tag). Mutations
+    /// declared with `#[mutation(invalidates_tags = ["users"])]` return those tags.
+    fn invalidates_tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Get cache keys that should be optimistically updated (simple invalidation)
     /// Override this to enable optimistic cache invalidation
     fn optimistic_invalidate(&self, _input: &Input) -> Vec<String> {
         Vec::new()
     }
+
+    /// Get provider cache entries that should be overwritten with the mutation's output
+    /// after a successful mutation, instead of being invalidated and refetched
+    /// Override this (or use `#[mutation(updates = [...])]`) to write results straight into the cache
+    fn updates(&self, _input: &Input, _output: &Self::Output) -> Vec<CacheUpdate> {
+        Vec::new()
+    }
+
+    /// Get how failed attempts of this mutation are retried
+    /// Override this (or use `#[mutation(retry = 3)]`) to retry transient failures
+    fn retry_policy(&self) -> Option<RetryPolicy> {
+        None
+    }
+
+    /// Get the maximum time a single attempt of this mutation may take
+    /// The timeout is only enforced when `timeout_error` provides an error to report
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Get the error reported when an attempt exceeds `timeout`
+    /// `#[mutation(timeout = "5s")]` converts a `ProviderError::Timeout` into the mutation's error
+    fn timeout_error(&self, _timeout: Duration) -> Option<Self::Error> {
+        None
+    }
+
+    /// Get the output to write into the `updates` entries before the mutation runs
+    /// Override this (or use `#[mutation(optimistic = expr)]`) to update the UI immediately;
+    /// `use_mutation` overwrites the entries with the `updates` of the mutation's output once it
+    /// succeeds, or rolls them back if it fails. Fetches finishing meanwhile don't replace the
+    /// optimistic value.
+    fn optimistic_value(&self, _input: &Input) -> Option<Self::Output> {
+        None
+    }
+
+    /// Get how long a successful mutation waits for the entries it invalidated to refetch
+    /// Override this (or use `#[mutation(await_invalidations)]`) so the mutation only settles,
+    /// e.g. reports `Success` and resolves [`use_mutation_async`]'s future, once the components
+    /// showing those entries got their new data or the duration elapsed
+    fn await_invalidations(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// How a mutation retries failed attempts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first failed attempt
+    pub max_retries: u32,
+    /// Delay before each retry
+    pub delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a policy that retries up to `max_retries` times without delay
+    pub const fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Wait `delay` before each retry
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// A pending write of a mutation's output into a provider's cache entry
+///
+/// Created with [`CacheUpdate::new`], which ties the written value to the target
+/// provider's `Output` type so the cache entry can be read back by `use_provider`.
+pub struct CacheUpdate {
+    key: String,
+    cache_name: Option<&'static str>,
+    entity: Option<String>,
+    tags: &'static [&'static str],
+    write: Box<dyn FnOnce(&ProviderCache) -> bool>,
+}
+
+impl CacheUpdate {
+    /// Create an update that stores `output` as the successful result of `provider` for `param`
+    pub fn new<P, Param>(provider: P, param: Param, output: P::Output) -> Self
+    where
+        P: Provider<Param>,
+        Param: ProviderParamBounds,
+    {
+        let key = provider.id(&param);
+        let cache_name = provider.cache_name();
+        let entity = provider.entity_key(&param);
+        let tags = provider.tags();
+        let write_key = key.clone();
+        Self {
+            key,
+            cache_name,
+            entity,
+            tags,
+            write: Box::new(move |cache| {
+                cache.assert_key_owner(&write_key, std::any::type_name::<P>());
+                if !provider.persistable() {
+                    cache.mark_persistable(&write_key, false);
+                }
+                provider.cache_result(cache, write_key.clone(), Ok(output))
+            }),
+        }
+    }
+
+    /// Get the cache key this update writes to
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Snapshot the entry this update overwrites, so it can be rolled back
+    ///
+    /// The entry is held for the optimistic value until the snapshot is dropped.
+    fn snapshot(&self) -> CacheSnapshot {
+        let cache = get_cache_and_registry(self.cache_name)
+            .ok()
+            .map(|(cache, _)| cache);
+        CacheSnapshot {
+            key: self.key.clone(),
+            cache_name: self.cache_name,
+            entity: self.entity.clone(),
+            tags: self.tags,
+            entry: cache.as_ref().and_then(|cache| cache.snapshot(&self.key)),
+            _hold: cache.map(|cache| cache.hold_optimistic(&self.key)),
+        }
+    }
+
+    /// Write the value into its provider's cache and refresh subscribers if it changed
+    fn apply(self) {
+        let Ok((cache, _)) = get_cache_and_registry(self.cache_name) else {
+            return;
+        };
+        (self.write)(&cache);
+    }
+}
+
+/// A provider cache entry as it was before an optimistic update overwrote it
+struct CacheSnapshot {
+    key: String,
+    cache_name: Option<&'static str>,
+    /// The entity and tags the provider indexes the entry under
+    entity: Option<String>,
+    tags: &'static [&'static str],
+    entry: Option<CacheEntry>,
+    _hold: Option<KeyGuard>,
+}
+
+impl CacheSnapshot {
+    /// Put the entry back as it was and refresh its subscribers
+    fn restore(self) {
+        let Ok((cache, _)) = get_cache_and_registry(self.cache_name) else {
+            return;
+        };
+        let restored = self.entry.is_some();
+        cache.restore(&self.key, self.entry);
+        // The entry may have been invalidated meanwhile, which dropped it from the indexes
+        if restored {
+            if let Some(entity) = self.entity {
+                cache.index_entity(entity, &self.key);
+            }
+            for &tag in self.tags {
+                cache.index_tag(tag, &self.key);
+            }
+        }
+    }
 }
 
 /// Type alias for the return type of mutation hooks
@@ -128,6 +328,13 @@ pub type MutationHookResult<M, Input, F> = (
     F,
 );
 
+/// Type alias for the return type of mutation hooks reporting progress
+pub type MutationProgressHookResult<M, Input, F> = (
+    Signal<MutationState<<M as Mutation<Input>>::Output, <M as Mutation<Input>>::Error>>,
+    Signal<MutationProgress>,
+    F,
+);
+
 /// Hook to create a mutation that can be triggered manually
 ///
 /// Returns a tuple containing:
@@ -166,56 +373,225 @@ pub type MutationHookResult<M, Input, F> = (
 /// }
 /// ```
 pub fn use_mutation<M, Input>(mutation: M) -> MutationHookResult<M, Input, impl Fn(Input) + Clone>
+where
+    M: Mutation<Input> + Send + Sync + 'static,
+    Input: Clone + PartialEq + Send + Sync + 'static,
+{
+    let (state, _, mutate_fn) = use_mutation_core(mutation);
+    (state, mutate_fn)
+}
+
+/// Hook to create a mutation that reports its progress while it runs
+///
+/// Works like [`use_mutation`], but also returns a signal with the [`MutationProgress`]
+/// reported by a `#[mutation(with_progress)]` mutation through its `ProgressReporter`.
+/// The progress is reset when the mutation is triggered and keeps its last value after the
+/// mutation completes.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::{prelude::*, progress::ProgressReporter};
+///
+/// #[mutation(with_progress)]
+/// async fn import_contacts(file: String, progress: ProgressReporter) -> Result<u32, String> {
+///     progress.step("Parsing");
+///     progress.set(0.5);
+///     Ok(42)
+/// }
+///
+/// #[component]
+/// fn ImportButton() -> Element {
+///     let (state, progress, import) = use_mutation_with_progress(import_contacts());
+///
+///     rsx! {
+///         button { onclick: move |_| import("contacts.csv".to_string()), "Import" }
+///         if state.read().is_loading() {
+///             progress { max: 100.0, value: progress.read().percent() }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_mutation_with_progress<M, Input>(
+    mutation: M,
+) -> MutationProgressHookResult<M, Input, impl Fn(Input) + Clone>
+where
+    M: Mutation<Input> + Send + Sync + 'static,
+    Input: Clone + PartialEq + Send + Sync + 'static,
+{
+    use_mutation_core(mutation)
+}
+
+/// Runs triggered mutations, applying their cache updates and tracking their progress
+fn use_mutation_core<M, Input>(
+    mutation: M,
+) -> MutationProgressHookResult<M, Input, impl Fn(Input) + Clone>
+where
+    M: Mutation<Input> + Send + Sync + 'static,
+    Input: Clone + PartialEq + Send + Sync + 'static,
+{
+    let (state, progress, start) = use_mutation_async_core(mutation);
+    let mutate_fn = move |input: Input| {
+        // The mutation runs in its own task, nothing awaits its result
+        drop(start(input));
+    };
+    (state, progress, mutate_fn)
+}
+
+/// Hook to create a mutation whose trigger returns a future of the mutation's result
+///
+/// Works like [`use_mutation`], but calling the returned function also gives a future
+/// resolving to the mutation's result once it settled, e.g. to navigate after saving. The
+/// mutation runs in a task of the component either way, so dropping the future doesn't stop
+/// it or skip rolling back its optimistic values. Mutations declared with
+/// `#[mutation(await_invalidations)]` settle only after the providers they invalidate
+/// finished refetching, so the next screen doesn't start out `Loading`.
+///
+/// The future never resolves if the component unmounts before the mutation settled, as that
+/// drops the mutation's task.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_todos() -> Result<Vec<String>, String> {
+///     Ok(Vec::new())
+/// }
+///
+/// #[mutation(invalidates = [fetch_todos], await_invalidations)]
+/// async fn add_todo(title: String) -> Result<(), String> {
+///     Ok(())
+/// }
+///
+/// #[component]
+/// fn NewTodo(on_added: EventHandler) -> Element {
+///     let (_state, add) = use_mutation_async(add_todo());
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 let add = add.clone();
+///                 async move {
+///                     // The list already shows the new todo once it opens
+///                     if add("Write docs".to_string()).await.is_ok() {
+///                         on_added.call(());
+///                     }
+///                 }
+///             },
+///             "Add"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_mutation_async<M, Input>(
+    mutation: M,
+) -> MutationHookResult<M, Input, impl Fn(Input) -> MutationFuture<M, Input> + Clone>
+where
+    M: Mutation<Input> + Send + Sync + 'static,
+    Input: Clone + PartialEq + Send + Sync + 'static,
+{
+    let (state, _, start) = use_mutation_async_core(mutation);
+    let mutate_async = move |input: Input| -> MutationFuture<M, Input> {
+        let settled = start(input);
+        Box::pin(async move {
+            match settled.await {
+                Ok(result) => result,
+                Err(oneshot::Canceled) => std::future::pending().await,
+            }
+        })
+    };
+    (state, mutate_async)
+}
+
+/// Future returned by the trigger of [`use_mutation_async`], resolving to the mutation's result
+pub type MutationFuture<M, Input> = std::pin::Pin<
+    Box<dyn Future<Output = Result<<M as Mutation<Input>>::Output, <M as Mutation<Input>>::Error>>>,
+>;
+
+/// Type alias for the receiver of a started mutation's result
+type MutationSettled<M, Input> =
+    oneshot::Receiver<Result<<M as Mutation<Input>>::Output, <M as Mutation<Input>>::Error>>;
+
+/// Starts triggered mutations in tasks of the component, applying their cache updates and
+/// tracking their progress, and returns a receiver of each mutation's result
+fn use_mutation_async_core<M, Input>(
+    mutation: M,
+) -> MutationProgressHookResult<M, Input, impl Fn(Input) -> MutationSettled<M, Input> + Clone>
 where
     M: Mutation<Input> + Send + Sync + 'static,
     Input: Clone + PartialEq + Send + Sync + 'static,
 {
     let state = use_signal(|| MutationState::Idle);
-    let cache = get_global_cache();
-    let refresh_registry = get_global_refresh_registry();
+    let progress = use_signal(MutationProgress::default);
+    ensure_global_providers();
 
-    let mutate_fn = {
+    let start = {
         let mutation = mutation.clone();
-        let cache = cache.expect("Global providers not initialized").clone();
-        let refresh_registry = refresh_registry
-            .expect("Global providers not initialized")
-            .clone();
         let mut state = state;
+        let mut progress = progress;
 
-        move |input: Input| {
+        move |input: Input| -> MutationSettled<M, Input> {
             let mutation = mutation.clone();
-            let cache = cache.clone();
-            let refresh_registry = refresh_registry.clone();
             let input = input.clone();
 
+            // Write the declared optimistic value into the updated entries right away
+            let optimistic = match mutation.optimistic_value(&input) {
+                Some(value) => OptimisticUpdate::apply(mutation.updates(&input, &value)),
+                None => OptimisticUpdate::default(),
+            };
+
+            let (settle, settled) = oneshot::channel();
             spawn(async move {
                 state.set(MutationState::Loading);
+                progress.set(MutationProgress::default());
 
                 debug!("🔄 [MUTATION] Starting mutation: {}", mutation.id());
 
-                match mutation.mutate(input).await {
+                // Progress reported by the mutation is shown while it runs
+                let (run, mut updates) = with_progress(run_mutation(&mutation, &input));
+                let mut run = pin!(run.fuse());
+                let result = loop {
+                    futures::select! {
+                        result = run => break result,
+                        update = updates.select_next_some() => progress.write().apply(update),
+                    }
+                };
+                // Reports sent right before the mutation returned
+                while let Ok(Some(update)) = updates.try_next() {
+                    progress.write().apply(update);
+                }
+
+                match result {
                     Ok(result) => {
                         debug!("✅ [MUTATION] Mutation succeeded: {}", mutation.id());
 
+                        // Write the result straight into the targeted provider caches,
+                        // replacing any optimistic value
+                        optimistic.reconcile(mutation.updates(&input, &result));
+
                         // Invalidate specified cache entries
-                        for cache_key in mutation.invalidates() {
-                            debug!("🗑️ [MUTATION] Invalidating cache key: {}", cache_key);
-                            cache.invalidate(&cache_key);
-                            refresh_registry.trigger_refresh(&cache_key);
-                        }
+                        invalidate_after_success(&mutation, &input).await;
 
-                        state.set(MutationState::Success(result));
+                        state.set(MutationState::Success(result.clone()));
+                        let _ = settle.send(Ok(result));
                     }
                     Err(error) => {
                         debug!("❌ [MUTATION] Mutation failed: {}", mutation.id());
-                        state.set(MutationState::Error(error));
+                        optimistic.rollback();
+                        state.set(MutationState::Error(error.clone()));
+                        let _ = settle.send(Err(error));
                     }
                 }
             });
+            settled
         }
     };
 
-    (state, mutate_fn)
+    (state, progress, start)
 }
 
 /// Hook to create a mutation with optimistic invalidation
@@ -256,21 +632,14 @@ where
     Input: Clone + PartialEq + Send + Sync + 'static,
 {
     let state = use_signal(|| MutationState::Idle);
-    let cache = get_global_cache();
-    let refresh_registry = get_global_refresh_registry();
+    ensure_global_providers();
 
     let mutate_fn = {
         let mutation = mutation.clone();
-        let cache = cache.expect("Global providers not initialized").clone();
-        let refresh_registry = refresh_registry
-            .expect("Global providers not initialized")
-            .clone();
         let mut state = state;
 
         move |input: Input| {
             let mutation = mutation.clone();
-            let cache = cache.clone();
-            let refresh_registry = refresh_registry.clone();
             let input = input.clone();
 
             spawn(async move {
@@ -282,8 +651,7 @@ where
                         optimistic_keys.len()
                     );
                     for cache_key in &optimistic_keys {
-                        cache.invalidate(cache_key);
-                        refresh_registry.trigger_refresh(cache_key);
+                        invalidate_in_all_caches(cache_key);
                     }
                 }
 
@@ -294,19 +662,18 @@ where
                     mutation.id()
                 );
 
-                match mutation.mutate(input).await {
+                match run_mutation(&mutation, &input).await {
                     Ok(result) => {
                         debug!(
                             "✅ [MUTATION] Optimistic mutation succeeded: {}",
                             mutation.id()
                         );
 
+                        // Write the result straight into the targeted provider caches
+                        apply_cache_updates(mutation.updates(&input, &result));
+
                         // Invalidate specified cache entries (ensuring fresh data)
-                        for cache_key in mutation.invalidates() {
-                            debug!("🗑️ [MUTATION] Invalidating cache key: {}", cache_key);
-                            cache.invalidate(&cache_key);
-                            refresh_registry.trigger_refresh(&cache_key);
-                        }
+                        invalidate_after_success(&mutation, &input).await;
 
                         state.set(MutationState::Success(result));
                     }
@@ -322,8 +689,7 @@ where
                                 "🔄 [ROLLBACK] Re-invalidating optimistic cache key after failure: {}",
                                 cache_key
                             );
-                            cache.invalidate(cache_key);
-                            refresh_registry.trigger_refresh(cache_key);
+                            invalidate_in_all_caches(cache_key);
                         }
 
                         state.set(MutationState::Error(error));
@@ -336,6 +702,299 @@ where
     (state, mutate_fn)
 }
 
+/// Hook to create a mutation that optimistically writes values into specific provider entries
+///
+/// `optimistic` maps the mutation input to the provider cache entries to overwrite, each
+/// built with [`CacheUpdate::new`] from a provider, its parameter and the optimistic value.
+/// The entries are snapshotted and overwritten before the mutation runs, so the UI updates
+/// immediately. On success the optimistic values are kept and the mutation's own `updates`
+/// and `invalidates` are applied to reconcile them with the server's result; on failure every
+/// entry is restored from its snapshot.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::{mutation::CacheUpdate, prelude::*};
+///
+/// #[component]
+/// fn TodoItem(todo: Todo) -> Element {
+///     let (state, mutate) = use_optimistic_mutation_for(update_todo(), |todo: &Todo| {
+///         vec![CacheUpdate::new(fetch_todo(), todo.id, todo.clone())]
+///     });
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| mutate(Todo { done: !todo.done, ..todo.clone() }),
+///             "Toggle"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_optimistic_mutation_for<M, Input, F>(
+    mutation: M,
+    optimistic: F,
+) -> MutationHookResult<M, Input, impl Fn(Input) + Clone>
+where
+    M: Mutation<Input> + Send + Sync + 'static,
+    Input: Clone + PartialEq + Send + Sync + 'static,
+    F: Fn(&Input) -> Vec<CacheUpdate> + 'static,
+{
+    let state = use_signal(|| MutationState::Idle);
+    ensure_global_providers();
+    // Triggers from earlier renders still apply the closure of the latest render
+    let optimistic = use_latest(optimistic);
+
+    let mutate_fn = {
+        let mutation = mutation.clone();
+        let mut state = state;
+
+        move |input: Input| {
+            let mutation = mutation.clone();
+
+            let updates = (optimistic.borrow())(&input);
+            let optimistic_update = OptimisticUpdate::apply(updates);
+
+            spawn(async move {
+                state.set(MutationState::Loading);
+
+                debug!(
+                    "🔄 [MUTATION] Starting optimistic mutation: {}",
+                    mutation.id()
+                );
+
+                match run_mutation(&mutation, &input).await {
+                    Ok(result) => {
+                        debug!(
+                            "✅ [MUTATION] Optimistic mutation succeeded: {}",
+                            mutation.id()
+                        );
+
+                        // Reconcile the optimistic values with the mutation's result
+                        optimistic_update.reconcile(mutation.updates(&input, &result));
+                        invalidate_after_success(&mutation, &input).await;
+
+                        state.set(MutationState::Success(result));
+                    }
+                    Err(error) => {
+                        debug!(
+                            "❌ [MUTATION] Optimistic mutation failed: {}",
+                            mutation.id()
+                        );
+
+                        optimistic_update.rollback();
+
+                        state.set(MutationState::Error(error));
+                    }
+                }
+            });
+        }
+    };
+
+    (state, mutate_fn)
+}
+
+/// Invalidate a cache key in the default cache and every named cache
+///
+/// Mutations only know the cache keys they invalidate, not which cache the
+/// owning provider stores its entries in.
+fn invalidate_in_all_caches(cache_key: &str) {
+    for (cache, _) in all_caches() {
+        cache.invalidate(cache_key);
+    }
+}
+
+/// Invalidate the entries and tags a successful mutation declares
+///
+/// With [`Mutation::await_invalidations`], waits until the invalidated entries settled.
+async fn invalidate_after_success<M, Input>(mutation: &M, input: &Input)
+where
+    M: Mutation<Input>,
+    Input: Clone + PartialEq + 'static,
+{
+    let mut invalidated = mutation.invalidates_for(input);
+    for cache_key in &invalidated {
+        debug!("🗑️ [MUTATION] Invalidating cache key: {}", cache_key);
+        invalidate_in_all_caches(cache_key);
+    }
+    for tag in mutation.invalidates_tags() {
+        debug!("🗑️ [MUTATION] Invalidating tag: {}", tag);
+        for (cache, _) in all_caches() {
+            invalidated.extend(cache.invalidate_tag(tag));
+        }
+    }
+
+    if let Some(timeout) = mutation.await_invalidations() {
+        settle_invalidations(&mutation.id(), invalidated, timeout).await;
+    }
+}
+
+/// Wait until every shown invalidated entry was refetched, or `timeout` elapsed
+///
+/// An entry settles once the hooks showing it stored a new result, successful or not, and
+/// no fetch of it is running. Entries no hook shows, according to each cache's
+/// [`RefreshRegistry`](crate::refresh::RefreshRegistry), aren't refetched and don't hold the
+/// mutation. The entries are checked again whenever a cache changes or a fetch starts or
+/// finishes.
+async fn settle_invalidations(mutation_id: &str, mut keys: Vec<String>, timeout: Duration) {
+    let started = Instant::now();
+    let caches = all_caches();
+    // Subscribed before the first check, so no refetch can settle unnoticed
+    let mut activity = select_all(caches.iter().flat_map(|(cache, _)| {
+        [
+            cache.changes().map(drop).boxed(),
+            cache.fetch_activity().map(drop).boxed(),
+        ]
+    }));
+    loop {
+        keys.retain(|key| {
+            !caches.iter().all(|(cache, registry)| {
+                registry.mounted_count(key) == 0
+                    || (!cache.is_fetching(key) && cache.snapshot(key).is_some())
+            })
+        });
+        if keys.is_empty() {
+            debug!("🏁 [MUTATION] Invalidations settled: {}", mutation_id);
+            return;
+        }
+        let remaining = timeout.saturating_sub(started.elapsed());
+        let timed_out = remaining.is_zero()
+            || match select(activity.next(), pin!(sleep(remaining))).await {
+                Either::Left((activity, _)) => activity.is_none(),
+                Either::Right(_) => true,
+            };
+        if timed_out {
+            debug!(
+                "⏰ [MUTATION] Stopped waiting for {} invalidated entries: {}",
+                keys.len(),
+                mutation_id
+            );
+            return;
+        }
+    }
+}
+
+/// Apply cache updates returned by `Mutation::updates`
+fn apply_cache_updates(updates: Vec<CacheUpdate>) {
+    for update in updates {
+        debug!("✏️ [MUTATION] Updating cache key: {}", update.key());
+        update.apply();
+    }
+}
+
+/// The optimistic values a running mutation wrote into provider cache entries
+///
+/// Fetched results for those entries are not stored until the mutation settles, so a fetch
+/// that started earlier can't bring back the old value: the UI goes from the optimistic value
+/// straight to the mutation's result, or back to the snapshot if the mutation fails or is
+/// dropped before it settled.
+#[derive(Default)]
+struct OptimisticUpdate {
+    snapshots: Vec<CacheSnapshot>,
+}
+
+impl OptimisticUpdate {
+    /// Snapshot every targeted entry, then overwrite it with its optimistic value
+    fn apply(updates: Vec<CacheUpdate>) -> Self {
+        let snapshots = updates.iter().map(CacheUpdate::snapshot).collect();
+        debug!(
+            "⚡ [OPTIMISTIC] Optimistically updating {} cache entries",
+            updates.len()
+        );
+        apply_cache_updates(updates);
+        Self { snapshots }
+    }
+
+    /// Replace the optimistic values with the entries written from the mutation's result
+    ///
+    /// Entries the result doesn't write keep their optimistic value.
+    fn reconcile(mut self, confirmed: Vec<CacheUpdate>) {
+        debug!(
+            "🤝 [OPTIMISTIC] Reconciling {} optimistic cache entries",
+            self.snapshots.len()
+        );
+        apply_cache_updates(confirmed);
+        self.snapshots.clear();
+    }
+
+    /// Restore the entries from their snapshots after a failed mutation
+    fn rollback(mut self) {
+        self.restore();
+    }
+
+    /// Restore the entries from the snapshots not yet restored or reconciled
+    fn restore(&mut self) {
+        // Restore in reverse order so repeated keys end at their original value
+        for snapshot in std::mem::take(&mut self.snapshots).into_iter().rev() {
+            debug!(
+                "🔄 [ROLLBACK] Restoring optimistic cache key: {}",
+                snapshot.key
+            );
+            snapshot.restore();
+        }
+    }
+}
+
+impl Drop for OptimisticUpdate {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+/// Run a mutation, retrying failed attempts according to its retry policy
+async fn run_mutation<M, Input>(mutation: &M, input: &Input) -> Result<M::Output, M::Error>
+where
+    M: Mutation<Input>,
+    Input: Clone + PartialEq + 'static,
+{
+    let policy = mutation.retry_policy().unwrap_or(RetryPolicy::new(0));
+    let mut retries = 0;
+    loop {
+        match run_mutation_attempt(mutation, input.clone()).await {
+            Err(_) if retries < policy.max_retries => {
+                retries += 1;
+                debug!(
+                    "🔁 [MUTATION] Retrying mutation {} ({}/{})",
+                    mutation.id(),
+                    retries,
+                    policy.max_retries
+                );
+                if !policy.delay.is_zero() {
+                    sleep(policy.delay).await;
+                }
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Run a single attempt of a mutation, failing it if it exceeds the mutation's timeout
+async fn run_mutation_attempt<M, Input>(mutation: &M, input: Input) -> Result<M::Output, M::Error>
+where
+    M: Mutation<Input>,
+    Input: Clone + PartialEq + 'static,
+{
+    let attempt = mutation.mutate(input);
+    let Some((timeout, error)) = mutation
+        .timeout()
+        .and_then(|timeout| Some((timeout, mutation.timeout_error(timeout)?)))
+    else {
+        return attempt.await;
+    };
+
+    match select(pin!(attempt), pin!(sleep(timeout))).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => {
+            debug!(
+                "⏱️ [MUTATION] Mutation {} timed out after {:?}",
+                mutation.id(),
+                timeout
+            );
+            Err(error)
+        }
+    }
+}
+
 /// Helper function to create cache keys for providers with parameters
 pub fn provider_cache_key<P, Param>(provider: P, param: Param) -> String
 where
@@ -345,6 +1004,18 @@ where
     provider.id(&param)
 }
 
+/// Gets the cache key a `#[mutation(invalidates = [...])]` entry invalidates
+///
+/// Like [`provider_cache_key`], with an error explaining how to pass the provider's parameters
+/// when they don't match.
+#[doc(hidden)]
+pub fn invalidated_cache_key<P, Param>(provider: P, param: Param) -> String
+where
+    P: crate::types::InvalidatedProvider<Param>,
+{
+    provider.invalidated_key(&param)
+}
+
 /// Helper function to create cache keys for providers without parameters
 pub fn provider_cache_key_simple<P>(provider: P) -> String
 where
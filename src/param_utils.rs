@@ -1,13 +1,15 @@
 //! Parameter normalization utilities for dioxus-provider
 
-use std::fmt::Debug;
-use std::hash::Hash;
+use std::{fmt::Debug, hash::Hash};
+
+use crate::types::ProviderKey;
 
 /// Trait for normalizing different parameter formats to work with providers
 ///
 /// This trait allows the `use_provider` hook to accept parameters in different formats:
 /// - `()` for no parameters
 /// - `(param,)` for single parameter in tuple (e.g., `(42,)`)
+/// - `(a, b, ...)` with up to four elements for providers taking several parameters
 /// - Common primitive types directly (e.g., `42`, `"foo".to_string()`)
 ///
 /// # Usage and Ambiguity
@@ -29,10 +31,15 @@ use std::hash::Hash;
 /// let user = use_provider(fetch_user(), (42,));   // single-element tuple
 /// let user = use_provider(fetch_user(), "foo".to_string()); // String
 /// let user = use_provider(fetch_user(), ("foo".to_string(),)); // tuple with String
+///
+/// #[provider]
+/// async fn fetch_comment(post_id: u32, comment_id: u32) -> Result<Comment, String> { ... }
+///
+/// let comment = use_provider(fetch_comment(), (1, 5)); // one element per parameter
 /// ```
 pub trait IntoProviderParam {
     /// The target parameter type after conversion
-    type Param: Clone + PartialEq + Hash + Debug + Send + Sync + 'static;
+    type Param: Clone + PartialEq + ProviderKey + Debug + Send + Sync + 'static;
 
     /// Convert the input into the parameter format expected by the provider
     fn into_param(self) -> Self::Param;
@@ -50,7 +57,7 @@ impl IntoProviderParam for () {
 // Implementation for tuple parameters: (Param,) -> Param
 impl<T> IntoProviderParam for (T,)
 where
-    T: Clone + PartialEq + Hash + Debug + Send + Sync + 'static,
+    T: Clone + PartialEq + ProviderKey + Debug + Send + Sync + 'static,
 {
     type Param = T;
 
@@ -59,6 +66,26 @@ where
     }
 }
 
+// Implementations for multi-parameter providers: (A, B, ...) -> (A, B, ...)
+macro_rules! impl_tuple_param {
+    ($($name:ident),+) => {
+        impl<$($name),+> IntoProviderParam for ($($name,)+)
+        where
+            $($name: Clone + PartialEq + Hash + Debug + Send + Sync + 'static,)+
+        {
+            type Param = ($($name,)+);
+
+            fn into_param(self) -> Self::Param {
+                self
+            }
+        }
+    };
+}
+
+impl_tuple_param!(A, B);
+impl_tuple_param!(A, B, C);
+impl_tuple_param!(A, B, C, D);
+
 // Common direct parameter implementations to avoid conflicts
 impl IntoProviderParam for u32 {
     type Param = u32;
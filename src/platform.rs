@@ -40,6 +40,39 @@ pub mod time {
         wasm_sleep(duration).await;
     }
 
+    /// How long a frame lasts where no frame clock is available, one frame at 60 fps
+    pub const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+    /// Wait until the next frame
+    ///
+    /// In the browser this resolves from `requestAnimationFrame`, so work waiting for it runs
+    /// right before the page is painted. Desktop and server have no frame clock to hook into,
+    /// so they sleep for [`FRAME_DURATION`].
+    pub async fn next_frame() {
+        #[cfg(target_family = "wasm")]
+        {
+            use wasm_bindgen::{JsCast, closure::Closure};
+
+            let (sender, receiver) = futures::channel::oneshot::channel();
+            // The callback frees itself once called, even if nobody waits for it anymore
+            let on_frame = Closure::once_into_js(move || {
+                let _ = sender.send(());
+            });
+            let requested = web_sys::window().is_some_and(|window| {
+                window
+                    .request_animation_frame(on_frame.unchecked_ref())
+                    .is_ok()
+            });
+            if requested {
+                let _ = receiver.await;
+            } else {
+                wasm_sleep(FRAME_DURATION).await;
+            }
+        }
+        #[cfg(not(target_family = "wasm"))]
+        tokio_sleep(FRAME_DURATION).await;
+    }
+
     /// Format timestamp as relative time (e.g., "5s ago", "2m ago")
     pub fn format_relative_time(timestamp: u64) -> String {
         let now = now_secs();
@@ -58,13 +91,93 @@ pub mod time {
 /// Cross-platform task management
 pub mod task {
     use super::*;
+    use crate::cancel::CancellationToken;
+
+    /// Handle to a background task started with [`spawn_task`]
+    ///
+    /// Unlike a dioxus `Task`, the handle is `Send + Sync`, so it can be kept in the global
+    /// registries and used to cancel the task from anywhere.
+    #[derive(Clone, Debug, Default)]
+    pub struct TaskHandle {
+        cancel: CancellationToken,
+    }
+
+    impl TaskHandle {
+        /// Cancel the task, dropping its future at the next await point
+        pub fn cancel(&self) {
+            self.cancel.cancel();
+        }
+
+        /// Check if the task has been cancelled
+        pub fn is_cancelled(&self) -> bool {
+            self.cancel.is_cancelled()
+        }
+    }
+
+    /// Spawn a cancellable background task that works on both web and desktop
+    ///
+    /// Every background operation (interval refreshes, periodic checks, revalidations and
+    /// debounced work) goes through this function. Both target families run the task on the
+    /// dioxus executor, which is a local executor on WASM, because provider tasks write to
+    /// signals that are not `Send`. The task also stops when the scope that spawned it is
+    /// dropped; called outside any scope, it is owned by the root scope.
+    ///
+    /// Outside a Dioxus runtime, e.g. in a background job or a test without a `VirtualDom`,
+    /// the task runs on a local executor instead: `spawn_local` on WASM, and a `LocalSet`
+    /// driven by the current tokio runtime on desktop and server. That requires a
+    /// current-thread runtime, such as the one of `#[tokio::test]`; without one the task is
+    /// not run and the returned handle is already cancelled, instead of panicking.
+    pub fn spawn_task<F>(future: F) -> TaskHandle
+    where
+        F: std::future::Future<Output = ()> + 'static,
+    {
+        let handle = TaskHandle::default();
+        let cancelled = handle.cancel.cancelled();
+        let spawned = spawn_in_runtime(async move {
+            let future = std::pin::pin!(future);
+            futures::future::select(future, cancelled).await;
+        });
+        if !spawned {
+            handle.cancel();
+        }
+        handle
+    }
+
+    /// Spawn a cancellable background task owned by the root scope
+    ///
+    /// Like [`spawn_task`], but the task keeps running when the component that started it
+    /// unmounts, for tasks shared by several components such as the interval timers.
+    pub(crate) fn spawn_root_task<F>(future: F) -> TaskHandle
+    where
+        F: std::future::Future<Output = ()> + 'static,
+    {
+        let handle = TaskHandle::default();
+        let cancelled = handle.cancel.cancelled();
+        let task = async move {
+            let future = std::pin::pin!(future);
+            futures::future::select(future, cancelled).await;
+        };
+        let spawned = match dioxus::core::Runtime::current() {
+            Ok(runtime) => {
+                runtime.spawn(dioxus::core::ScopeId::ROOT, task);
+                true
+            }
+            Err(_) => local::spawn(task),
+        };
+        if !spawned {
+            handle.cancel();
+        }
+        handle
+    }
 
     /// Spawn an async task that works on both web and desktop
+    ///
+    /// Like [`spawn_task`], the task falls back to a local executor outside a Dioxus runtime.
     pub fn spawn<F>(future: F)
     where
         F: std::future::Future<Output = ()> + 'static,
     {
-        dioxus_spawn(future);
+        spawn_in_runtime(future);
     }
 
     /// Spawn a task with a name for debugging
@@ -72,11 +185,454 @@ pub mod task {
     where
         F: std::future::Future<Output = ()> + 'static,
     {
-        dioxus_spawn(async move {
+        let spawned = spawn_in_runtime(async move {
             tracing::debug!("Starting task: {}", name);
             future.await;
             tracing::debug!("Completed task: {}", name);
         });
+        if !spawned {
+            tracing::debug!("Skipped task without an executor: {}", name);
+        }
+    }
+
+    /// Run synchronous, CPU-bound work without blocking the async executor
+    ///
+    /// On desktop and server the work runs on tokio's blocking thread pool, so the UI and
+    /// other tasks keep running meanwhile; a panic in `work` is resumed in the caller. WASM
+    /// has no threads to offload to, so there (and outside a tokio runtime) the work runs on
+    /// the current thread and a warning is logged. Used by `#[provider(blocking)]`.
+    pub async fn run_blocking<T, F>(work: F) -> T
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        #[cfg(not(target_family = "wasm"))]
+        if tokio::runtime::Handle::try_current().is_ok() {
+            match tokio::task::spawn_blocking(work).await {
+                Ok(value) => return value,
+                Err(error) => match error.try_into_panic() {
+                    Ok(panic) => std::panic::resume_unwind(panic),
+                    // The runtime is shutting down, so nobody waits for the result anymore
+                    Err(_) => return std::future::pending().await,
+                },
+            }
+        }
+
+        static WARNED: std::sync::Once = std::sync::Once::new();
+        WARNED.call_once(|| {
+            tracing::warn!(
+                "⚠️ [TASK] No blocking thread pool available - running blocking work on the current thread"
+            );
+        });
+        work()
+    }
+
+    /// Spawn a task on the dioxus executor, or on the local executor without a Dioxus runtime
+    ///
+    /// Returns false if the task couldn't be spawned at all.
+    fn spawn_in_runtime<F>(future: F) -> bool
+    where
+        F: std::future::Future<Output = ()> + 'static,
+    {
+        let Ok(runtime) = dioxus::core::Runtime::current() else {
+            return local::spawn(future);
+        };
+        if dioxus::core::current_scope_id().is_ok() {
+            dioxus_spawn(future);
+        } else {
+            runtime.spawn(dioxus::core::ScopeId::ROOT, future);
+        }
+        true
+    }
+
+    /// Executor for tasks spawned outside a Dioxus runtime
+    mod local {
+        /// Spawn a task with `spawn_local` from `wasm-bindgen-futures`
+        #[cfg(target_family = "wasm")]
+        pub(super) fn spawn<F>(future: F) -> bool
+        where
+            F: std::future::Future<Output = ()> + 'static,
+        {
+            wasm_bindgen_futures::spawn_local(future);
+            true
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        thread_local! {
+            /// Tasks spawned on this thread outside a Dioxus runtime
+            static TASKS: std::cell::RefCell<tokio::task::LocalSet> = Default::default();
+            /// Whether a tokio task is driving `TASKS`
+            static DRIVEN: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+        }
+
+        /// Spawn a task on this thread's `LocalSet`, driven by the current tokio runtime
+        ///
+        /// Provider tasks aren't `Send`, so the runtime must poll the set on this thread,
+        /// which only a current-thread runtime guarantees.
+        #[cfg(not(target_family = "wasm"))]
+        pub(super) fn spawn<F>(future: F) -> bool
+        where
+            F: std::future::Future<Output = ()> + 'static,
+        {
+            use tokio::runtime::{Handle, RuntimeFlavor};
+
+            let Ok(runtime) = Handle::try_current() else {
+                tracing::warn!(
+                    "⚠️ [TASK] No Dioxus or tokio runtime - skipping background task (use fetch_provider outside components)"
+                );
+                return false;
+            };
+            if runtime.runtime_flavor() != RuntimeFlavor::CurrentThread {
+                tracing::warn!(
+                    "⚠️ [TASK] No Dioxus runtime and the tokio runtime is multi-threaded - skipping background task"
+                );
+                return false;
+            }
+
+            TASKS.with(|tasks| match tasks.try_borrow() {
+                Ok(tasks) => {
+                    tasks.spawn_local(future);
+                }
+                // Spawned by a task while the set is polled, so the set is the current one
+                Err(_) => {
+                    tokio::task::spawn_local(future);
+                }
+            });
+            if !DRIVEN.replace(true) {
+                runtime.spawn(std::future::poll_fn(|cx| {
+                    TASKS.with(|tasks| {
+                        let poll = std::pin::Pin::new(&mut *tasks.borrow_mut()).poll(cx);
+                        if poll.is_ready() {
+                            DRIVEN.set(false);
+                        }
+                        poll
+                    })
+                }));
+            }
+            true
+        }
+    }
+}
+
+/// Cross-platform document visibility tracking
+pub mod visibility {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Whether the application is currently visible to the user
+    static DOCUMENT_VISIBLE: AtomicBool = AtomicBool::new(true);
+
+    /// Check whether the application is visible to the user
+    ///
+    /// On web this follows the document's `visibilitychange` events. Other platforms are
+    /// considered visible until the host reports otherwise through [`set_document_visible`].
+    pub fn is_document_visible() -> bool {
+        #[cfg(target_family = "wasm")]
+        track_document_visibility();
+        DOCUMENT_VISIBLE.load(Ordering::SeqCst)
+    }
+
+    /// Report whether the application is visible to the user
+    ///
+    /// Desktop hosts can call this when their window is minimized or restored. On web the
+    /// flag is kept in sync with the document automatically.
+    pub fn set_document_visible(visible: bool) {
+        DOCUMENT_VISIBLE.store(visible, Ordering::SeqCst);
+    }
+
+    /// Install a `visibilitychange` listener feeding the visibility flag, once
+    #[cfg(target_family = "wasm")]
+    fn track_document_visibility() {
+        use std::sync::Once;
+        use wasm_bindgen::{JsCast, closure::Closure};
+
+        static LISTENER: Once = Once::new();
+        LISTENER.call_once(|| {
+            let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+                return;
+            };
+            set_document_visible(!document.hidden());
+            let tracked_document = document.clone();
+            let on_visibility_change = Closure::<dyn FnMut()>::new(move || {
+                set_document_visible(!tracked_document.hidden());
+            });
+            let _ = document.add_event_listener_with_callback(
+                "visibilitychange",
+                on_visibility_change.as_ref().unchecked_ref(),
+            );
+            // The listener lives for the rest of the page
+            on_visibility_change.forget();
+        });
+    }
+}
+
+/// Render target detection for fullstack apps
+pub mod render {
+    use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+    /// Where the application is being rendered
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum RenderTarget {
+        /// In the browser or a desktop window, including after hydration
+        #[default]
+        Client,
+        /// On the server, producing HTML for server-side rendering
+        Server,
+    }
+
+    /// The target reported by the host: 0 if none, 1 for the client and 2 for the server
+    static REPORTED_TARGET: AtomicU8 = AtomicU8::new(0);
+
+    /// Whether providers are kept from fetching during server-side rendering
+    static SSR_PREFETCH_DISABLED: AtomicBool = AtomicBool::new(false);
+
+    /// Get the target the application is rendered for
+    ///
+    /// The target reported through [`set_render_target`] wins. Otherwise the server build
+    /// of a Dioxus fullstack app, built with the `server` feature, renders for the server and
+    /// everything else is rendered for the client.
+    pub fn render_target() -> RenderTarget {
+        match REPORTED_TARGET.load(Ordering::SeqCst) {
+            1 => RenderTarget::Client,
+            2 => RenderTarget::Server,
+            _ if is_server_build() => RenderTarget::Server,
+            _ => RenderTarget::Client,
+        }
+    }
+
+    /// Report the target the application is rendered for
+    ///
+    /// Servers rendering without the `server` feature, e.g. with `dioxus-ssr` directly, call
+    /// this with `RenderTarget::Server` at startup, so providers declared with
+    /// `#[provider(ssr = false)]` don't fetch during server-side rendering and are left to
+    /// the client.
+    pub fn set_render_target(target: RenderTarget) {
+        let reported = match target {
+            RenderTarget::Client => 1,
+            RenderTarget::Server => 2,
+        };
+        REPORTED_TARGET.store(reported, Ordering::SeqCst);
+    }
+
+    /// Whether providers fetch during server-side rendering (true by default)
+    pub fn ssr_prefetch() -> bool {
+        !SSR_PREFETCH_DISABLED.load(Ordering::SeqCst)
+    }
+
+    /// Set whether providers fetch during server-side rendering
+    ///
+    /// When enabled, providers that allow it fetch on the server and render their data into
+    /// the page. When disabled, every provider stays `Loading` on the server and is fetched
+    /// by the client.
+    pub fn set_ssr_prefetch(enabled: bool) {
+        SSR_PREFETCH_DISABLED.store(!enabled, Ordering::SeqCst);
+    }
+
+    /// Whether the client is hydrating HTML rendered by the server
+    ///
+    /// Only web clients of Dioxus fullstack apps built with the `fullstack` feature hydrate;
+    /// elsewhere this is always false.
+    pub fn is_hydrating() -> bool {
+        #[cfg(all(feature = "fullstack", target_arch = "wasm32"))]
+        {
+            dioxus_fullstack_protocol::is_hydrating()
+        }
+        #[cfg(not(all(feature = "fullstack", target_arch = "wasm32")))]
+        {
+            false
+        }
+    }
+
+    /// Whether this is the server build of a Dioxus fullstack app
+    fn is_server_build() -> bool {
+        cfg!(all(feature = "server", not(target_arch = "wasm32")))
+    }
+}
+
+/// Cross-platform memory pressure handling
+pub mod memory {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Set once a memory pressure handler has been installed
+    static HANDLER_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+    /// Opt in to shedding cached entries when the platform signals memory pressure
+    ///
+    /// On web, a `visibilitychange` listener runs a soft sweep (each cache's regular
+    /// [`maintain`](crate::cache::ProviderCache::maintain)) whenever the page is hidden.
+    /// On Linux, Android, macOS and Windows a background thread samples the system's
+    /// memory pressure every [`PRESSURE_POLL_INTERVAL`] and calls
+    /// [`handle_memory_pressure`](crate::global::handle_memory_pressure) each time the
+    /// system enters a low-memory state. Elsewhere nothing is installed; call
+    /// `handle_memory_pressure` from the host's own notification instead.
+    ///
+    /// Calling this more than once has no further effect. Returns whether an automatic
+    /// trigger is installed.
+    pub fn register_memory_pressure_handler() -> bool {
+        if HANDLER_REGISTERED.load(Ordering::SeqCst) {
+            return true;
+        }
+        let registered = install_handler();
+        HANDLER_REGISTERED.store(registered, Ordering::SeqCst);
+        registered
+    }
+
+    #[cfg(target_family = "wasm")]
+    fn install_handler() -> bool {
+        use wasm_bindgen::{JsCast, closure::Closure};
+
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return false;
+        };
+        let hidden_document = document.clone();
+        let on_visibility_change = Closure::<dyn FnMut()>::new(move || {
+            if hidden_document.hidden() {
+                for (cache, _) in crate::global::all_caches() {
+                    cache.maintain();
+                }
+            }
+        });
+        let registered = document
+            .add_event_listener_with_callback(
+                "visibilitychange",
+                on_visibility_change.as_ref().unchecked_ref(),
+            )
+            .is_ok();
+        // The listener lives for the rest of the page
+        on_visibility_change.forget();
+        registered
+    }
+
+    /// How often the desktop watcher samples the system's memory pressure
+    pub const PRESSURE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+    #[cfg(not(target_family = "wasm"))]
+    fn install_handler() -> bool {
+        if probe::under_pressure().is_none() {
+            tracing::debug!(
+                "🧹 [MEMORY] No memory pressure signal on this platform - call handle_memory_pressure() from the host"
+            );
+            return false;
+        }
+        std::thread::Builder::new()
+            .name("dioxus-provider-memory".to_string())
+            .spawn(watch_pressure)
+            .is_ok()
+    }
+
+    /// Sheds unused entries whenever the system goes from normal to low memory
+    #[cfg(not(target_family = "wasm"))]
+    fn watch_pressure() {
+        let mut was_under_pressure = false;
+        loop {
+            let under_pressure = probe::under_pressure().unwrap_or(false);
+            if under_pressure && !was_under_pressure {
+                tracing::debug!("🧹 [MEMORY] System memory pressure detected");
+                crate::global::handle_memory_pressure();
+            }
+            was_under_pressure = under_pressure;
+            std::thread::sleep(PRESSURE_POLL_INTERVAL);
+        }
+    }
+
+    /// Share of physical memory that must stay available before the system counts as
+    /// under pressure, where no kernel pressure signal exists
+    #[cfg(any(target_os = "linux", target_os = "android", windows))]
+    const MIN_AVAILABLE_RATIO: f64 = 0.1;
+
+    /// Share of the last 10 seconds some task spent stalled on memory, in percent, from
+    /// which Linux counts as under pressure
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const STALL_PERCENT_THRESHOLD: f64 = 10.0;
+
+    /// Reads the `some avg10` stall percentage from `/proc/pressure/memory`
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(crate) fn parse_pressure_stall(contents: &str) -> Option<f64> {
+        contents
+            .lines()
+            .find_map(|line| line.strip_prefix("some "))?
+            .split_whitespace()
+            .find_map(|field| field.strip_prefix("avg10="))?
+            .parse()
+            .ok()
+    }
+
+    /// Reads the available share of physical memory from `/proc/meminfo`
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(crate) fn parse_available_ratio(contents: &str) -> Option<f64> {
+        let field = |name: &str| -> Option<f64> {
+            contents
+                .lines()
+                .find_map(|line| line.strip_prefix(name))?
+                .split_whitespace()
+                .next()?
+                .parse()
+                .ok()
+        };
+        let total = field("MemTotal:")?;
+        let available = field("MemAvailable:")?;
+        (total > 0.0).then(|| available / total)
+    }
+
+    /// Platform probes, each returning `None` when the signal cannot be read
+    #[cfg(not(target_family = "wasm"))]
+    mod probe {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        pub(super) fn under_pressure() -> Option<bool> {
+            // Pressure stall information needs Linux 4.20; older kernels fall back to
+            // the share of available memory
+            if let Some(stall) = std::fs::read_to_string("/proc/pressure/memory")
+                .ok()
+                .as_deref()
+                .and_then(super::parse_pressure_stall)
+            {
+                return Some(stall >= super::STALL_PERCENT_THRESHOLD);
+            }
+            let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+            super::parse_available_ratio(&meminfo).map(|ratio| ratio < super::MIN_AVAILABLE_RATIO)
+        }
+
+        #[cfg(target_os = "macos")]
+        pub(super) fn under_pressure() -> Option<bool> {
+            // 1 = normal, 2 = warning, 4 = critical
+            let mut level: libc::c_int = 0;
+            let mut size = std::mem::size_of::<libc::c_int>();
+            // SAFETY: the name is NUL-terminated and `level`/`size` describe a valid
+            // `c_int` output buffer
+            let status = unsafe {
+                libc::sysctlbyname(
+                    c"kern.memorystatus_vm_pressure_level".as_ptr(),
+                    (&mut level as *mut libc::c_int).cast(),
+                    &mut size,
+                    std::ptr::null_mut(),
+                    0,
+                )
+            };
+            (status == 0).then_some(level >= 2)
+        }
+
+        #[cfg(windows)]
+        pub(super) fn under_pressure() -> Option<bool> {
+            use windows_sys::Win32::System::SystemInformation::{
+                GlobalMemoryStatusEx, MEMORYSTATUSEX,
+            };
+
+            // SAFETY: MEMORYSTATUSEX is plain data, valid when zeroed
+            let mut status: MEMORYSTATUSEX = unsafe { std::mem::zeroed() };
+            status.dwLength = std::mem::size_of::<MEMORYSTATUSEX>() as u32;
+            // SAFETY: `status` is a valid MEMORYSTATUSEX with its length set
+            let ok = unsafe { GlobalMemoryStatusEx(&mut status) } != 0;
+            ok.then(|| (100 - status.dwMemoryLoad) as f64 / 100.0 < super::MIN_AVAILABLE_RATIO)
+        }
+
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            windows
+        )))]
+        pub(super) fn under_pressure() -> Option<bool> {
+            None
+        }
     }
 }
 
@@ -92,8 +648,71 @@ pub mod config {
 
     /// Default unused entry threshold
     pub const DEFAULT_UNUSED_THRESHOLD: Duration = Duration::from_secs(300);
+
+    /// Default estimated size of a cache entry without a size estimate, in bytes
+    pub const DEFAULT_ENTRY_SIZE: usize = 1024;
 }
 
 pub use config::*;
 /// Re-export commonly used platform functions
-pub use time::{format_relative_time, now_secs, sleep};
+pub use memory::register_memory_pressure_handler;
+pub use render::{
+    RenderTarget, is_hydrating, render_target, set_render_target, set_ssr_prefetch, ssr_prefetch,
+};
+pub use task::{TaskHandle, spawn_task};
+pub use time::{format_relative_time, next_frame, now_secs, sleep};
+pub use visibility::{is_document_visible, set_document_visible};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus::dioxus_core::{ScopeId, VirtualDom};
+    use dioxus::prelude::*;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    };
+
+    async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+        let _ = tokio::time::timeout(duration, dom.wait_for_work()).await;
+    }
+
+    #[tokio::test]
+    async fn test_spawned_task_runs_until_cancelled() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+        let ticks = Arc::new(AtomicU32::new(0));
+
+        let handle = dom.runtime().on_scope(ScopeId::ROOT, || {
+            let ticks = ticks.clone();
+            spawn_task(async move {
+                loop {
+                    ticks.fetch_add(1, Ordering::SeqCst);
+                    sleep(Duration::from_millis(10)).await;
+                }
+            })
+        });
+        run_for(&mut dom, Duration::from_millis(55)).await;
+        assert!(ticks.load(Ordering::SeqCst) >= 2);
+
+        handle.cancel();
+        run_for(&mut dom, Duration::from_millis(20)).await;
+        let ticks_at_cancel = ticks.load(Ordering::SeqCst);
+        run_for(&mut dom, Duration::from_millis(50)).await;
+        assert!(handle.is_cancelled());
+        assert_eq!(ticks.load(Ordering::SeqCst), ticks_at_cancel);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn test_memory_pressure_parsing() {
+        let pressure = "some avg10=12.50 avg60=3.00 avg300=0.06 total=230996020\n\
+                        full avg10=1.00 avg60=0.00 avg300=0.00 total=147560511\n";
+        assert_eq!(memory::parse_pressure_stall(pressure), Some(12.5));
+        assert_eq!(memory::parse_pressure_stall(""), None);
+
+        let meminfo = "MemTotal:        1000 kB\nMemFree:  20 kB\nMemAvailable:     50 kB\n";
+        assert_eq!(memory::parse_available_ratio(meminfo), Some(0.05));
+        assert_eq!(memory::parse_available_ratio("MemTotal: 1000 kB\n"), None);
+    }
+}
@@ -0,0 +1,127 @@
+//! # Mutation Progress
+//!
+//! This module lets a long-running mutation, such as an upload or a multi-step server
+//! operation, report how far it got. A mutation declared with `#[mutation(with_progress)]`
+//! takes a trailing [`ProgressReporter`] parameter; every fraction or step it reports is
+//! shown by [`use_mutation_with_progress`](crate::mutation::use_mutation_with_progress)
+//! as a [`MutationProgress`] while the mutation keeps running.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus_provider::{prelude::*, progress::ProgressReporter};
+//!
+//! # async fn upload_chunk(chunk: &[u8]) -> Result<(), String> { todo!() }
+//! #[mutation(with_progress)]
+//! async fn upload(data: Vec<u8>, progress: ProgressReporter) -> Result<(), String> {
+//!     progress.step("Uploading");
+//!     let chunks = data.chunks(1024).collect::<Vec<_>>();
+//!     for (index, chunk) in chunks.iter().enumerate() {
+//!         upload_chunk(chunk).await?;
+//!         progress.set((index + 1) as f64 / chunks.len() as f64);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Reporting is a no-op when the mutation runs without a hook showing its progress, e.g.
+//! through `use_mutation` or a direct call to
+//! [`Mutation::mutate`](crate::mutation::Mutation::mutate).
+
+use std::future::Future;
+
+use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender, unbounded};
+
+tokio::task_local! {
+    static REPORTER: UnboundedSender<ProgressUpdate>;
+}
+
+/// The progress a running mutation has reported
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MutationProgress {
+    /// The completed fraction, from 0.0 to 1.0 (None until the mutation reports one)
+    pub fraction: Option<f64>,
+    /// The step the mutation is in (None until the mutation reports one)
+    pub step: Option<String>,
+}
+
+impl MutationProgress {
+    /// Returns the completed fraction as a percentage, or 0 before one is reported
+    pub fn percent(&self) -> f64 {
+        self.fraction.unwrap_or(0.0) * 100.0
+    }
+
+    /// Applies a reported update, keeping the part it doesn't change
+    pub(crate) fn apply(&mut self, update: ProgressUpdate) {
+        match update {
+            ProgressUpdate::Fraction(fraction) => self.fraction = Some(fraction),
+            ProgressUpdate::Step(step) => self.step = Some(step),
+        }
+    }
+}
+
+/// A single report sent by a [`ProgressReporter`]
+#[derive(Debug)]
+pub(crate) enum ProgressUpdate {
+    Fraction(f64),
+    Step(String),
+}
+
+/// Sends the progress of a mutation to the hook running it
+///
+/// Passed as the trailing parameter of a `#[mutation(with_progress)]` function.
+#[derive(Clone)]
+pub struct ProgressReporter {
+    sender: Option<UnboundedSender<ProgressUpdate>>,
+}
+
+impl ProgressReporter {
+    /// Creates a reporter that discards every report
+    pub fn disconnected() -> Self {
+        Self { sender: None }
+    }
+
+    /// Gets the reporter of the mutation running this future
+    ///
+    /// Used by `#[mutation(with_progress)]`.
+    #[doc(hidden)]
+    pub fn claim() -> Self {
+        Self {
+            sender: REPORTER.try_with(Clone::clone).ok(),
+        }
+    }
+
+    /// Reports the completed fraction, clamped to 0.0..=1.0
+    pub fn set(&self, fraction: f64) {
+        self.send(ProgressUpdate::Fraction(fraction.clamp(0.0, 1.0)));
+    }
+
+    /// Reports the step the mutation is in, e.g. "Uploading"
+    pub fn step(&self, step: impl Into<String>) {
+        self.send(ProgressUpdate::Step(step.into()));
+    }
+
+    /// Checks whether reports are shown by a hook
+    pub fn is_connected(&self) -> bool {
+        self.sender
+            .as_ref()
+            .is_some_and(|sender| !sender.is_closed())
+    }
+
+    fn send(&self, update: ProgressUpdate) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.unbounded_send(update);
+        }
+    }
+}
+
+/// Runs a future whose mutation sends its progress to the returned receiver
+pub(crate) fn with_progress<F: Future>(
+    future: F,
+) -> (
+    impl Future<Output = F::Output>,
+    UnboundedReceiver<ProgressUpdate>,
+) {
+    let (sender, receiver) = unbounded();
+    (REPORTER.scope(sender, future), receiver)
+}
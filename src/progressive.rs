@@ -0,0 +1,98 @@
+//! # Progressive Providers
+//!
+//! This module lets a single fetch show partial data before it completes, e.g. a cached
+//! summary followed by the full record. A provider declared with `#[provider(progressive)]`
+//! takes a trailing [`Emitter`] parameter; every value passed to [`Emitter::emit`] is stored
+//! in the cache and shown as `ProviderState::Success` while the fetch keeps running, and the
+//! value the provider returns finalizes the entry.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus_provider::{prelude::*, progressive::Emitter};
+//!
+//! # async fn load_preview(id: u32) -> String { todo!() }
+//! # async fn load_article(id: u32) -> Result<String, String> { todo!() }
+//! #[provider(progressive)]
+//! async fn fetch_article(id: u32, emit: Emitter<String>) -> Result<String, String> {
+//!     emit.emit(load_preview(id).await);
+//!     load_article(id).await
+//! }
+//! ```
+//!
+//! Unlike a stream, the provider still resolves to one final value. Partial values are only
+//! shown by `use_provider`; background revalidations and direct calls to
+//! [`Provider::run`](crate::hooks::Provider::run) ignore them.
+
+use std::{any::Any, cell::RefCell, future::Future};
+
+use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender, unbounded};
+
+tokio::task_local! {
+    static EMITTER: RefCell<Option<Box<dyn Any + Send>>>;
+}
+
+/// Sends the partial values of a progressive provider to the hook fetching it
+///
+/// Passed as the trailing parameter of a `#[provider(progressive)]` function. Emitting is a
+/// no-op when nothing listens for partial values, e.g. when the provider runs directly or
+/// is composed into another provider.
+pub struct Emitter<T> {
+    sender: Option<UnboundedSender<T>>,
+}
+
+impl<T: Send + 'static> Emitter<T> {
+    /// Creates an emitter that discards every value
+    pub fn disconnected() -> Self {
+        Self { sender: None }
+    }
+
+    /// Takes the emitter of the fetch running this future
+    ///
+    /// Used by `#[provider(progressive)]`. Only the first claim in a fetch gets a connected
+    /// emitter, so providers composed into a progressive provider can't emit in its place.
+    #[doc(hidden)]
+    pub fn claim() -> Self {
+        let sender = EMITTER
+            .try_with(|slot| {
+                let mut slot = slot.borrow_mut();
+                match slot.take()?.downcast::<UnboundedSender<T>>() {
+                    Ok(sender) => Some(*sender),
+                    Err(other) => {
+                        *slot = Some(other);
+                        None
+                    }
+                }
+            })
+            .ok()
+            .flatten();
+        Self { sender }
+    }
+
+    /// Shows `value` as the provider's data until the next emission or the final result
+    pub fn emit(&self, value: T) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.unbounded_send(value);
+        }
+    }
+
+    /// Checks whether emitted values are shown by a hook
+    pub fn is_connected(&self) -> bool {
+        self.sender
+            .as_ref()
+            .is_some_and(|sender| !sender.is_closed())
+    }
+}
+
+/// Runs a future whose progressive provider sends its partial values to the returned receiver
+pub(crate) fn with_emitter<T, F>(
+    future: F,
+) -> (impl Future<Output = F::Output>, UnboundedReceiver<T>)
+where
+    T: Send + 'static,
+    F: Future,
+{
+    let (sender, receiver) = unbounded::<T>();
+    let slot: Box<dyn Any + Send> = Box::new(sender);
+    (EMITTER.scope(RefCell::new(Some(slot)), future), receiver)
+}
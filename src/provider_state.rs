@@ -1,10 +1,13 @@
 //! ProviderState: Async state enum for dioxus-provider
 
 use dioxus::core::Task;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 /// Represents the state of an async operation
 #[derive(Clone, PartialEq, Debug)]
 pub enum ProviderState<T, E> {
+    /// The operation has not started, e.g. a lazy provider that hasn't been asked to fetch
+    Idle,
     /// The operation is currently loading
     Loading { task: Task },
     /// The operation completed successfully with data
@@ -14,6 +17,11 @@ pub enum ProviderState<T, E> {
 }
 
 impl<T, E> ProviderState<T, E> {
+    /// Returns true if the operation has not started
+    pub fn is_idle(&self) -> bool {
+        matches!(self, ProviderState::Idle)
+    }
+
     /// Returns true if the state is currently loading
     pub fn is_loading(&self) -> bool {
         matches!(self, ProviderState::Loading { task: _ })
@@ -29,6 +37,14 @@ impl<T, E> ProviderState<T, E> {
         matches!(self, ProviderState::Error(_))
     }
 
+    /// Returns true if the state contains successful data for which `is_empty` is true
+    ///
+    /// Unlike checking `data()`, this is false while loading, so "no items yet" and
+    /// "loaded, but no items" can be told apart.
+    pub fn is_empty_success_by(&self, is_empty: impl FnOnce(&T) -> bool) -> bool {
+        self.data().is_some_and(is_empty)
+    }
+
     /// Returns the data if successful, None otherwise
     pub fn data(&self) -> Option<&T> {
         match self {
@@ -54,6 +70,7 @@ impl<T, E> ProviderState<T, E> {
             ProviderState::Success(data) => ProviderState::Success(op(data)),
             ProviderState::Error(e) => ProviderState::Error(e),
             ProviderState::Loading { task } => ProviderState::Loading { task },
+            ProviderState::Idle => ProviderState::Idle,
         }
     }
 
@@ -66,6 +83,7 @@ impl<T, E> ProviderState<T, E> {
             ProviderState::Success(data) => ProviderState::Success(data),
             ProviderState::Error(e) => ProviderState::Error(op(e)),
             ProviderState::Loading { task } => ProviderState::Loading { task },
+            ProviderState::Idle => ProviderState::Idle,
         }
     }
 
@@ -78,6 +96,71 @@ impl<T, E> ProviderState<T, E> {
             ProviderState::Success(data) => op(data),
             ProviderState::Error(e) => ProviderState::Error(e),
             ProviderState::Loading { task } => ProviderState::Loading { task },
+            ProviderState::Idle => ProviderState::Idle,
         }
     }
 }
+
+impl<T: IsEmpty, E> ProviderState<T, E> {
+    /// Returns true if the state contains successful data that is empty, e.g. an empty `Vec`
+    ///
+    /// False while idle, loading or failed. Use [`ProviderState::is_empty_success_by`] for
+    /// data that doesn't implement [`IsEmpty`].
+    pub fn is_empty_success(&self) -> bool {
+        self.is_empty_success_by(IsEmpty::is_empty)
+    }
+}
+
+/// Collections that can be empty, used by [`ProviderState::is_empty_success`]
+pub trait IsEmpty {
+    /// Returns true if there are no items
+    fn is_empty(&self) -> bool;
+}
+
+impl<T> IsEmpty for Vec<T> {
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for VecDeque<T> {
+    fn is_empty(&self) -> bool {
+        VecDeque::is_empty(self)
+    }
+}
+
+impl<K, V, S> IsEmpty for HashMap<K, V, S> {
+    fn is_empty(&self) -> bool {
+        HashMap::is_empty(self)
+    }
+}
+
+impl<T, S> IsEmpty for HashSet<T, S> {
+    fn is_empty(&self) -> bool {
+        HashSet::is_empty(self)
+    }
+}
+
+impl<K, V> IsEmpty for BTreeMap<K, V> {
+    fn is_empty(&self) -> bool {
+        BTreeMap::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for BTreeSet<T> {
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+}
+
+impl IsEmpty for String {
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for Option<T> {
+    fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+}
@@ -11,28 +11,111 @@
 //! - **Interval Tasks**: Manages background tasks for auto-refreshing providers
 //! - **Revalidation Control**: Prevents duplicate revalidations and manages ongoing operations
 //!
+//! ## Lock Order
+//!
+//! Marking a reactive context dirty runs its callback synchronously, and that callback may
+//! read the cache or call back into the registry. To rule out deadlocks:
+//!
+//! - The registry never holds one of its own locks while marking contexts dirty or calling
+//!   state listeners; it collects them first and releases the lock.
+//! - Callers never hold a [`ProviderCache`](crate::cache::ProviderCache) lock while calling
+//!   into the registry; they drop the cache guard before `trigger_refresh` and friends.
+//!
 //! ## Cross-Platform Compatibility
 //!
 //! This module uses cross-platform abstractions:
-//! - `dioxus::spawn` for background tasks (works on both web and desktop)
+//! - `platform::spawn_task` for cancellable background tasks (works on both web and desktop)
 //! - `wasmtimer` for web timing and `tokio` for desktop timing
 //! - Automatic task cleanup when components unmount
 
-use dioxus::{core::ReactiveContext, prelude::*};
+use dioxus::core::ReactiveContext;
 use std::{
+    any::Any,
     collections::{HashMap, HashSet},
-    sync::{Arc, Mutex},
+    sync::{
+        Arc, Mutex, Weak,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
     time::Duration,
 };
 
+use crate::platform::{TaskHandle, next_frame, spawn_task, task::spawn_root_task};
+
 #[cfg(not(target_family = "wasm"))]
 use tokio::time;
 #[cfg(target_family = "wasm")]
 use wasmtimer::tokio as time;
 
+// Platform-specific time imports
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+#[cfg(target_family = "wasm")]
+use web_time::Instant;
+
 /// Type alias for reactive context storage
 type ReactiveContextSet = Arc<Mutex<HashSet<ReactiveContext>>>;
-type ReactiveContextRegistry = Arc<Mutex<HashMap<String, ReactiveContextSet>>>;
+
+/// Type alias for the generations of the fetches running for each provider key
+type FetchGenerationRegistry = Arc<Mutex<HashMap<String, KeyFetches>>>;
+
+/// Generations of the fetches of one provider key
+#[derive(Default)]
+struct KeyFetches {
+    /// Generation handed to the latest started fetch
+    started: u64,
+    /// Generation of the latest fetch that stored its result
+    stored: u64,
+    /// Number of fetches that have not ended yet
+    running: usize,
+}
+
+/// A fetch of a provider key started with [`RefreshRegistry::start_fetch`]
+///
+/// Dropping it ends the fetch, e.g. when the task running it completes or is cancelled. The
+/// key's generations are forgotten once none of its fetches is running.
+pub struct FetchGeneration {
+    generations: FetchGenerationRegistry,
+    key: String,
+    generation: u64,
+}
+
+impl FetchGeneration {
+    /// Get the generation of this fetch, increasing with each fetch started for the key
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Claim the right to store this fetch's result
+    ///
+    /// Returns false if a newer fetch already stored its result, so a slow response can't
+    /// overwrite it. Newer fetches that were aborted or are still running don't count.
+    pub fn claim_store(&self) -> bool {
+        let Ok(mut generations) = self.generations.lock() else {
+            return true;
+        };
+        match generations.get_mut(&self.key) {
+            Some(fetches) if fetches.stored > self.generation => false,
+            Some(fetches) => {
+                fetches.stored = self.generation;
+                true
+            }
+            None => true,
+        }
+    }
+}
+
+impl Drop for FetchGeneration {
+    fn drop(&mut self) {
+        if let Ok(mut generations) = self.generations.lock()
+            && let Some(fetches) = generations.get_mut(&self.key)
+        {
+            fetches.running = fetches.running.saturating_sub(1);
+            if fetches.running == 0 {
+                generations.remove(&self.key);
+            }
+        }
+    }
+}
 
 /// Task type for different periodic operations
 #[derive(Debug, Clone, PartialEq)]
@@ -47,8 +130,24 @@ pub enum TaskType {
     CacheExpiration,
 }
 
-/// Registry for periodic tasks (intervals and stale checks)
-type PeriodicTaskRegistry = Arc<Mutex<HashMap<String, (TaskType, Duration, ())>>>;
+/// Callback notified with the new state (a type-erased `ProviderState`) of a provider key
+pub type StateListener = Arc<dyn Fn(&dyn Any) + Send + Sync>;
+
+/// State listeners for each provider key, tagged with their listener id
+type StateListeners = Mutex<HashMap<String, Vec<(u64, StateListener)>>>;
+
+/// Periodic tasks (intervals and stale checks) by task key
+type PeriodicTasks = Mutex<HashMap<String, (TaskType, Duration, TaskHandle)>>;
+
+/// Callback run on every tick of a key's interval refresh task
+type IntervalCallback = Arc<Mutex<dyn Fn() + Send>>;
+
+/// A timer ticking the interval refresh tasks of every key polling at its interval
+struct IntervalTimer {
+    handle: TaskHandle,
+    /// The callback of each key, with a handle cancelled once the key stops polling
+    keys: HashMap<String, (TaskHandle, IntervalCallback)>,
+}
 
 /// Global registry for refresh signals that can trigger provider re-execution
 ///
@@ -62,14 +161,85 @@ type PeriodicTaskRegistry = Arc<Mutex<HashMap<String, (TaskType, Duration, ())>>
 /// different contexts and background tasks.
 #[derive(Clone, Default)]
 pub struct RefreshRegistry {
+    inner: Arc<RefreshRegistryInner>,
+}
+
+/// The state of a [`RefreshRegistry`], shared by its clones
+#[derive(Default)]
+struct RefreshRegistryInner {
     /// Counters for tracking how many times each provider has been refreshed
-    refresh_counters: Arc<Mutex<HashMap<String, u64>>>,
+    refresh_counters: Mutex<HashMap<String, u64>>,
     /// Registry of reactive contexts subscribed to each provider key
-    reactive_contexts: ReactiveContextRegistry,
+    reactive_contexts: Mutex<HashMap<String, ReactiveContextSet>>,
     /// Registry of periodic tasks (both interval refresh and stale checking)
-    periodic_tasks: PeriodicTaskRegistry,
+    periodic_tasks: PeriodicTasks,
+    /// Timers driving the interval refresh tasks, shared by keys with the same interval
+    interval_timers: Mutex<HashMap<Duration, IntervalTimer>>,
     /// Set of provider keys that are currently being revalidated
-    ongoing_revalidations: Arc<Mutex<HashSet<String>>>,
+    ongoing_revalidations: Mutex<HashSet<String>>,
+    /// Generation of the latest pending debounced operation for each provider key
+    pending_debounces: Mutex<HashMap<String, u64>>,
+    /// Generations of the fetches running for each provider key, shared with the
+    /// [`FetchGeneration`]s handed out
+    fetch_generations: FetchGenerationRegistry,
+    /// Number of pauses held on each provider key whose interval refresh ticks are skipped
+    paused_intervals: Mutex<HashMap<String, usize>>,
+    /// Intervals replacing the declared interval of provider keys, zero disables polling
+    interval_overrides: Mutex<HashMap<String, Duration>>,
+    /// Number of mounted hooks overriding the interval of each provider key
+    interval_override_hooks: Mutex<HashMap<String, usize>>,
+    /// Callbacks notified when a provider key's cached state changes
+    state_listeners: StateListeners,
+    /// Id handed to the next registered state listener
+    next_listener_id: AtomicU64,
+    /// Number of mounted `use_provider` hooks showing each provider key, with the time
+    /// the count last changed
+    mounted_hooks: Mutex<HashMap<String, (usize, Instant)>>,
+    /// Whether refreshes mark their subscribers dirty once per frame
+    frame_coalescing: AtomicBool,
+    /// Keys refreshed since the scheduled frame, None while no frame is scheduled; shared
+    /// with the task waiting for the frame
+    pending_frame: Arc<Mutex<Option<HashSet<String>>>>,
+}
+
+/// A reference to a [`RefreshRegistry`] that doesn't keep it alive
+///
+/// Created with [`RefreshRegistry::downgrade`], e.g. for callbacks stored in a cache whose
+/// tasks the registry holds, which would otherwise keep each other alive.
+#[derive(Clone)]
+pub struct WeakRefreshRegistry {
+    inner: Weak<RefreshRegistryInner>,
+}
+
+impl WeakRefreshRegistry {
+    /// Get the registry back, or None once every clone of it was dropped
+    pub fn upgrade(&self) -> Option<RefreshRegistry> {
+        self.inner
+            .upgrade()
+            .map(|inner| RefreshRegistry { inner })
+    }
+}
+
+/// Forgets the keys waiting for a frame that will never come, e.g. because its task was
+/// dropped together with the runtime, so later refreshes schedule a new one
+struct PendingFrameGuard(Option<Arc<Mutex<Option<HashSet<String>>>>>);
+
+impl PendingFrameGuard {
+    /// Stop guarding once the task took the pending keys, as keys queued from then on
+    /// belong to the next frame's task
+    fn disarm(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for PendingFrameGuard {
+    fn drop(&mut self) {
+        if let Some(pending) = self.0.take()
+            && let Ok(mut pending) = pending.lock()
+        {
+            pending.take();
+        }
+    }
 }
 
 impl RefreshRegistry {
@@ -78,11 +248,18 @@ impl RefreshRegistry {
         Self::default()
     }
 
+    /// Get a reference to this registry that doesn't keep it alive
+    pub fn downgrade(&self) -> WeakRefreshRegistry {
+        WeakRefreshRegistry {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
     /// Get the current refresh count for a provider key
     ///
     /// Returns the number of times the provider has been refreshed, or 0 if not found.
     pub fn get_refresh_count(&self, key: &str) -> u64 {
-        if let Ok(counters) = self.refresh_counters.lock() {
+        if let Ok(counters) = self.inner.refresh_counters.lock() {
             *counters.get(key).unwrap_or(&0)
         } else {
             0
@@ -94,7 +271,7 @@ impl RefreshRegistry {
     /// When the provider is refreshed, the reactive context will be marked as dirty,
     /// causing any components using it to re-render.
     pub fn subscribe_to_refresh(&self, key: &str, reactive_context: ReactiveContext) {
-        if let Ok(mut contexts) = self.reactive_contexts.lock() {
+        if let Ok(mut contexts) = self.inner.reactive_contexts.lock() {
             let key_contexts = contexts
                 .entry(key.to_string())
                 .or_insert_with(|| Arc::new(Mutex::new(HashSet::new())));
@@ -104,40 +281,147 @@ impl RefreshRegistry {
         }
     }
 
+    /// Unsubscribe a reactive context from refresh events for a provider key
+    ///
+    /// The key is dropped from the registry once its last subscriber is removed.
+    pub fn unsubscribe_from_refresh(&self, key: &str, reactive_context: &ReactiveContext) {
+        if let Ok(mut contexts) = self.inner.reactive_contexts.lock() {
+            let is_empty = match contexts.get(key) {
+                Some(key_contexts) => key_contexts
+                    .lock()
+                    .map(|mut context_set| {
+                        context_set.remove(reactive_context);
+                        context_set.is_empty()
+                    })
+                    .unwrap_or(false),
+                None => false,
+            };
+            if is_empty {
+                contexts.remove(key);
+            }
+        }
+    }
+
+    /// Coalesce the refreshes of each frame into a single re-render of every subscriber
+    ///
+    /// Fast pollers, e.g. a provider with `interval = "100ms"`, or a burst of cache writes
+    /// can refresh a key many times between two frames. With frame coalescing on, refreshes
+    /// still increment their counters immediately, but the subscribed reactive contexts are
+    /// only marked dirty on the next frame (`requestAnimationFrame` on WASM, a 16ms timer
+    /// elsewhere), once for all keys refreshed meanwhile. Outside a Dioxus runtime the
+    /// contexts are marked at once. Off by default.
+    pub fn set_frame_coalescing(&self, enabled: bool) {
+        self.inner.frame_coalescing.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Check if refreshes are coalesced until the next frame
+    pub fn is_frame_coalescing(&self) -> bool {
+        self.inner.frame_coalescing.load(Ordering::SeqCst)
+    }
+
     /// Trigger a refresh for a provider key
     ///
     /// This increments the refresh counter and marks all subscribed reactive contexts
-    /// as dirty, causing components to re-render and providers to re-execute.
+    /// as dirty, causing components to re-render and providers to re-execute. With
+    /// [frame coalescing](RefreshRegistry::set_frame_coalescing), the contexts are marked on
+    /// the next frame.
     pub fn trigger_refresh(&self, key: &str) {
-        // Increment the counter
-        if let Ok(mut counters) = self.refresh_counters.lock() {
-            let counter = counters.entry(key.to_string()).or_insert(0);
-            *counter += 1;
-        }
-
-        // Mark all reactive contexts as dirty
-        if let Ok(contexts) = self.reactive_contexts.lock() {
-            if let Some(key_contexts) = contexts.get(key) {
-                if let Ok(context_set) = key_contexts.lock() {
-                    for reactive_context in context_set.iter() {
-                        reactive_context.mark_dirty();
-                    }
-                }
+        // Marks the contexts dirty after releasing the registry locks
+        self.trigger_refresh_many(&[key]);
+    }
+
+    /// Trigger a refresh for many provider keys at once
+    ///
+    /// Behaves like calling [`RefreshRegistry::trigger_refresh`] for each key, but takes the
+    /// registry locks once and marks a reactive context subscribed to several of the keys
+    /// dirty only once, so batch writes such as [`ProviderCache::set_many`](crate::cache::ProviderCache::set_many)
+    /// re-render each subscriber a single time.
+    pub fn trigger_refresh_many<S: AsRef<str>>(&self, keys: &[S]) {
+        if let Ok(mut counters) = self.inner.refresh_counters.lock() {
+            for key in keys {
+                *counters.entry(key.as_ref().to_string()).or_insert(0) += 1;
+            }
+        }
+
+        if self.is_frame_coalescing() && self.defer_to_next_frame(keys) {
+            return;
+        }
+        self.mark_subscribers_dirty(keys);
+    }
+
+    /// Queue keys to have their subscribers marked dirty on the next frame, scheduling it
+    /// unless one is already pending
+    ///
+    /// Returns false outside a Dioxus runtime, where no frame can be awaited.
+    fn defer_to_next_frame<S: AsRef<str>>(&self, keys: &[S]) -> bool {
+        let keys = keys.iter().map(|key| key.as_ref().to_string());
+        {
+            let Ok(mut pending) = self.inner.pending_frame.lock() else {
+                return false;
+            };
+            if let Some(pending) = pending.as_mut() {
+                pending.extend(keys);
+                return true;
+            }
+            if dioxus::core::Runtime::current().is_err() {
+                return false;
+            }
+            *pending = Some(keys.collect());
+        }
+
+        let registry = self.clone();
+        spawn_root_task(async move {
+            let mut guard = PendingFrameGuard(Some(registry.inner.pending_frame.clone()));
+            next_frame().await;
+            let keys: Vec<String> = match registry.inner.pending_frame.lock() {
+                Ok(mut pending) => pending.take().into_iter().flatten().collect(),
+                Err(_) => return,
+            };
+            guard.disarm();
+            registry.mark_subscribers_dirty(&keys);
+        });
+        true
+    }
+
+    /// Mark every reactive context subscribed to one of the keys dirty, once each
+    fn mark_subscribers_dirty<S: AsRef<str>>(&self, keys: &[S]) {
+        let key_contexts: Vec<_> = match self.inner.reactive_contexts.lock() {
+            Ok(contexts) => keys
+                .iter()
+                .filter_map(|key| contexts.get(key.as_ref()).cloned())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        // ReactiveContext hashes by its id, which interior mutability never changes
+        #[allow(clippy::mutable_key_type)]
+        let mut dirty: HashSet<ReactiveContext> = HashSet::new();
+        for key_contexts in key_contexts {
+            if let Ok(context_set) = key_contexts.lock() {
+                dirty.extend(context_set.iter().copied());
             }
         }
+        // Callbacks may re-enter the registry, so no lock is held from here on
+        for reactive_context in dirty {
+            reactive_context.mark_dirty();
+        }
     }
 
     /// Clear all cached data and trigger refresh for all providers
     ///
-    /// This is useful for global cache invalidation scenarios.
+    /// This is useful for global cache invalidation scenarios. Every key that has been
+    /// refreshed before or has a subscriber is refreshed, so subscribers of providers that
+    /// were never refreshed are marked dirty as well.
     pub fn clear_all(&self) {
-        if let Ok(counters) = self.refresh_counters.lock() {
-            let keys: Vec<String> = counters.keys().cloned().collect();
-            drop(counters);
+        let mut keys: HashSet<String> = match self.inner.refresh_counters.lock() {
+            Ok(counters) => counters.keys().cloned().collect(),
+            Err(_) => HashSet::new(),
+        };
+        if let Ok(contexts) = self.inner.reactive_contexts.lock() {
+            keys.extend(contexts.keys().cloned());
+        }
 
-            for key in keys {
-                self.trigger_refresh(&key);
-            }
+        for key in keys {
+            self.trigger_refresh(&key);
         }
     }
 
@@ -148,10 +432,15 @@ impl RefreshRegistry {
     /// If an existing task exists with a longer interval, it will be replaced.
     /// Tasks with shorter intervals are preserved to avoid unnecessary re-creation.
     ///
+    /// Interval refresh tasks don't get a timer of their own: all keys polling at the same
+    /// interval are ticked together by one shared timer, so a dashboard of many `1s`
+    /// providers runs a single timer. The shared timer outlives the component that started
+    /// it and drops keys once no `use_provider` hook shows them anymore.
+    ///
     /// ## Cross-Platform Implementation
     ///
-    /// Uses `dioxus::spawn` to create tasks that work on both web and desktop platforms.
-    /// Tasks are automatically cancelled when the component unmounts.
+    /// Uses `platform::spawn_task` to create tasks that work on both web and desktop platforms.
+    /// Tasks are cancelled when stopped or replaced, and when the component unmounts.
     pub fn start_periodic_task<F>(
         &self,
         key: &str,
@@ -161,7 +450,17 @@ impl RefreshRegistry {
     ) where
         F: Fn() + Send + 'static,
     {
-        if let Ok(mut tasks) = self.periodic_tasks.lock() {
+        // An overridden interval replaces the one the provider declared
+        let interval = match task_type {
+            TaskType::IntervalRefresh => match self.interval_override(key) {
+                Some(interval_override) if interval_override.is_zero() => return,
+                Some(interval_override) => interval_override,
+                None => interval,
+            },
+            _ => interval,
+        };
+
+        if let Ok(mut tasks) = self.inner.periodic_tasks.lock() {
             let task_key = format!("{key}:{task_type:?}");
 
             // For certain task types, don't create multiple tasks for the same provider
@@ -176,8 +475,9 @@ impl RefreshRegistry {
             // Cancel existing task if it exists and the new interval is shorter (for interval tasks)
             let should_create_new_task = match tasks.get(&task_key) {
                 None => true,
-                Some((_, current_interval, _)) => {
+                Some((_, current_interval, handle)) => {
                     if task_type == TaskType::IntervalRefresh && interval < *current_interval {
+                        handle.cancel();
                         tasks.remove(&task_key);
                         true
                     } else {
@@ -193,26 +493,118 @@ impl RefreshRegistry {
                         Duration::min(interval / 4, Duration::from_secs(30)),
                         Duration::from_secs(1),
                     ),
-                    TaskType::CacheExpiration => Duration::max(
-                        Duration::min(interval / 4, Duration::from_secs(30)),
-                        Duration::from_secs(1),
-                    ),
                     _ => interval,
                 };
 
-                let _task_key_clone = task_key.clone();
-                let task_fn = Arc::new(task_fn);
+                let handle = if task_type == TaskType::IntervalRefresh {
+                    self.join_interval_timer(key, actual_interval, task_fn)
+                } else {
+                    spawn_task(async move {
+                        loop {
+                            time::sleep(actual_interval).await;
+                            task_fn();
+                        }
+                    })
+                };
+
+                tasks.insert(task_key, (task_type, interval, handle));
+            }
+        }
+    }
+
+    /// Add a key to the shared timer of its interval, starting the timer if there is none
+    ///
+    /// Returns the handle that removes the key from the timer when cancelled.
+    fn join_interval_timer<F>(&self, key: &str, interval: Duration, task_fn: F) -> TaskHandle
+    where
+        F: Fn() + Send + 'static,
+    {
+        let membership = TaskHandle::default();
+        let callback: IntervalCallback = Arc::new(Mutex::new(task_fn));
+        if let Ok(mut timers) = self.inner.interval_timers.lock() {
+            let timer = timers.entry(interval).or_insert_with(|| IntervalTimer {
+                handle: TaskHandle::default(),
+                keys: HashMap::new(),
+            });
+            // A timer that couldn't be spawned, e.g. outside a Dioxus runtime, is retried
+            if timer.keys.is_empty() || timer.handle.is_cancelled() {
+                timer.handle = self.spawn_interval_timer(interval);
+            }
+            timer
+                .keys
+                .insert(key.to_string(), (membership.clone(), callback));
+        }
+        membership
+    }
 
-                spawn(async move {
-                    loop {
-                        time::sleep(actual_interval).await;
-                        task_fn();
+    /// Spawn the timer ticking the keys of an interval until none of them is left
+    fn spawn_interval_timer(&self, interval: Duration) -> TaskHandle {
+        let registry = self.clone();
+        spawn_root_task(async move {
+            loop {
+                time::sleep(interval).await;
+                let Some(due) = registry.due_interval_callbacks(interval) else {
+                    break;
+                };
+                for callback in due {
+                    if let Ok(callback) = callback.lock() {
+                        callback();
                     }
-                });
+                }
+            }
+        })
+    }
 
-                tasks.insert(task_key, (task_type, interval, ()));
+    /// Get the callbacks to run on a tick of an interval's timer
+    ///
+    /// Keys that stopped polling or are no longer shown by any hook leave the timer, and
+    /// paused keys are skipped. Returns None once the timer has no keys left, removing it.
+    fn due_interval_callbacks(&self, interval: Duration) -> Option<Vec<IntervalCallback>> {
+        let mut unmounted = Vec::new();
+        let due = {
+            let mut timers = self.inner.interval_timers.lock().ok()?;
+            let timer = timers.get_mut(&interval)?;
+            timer.keys.retain(|key, (membership, _)| {
+                if !membership.is_cancelled() && self.unmounted_for(key).is_some() {
+                    membership.cancel();
+                    unmounted.push(key.clone());
+                }
+                !membership.is_cancelled()
+            });
+            if timer.keys.is_empty() {
+                timers.remove(&interval);
+                None
+            } else {
+                // Paused interval tasks keep their schedule but skip the tick
+                Some(
+                    timer
+                        .keys
+                        .iter()
+                        .filter(|(key, _)| !self.is_interval_paused(key))
+                        .map(|(_, (_, callback))| callback.clone())
+                        .collect(),
+                )
+            }
+        };
+
+        // Forget the tasks of unmounted keys, so remounting them starts polling again
+        if !unmounted.is_empty()
+            && let Ok(mut tasks) = self.inner.periodic_tasks.lock()
+        {
+            for key in &unmounted {
+                let task_key = format!("{key}:{:?}", TaskType::IntervalRefresh);
+                if tasks
+                    .get(&task_key)
+                    .is_some_and(|(_, _, handle)| handle.is_cancelled())
+                {
+                    tasks.remove(&task_key);
+                }
             }
         }
+        for key in &unmounted {
+            self.forget_unmounted_key(key);
+        }
+        due
     }
 
     /// Start an interval task for automatic provider refresh
@@ -225,6 +617,71 @@ impl RefreshRegistry {
         self.start_periodic_task(key, TaskType::IntervalRefresh, interval, refresh_fn);
     }
 
+    /// Replace the interval task for a provider key, whatever the interval of the current one
+    ///
+    /// Unlike [`RefreshRegistry::start_interval_task`], which only replaces a task when the
+    /// new interval is shorter, the current task is always cancelled. The interval is
+    /// stored as an override, so later calls to `start_interval_task` for the key (e.g.
+    /// from the provider's declared interval) keep it. A zero interval stops polling.
+    pub fn restart_interval_task<F>(&self, key: &str, interval: Duration, refresh_fn: F)
+    where
+        F: Fn() + Send + 'static,
+    {
+        if let Ok(mut overrides) = self.inner.interval_overrides.lock() {
+            overrides.insert(key.to_string(), interval);
+        }
+        self.stop_interval_task(key);
+        self.start_interval_task(key, interval, refresh_fn);
+    }
+
+    /// Get the interval set for a provider key with [`RefreshRegistry::restart_interval_task`]
+    pub fn interval_override(&self, key: &str) -> Option<Duration> {
+        self.inner.interval_overrides
+            .lock()
+            .ok()
+            .and_then(|overrides| overrides.get(key).copied())
+    }
+
+    /// Remove the interval override of a provider key
+    ///
+    /// The running task is left alone; the next `start_interval_task` call uses the
+    /// interval it is given again.
+    pub fn clear_interval_override(&self, key: &str) {
+        if let Ok(mut overrides) = self.inner.interval_overrides.lock() {
+            overrides.remove(key);
+        }
+    }
+
+    /// Record that a mounted hook overrides the interval of a provider key
+    pub fn retain_interval_override(&self, key: &str) {
+        if let Ok(mut hooks) = self.inner.interval_override_hooks.lock() {
+            *hooks.entry(key.to_string()).or_default() += 1;
+        }
+    }
+
+    /// Release an override taken with [`RefreshRegistry::retain_interval_override`]
+    ///
+    /// Once the last hook overriding the key releases it, the override is removed and the
+    /// interval task stopped. Returns whether that happened.
+    pub fn release_interval_override(&self, key: &str) -> bool {
+        let released = match self.inner.interval_override_hooks.lock() {
+            Ok(mut hooks) => match hooks.get_mut(key) {
+                Some(count) if *count > 1 => {
+                    *count -= 1;
+                    false
+                }
+                Some(_) => hooks.remove(key).is_some(),
+                None => false,
+            },
+            Err(_) => false,
+        };
+        if released {
+            self.clear_interval_override(key);
+            self.stop_interval_task(key);
+        }
+        released
+    }
+
     /// Start a stale check task for SWR behavior
     ///
     /// This is a convenience method for starting stale checking tasks.
@@ -237,12 +694,13 @@ impl RefreshRegistry {
 
     /// Stop a periodic task
     ///
-    /// Removes the task from the registry. The actual task will complete its current
-    /// iteration and then stop.
+    /// Removes the task from the registry and cancels it, so it stops before its next tick.
     pub fn stop_periodic_task(&self, key: &str, task_type: TaskType) {
-        if let Ok(mut tasks) = self.periodic_tasks.lock() {
+        if let Ok(mut tasks) = self.inner.periodic_tasks.lock() {
             let task_key = format!("{key}:{task_type:?}");
-            tasks.remove(&task_key);
+            if let Some((_, _, handle)) = tasks.remove(&task_key) {
+                handle.cancel();
+            }
         }
     }
 
@@ -260,11 +718,103 @@ impl RefreshRegistry {
         self.stop_periodic_task(key, TaskType::StaleCheck);
     }
 
+    /// Pause the interval refresh task for a provider key
+    ///
+    /// The task keeps running but skips its ticks until resumed, so cached data and the
+    /// interval schedule are left untouched. Pauses are counted and each call must be matched
+    /// by a [`RefreshRegistry::resume_interval_task`]. While hooks showing the key are
+    /// mounted, the task only skips ticks once every one of them holds a pause, so pausing
+    /// from one component doesn't stop polling for the others.
+    pub fn pause_interval_task(&self, key: &str) {
+        if let Ok(mut paused) = self.inner.paused_intervals.lock() {
+            *paused.entry(key.to_string()).or_default() += 1;
+        }
+    }
+
+    /// Release a pause taken with [`RefreshRegistry::pause_interval_task`] for a provider key
+    pub fn resume_interval_task(&self, key: &str) {
+        if let Ok(mut paused) = self.inner.paused_intervals.lock()
+            && let Some(count) = paused.get_mut(key)
+        {
+            *count -= 1;
+            if *count == 0 {
+                paused.remove(key);
+            }
+        }
+    }
+
+    /// Check if the interval refresh task for a provider key is paused
+    pub fn is_interval_paused(&self, key: &str) -> bool {
+        let pauses = match self.inner.paused_intervals.lock() {
+            Ok(paused) => paused.get(key).copied().unwrap_or(0),
+            Err(_) => 0,
+        };
+        pauses > 0 && pauses >= self.mounted_count(key)
+    }
+
+    /// Register a callback notified whenever a fetch changes the cached state of a provider key
+    ///
+    /// Returns an id for removing the listener with [`RefreshRegistry::remove_state_listener`].
+    /// Prefer `global::subscribe_provider`, which hands the callback a typed `ProviderState`.
+    pub fn add_state_listener(&self, key: &str, listener: StateListener) -> u64 {
+        let id = self.inner.next_listener_id.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut listeners) = self.inner.state_listeners.lock() {
+            listeners
+                .entry(key.to_string())
+                .or_default()
+                .push((id, listener));
+        }
+        id
+    }
+
+    /// Remove a state listener registered with [`RefreshRegistry::add_state_listener`]
+    pub fn remove_state_listener(&self, key: &str, id: u64) {
+        if let Ok(mut listeners) = self.inner.state_listeners.lock()
+            && let Some(key_listeners) = listeners.get_mut(key)
+        {
+            key_listeners.retain(|(listener_id, _)| *listener_id != id);
+            if key_listeners.is_empty() {
+                listeners.remove(key);
+            }
+        }
+    }
+
+    /// Check if any state listeners are registered for a provider key
+    pub fn has_state_listeners(&self, key: &str) -> bool {
+        if let Ok(listeners) = self.inner.state_listeners.lock() {
+            listeners.contains_key(key)
+        } else {
+            false
+        }
+    }
+
+    /// Notify the state listeners of a provider key about its new state
+    ///
+    /// The listeners are called after the registry lock is released, so they may add or
+    /// remove listeners themselves.
+    pub fn notify_state_listeners(&self, key: &str, state: &dyn Any) {
+        let key_listeners: Vec<StateListener> = match self.inner.state_listeners.lock() {
+            Ok(listeners) => listeners
+                .get(key)
+                .map(|key_listeners| {
+                    key_listeners
+                        .iter()
+                        .map(|(_, listener)| listener.clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(_) => return,
+        };
+        for listener in key_listeners {
+            listener(state);
+        }
+    }
+
     /// Check if a revalidation is currently in progress for a provider key
     ///
     /// This prevents duplicate revalidations from being started simultaneously.
     pub fn is_revalidation_in_progress(&self, key: &str) -> bool {
-        if let Ok(revalidations) = self.ongoing_revalidations.lock() {
+        if let Ok(revalidations) = self.inner.ongoing_revalidations.lock() {
             revalidations.contains(key)
         } else {
             false
@@ -276,7 +826,7 @@ impl RefreshRegistry {
     /// Returns true if the revalidation was started, false if one was already in progress.
     /// This prevents duplicate revalidations from running simultaneously.
     pub fn start_revalidation(&self, key: &str) -> bool {
-        if let Ok(mut revalidations) = self.ongoing_revalidations.lock() {
+        if let Ok(mut revalidations) = self.inner.ongoing_revalidations.lock() {
             if revalidations.contains(key) {
                 false
             } else {
@@ -292,42 +842,238 @@ impl RefreshRegistry {
     ///
     /// This should be called when a revalidation finishes, regardless of success or failure.
     pub fn complete_revalidation(&self, key: &str) {
-        if let Ok(mut revalidations) = self.ongoing_revalidations.lock() {
+        if let Ok(mut revalidations) = self.inner.ongoing_revalidations.lock() {
             revalidations.remove(key);
         }
     }
 
+    /// Schedule a debounced operation for a provider key
+    ///
+    /// The operation runs once `debounce` has elapsed without another call for the
+    /// same key. Each call supersedes the previously pending operation, so a burst
+    /// of calls results in a single execution after the calls quiet down.
+    pub fn schedule_debounced<F>(&self, key: &str, debounce: Duration, operation: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        let generation = if let Ok(mut pending) = self.inner.pending_debounces.lock() {
+            let generation = pending.entry(key.to_string()).or_insert(0);
+            *generation += 1;
+            *generation
+        } else {
+            return;
+        };
+
+        let registry = self.clone();
+        let key = key.to_string();
+        spawn_task(async move {
+            time::sleep(debounce).await;
+            if registry.finish_debounce(&key, generation) {
+                operation();
+            }
+        });
+    }
+
+    /// Check if a debounced operation is pending for a provider key
+    pub fn has_pending_debounce(&self, key: &str) -> bool {
+        if let Ok(pending) = self.inner.pending_debounces.lock() {
+            pending.contains_key(key)
+        } else {
+            false
+        }
+    }
+
+    /// Clear the pending debounce for a key if `generation` is still the latest call
+    ///
+    /// Returns true if the caller holds the latest generation and should run its operation.
+    fn finish_debounce(&self, key: &str, generation: u64) -> bool {
+        if let Ok(mut pending) = self.inner.pending_debounces.lock()
+            && pending.get(key) == Some(&generation)
+        {
+            pending.remove(key);
+            return true;
+        }
+        false
+    }
+
+    /// Start a fetch of a provider key, returning its generation
+    ///
+    /// Generations increase monotonically per key while its fetches overlap, so a fetch can
+    /// tell with [`FetchGeneration::claim_store`] whether a newer fetch of the same key, e.g.
+    /// from repeated manual refreshes, already stored its result. Keep the returned value
+    /// until the fetch ends.
+    pub fn start_fetch(&self, key: &str) -> FetchGeneration {
+        let generation = match self.inner.fetch_generations.lock() {
+            Ok(mut generations) => {
+                let fetches = generations.entry(key.to_string()).or_default();
+                fetches.started += 1;
+                fetches.running += 1;
+                fetches.started
+            }
+            Err(_) => 0,
+        };
+        FetchGeneration {
+            generations: self.inner.fetch_generations.clone(),
+            key: key.to_string(),
+            generation,
+        }
+    }
+
+    /// Record that a mounted hook shows a provider key
+    pub fn mount_key(&self, key: &str) {
+        if let Ok(mut mounted) = self.inner.mounted_hooks.lock() {
+            let (count, changed_at) = mounted
+                .entry(key.to_string())
+                .or_insert((0, Instant::now()));
+            *count += 1;
+            *changed_at = Instant::now();
+        }
+    }
+
+    /// Record that a hook no longer shows a provider key, after unmounting or a param change
+    ///
+    /// Once the last hook is gone, the key is forgotten unless its dispose or interval task
+    /// still needs to know when that happened.
+    pub fn unmount_key(&self, key: &str) {
+        let unmounted = if let Ok(mut mounted) = self.inner.mounted_hooks.lock()
+            && let Some((count, changed_at)) = mounted.get_mut(key)
+        {
+            *count = count.saturating_sub(1);
+            *changed_at = Instant::now();
+            *count == 0
+        } else {
+            false
+        };
+        if unmounted {
+            self.forget_unmounted_key(key);
+        }
+    }
+
+    /// Forget a provider key no hook shows, once no task of the key reads its unmount time
+    pub fn forget_unmounted_key(&self, key: &str) {
+        let tracked = self.inner.periodic_tasks.lock().is_ok_and(|tasks| {
+            tasks.contains_key(&format!("{key}_dispose:{:?}", TaskType::CacheCleanup))
+                || tasks.contains_key(&format!("{key}:{:?}", TaskType::IntervalRefresh))
+        });
+        if !tracked
+            && let Ok(mut mounted) = self.inner.mounted_hooks.lock()
+            && mounted.get(key).is_some_and(|(count, _)| *count == 0)
+        {
+            mounted.remove(key);
+        }
+    }
+
+    /// Get the number of mounted hooks showing a provider key
+    ///
+    /// Unlike [`RefreshRegistry::subscriber_count`], this drops back to zero once every
+    /// component using the key has unmounted.
+    pub fn mounted_count(&self, key: &str) -> usize {
+        self.inner.mounted_hooks
+            .lock()
+            .ok()
+            .and_then(|mounted| mounted.get(key).map(|(count, _)| *count))
+            .unwrap_or(0)
+    }
+
+    /// Get how long ago the last hook showing a provider key unmounted
+    ///
+    /// None while a hook shows the key, or if no hook ever showed it.
+    pub fn unmounted_for(&self, key: &str) -> Option<Duration> {
+        let mounted = self.inner.mounted_hooks.lock().ok()?;
+        match mounted.get(key)? {
+            (0, changed_at) => Some(changed_at.elapsed()),
+            _ => None,
+        }
+    }
+
+    /// Get the number of reactive contexts subscribed to a provider key
+    pub fn subscriber_count(&self, key: &str) -> usize {
+        // Release the registry lock before locking the per-key set
+        let key_contexts = match self.inner.reactive_contexts.lock() {
+            Ok(contexts) => contexts.get(key).cloned(),
+            Err(_) => None,
+        };
+        key_contexts
+            .and_then(|key_contexts| key_contexts.lock().ok().map(|set| set.len()))
+            .unwrap_or(0)
+    }
+
+    /// Get the provider keys that have an interval refresh task, with their intervals
+    pub fn active_interval_keys(&self) -> Vec<(String, Duration)> {
+        if let Ok(tasks) = self.inner.periodic_tasks.lock() {
+            tasks
+                .iter()
+                .filter(|(_, (task_type, _, _))| *task_type == TaskType::IntervalRefresh)
+                .map(|(task_key, (task_type, interval, _))| {
+                    (provider_key_of(task_key, task_type), *interval)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Get the provider keys that have a periodic task, with the type of each task
+    pub fn periodic_task_keys(&self) -> Vec<(String, TaskType)> {
+        if let Ok(tasks) = self.inner.periodic_tasks.lock() {
+            tasks
+                .iter()
+                .map(|(task_key, (task_type, _, _))| {
+                    (provider_key_of(task_key, task_type), task_type.clone())
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Get the provider keys that are currently being revalidated
+    pub fn revalidating_keys(&self) -> Vec<String> {
+        if let Ok(revalidations) = self.inner.ongoing_revalidations.lock() {
+            revalidations.iter().cloned().collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Get statistics about the refresh registry
     pub fn stats(&self) -> RefreshRegistryStats {
-        let refresh_count = if let Ok(counters) = self.refresh_counters.lock() {
+        let refresh_count = if let Ok(counters) = self.inner.refresh_counters.lock() {
             counters.len()
         } else {
             0
         };
 
-        let context_count = if let Ok(contexts) = self.reactive_contexts.lock() {
+        let context_count = if let Ok(contexts) = self.inner.reactive_contexts.lock() {
             contexts.len()
         } else {
             0
         };
 
-        let task_count = if let Ok(tasks) = self.periodic_tasks.lock() {
+        let task_count = if let Ok(tasks) = self.inner.periodic_tasks.lock() {
             tasks.len()
         } else {
             0
         };
 
-        let revalidation_count = if let Ok(revalidations) = self.ongoing_revalidations.lock() {
+        let revalidation_count = if let Ok(revalidations) = self.inner.ongoing_revalidations.lock() {
             revalidations.len()
         } else {
             0
         };
 
+        let interval_timer_count = if let Ok(timers) = self.inner.interval_timers.lock() {
+            timers.len()
+        } else {
+            0
+        };
+
         RefreshRegistryStats {
             refresh_count,
             context_count,
             task_count,
             revalidation_count,
+            interval_timer_count,
         }
     }
 
@@ -336,7 +1082,7 @@ impl RefreshRegistry {
         let mut stats = RefreshCleanupStats::default();
 
         // Clean up unused reactive contexts
-        if let Ok(mut contexts) = self.reactive_contexts.lock() {
+        if let Ok(mut contexts) = self.inner.reactive_contexts.lock() {
             let initial_context_count = contexts.len();
             contexts.retain(|_, context_set| {
                 if let Ok(set) = context_set.lock() {
@@ -349,13 +1095,70 @@ impl RefreshRegistry {
         }
 
         // Clean up completed revalidations (should be empty, but just in case)
-        if let Ok(mut revalidations) = self.ongoing_revalidations.lock() {
+        if let Ok(mut revalidations) = self.inner.ongoing_revalidations.lock() {
             stats.revalidations_cleared = revalidations.len();
             revalidations.clear();
         }
 
         stats
     }
+
+    /// Cancel every periodic task and forget all subscriptions, listeners and bookkeeping
+    ///
+    /// Interval refreshes, stale checks, cache cleanups and delayed disposals stop before
+    /// their next tick, and pending debounced operations are dropped. Fetch generations are
+    /// kept, so fetches still running from before the shutdown stay superseded. Returns the
+    /// number of periodic tasks that were cancelled.
+    pub fn shutdown(&self) -> usize {
+        let mut cancelled = 0;
+        if let Ok(mut tasks) = self.inner.periodic_tasks.lock() {
+            for (_, (_, _, handle)) in tasks.drain() {
+                handle.cancel();
+                cancelled += 1;
+            }
+        }
+        if let Ok(mut timers) = self.inner.interval_timers.lock() {
+            for (_, timer) in timers.drain() {
+                timer.handle.cancel();
+            }
+        }
+        if let Ok(mut counters) = self.inner.refresh_counters.lock() {
+            counters.clear();
+        }
+        if let Ok(mut contexts) = self.inner.reactive_contexts.lock() {
+            contexts.clear();
+        }
+        if let Ok(mut revalidations) = self.inner.ongoing_revalidations.lock() {
+            revalidations.clear();
+        }
+        if let Ok(mut debounces) = self.inner.pending_debounces.lock() {
+            debounces.clear();
+        }
+        if let Ok(mut paused) = self.inner.paused_intervals.lock() {
+            paused.clear();
+        }
+        if let Ok(mut overrides) = self.inner.interval_overrides.lock() {
+            overrides.clear();
+        }
+        if let Ok(mut hooks) = self.inner.interval_override_hooks.lock() {
+            hooks.clear();
+        }
+        if let Ok(mut listeners) = self.inner.state_listeners.lock() {
+            listeners.clear();
+        }
+        if let Ok(mut mounted) = self.inner.mounted_hooks.lock() {
+            mounted.clear();
+        }
+        cancelled
+    }
+}
+
+/// Strip the `:{task_type:?}` suffix from a periodic task key
+fn provider_key_of(task_key: &str, task_type: &TaskType) -> String {
+    task_key
+        .strip_suffix(&format!(":{task_type:?}"))
+        .unwrap_or(task_key)
+        .to_string()
 }
 
 /// Statistics for the refresh registry
@@ -365,6 +1168,7 @@ pub struct RefreshRegistryStats {
     pub context_count: usize,
     pub task_count: usize,
     pub revalidation_count: usize,
+    pub interval_timer_count: usize,
 }
 
 /// Statistics for refresh registry cleanup operations
@@ -373,3 +1177,303 @@ pub struct RefreshCleanupStats {
     pub contexts_removed: usize,
     pub revalidations_cleared: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus::dioxus_core::{ScopeId, VirtualDom};
+    use dioxus::prelude::*;
+
+    #[tokio::test]
+    async fn test_debounced_calls_coalesce_into_single_refresh() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            for _ in 0..5 {
+                let registry_for_task = registry.clone();
+                registry.schedule_debounced("search", Duration::from_millis(50), move || {
+                    registry_for_task.trigger_refresh("search");
+                });
+            }
+        });
+        assert!(registry.has_pending_debounce("search"));
+
+        let _ = tokio::time::timeout(Duration::from_millis(200), dom.wait_for_work()).await;
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+
+        assert_eq!(registry.get_refresh_count("search"), 1);
+        assert!(!registry.has_pending_debounce("search"));
+    }
+
+    #[tokio::test]
+    async fn test_paused_interval_task_skips_ticks_until_resumed() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        let ticks = Arc::new(AtomicU32::new(0));
+        let ticks_for_task = ticks.clone();
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            registry.start_interval_task("metrics", Duration::from_millis(20), move || {
+                ticks_for_task.fetch_add(1, Ordering::SeqCst);
+            });
+        });
+
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+        assert!(ticks.load(Ordering::SeqCst) > 0);
+
+        registry.pause_interval_task("metrics");
+        let paused_at = ticks.load(Ordering::SeqCst);
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), paused_at);
+
+        registry.resume_interval_task("metrics");
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+        assert!(ticks.load(Ordering::SeqCst) > paused_at);
+    }
+
+    #[test]
+    fn test_interval_stays_paused_until_every_pause_is_released() {
+        let registry = RefreshRegistry::new();
+        registry.pause_interval_task("metrics");
+        registry.pause_interval_task("metrics");
+
+        registry.resume_interval_task("metrics");
+        assert!(registry.is_interval_paused("metrics"));
+        registry.resume_interval_task("metrics");
+        assert!(!registry.is_interval_paused("metrics"));
+
+        // Extra resumes don't cancel a later pause
+        registry.resume_interval_task("metrics");
+        registry.pause_interval_task("metrics");
+        assert!(registry.is_interval_paused("metrics"));
+    }
+
+    #[test]
+    fn test_interval_keeps_ticking_for_hooks_that_did_not_pause() {
+        let registry = RefreshRegistry::new();
+        registry.mount_key("metrics");
+        registry.mount_key("metrics");
+
+        registry.pause_interval_task("metrics");
+        assert!(!registry.is_interval_paused("metrics"));
+        registry.pause_interval_task("metrics");
+        assert!(registry.is_interval_paused("metrics"));
+
+        // One hook releases its pause and unmounts, the other still holds its pause
+        registry.resume_interval_task("metrics");
+        registry.unmount_key("metrics");
+        assert!(registry.is_interval_paused("metrics"));
+    }
+
+    #[tokio::test]
+    async fn test_stopped_interval_task_stops_ticking() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        let ticks = Arc::new(AtomicU32::new(0));
+        let ticks_for_task = ticks.clone();
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            registry.start_interval_task("metrics", Duration::from_millis(20), move || {
+                ticks_for_task.fetch_add(1, Ordering::SeqCst);
+            });
+        });
+
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+        assert!(ticks.load(Ordering::SeqCst) > 0);
+
+        registry.stop_interval_task("metrics");
+        let _ = tokio::time::timeout(Duration::from_millis(30), dom.wait_for_work()).await;
+        let stopped_at = ticks.load(Ordering::SeqCst);
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), stopped_at);
+        assert_eq!(registry.stats().task_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_equal_interval_tasks_share_one_timer() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        let ticks: Vec<_> = (0..10).map(|_| Arc::new(AtomicU32::new(0))).collect();
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            for (index, ticks) in ticks.iter().enumerate() {
+                let ticks = ticks.clone();
+                registry.start_interval_task(
+                    &format!("widget-{index}"),
+                    Duration::from_millis(20),
+                    move || {
+                        ticks.fetch_add(1, Ordering::SeqCst);
+                    },
+                );
+            }
+        });
+        let stats = registry.stats();
+        assert_eq!(stats.task_count, 10);
+        assert_eq!(stats.interval_timer_count, 1);
+
+        let _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+        assert!(ticks.iter().all(|ticks| ticks.load(Ordering::SeqCst) > 0));
+
+        // Another interval gets a timer of its own
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            registry.start_interval_task("clock", Duration::from_millis(30), || {});
+        });
+        assert_eq!(registry.stats().interval_timer_count, 2);
+
+        // The timer stops once the last of its keys stops polling
+        registry.stop_interval_task("clock");
+        let _ = tokio::time::timeout(Duration::from_millis(60), dom.wait_for_work()).await;
+        assert_eq!(registry.stats().interval_timer_count, 1);
+    }
+
+    #[test]
+    fn test_clear_all_marks_subscribers_without_counter_dirty() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        let (context, mut dirty) = dom.runtime().on_scope(ScopeId::ROOT, ReactiveContext::new);
+        registry.subscribe_to_refresh("never-refreshed", context);
+        assert_eq!(registry.get_refresh_count("never-refreshed"), 0);
+
+        registry.clear_all();
+
+        assert!(dirty.try_next().is_ok());
+        assert_eq!(registry.get_refresh_count("never-refreshed"), 1);
+    }
+
+    #[test]
+    fn test_trigger_refresh_many_marks_shared_subscriber_once() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        let (context, mut dirty) = dom.runtime().on_scope(ScopeId::ROOT, ReactiveContext::new);
+        for key in ["user:1", "user:2", "user:3"] {
+            registry.subscribe_to_refresh(key, context);
+        }
+
+        registry.trigger_refresh_many(&["user:1", "user:2", "user:3"]);
+
+        assert!(dirty.try_next().is_ok());
+        assert!(dirty.try_next().is_err());
+        assert_eq!(registry.get_refresh_count("user:2"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_frame_coalescing_marks_subscriber_once_per_frame() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        registry.set_frame_coalescing(true);
+        let (context, mut dirty) = dom.runtime().on_scope(ScopeId::ROOT, ReactiveContext::new);
+        registry.subscribe_to_refresh("ticker", context);
+        registry.subscribe_to_refresh("clock", context);
+
+        let runtime = dom.runtime();
+        let refresh_burst = || {
+            runtime.on_scope(ScopeId::ROOT, || {
+                for _ in 0..20 {
+                    registry.trigger_refresh("ticker");
+                }
+                registry.trigger_refresh("clock");
+            })
+        };
+
+        refresh_burst();
+        assert_eq!(registry.get_refresh_count("ticker"), 20);
+        assert!(dirty.try_next().is_err());
+
+        let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        assert!(dirty.try_next().is_ok());
+        assert!(dirty.try_next().is_err());
+
+        // The next burst waits for a frame of its own
+        refresh_burst();
+        assert!(dirty.try_next().is_err());
+        let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        assert!(dirty.try_next().is_ok());
+        assert!(dirty.try_next().is_err());
+        assert_eq!(registry.get_refresh_count("ticker"), 40);
+    }
+
+    #[test]
+    fn test_subscriber_count_tracks_subscribe_and_unsubscribe() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        let (first, second) = dom.runtime().on_scope(ScopeId::ROOT, || {
+            (ReactiveContext::new().0, ReactiveContext::new().0)
+        });
+
+        registry.subscribe_to_refresh("user", first);
+        registry.subscribe_to_refresh("user", second);
+        assert_eq!(registry.subscriber_count("user"), 2);
+
+        registry.unsubscribe_from_refresh("user", &first);
+        assert_eq!(registry.subscriber_count("user"), 1);
+
+        registry.unsubscribe_from_refresh("user", &second);
+        assert_eq!(registry.subscriber_count("user"), 0);
+        assert_eq!(registry.stats().context_count, 0);
+    }
+
+    #[test]
+    fn test_unmounted_key_is_forgotten_once_no_task_needs_it() {
+        let registry = RefreshRegistry::new();
+        registry.mount_key("user");
+        registry.mount_key("user");
+        registry.unmount_key("user");
+        assert_eq!(registry.mounted_count("user"), 1);
+
+        registry.unmount_key("user");
+        assert_eq!(registry.unmounted_for("user"), None);
+        assert!(registry.inner.mounted_hooks.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_introspection_reports_revalidations_and_tasks() {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+
+        let registry = RefreshRegistry::new();
+        assert!(registry.start_revalidation("user"));
+        assert_eq!(registry.revalidating_keys(), vec!["user".to_string()]);
+        registry.complete_revalidation("user");
+        assert!(registry.revalidating_keys().is_empty());
+
+        dom.runtime().on_scope(ScopeId::ROOT, || {
+            registry.start_interval_task("feed", Duration::from_secs(5), || {});
+            registry.start_stale_check_task("feed", Duration::from_secs(10), || {});
+        });
+
+        assert_eq!(
+            registry.active_interval_keys(),
+            vec![("feed".to_string(), Duration::from_secs(5))]
+        );
+        let mut task_keys = registry.periodic_task_keys();
+        task_keys.sort_by_key(|(_, task_type)| format!("{task_type:?}"));
+        assert_eq!(
+            task_keys,
+            vec![
+                ("feed".to_string(), TaskType::IntervalRefresh),
+                ("feed".to_string(), TaskType::StaleCheck),
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! # Scoped Providers
+//!
+//! Some data belongs to a part of the app rather than to the whole of it, e.g. the draft of
+//! the document open in an editor tab. [`ScopeBoundary`] marks a part of the component tree
+//! as a scope, and [`use_scoped_provider`] inside it caches a provider under the scope's key,
+//! so the same provider and parameter get an independent entry in every scope. The boundary
+//! drops all entries of its scope once it unmounts. This gives per-tab
+//! or per-document caches without setting up a named cache for each.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use dioxus::prelude::*;
+//! use dioxus_provider::prelude::*;
+//!
+//! #[provider]
+//! async fn fetch_draft() -> Result<String, String> {
+//!     Ok(String::new())
+//! }
+//!
+//! #[component]
+//! fn EditorTab(tab_id: u32) -> Element {
+//!     rsx! {
+//!         ScopeBoundary { scope: format!("tab-{tab_id}"), Editor {} }
+//!     }
+//! }
+//!
+//! #[component]
+//! fn Editor() -> Element {
+//!     // Every tab edits its own draft
+//!     let draft = use_scoped_provider(fetch_draft(), ());
+//!     rsx! {}
+//! }
+//! ```
+
+use dioxus::{core::use_drop, prelude::*};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use tracing::debug;
+
+use crate::{
+    cache::ProviderCache,
+    cancel::CancellationToken,
+    conditional::FetchOutcome,
+    global::{all_caches, ensure_global_providers},
+    hooks::{Provider, ProviderMetadata, ProviderState, use_provider},
+    param_utils::IntoProviderParam,
+    types::ProviderParamBounds,
+};
+
+/// Provider adapter that stores its inner provider's entries under a scope
+///
+/// The adapter keeps the inner provider's configuration and behavior, but prefixes its cache
+/// keys with the scope, so each scope caches the provider separately. Entries of a scope are
+/// dropped together with [`invalidate_scope`].
+#[derive(Clone, PartialEq)]
+pub struct ScopedProvider<P> {
+    inner: P,
+    scope: String,
+}
+
+impl<P> ScopedProvider<P> {
+    /// Wrap a provider so its entries are cached under `scope`
+    pub fn new(inner: P, scope: impl Into<String>) -> Self {
+        Self {
+            inner,
+            scope: scope.into(),
+        }
+    }
+
+    /// Get the scope the provider's entries are cached under
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+}
+
+/// Get the prefix of the cache keys of a scope's entries
+///
+/// The scope is quoted, so a scope never matches the keys of another scope it is a prefix of.
+fn scope_key_prefix(scope: &str) -> String {
+    format!("scope:{scope:?}:")
+}
+
+impl<P, Param> Provider<Param> for ScopedProvider<P>
+where
+    P: Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    type Output = P::Output;
+    type Error = P::Error;
+
+    async fn run(&self, param: Param) -> Result<Self::Output, Self::Error> {
+        self.inner.run(param).await
+    }
+
+    async fn run_with_cancel(
+        &self,
+        param: Param,
+        cancel: CancellationToken,
+    ) -> Result<Self::Output, Self::Error> {
+        self.inner.run_with_cancel(param, cancel).await
+    }
+
+    async fn run_conditional(
+        &self,
+        param: Param,
+        cancel: CancellationToken,
+    ) -> Result<FetchOutcome<Self::Output>, Self::Error> {
+        self.inner.run_conditional(param, cancel).await
+    }
+
+    fn id(&self, param: &Param) -> String {
+        format!("{}{}", scope_key_prefix(&self.scope), self.inner.id(param))
+    }
+
+    fn describe_param(&self, param: &Param) -> String {
+        self.inner.describe_param(param)
+    }
+
+    fn interval(&self) -> Option<Duration> {
+        self.inner.interval()
+    }
+
+    fn interval_when_visible_only(&self) -> bool {
+        self.inner.interval_when_visible_only()
+    }
+
+    fn cache_expiration(&self) -> Option<Duration> {
+        self.inner.cache_expiration()
+    }
+
+    fn stale_time(&self) -> Option<Duration> {
+        self.inner.stale_time()
+    }
+
+    fn error_cache_expiration(&self) -> Option<Duration> {
+        self.inner.error_cache_expiration()
+    }
+
+    fn error_fallback(&self) -> Option<Self::Output> {
+        self.inner.error_fallback()
+    }
+
+    fn check_interval(&self) -> Option<Duration> {
+        self.inner.check_interval()
+    }
+
+    fn dispose_delay(&self) -> Option<Duration> {
+        self.inner.dispose_delay()
+    }
+
+    fn max_concurrency(&self) -> Option<usize> {
+        self.inner.max_concurrency()
+    }
+
+    fn should_refetch(&self, entry_age: Duration, cached: &Self::Output) -> bool {
+        self.inner.should_refetch(entry_age, cached)
+    }
+
+    fn expiration_jitter(&self) -> Option<f64> {
+        self.inner.expiration_jitter()
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        self.inner.tags()
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.inner.type_name()
+    }
+
+    fn metadata(&self) -> ProviderMetadata {
+        self.inner.metadata()
+    }
+
+    fn contextualize_error(&self, key: &str, param: &Param, error: Self::Error) -> Self::Error {
+        self.inner.contextualize_error(key, param, error)
+    }
+
+    fn cache_result(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<Self::Output, Self::Error>,
+    ) -> bool {
+        self.inner.cache_result(cache, key, result)
+    }
+
+    fn cache_missed_result(
+        &self,
+        cache: &ProviderCache,
+        key: String,
+        result: Result<Self::Output, Self::Error>,
+    ) -> bool {
+        self.inner.cache_missed_result(cache, key, result)
+    }
+
+    fn merge_fetched(
+        &self,
+        cache: &ProviderCache,
+        key: &str,
+        result: Result<Self::Output, Self::Error>,
+    ) -> Result<Self::Output, Self::Error> {
+        self.inner.merge_fetched(cache, key, result)
+    }
+
+    fn approximate_size(&self, result: &Result<Self::Output, Self::Error>) -> Option<usize> {
+        self.inner.approximate_size(result)
+    }
+
+    fn entity_key(&self, param: &Param) -> Option<String> {
+        self.inner.entity_key(param)
+    }
+
+    fn persistable(&self) -> bool {
+        self.inner.persistable()
+    }
+
+    fn ssr(&self) -> bool {
+        self.inner.ssr()
+    }
+
+    fn cacheable(&self) -> bool {
+        self.inner.cacheable()
+    }
+
+    fn cache_name(&self) -> Option<&'static str> {
+        self.inner.cache_name()
+    }
+}
+
+/// Hook for using a provider cached separately for each scope
+///
+/// Works like [`use_provider`], but the scope of the nearest [`ScopeBoundary`] is part of
+/// the cache key: the same provider and parameter used under two scopes fetch and cache
+/// independent values, while components under the same scope share one entry. The
+/// component follows the boundary when its scope changes.
+///
+/// # Panics
+///
+/// If the component is not inside a [`ScopeBoundary`].
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_provider::prelude::*;
+///
+/// #[provider]
+/// async fn fetch_selection() -> Result<Vec<u32>, String> {
+///     Ok(Vec::new())
+/// }
+///
+/// #[component]
+/// fn Document(document_id: String) -> Element {
+///     rsx! {
+///         ScopeBoundary { scope: document_id, Selection {} }
+///     }
+/// }
+///
+/// #[component]
+/// fn Selection() -> Element {
+///     let selection = use_scoped_provider(fetch_selection(), ());
+///     rsx! {}
+/// }
+/// ```
+pub fn use_scoped_provider<P, Args>(
+    provider: P,
+    args: Args,
+) -> Signal<ProviderState<P::Output, P::Error>>
+where
+    P: Provider<Args::Param> + Send,
+    Args: IntoProviderParam,
+{
+    let BoundaryScope(scope) = try_use_context::<BoundaryScope>()
+        .expect("use_scoped_provider must be called inside a ScopeBoundary");
+    // Reading the scope re-renders the component when the boundary switches to another one
+    let scope = scope.read().clone();
+    use_provider(ScopedProvider::new(provider, scope), args)
+}
+
+/// The scope of the nearest [`ScopeBoundary`], provided to its descendants
+#[derive(Clone, Copy)]
+struct BoundaryScope(Memo<String>);
+
+/// Drop the cached entries of every provider used under a scope, in every cache
+///
+/// Components still showing one of the entries refetch it. Returns the number of entries
+/// removed.
+pub fn invalidate_scope(scope: &str) -> usize {
+    ensure_global_providers();
+    let prefix = scope_key_prefix(scope);
+    let mut invalidated = 0;
+    for (cache, _) in all_caches() {
+        invalidated += cache.invalidate_prefix(&prefix).len();
+    }
+    debug!(
+        "🗑️ [SCOPE] Invalidated {} entries of scope: {}",
+        invalidated, scope
+    );
+    invalidated
+}
+
+/// Marks a part of the component tree as a scope, dropping its cached entries on exit
+///
+/// Descendants using [`use_scoped_provider`] cache their providers under `scope`. Once the
+/// boundary unmounts, or its `scope` changes, every entry cached under the scope it left is
+/// invalidated, so a reopened tab or document starts from fresh data and closed ones don't
+/// hold memory.
+#[component]
+pub fn ScopeBoundary(scope: String, children: Element) -> Element {
+    let current = use_memo(use_reactive!(|scope| scope));
+    use_context_provider(|| BoundaryScope(current));
+    let entered: Rc<RefCell<String>> = use_hook(|| Rc::new(RefCell::new(current.peek().clone())));
+
+    // Switching to another scope leaves the previous one, once the switch has rendered
+    {
+        let entered = entered.clone();
+        use_effect(move || {
+            let scope = current();
+            let previous = entered.replace(scope.clone());
+            if previous != scope {
+                invalidate_scope(&previous);
+            }
+        });
+    }
+
+    use_drop(move || {
+        invalidate_scope(&entered.borrow());
+    });
+
+    children
+}
@@ -0,0 +1,72 @@
+//! # Server Function Providers
+//!
+//! Dioxus server functions return `Result<T, ServerFnError>`. Enabled with the `fullstack`
+//! feature, `#[provider(server_fn)]` lets a provider body call them with `?` and return
+//! their result as is: the `ServerFnError` is converted into a [`ProviderError`], which is
+//! `Clone + PartialEq` and can be cached like any other provider error.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use dioxus::prelude::*;
+//! use dioxus_provider::prelude::*;
+//!
+//! #[server]
+//! async fn load_profile(id: u32) -> Result<String, ServerFnError> {
+//!     Ok(format!("Profile {id}"))
+//! }
+//!
+//! #[provider(server_fn)]
+//! async fn fetch_profile(id: u32) -> Result<String, ServerFnError> {
+//!     let profile = load_profile(id).await?;
+//!     Ok(profile.to_uppercase())
+//! }
+//!
+//! #[component]
+//! fn Profile(id: u32) -> Element {
+//!     // The provider's error type is `ProviderError`
+//!     let profile = use_provider(fetch_profile(), id);
+//!     rsx! {}
+//! }
+//! ```
+
+pub use dioxus_fullstack::{ServerFnError, server_fn::error::ServerFnErrorErr};
+
+use crate::errors::ProviderError;
+
+/// HTTP status of the response Dioxus fullstack sends for a failed server function
+const SERVER_ERROR_STATUS: u16 = 500;
+
+/// Convert the error of a server function into a [`ProviderError`]
+///
+/// Errors returned by the server function or its middleware keep their message as
+/// [`ProviderError::ServerFn`], failures to reach the server become
+/// [`ProviderError::Network`] and failures to encode or decode the call become
+/// [`ProviderError::DataParsing`]. Used by `#[provider(server_fn)]`.
+pub fn into_provider_error(error: ServerFnError) -> ProviderError {
+    match error {
+        ServerFnError::ServerError(message) => ProviderError::ServerFn {
+            status: SERVER_ERROR_STATUS,
+            message,
+        },
+        ServerFnError::CommunicationError(error) => match error {
+            ServerFnErrorErr::ServerError(message) | ServerFnErrorErr::MiddlewareError(message) => {
+                ProviderError::ServerFn {
+                    status: SERVER_ERROR_STATUS,
+                    message,
+                }
+            }
+            ServerFnErrorErr::Request(error) => ProviderError::Network(error),
+            ServerFnErrorErr::Serialization(error)
+            | ServerFnErrorErr::Deserialization(error)
+            | ServerFnErrorErr::Args(error) => ProviderError::DataParsing(error),
+            error => ProviderError::Generic(error.to_string()),
+        },
+    }
+}
+
+impl From<ServerFnError> for ProviderError {
+    fn from(error: ServerFnError) -> Self {
+        into_provider_error(error)
+    }
+}
@@ -28,16 +28,74 @@ impl ReactiveContext {
     }
 }
 
+/// Stable string key identifying a provider parameter in the cache
+///
+/// Implemented for every `Hash` type by hashing the value. Parameters that can't implement
+/// `Hash`, such as structs of floats or opaque handles from other crates, can implement this
+/// trait instead, as long as equal parameters produce equal keys. A type implementing
+/// `ProviderKey` by hand can't also implement `Hash`.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dioxus_provider::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// pub struct PriceRange {
+///     min: f64,
+///     max: f64,
+/// }
+///
+/// impl ProviderKey for PriceRange {
+///     fn provider_key(&self) -> String {
+///         format!("{}..{}", self.min, self.max)
+///     }
+/// }
+///
+/// #[provider]
+/// async fn fetch_products(range: PriceRange) -> Result<Vec<String>, String> {
+///     Ok(vec![])
+/// }
+/// ```
+pub trait ProviderKey {
+    /// Get the key for this parameter
+    fn provider_key(&self) -> String;
+}
+
+impl<T: std::hash::Hash + ?Sized> ProviderKey for T {
+    fn provider_key(&self) -> String {
+        use std::hash::Hasher;
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        format!("{:x}", hasher.finish())
+    }
+}
+
 /// Common trait bounds for provider parameters
 pub trait ProviderParamBounds:
-    Clone + PartialEq + std::hash::Hash + std::fmt::Debug + Send + Sync + 'static
+    Clone + PartialEq + ProviderKey + std::fmt::Debug + Send + Sync + 'static
 {
 }
 impl<T> ProviderParamBounds for T where
-    T: Clone + PartialEq + std::hash::Hash + std::fmt::Debug + Send + Sync + 'static
+    T: Clone + PartialEq + ProviderKey + std::fmt::Debug + Send + Sync + 'static
 {
 }
 
+/// Builds the cache key of a provider type from a parameter key
+///
+/// Used by the default [`Provider::id`](crate::hooks::Provider::id), and by disk persistence
+/// to rebuild the keys of entries saved by an earlier build of the app.
+#[doc(hidden)]
+pub fn provider_type_key<P: 'static>(param_key: &str) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::any::TypeId::of::<P>().hash(&mut hasher);
+    param_key.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
 /// Common trait bounds for provider output types
 pub trait ProviderOutputBounds: Clone + PartialEq + Send + Sync + 'static {}
 impl<T> ProviderOutputBounds for T where T: Clone + PartialEq + Send + Sync + 'static {}
@@ -45,3 +103,95 @@ impl<T> ProviderOutputBounds for T where T: Clone + PartialEq + Send + Sync + 's
 /// Common trait bounds for provider error types
 pub trait ProviderErrorBounds: Clone + PartialEq + Send + Sync + 'static {}
 impl<T> ProviderErrorBounds for T where T: Clone + PartialEq + Send + Sync + 'static {}
+
+/// Compile-time check that a provider's output type can be cached
+///
+/// Emitted by `#[provider]` for the output type, so a missing bound is reported at the
+/// function's signature instead of inside the generated `Provider` impl.
+#[diagnostic::on_unimplemented(
+    message = "Provider output type `{Self}` must implement Clone + PartialEq + Send + Sync",
+    label = "`{Self}` can't be cached as a provider output",
+    note = "cached results are shared between components and background tasks, so they are cloned, compared and sent across threads"
+)]
+pub trait ProviderOutputType {}
+
+#[diagnostic::do_not_recommend]
+impl<T: ProviderOutputBounds> ProviderOutputType for T {}
+
+/// Compile-time check that a provider's error type can be cached
+///
+/// Emitted by `#[provider]` for the error type, see [`ProviderOutputType`].
+#[diagnostic::on_unimplemented(
+    message = "Provider error type `{Self}` must implement Clone + PartialEq + Send + Sync",
+    label = "`{Self}` can't be cached as a provider error",
+    note = "errors are cached like outputs, so they are cloned, compared and sent across threads"
+)]
+pub trait ProviderErrorType {}
+
+#[diagnostic::do_not_recommend]
+impl<T: ProviderErrorBounds> ProviderErrorType for T {}
+
+/// Compile-time check that a composed provider takes the composing function's parameters
+///
+/// Emitted by `compose = [...]` on providers and mutations, which run every composed provider
+/// with a clone of the function's parameters (a tuple if there are several).
+#[diagnostic::on_unimplemented(
+    message = "Composed provider `{Self}` doesn't take this function's parameters `{Param}`",
+    label = "`{Self}` is not a provider of `{Param}`",
+    note = "composed providers run with the composing function's parameters, so their parameter types must match"
+)]
+pub trait ComposedProviderParam<Param> {}
+
+#[diagnostic::do_not_recommend]
+impl<P, Param> ComposedProviderParam<Param> for P
+where
+    P: crate::hooks::Provider<Param>,
+    Param: ProviderParamBounds,
+{
+}
+
+/// Compile-time check that a provider invalidated by a mutation takes the given parameter
+///
+/// Emitted by `#[mutation(invalidates = [...])]`. Providers listed without arguments are
+/// invalidated for `()`, so providers taking parameters must be listed as calls that pick
+/// them from the mutation's inputs.
+#[diagnostic::on_unimplemented(
+    message = "Provider `{Self}` can't be invalidated with a `{Param}` parameter",
+    label = "`{Self}` is not a provider of `{Param}`",
+    note = "pass the provider's parameters from the mutation's inputs, e.g. `invalidates = [fetch_user(id)]`"
+)]
+pub trait InvalidatedProvider<Param> {
+    /// Gets the cache key invalidated for `param`
+    fn invalidated_key(&self, param: &Param) -> String;
+}
+
+#[diagnostic::do_not_recommend]
+impl<P, Param> InvalidatedProvider<Param> for P
+where
+    P: crate::hooks::Provider<Param>,
+    Param: ProviderParamBounds,
+{
+    fn invalidated_key(&self, param: &Param) -> String {
+        self.id(param)
+    }
+}
+
+/// Compile-time check that a composed provider's error converts into the composing provider's error
+///
+/// Emitted by `#[provider(compose = [...])]` so that `?` works on composed results. Implemented
+/// for every provider whose error type the target error implements `From` for.
+#[diagnostic::on_unimplemented(
+    message = "Composed provider `{Self}` error type must be convertible via From into this provider's error `{E}`",
+    label = "error type not convertible into `{E}`",
+    note = "implement `From` for `{E}` so `?` works on the composed result"
+)]
+pub trait ComposedProviderError<Param, E> {}
+
+#[diagnostic::do_not_recommend]
+impl<P, Param, E> ComposedProviderError<Param, E> for P
+where
+    P: crate::hooks::Provider<Param>,
+    Param: ProviderParamBounds,
+    E: From<P::Error>,
+{
+}
@@ -0,0 +1,106 @@
+// Tests for #[provider(accumulate = ...)] merging fetched items into one bounded cached list
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub id: u32,
+    pub fetch: u32,
+}
+
+fn event_id(event: &Event) -> u32 {
+    event.id
+}
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+// Each fetch returns the newest event again and one new event
+#[provider(accumulate = event_id, max_items = 4)]
+async fn fetch_events() -> Result<Vec<Event>, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    Ok(vec![
+        Event { id: fetch, fetch },
+        Event {
+            id: fetch + 1,
+            fetch,
+        },
+    ])
+}
+
+static ALERT_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+// Each fetch returns one new event, except the second which fails
+#[provider(accumulate = event_id)]
+async fn fetch_alerts() -> Result<Vec<Event>, String> {
+    let fetch = ALERT_FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    if fetch == 2 {
+        return Err("alerts unavailable".to_string());
+    }
+    Ok(vec![Event { id: fetch, fetch }])
+}
+
+// The id and fetch of each shown event
+fn shown_events() -> Option<Vec<(u32, u32)>> {
+    match &*use_provider(fetch_events(), ()).read() {
+        ProviderState::Success(events) => {
+            Some(events.iter().map(|event| (event.id, event.fetch)).collect())
+        }
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_fetched_items_accumulate_into_bounded_list() {
+    let (mut dom, renders) = common::record(shown_events);
+    let shown = || renders.last().flatten().unwrap_or_default();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(shown(), [(1, 1), (2, 1)]);
+
+    // A refetched item replaces its cached copy, new items are appended
+    fetch_provider(fetch_events(), ()).await.unwrap().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(shown(), [(1, 1), (2, 2), (3, 2)]);
+
+    fetch_provider(fetch_events(), ()).await.unwrap().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(shown(), [(1, 1), (2, 2), (3, 3), (4, 3)]);
+
+    // The list keeps the newest four items
+    fetch_provider(fetch_events(), ()).await.unwrap().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(shown(), [(2, 2), (3, 3), (4, 4), (5, 4)]);
+
+    let cache = get_global_cache().unwrap();
+    let cached = cache
+        .get::<Result<Vec<Event>, String>>(&fetch_events().id(&()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.len(), 4);
+    assert_eq!(cached.last(), Some(&Event { id: 5, fetch: 4 }));
+}
+
+#[tokio::test]
+async fn test_failed_fetch_keeps_accumulated_items() {
+    // The ids of the cached events after another fetch
+    let fetch_ids = || async {
+        let _ = fetch_provider(fetch_alerts(), ()).await.unwrap();
+        let cache = get_global_cache().unwrap();
+        cache
+            .get::<Result<Vec<Event>, String>>(&fetch_alerts().id(&()))
+            .unwrap()
+            .map(|events| events.iter().map(|event| event.id).collect::<Vec<_>>())
+    };
+    assert_eq!(fetch_ids().await, Ok(vec![1]));
+
+    // The failed poll leaves the accumulated list in place
+    assert_eq!(fetch_ids().await, Ok(vec![1]));
+    assert_eq!(fetch_ids().await, Ok(vec![1, 3]));
+}
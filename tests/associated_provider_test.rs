@@ -0,0 +1,52 @@
+// Tests for #[providers] declaring providers as associated functions of a type
+
+use dioxus_provider::prelude::*;
+
+pub struct Api;
+
+#[providers]
+impl Api {
+    const PREFIX: &'static str = "user";
+
+    fn label(id: u32) -> String {
+        format!("{} {id}", Self::PREFIX)
+    }
+
+    #[provider(cache_expiration = "5min")]
+    pub async fn fetch_user(id: u32) -> Result<String, String> {
+        Ok(Self::label(id))
+    }
+
+    #[provider]
+    pub async fn fetch_count() -> Result<u32, String> {
+        Ok(3)
+    }
+}
+
+pub struct Admin;
+
+#[providers]
+impl Admin {
+    #[provider]
+    pub async fn fetch_user(id: u32) -> Result<String, String> {
+        Ok(format!("admin {id}"))
+    }
+}
+
+#[tokio::test]
+async fn test_associated_providers_run() {
+    assert_eq!(Api::fetch_user().run(7).await, Ok("user 7".to_string()));
+    assert_eq!(Api::fetch_count().run(()).await, Ok(3));
+    assert_eq!(
+        Api::fetch_user().cache_expiration(),
+        Some(std::time::Duration::from_secs(300))
+    );
+    assert_eq!(Api::label(1), "user 1");
+}
+
+#[test]
+fn test_associated_providers_have_distinct_keys() {
+    let _: ApiFetchUser = Api::fetch_user();
+    let _: AdminFetchUser = Admin::fetch_user();
+    assert_ne!(Api::fetch_user().id(&1), Admin::fetch_user().id(&1));
+}
@@ -0,0 +1,119 @@
+// Tests for #[provider(batch_window = ...)] coalescing cache misses into one run_batch call
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+static BATCHES: Mutex<Vec<Vec<u32>>> = Mutex::new(Vec::new());
+static SINGLE_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(batch_window = "10ms")]
+async fn fetch_item(id: u32) -> Result<String, String> {
+    SINGLE_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("single {id}"))
+}
+
+impl BatchProvider<u32> for FetchItem {
+    async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+        BATCHES.lock().unwrap().push(ids.to_vec());
+        ids.iter()
+            .map(|id| match id {
+                13 => Err("item 13 is missing".to_string()),
+                id => Ok(format!("item {id}")),
+            })
+            .collect()
+    }
+}
+
+static PART_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(batch_window = "10ms")]
+async fn fetch_part(id: u32) -> Result<String, String> {
+    PART_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("single part {id}"))
+}
+
+/// Returns results for the first two parameters only
+impl BatchProvider<u32> for FetchPart {
+    async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+        ids.iter()
+            .take(2)
+            .map(|id| Ok(format!("part {id}")))
+            .collect()
+    }
+}
+
+thread_local! {
+    static STATES: RefCell<HashMap<u32, ProviderState<String, String>>> = RefCell::new(HashMap::new());
+}
+
+#[component]
+fn Item(id: u32) -> Element {
+    let item = use_provider(fetch_item(), id);
+    STATES.with(|states| states.borrow_mut().insert(id, item.read().clone()));
+    rsx! {}
+}
+
+#[tokio::test]
+async fn test_misses_within_window_share_one_batch() {
+    let mut dom = common::mount(|| {
+        rsx! {
+            for id in 0..20u32 {
+                Item { key: "{id}", id }
+            }
+        }
+    });
+    run_for(&mut dom, Duration::from_millis(200)).await;
+
+    let batches = BATCHES.lock().unwrap().clone();
+    assert_eq!(batches.len(), 1);
+    let mut ids = batches[0].clone();
+    ids.sort();
+    assert_eq!(ids, (0..20).collect::<Vec<_>>());
+    assert_eq!(SINGLE_FETCHES.load(Ordering::SeqCst), 0);
+
+    // Each component gets its own result, and a failed id doesn't fail the others
+    STATES.with(|states| {
+        let states = states.borrow();
+        assert_eq!(states[&4], ProviderState::Success("item 4".to_string()));
+        assert_eq!(
+            states[&13],
+            ProviderState::Error("item 13 is missing".to_string())
+        );
+        assert_eq!(states[&19], ProviderState::Success("item 19".to_string()));
+    });
+
+    // A window holding a single fetch runs the provider's own body
+    assert_eq!(
+        fetch_provider(fetch_item(), 42).await.unwrap(),
+        Ok("single 42".to_string())
+    );
+    assert_eq!(BATCHES.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_short_batch_fetches_missing_results_on_their_own() {
+    let parts = futures::future::join_all(
+        (0..4u32).map(|id| async move { fetch_provider(fetch_part(), id).await.unwrap() }),
+    )
+    .await;
+
+    assert_eq!(PART_FETCHES.load(Ordering::SeqCst), 2);
+    let batched = parts
+        .iter()
+        .filter(|part| part.as_ref().is_ok_and(|part| !part.starts_with("single")))
+        .count();
+    assert_eq!(batched, 2);
+    assert_eq!(parts.iter().filter(|part| part.is_ok()).count(), 4);
+}
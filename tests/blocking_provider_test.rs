@@ -0,0 +1,60 @@
+// Tests for #[provider(blocking)] running CPU-bound bodies on the blocking thread pool
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[provider(blocking)]
+fn sum_primes(limit: u32) -> Result<u64, String> {
+    RUNS.fetch_add(1, Ordering::SeqCst);
+    // Hold the thread like a long parse or image decode would
+    std::thread::sleep(Duration::from_millis(150));
+    Ok((2..limit)
+        .filter(|n| (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+        .map(u64::from)
+        .sum())
+}
+
+#[tokio::test]
+async fn test_blocking_provider_does_not_block_other_tasks() {
+    let ticks = Arc::new(AtomicU32::new(0));
+    let ticker = tokio::spawn({
+        let ticks = ticks.clone();
+        async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                ticks.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    });
+
+    let (mut dom, sum) = common::record(|| use_provider(sum_primes(), 100u32).read().clone());
+
+    // The executor keeps running other tasks while the body holds its thread
+    run_for(&mut dom, Duration::from_millis(80)).await;
+    assert!(matches!(sum.last(), Some(ProviderState::Loading { .. })));
+    assert!(ticks.load(Ordering::SeqCst) >= 3);
+
+    run_for(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(sum.last(), Some(ProviderState::Success(1060)));
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // The result is cached like any other provider's
+    let (mut dom, sum) = common::record(|| use_provider(sum_primes(), 100u32).read().clone());
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(sum.last(), Some(ProviderState::Success(1060)));
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    ticker.abort();
+}
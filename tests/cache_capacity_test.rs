@@ -0,0 +1,65 @@
+// Tests for the cache capacity stats counting evicted and expired entries
+
+use dioxus_provider::cache::{CacheCapacityStats, CacheConfig, ProviderCache};
+use std::time::Duration;
+
+#[test]
+fn test_filling_cache_counts_evictions() {
+    let cache = ProviderCache::with_config(CacheConfig {
+        max_size: 3,
+        default_entry_size: 100,
+        ..CacheConfig::default()
+    });
+    for id in 0..5u32 {
+        cache.set(format!("user-{id}"), id);
+    }
+    assert_eq!(cache.total_memory_estimate(), 500);
+
+    assert_eq!(cache.evict_lru_entries(3), 2);
+    assert_eq!(cache.evict_lru_entries(3), 0);
+    cache.set("user-5".to_string(), 5u32);
+    assert_eq!(cache.evict_lru_entries(3), 1);
+
+    assert_eq!(
+        cache.capacity_stats(),
+        CacheCapacityStats {
+            entry_count: 3,
+            max_size: 3,
+            memory_estimate: 300,
+            max_bytes: None,
+            evictions: 3,
+            expirations: 0,
+        }
+    );
+
+    // The counts survive clearing the cache and are shared with its clones
+    cache.clear();
+    assert_eq!(cache.clone().eviction_count(), 3);
+    assert_eq!(cache.total_memory_estimate(), 0);
+}
+
+#[test]
+fn test_expired_reads_count_expirations() {
+    let cache = ProviderCache::new();
+    cache.set("session".to_string(), 1u32);
+    cache.set("profile".to_string(), 2u32);
+    std::thread::sleep(Duration::from_millis(20));
+
+    let expiration = Some(Duration::from_millis(10));
+    assert_eq!(
+        cache.get_with_expiration::<u32>("session", expiration),
+        None
+    );
+    // Already removed, so it isn't counted twice
+    assert_eq!(
+        cache.get_with_expiration::<u32>("session", expiration),
+        None
+    );
+    assert_eq!(
+        cache.get_with_expiration::<u32>("profile", Some(Duration::from_secs(60))),
+        Some(2)
+    );
+
+    assert_eq!(cache.expiration_count(), 1);
+    assert_eq!(cache.eviction_count(), 0);
+}
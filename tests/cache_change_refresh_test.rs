@@ -0,0 +1,115 @@
+// Tests for components re-rendering when the cache is changed outside of hooks
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    cache::ProviderCache, global::get_global_cache, prelude::*, refresh::RefreshRegistry,
+};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_counter() -> Result<u32, String> {
+    Ok(FETCHES.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+#[tokio::test]
+async fn test_cache_changes_rerender_subscribed_components() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_counter().id(&());
+
+    let (mut dom, states) = common::record(|| use_provider(fetch_counter(), ()).read().clone());
+    let last_state = || states.last();
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(1)));
+
+    // A direct write is shown without refetching
+    assert!(cache.set(key.clone(), Ok::<u32, String>(42)));
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(42)));
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+    // Every removal path makes the component refetch
+    cache.invalidate(&key);
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(2)));
+
+    cache.clear();
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(3)));
+
+    assert_eq!(cache.evict_lru_entries(0), 1);
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(4)));
+
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    assert!(
+        cache
+            .get_with_expiration::<Result<u32, String>>(&key, Some(Duration::from_millis(1)))
+            .is_none()
+    );
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(5)));
+
+    let updated = cache.set_many([(key.clone(), Ok::<u32, String>(7))]);
+    assert_eq!(updated, vec![key.clone()]);
+    settle(&mut dom).await;
+    assert_eq!(last_state(), Some(ProviderState::Success(7)));
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn test_connected_cache_refreshes_on_clear_and_invalidate() {
+    let cache = ProviderCache::new();
+    let registry = RefreshRegistry::new();
+    cache.connect_refresh_registry(&registry);
+    cache.set("a".to_string(), 1u32);
+    cache.set("b".to_string(), 2u32);
+    assert_eq!(registry.get_refresh_count("a"), 1);
+
+    cache.clear();
+    assert_eq!(registry.get_refresh_count("a"), 2);
+    assert_eq!(registry.get_refresh_count("b"), 2);
+
+    cache.invalidate("c");
+    assert_eq!(registry.get_refresh_count("c"), 1);
+}
+
+#[test]
+fn test_invalidate_reports_whether_an_entry_was_removed() {
+    let cache = ProviderCache::new();
+    let registry = RefreshRegistry::new();
+    cache.connect_refresh_registry(&registry);
+    cache.set("present".to_string(), 1u32);
+
+    assert!(cache.invalidate("present"));
+    assert!(!cache.invalidate("present"));
+    assert!(!cache.invalidate("absent"));
+    assert_eq!(cache.size(), 0);
+
+    // Subscribers are refreshed either way, unlike with `remove`
+    assert_eq!(registry.get_refresh_count("present"), 3);
+    assert_eq!(registry.get_refresh_count("absent"), 1);
+    assert!(!cache.remove("absent"));
+    assert_eq!(registry.get_refresh_count("absent"), 1);
+}
+
+#[test]
+fn test_connected_cache_does_not_keep_registry_alive() {
+    let cache = ProviderCache::new();
+    let registry = RefreshRegistry::new();
+    let weak = registry.downgrade();
+    cache.connect_refresh_registry(&registry);
+    assert!(weak.upgrade().is_some());
+
+    drop(registry);
+    assert!(weak.upgrade().is_none());
+    cache.set("a".to_string(), 1u32);
+}
@@ -0,0 +1,32 @@
+// Tests for clear_provider_cache and invalidate_provider called outside of any component
+
+use dioxus_provider::{
+    global::{get_global_cache, get_global_refresh_registry},
+    prelude::*,
+};
+
+#[provider]
+async fn fetch_score(id: u32) -> Result<u32, String> {
+    Ok(id * 10)
+}
+
+#[test]
+fn free_functions_work_without_hook_context() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let registry = get_global_refresh_registry().unwrap();
+
+    let first_key = fetch_score().id(&1);
+    let second_key = fetch_score().id(&2);
+    cache.set(first_key.clone(), Ok::<u32, String>(10));
+    cache.set(second_key.clone(), Ok::<u32, String>(20));
+
+    // Writing the entry and invalidating it each refresh its subscribers
+    invalidate_provider(fetch_score(), 1).unwrap();
+    assert!(cache.get::<Result<u32, String>>(&first_key).is_none());
+    assert_eq!(cache.get::<Result<u32, String>>(&second_key), Some(Ok(20)));
+    assert_eq!(registry.get_refresh_count(&first_key), 2);
+
+    clear_provider_cache();
+    assert_eq!(cache.size(), 0);
+}
@@ -0,0 +1,67 @@
+// Tests for ProviderCache::watch and ProviderCache::changes streaming cache changes
+
+use dioxus_provider::cache::ProviderCache;
+use futures::StreamExt;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_watch_yields_values_on_set_and_invalidate() {
+    let cache = ProviderCache::new();
+    cache.set("user".to_string(), "alice".to_string());
+    let mut watch = Box::pin(cache.watch::<String>("user"));
+    assert_eq!(watch.next().await, Some(Some("alice".to_string())));
+
+    // Other keys and unchanged writes are not reported
+    cache.set("other".to_string(), "x".to_string());
+    cache.set("user".to_string(), "alice".to_string());
+    cache.set("user".to_string(), "bob".to_string());
+    assert_eq!(watch.next().await, Some(Some("bob".to_string())));
+
+    cache.invalidate("user");
+    assert_eq!(watch.next().await, Some(None));
+
+    cache.set("user".to_string(), "carol".to_string());
+    assert_eq!(watch.next().await, Some(Some("carol".to_string())));
+
+    // An entry holding another type reads as None
+    cache.set("user".to_string(), 42u32);
+    assert_eq!(watch.next().await, Some(None));
+    assert!(
+        tokio::time::timeout(Duration::from_millis(20), watch.next())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_dropped_watch_stops_watching() {
+    let cache = ProviderCache::new();
+    let first = cache.watch::<u32>("count");
+    let mut second = Box::pin(cache.watch::<u32>("count"));
+    assert_eq!(second.next().await, Some(None));
+    drop(first);
+
+    cache.set("count".to_string(), 1u32);
+    assert_eq!(second.next().await, Some(Some(1)));
+}
+
+#[tokio::test]
+async fn test_changes_yields_changed_keys() {
+    let cache = ProviderCache::new();
+    let mut changes = Box::pin(cache.changes());
+
+    cache.set("user".to_string(), "alice".to_string());
+    cache.set("user".to_string(), "alice".to_string());
+    cache.set("team".to_string(), "core".to_string());
+    cache.clear();
+    assert_eq!(changes.next().await, Some(vec!["user".to_string()]));
+    assert_eq!(changes.next().await, Some(vec!["team".to_string()]));
+    let mut cleared = changes.next().await.unwrap();
+    cleared.sort();
+    assert_eq!(cleared, vec!["team".to_string(), "user".to_string()]);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(20), changes.next())
+            .await
+            .is_err()
+    );
+}
@@ -0,0 +1,47 @@
+// Tests for #[provider(with_cancel)] tripping the token when a fetch is cancelled
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{cancel::CancellationToken, prelude::*};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+static OBSERVED_CANCEL: AtomicBool = AtomicBool::new(false);
+
+#[provider(with_cancel)]
+async fn fetch_export(job_id: u32, cancel: CancellationToken) -> Result<String, String> {
+    STARTED.store(true, Ordering::SeqCst);
+
+    // Background work owned by the fetch stops as soon as the fetch is cancelled
+    let watcher = cancel.clone();
+    tokio::spawn(async move {
+        watcher.cancelled().await;
+        OBSERVED_CANCEL.store(true, Ordering::SeqCst);
+    });
+
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    Ok(format!("export {}", job_id))
+}
+
+#[tokio::test]
+async fn test_cancelling_fetch_trips_token() {
+    init_global_providers().unwrap();
+
+    let (mut dom, export) = common::record(|| use_provider(fetch_export(), 1u32));
+    let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+    assert!(STARTED.load(Ordering::SeqCst));
+    assert!(!OBSERVED_CANCEL.load(Ordering::SeqCst));
+
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || match *export.last().unwrap().peek() {
+            ProviderState::Loading { task } => task.cancel(),
+            _ => panic!("fetch should still be in flight"),
+        });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(OBSERVED_CANCEL.load(Ordering::SeqCst));
+}
@@ -0,0 +1,62 @@
+// Tests for check_interval removing expired entries close to their expiration
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{cache::CacheConfig, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static PRECISE_FETCHES: AtomicU32 = AtomicU32::new(0);
+static CONFIGURED_FETCHES: AtomicU32 = AtomicU32::new(0);
+static DEFAULT_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(cache_expiration = "200ms", check_interval = "10ms")]
+async fn fetch_precise() -> Result<u32, String> {
+    Ok(PRECISE_FETCHES.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+// Checked at the interval of its cache's configuration
+#[provider(cache_expiration = "200ms", cache = "precise")]
+async fn fetch_configured() -> Result<u32, String> {
+    Ok(CONFIGURED_FETCHES.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+// Checked every second, the shortest default interval
+#[provider(cache_expiration = "200ms")]
+async fn fetch_default() -> Result<u32, String> {
+    Ok(DEFAULT_FETCHES.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+fn fetches() -> [u32; 3] {
+    [&PRECISE_FETCHES, &CONFIGURED_FETCHES, &DEFAULT_FETCHES].map(|f| f.load(Ordering::SeqCst))
+}
+
+#[tokio::test]
+async fn test_expiration_fires_close_to_ttl() {
+    init_global_providers().unwrap();
+    init_named_cache(
+        "precise",
+        CacheConfig {
+            check_interval: Some(Duration::from_millis(10)),
+            ..CacheConfig::default()
+        },
+    );
+
+    let mut dom = common::mount(|| {
+        let _precise = use_provider(fetch_precise(), ());
+        let _configured = use_provider(fetch_configured(), ());
+        let _default = use_provider(fetch_default(), ());
+        rsx! {}
+    });
+    run_for(&mut dom, Duration::from_millis(170)).await;
+    assert_eq!(fetches(), [1, 1, 1]);
+
+    // Tight checks remove the entries within a few milliseconds of expiring, and the
+    // components refetch them; the default check hasn't run yet
+    run_for(&mut dom, Duration::from_millis(80)).await;
+    assert_eq!(fetches(), [2, 2, 1]);
+}
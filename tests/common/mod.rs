@@ -0,0 +1,118 @@
+// Helpers shared by the integration tests
+
+#![allow(dead_code)]
+
+use dioxus::prelude::*;
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+/// Type alias for the body of the root component built by [`mount`]
+type RenderFn = Rc<dyn Fn() -> Element>;
+
+/// Root component running the closure passed to [`mount`]
+#[allow(non_snake_case)]
+fn Root(render: RenderFn) -> Element {
+    render()
+}
+
+/// Builds a `VirtualDom` whose root component runs `render`, and renders it once
+///
+/// Hooks called by `render` belong to the root scope, so a test can write its app as a
+/// closure instead of a `thread_local` plus an `App` component.
+pub fn mount(render: impl Fn() -> Element + 'static) -> VirtualDom {
+    let render: RenderFn = Rc::new(render);
+    let mut dom = VirtualDom::new_with_props(Root, render);
+    dom.rebuild_in_place();
+    dom
+}
+
+/// Mounts a root component that runs `hook` and records its value on every render
+pub fn record<T: 'static>(hook: impl Fn() -> T + 'static) -> (VirtualDom, Renders<T>) {
+    record_with(move || (hook(), rsx! {}))
+}
+
+/// Like [`record`], but `render` also returns the element the root component shows
+pub fn record_with<T: 'static>(
+    render: impl Fn() -> (T, Element) + 'static,
+) -> (VirtualDom, Renders<T>) {
+    let renders = Renders::default();
+    let recorder = renders.clone();
+    let dom = mount(move || {
+        let (value, element) = render();
+        recorder.push(value);
+        element
+    });
+    (dom, renders)
+}
+
+/// The values recorded by a test component, one per render
+pub struct Renders<T>(Rc<RefCell<Vec<T>>>);
+
+impl<T> Default for Renders<T> {
+    fn default() -> Self {
+        Self(Rc::default())
+    }
+}
+
+impl<T> Clone for Renders<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Renders<T> {
+    /// Records a value
+    pub fn push(&self, value: T) {
+        self.0.borrow_mut().push(value);
+    }
+
+    /// Takes the values recorded so far, so later assertions only see new renders
+    pub fn take(&self) -> Vec<T> {
+        std::mem::take(&mut *self.0.borrow_mut())
+    }
+
+    /// The number of values recorded so far
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Whether nothing was recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl<T: Clone> Renders<T> {
+    /// Every value recorded so far, oldest first
+    pub fn all(&self) -> Vec<T> {
+        self.0.borrow().clone()
+    }
+
+    /// The value recorded by the latest render
+    pub fn last(&self) -> Option<T> {
+        self.0.borrow().last().cloned()
+    }
+}
+
+/// Processes work and renders for `duration`
+pub async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+    let _ = tokio::time::timeout(duration, async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+    })
+    .await;
+}
+
+/// Lets spawned fetches finish and the components re-render with their results
+pub async fn settle(dom: &mut VirtualDom) {
+    settle_rounds(dom, 5, Duration::from_millis(20)).await;
+}
+
+/// Waits up to `wait` for work and renders, `rounds` times
+pub async fn settle_rounds(dom: &mut VirtualDom, rounds: usize, wait: Duration) {
+    for _ in 0..rounds {
+        let _ = tokio::time::timeout(wait, dom.wait_for_work()).await;
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    }
+}
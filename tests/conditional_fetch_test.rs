@@ -0,0 +1,123 @@
+// Tests for #[provider(conditional)] revalidating with an ETag and keeping the cached value on a 304
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{conditional::cache_meta, global::get_global_cache, prelude::*};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static VERSION: AtomicU32 = AtomicU32::new(1);
+static FULL_RESPONSES: AtomicU32 = AtomicU32::new(0);
+static NOT_MODIFIED_RESPONSES: AtomicU32 = AtomicU32::new(0);
+
+/// A fake HTTP endpoint answering `If-None-Match` with a 304 while the article is unchanged
+fn get_article(id: u32, if_none_match: Option<String>) -> (u16, String, String) {
+    let etag = format!("\"v{}\"", VERSION.load(Ordering::SeqCst));
+    if if_none_match.as_ref() == Some(&etag) {
+        NOT_MODIFIED_RESPONSES.fetch_add(1, Ordering::SeqCst);
+        return (304, etag, String::new());
+    }
+    FULL_RESPONSES.fetch_add(1, Ordering::SeqCst);
+    let body = format!("article {id} {}", etag.trim_matches('"'));
+    (200, etag, body)
+}
+
+#[provider(stale_time = "1m", conditional)]
+async fn fetch_article(id: u32) -> Result<FetchOutcome<String>, String> {
+    let meta = cache_meta();
+    let (status, etag, body) = get_article(id, meta.as_ref().and_then(|meta| meta.get("etag")));
+    if status == 304 {
+        return Ok(FetchOutcome::NotModified);
+    }
+    if let Some(meta) = meta {
+        meta.set("etag", etag);
+    }
+    Ok(FetchOutcome::Modified(body))
+}
+
+#[tokio::test]
+async fn test_not_modified_revalidation_keeps_cached_value() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_article().id(&1);
+
+    let (mut dom, states) = common::record(|| use_provider(fetch_article(), 1u32).read().clone());
+    settle(&mut dom).await;
+    assert_eq!(FULL_RESPONSES.load(Ordering::SeqCst), 1);
+    assert_eq!(cache.entry_meta(&key, "etag"), Some("\"v1\"".to_string()));
+
+    // The server answers 304: the cached value is kept and becomes fresh again
+    states.take();
+    assert!(mark_provider_stale(fetch_article(), 1u32).unwrap());
+    settle(&mut dom).await;
+    assert_eq!(NOT_MODIFIED_RESPONSES.load(Ordering::SeqCst), 1);
+    assert_eq!(FULL_RESPONSES.load(Ordering::SeqCst), 1);
+    let kept = ProviderState::Success("article 1 v1".to_string());
+    assert!(states.all().iter().all(|state| *state == kept));
+    let (cached, is_stale) = cache
+        .get_with_staleness::<Result<String, String>>(&key, fetch_article().stale_time(), None)
+        .unwrap();
+    assert_eq!(cached, Ok("article 1 v1".to_string()));
+    assert!(!is_stale);
+
+    // Once the article changes, the revalidation stores the new body and ETag
+    VERSION.store(2, Ordering::SeqCst);
+    assert!(mark_provider_stale(fetch_article(), 1u32).unwrap());
+    settle(&mut dom).await;
+    assert_eq!(FULL_RESPONSES.load(Ordering::SeqCst), 2);
+    assert_eq!(cache.entry_meta(&key, "etag"), Some("\"v2\"".to_string()));
+    assert_eq!(
+        states.last(),
+        Some(ProviderState::Success("article 1 v2".to_string()))
+    );
+
+    // Removing the entry drops its metadata, and calling `run` directly never sends a validator
+    assert!(cache.remove(&key));
+    assert_eq!(cache.entry_meta(&key, "etag"), None);
+    assert_eq!(fetch_article().run(1).await, Ok("article 1 v2".to_string()));
+}
+
+/// The id the next fetch of `fetch_counter` answers `NotModified` for
+static NOT_MODIFIED_ID: AtomicU32 = AtomicU32::new(0);
+
+// Keeps its validator outside the cache metadata, so it answers 304 even when called directly
+#[provider(conditional)]
+async fn fetch_counter(id: u32) -> Result<FetchOutcome<u32>, String> {
+    if NOT_MODIFIED_ID.swap(0, Ordering::SeqCst) == id {
+        return Ok(FetchOutcome::NotModified);
+    }
+    Ok(FetchOutcome::Modified(id * 10))
+}
+
+#[tokio::test]
+async fn test_run_without_validator_keeps_cached_value_or_fetches_again() {
+    init_global_providers().unwrap();
+
+    assert_eq!(fetch_counter().run(4).await, Ok(40));
+    // Not modified while nothing is cached: the fetch runs again
+    NOT_MODIFIED_ID.store(5, Ordering::SeqCst);
+    assert_eq!(fetch_counter().run(5).await, Ok(50));
+
+    // Not modified with a cached result: the cached result is returned
+    seed_provider(fetch_counter(), 6, 66).unwrap();
+    NOT_MODIFIED_ID.store(6, Ordering::SeqCst);
+    assert_eq!(fetch_counter().run(6).await, Ok(66));
+}
+
+// Answers 304 to every request, as a server may do even without a validator
+#[provider(conditional)]
+async fn fetch_always_not_modified(id: u32) -> Result<FetchOutcome<u32>, ProviderError> {
+    let _ = id;
+    Ok(FetchOutcome::NotModified)
+}
+
+#[tokio::test]
+async fn test_not_modified_without_cached_value_is_an_error() {
+    init_global_providers().unwrap();
+
+    assert!(matches!(
+        fetch_always_not_modified().run(1).await,
+        Err(ProviderError::Cache(_))
+    ));
+}
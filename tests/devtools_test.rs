@@ -0,0 +1,68 @@
+// Smoke tests for the ProviderDevtools panel rendering a populated cache
+#![cfg(feature = "devtools")]
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    devtools::devtools_entries,
+    global::{get_global_cache, get_global_refresh_registry},
+    prelude::*,
+};
+use std::time::Duration;
+
+#[provider(interval = "1h")]
+async fn fetch_feed() -> Result<Vec<String>, String> {
+    Ok(vec!["first".to_string()])
+}
+
+#[provider]
+async fn fetch_broken() -> Result<String, String> {
+    Err("offline".to_string())
+}
+
+#[tokio::test]
+async fn test_devtools_renders_populated_cache() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let registry = get_global_refresh_registry().unwrap();
+    cache.set("manual".to_string(), 7u32);
+
+    let mut dom = common::mount(|| {
+        let feed = use_provider(fetch_feed(), ());
+        let broken = use_provider(fetch_broken(), ());
+        rsx! {
+            "{feed.read().is_loading()} {broken.read().is_loading()}"
+            ProviderDevtools { start_open: true, poll_interval: Duration::from_millis(10) }
+        }
+    });
+    settle(&mut dom).await;
+
+    let entries = devtools_entries(cache, registry);
+    let keys: Vec<&str> = entries.iter().map(|entry| entry.key.as_str()).collect();
+    let mut expected = vec![
+        fetch_feed().id(&()),
+        fetch_broken().id(&()),
+        "manual".to_string(),
+    ];
+    expected.sort();
+    assert_eq!(keys, expected);
+
+    let feed = entries
+        .iter()
+        .find(|entry| entry.key == fetch_feed().id(&()))
+        .unwrap();
+    assert_eq!(feed.interval, Some(Duration::from_secs(3600)));
+    assert!(feed.subscribers > 0);
+    assert!(!feed.info.is_error);
+    #[cfg(debug_assertions)]
+    assert!(feed.provider.unwrap().ends_with("FetchFeed"));
+
+    let broken = entries
+        .iter()
+        .find(|entry| entry.key == fetch_broken().id(&()))
+        .unwrap();
+    assert!(broken.info.is_error);
+    assert_eq!(broken.interval, None);
+}
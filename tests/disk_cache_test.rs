@@ -0,0 +1,230 @@
+// Tests for the `disk-persistence` feature saving cached results to a file and loading them
+#![cfg(feature = "disk-persistence")]
+
+use dioxus::prelude::*;
+use dioxus_provider::{
+    cache::{
+        CacheConfig, ProviderCache,
+        disk::{DiskCache, DiskCacheError, use_disk_persistence},
+    },
+    global::{get_global_cache, get_named_cache, init_named_cache},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+}
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<User, String> {
+    Ok(User {
+        id,
+        name: format!("user {id}"),
+    })
+}
+
+/// A file path unique to the test, in a fresh directory
+fn temp_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "dioxus-provider-disk-{}-{name}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.join("cache.json")
+}
+
+fn user_cache(path: &PathBuf) -> DiskCache {
+    DiskCache::new(path).register_provider(fetch_user())
+}
+
+#[test]
+fn test_saved_cache_reloads_into_fresh_cache() {
+    let path = temp_path("roundtrip");
+    let alice_key = fetch_user().id(&1);
+    let bob_key = fetch_user().id(&2);
+
+    let cache = ProviderCache::new();
+    cache.set(alice_key.clone(), fetch_user_ok(1));
+    cache.set(bob_key.clone(), fetch_user_ok(2));
+    cache.set("unregistered".to_string(), 42u64);
+    cache.set(
+        fetch_user().id(&3),
+        Err::<User, String>("not found".to_string()),
+    );
+    assert_eq!(user_cache(&path).save(&cache).unwrap(), 2);
+
+    std::thread::sleep(Duration::from_millis(20));
+    let fresh = ProviderCache::new();
+    assert_eq!(user_cache(&path).load(&fresh).unwrap(), 2);
+    assert_eq!(fresh.size(), 2);
+    assert_eq!(
+        fresh.get::<Result<User, String>>(&alice_key),
+        Some(fetch_user_ok(1))
+    );
+    assert_eq!(
+        fresh.get::<Result<User, String>>(&bob_key),
+        Some(fetch_user_ok(2))
+    );
+    // Entries keep aging across the restart instead of looking freshly fetched
+    assert!(fresh.snapshot(&alice_key).unwrap().age() >= Duration::from_millis(20));
+}
+
+#[test]
+fn test_other_version_missing_and_corrupt_files_load_nothing() {
+    let path = temp_path("versions");
+    let cache = ProviderCache::new();
+    assert_eq!(user_cache(&path).load(&cache).unwrap(), 0);
+
+    cache.set(fetch_user().id(&1), fetch_user_ok(1));
+    user_cache(&path).with_version(1).save(&cache).unwrap();
+    let fresh = ProviderCache::new();
+    assert_eq!(user_cache(&path).with_version(2).load(&fresh).unwrap(), 0);
+    assert_eq!(fresh.size(), 0);
+
+    std::fs::write(&path, "{ not json").unwrap();
+    assert!(matches!(
+        user_cache(&path).load(&fresh),
+        Err(DiskCacheError::Corrupt(_))
+    ));
+    assert_eq!(fresh.size(), 0);
+}
+
+#[tokio::test]
+async fn test_hook_writes_cache_after_changes() {
+    let path = temp_path("hook");
+    let mut dom = VirtualDom::new_with_props(
+        |path: PathBuf| {
+            use_disk_persistence(|| user_cache(&path).with_write_delay(Duration::from_millis(20)));
+            rsx! {}
+        },
+        path.clone(),
+    );
+    dom.rebuild_in_place();
+
+    let cache = get_global_cache().unwrap();
+    cache.set(fetch_user().id(&7), fetch_user_ok(7));
+    let _ = tokio::time::timeout(Duration::from_millis(100), async {
+        loop {
+            dom.wait_for_work().await;
+        }
+    })
+    .await;
+
+    let fresh = ProviderCache::new();
+    assert_eq!(user_cache(&path).load(&fresh).unwrap(), 1);
+    assert_eq!(
+        fresh.get::<Result<User, String>>(&fetch_user().id(&7)),
+        Some(fetch_user_ok(7))
+    );
+}
+
+fn fetch_user_ok(id: u32) -> Result<User, String> {
+    Ok(User {
+        id,
+        name: format!("user {id}"),
+    })
+}
+
+// Kept in their own cache, so the hook test saving the global cache doesn't see them
+#[provider(cache = "export")]
+async fn fetch_profile(id: u32) -> Result<User, String> {
+    fetch_user_ok(id)
+}
+
+#[provider(cache = "export", no_persist = true)]
+async fn fetch_session(id: u32) -> Result<User, String> {
+    fetch_user_ok(id)
+}
+
+#[test]
+fn test_export_snapshot_leaves_out_no_persist_entries() {
+    init_named_cache("export", CacheConfig::default());
+    let cache = get_named_cache("export").unwrap();
+    seed_provider(fetch_profile(), 1, fetch_user_ok(1).unwrap()).unwrap();
+    seed_provider(fetch_session(), 1, fetch_user_ok(1).unwrap()).unwrap();
+
+    let disk = DiskCache::new(temp_path("export"))
+        .register_provider(fetch_profile())
+        .register_provider(fetch_session());
+    let (count, contents) = disk.export_snapshot(&cache).unwrap();
+    let file: serde_json::Value = serde_json::from_slice(&contents).unwrap();
+    let keys: Vec<&str> = file["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["key"].as_str().unwrap())
+        .collect();
+    // Saved under the parameter key, since the hashed cache key can change between builds
+    assert_eq!(keys, vec![fetch_profile().param_key(&1)]);
+    assert_eq!(
+        file["entries"][0]["tag"],
+        std::any::type_name::<FetchProfile>()
+    );
+    assert_eq!(count, 1);
+}
+
+/// A parameter with a readable key, so a cache file can be written by hand
+#[derive(Clone, Debug, PartialEq)]
+pub struct Handle(String);
+
+impl ProviderKey for Handle {
+    fn provider_key(&self) -> String {
+        self.0.clone()
+    }
+}
+
+#[provider]
+async fn fetch_by_handle(handle: Handle) -> Result<User, String> {
+    Ok(User {
+        id: 0,
+        name: handle.0,
+    })
+}
+
+#[test]
+fn test_file_of_another_build_loads_under_rebuilt_keys() {
+    // Written by hand rather than by `save`, so loading doesn't rely on this build's keys
+    let path = temp_path("fixture");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let saved_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    std::fs::write(
+        &path,
+        format!(
+            r#"{{
+                "version": 0,
+                "saved_at_ms": {saved_at_ms},
+                "entries": [
+                    {{
+                        "key": "ada",
+                        "param_key": true,
+                        "tag": "disk_cache_test::FetchByHandle",
+                        "age_ms": 0,
+                        "value": {{ "id": 0, "name": "Ada" }}
+                    }}
+                ]
+            }}"#
+        ),
+    )
+    .unwrap();
+
+    let cache = ProviderCache::new();
+    let disk = DiskCache::new(&path).register_provider(fetch_by_handle());
+    assert_eq!(disk.load(&cache).unwrap(), 1);
+    assert_eq!(
+        cache.get::<Result<User, String>>(&fetch_by_handle().id(&Handle("ada".to_string()))),
+        Some(Ok(User {
+            id: 0,
+            name: "Ada".to_string(),
+        }))
+    );
+}
@@ -0,0 +1,77 @@
+// Tests for #[provider(dispose_delay)] dropping entries once no component has used them for a while
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    global::{get_global_cache, get_global_refresh_registry},
+    prelude::*,
+};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(dispose_delay = "150ms")]
+async fn fetch_article(id: u32) -> Result<String, String> {
+    FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("article {id}"))
+}
+
+#[component]
+fn Article(id: u32) -> Element {
+    let article = use_provider(fetch_article(), id);
+    rsx! { "{article.read().is_loading()}" }
+}
+
+fn is_cached(id: u32) -> bool {
+    get_global_cache()
+        .unwrap()
+        .entries()
+        .iter()
+        .any(|(key, _)| *key == fetch_article().id(&id))
+}
+
+#[tokio::test]
+async fn test_unused_entry_is_disposed_after_delay() {
+    init_global_providers().unwrap();
+    assert_eq!(
+        fetch_article().dispose_delay(),
+        Some(Duration::from_millis(150))
+    );
+
+    let (mut dom, show_first) = common::record_with(|| {
+        let show_first = use_signal(|| true);
+        let element = rsx! {
+            if show_first() {
+                Article { id: 1 }
+            }
+            Article { id: 2 }
+        };
+        (show_first, element)
+    });
+    run_for(&mut dom, Duration::from_millis(300)).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+
+    // Mounted entries are kept however long ago they were read
+    assert!(is_cached(1));
+    assert!(is_cached(2));
+
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        show_first.last().unwrap().set(false);
+    });
+    run_for(&mut dom, Duration::from_millis(75)).await;
+    assert!(is_cached(1), "disposed before the delay elapsed");
+    let registry = get_global_refresh_registry().unwrap();
+    assert!(registry.unmounted_for(&fetch_article().id(&1)).is_some());
+
+    run_for(&mut dom, Duration::from_millis(200)).await;
+    assert!(!is_cached(1));
+    // The disposed key's unmount time is no longer needed
+    assert_eq!(registry.unmounted_for(&fetch_article().id(&1)), None);
+    assert!(is_cached(2));
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,77 @@
+// Tests for #[provider(entity_key = ...)] and invalidating entries across providers by entity
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static DASHBOARD_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(entity_key = user_id)]
+async fn fetch_user_dashboard(user_id: u32) -> Result<String, String> {
+    DASHBOARD_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("dashboard of {}", user_id))
+}
+
+#[provider(entity_key = user_id)]
+async fn fetch_user_posts(user_id: u32) -> Result<Vec<String>, String> {
+    Ok(vec![format!("post by {}", user_id)])
+}
+
+#[provider(entity_key = user_id)]
+async fn fetch_user_session(device: String, user_id: u32) -> Result<String, String> {
+    Ok(format!("session of {} on {}", user_id, device))
+}
+
+#[tokio::test]
+async fn test_invalidate_entity_drops_entries_across_providers() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let dashboard_key = fetch_user_dashboard().id(&1);
+    let posts_key = fetch_user_posts().id(&1);
+    let session_key = fetch_user_session().id(&("web".to_string(), 1u32));
+    let other_user_key = fetch_user_posts().id(&2);
+
+    let mut dom = common::mount(|| {
+        let dashboard = use_provider(fetch_user_dashboard(), 1u32);
+        let posts = use_provider(fetch_user_posts(), 1u32);
+        let session = use_provider(fetch_user_session(), (("web".to_string(), 1u32),));
+        let other_user = use_provider(fetch_user_posts(), 2u32);
+        rsx! {
+            "{dashboard.read().is_loading()} {posts.read().is_loading()}"
+            "{session.read().is_loading()} {other_user.read().is_loading()}"
+        }
+    });
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(cache.size(), 4);
+
+    assert_eq!(invalidate_entity(&1u32), 3);
+    assert!(
+        cache
+            .get::<Result<String, String>>(&dashboard_key)
+            .is_none()
+    );
+    assert!(
+        cache
+            .get::<Result<Vec<String>, String>>(&posts_key)
+            .is_none()
+    );
+    assert!(cache.get::<Result<String, String>>(&session_key).is_none());
+    assert_eq!(
+        cache.get::<Result<Vec<String>, String>>(&other_user_key),
+        Some(Ok(vec!["post by 2".to_string()]))
+    );
+
+    // Mounted providers of the invalidated entity refetch
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(DASHBOARD_FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        cache.get::<Result<String, String>>(&session_key),
+        Some(Ok("session of 1 on web".to_string()))
+    );
+}
@@ -0,0 +1,63 @@
+// Tests for #[provider(error = ...)] converting the errors of anyhow-style bodies
+
+use dioxus_provider::prelude::*;
+use std::fmt;
+
+#[provider(error = String)]
+async fn parse_port(raw: String) -> anyhow::Result<u16> {
+    let port: u16 = raw.trim().parse()?;
+    if port == 0 {
+        anyhow::bail!("port 0 is reserved");
+    }
+    Ok(port)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Invalid(reason) => write!(f, "invalid config: {reason}"),
+        }
+    }
+}
+
+fn to_config_error(error: anyhow::Error) -> ConfigError {
+    ConfigError::Invalid(format!("{error:#}"))
+}
+
+#[provider(error = ConfigError, map_err = to_config_error)]
+async fn load_retries(raw: String) -> anyhow::Result<u32> {
+    use anyhow::Context;
+    let retries = raw.parse::<u32>().context("retries must be a number")?;
+    Ok(retries)
+}
+
+#[tokio::test]
+async fn test_body_errors_convert_to_declared_error() {
+    let provider = parse_port();
+    assert_eq!(provider.run(" 8080 ".to_string()).await, Ok(8080));
+    assert_eq!(
+        provider.run("http".to_string()).await,
+        Err("invalid digit found in string".to_string())
+    );
+    assert_eq!(
+        provider.run("0".to_string()).await,
+        Err("port 0 is reserved".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_converts_with_given_function() {
+    let provider = load_retries();
+    assert_eq!(provider.run("3".to_string()).await, Ok(3));
+    assert_eq!(
+        provider.run("many".to_string()).await,
+        Err(ConfigError::Invalid(
+            "retries must be a number: invalid digit found in string".to_string()
+        ))
+    );
+}
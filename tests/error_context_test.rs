@@ -0,0 +1,50 @@
+// Tests for #[provider(error_context = true)] annotating errors with the failing provider
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+
+#[provider(error_context = true)]
+async fn fetch_account(id: u32) -> Result<String, ProviderError> {
+    Err(ProviderError::Network(format!("account {} unreachable", id)))
+}
+
+#[provider]
+async fn fetch_plain(id: u32) -> Result<String, ProviderError> {
+    Err(ProviderError::Network(format!("plain {} unreachable", id)))
+}
+
+#[tokio::test]
+async fn test_cached_error_includes_provider_key() {
+    init_global_providers().unwrap();
+
+    let mut dom = common::mount(|| {
+        let _account = use_provider(fetch_account(), 5u32);
+        let _plain = use_provider(fetch_plain(), 5u32);
+        rsx! {}
+    });
+    settle(&mut dom).await;
+
+    let cache = get_global_cache().unwrap();
+    let key = fetch_account().id(&5);
+    let error = cache
+        .get::<Result<String, ProviderError>>(&key)
+        .unwrap()
+        .unwrap_err();
+
+    assert_eq!(error.provider_key(), Some(key.as_str()));
+    assert_eq!(
+        error.root_cause(),
+        &ProviderError::Network("account 5 unreachable".to_string())
+    );
+    assert!(error.to_string().contains(&key));
+
+    // Providers that don't opt in cache their errors unchanged
+    let plain_error = cache
+        .get::<Result<String, ProviderError>>(&fetch_plain().id(&5))
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(plain_error.provider_key(), None);
+}
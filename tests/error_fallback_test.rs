@@ -0,0 +1,111 @@
+// Tests for #[provider(error_fallback = ...)] showing fallback data when a fetch fails
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+static SCORE_FETCHES: AtomicU32 = AtomicU32::new(0);
+static RATE_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+// The first fetch fails, the retry after the error expires succeeds
+#[provider(error_ttl = "50ms", check_interval = "10ms", error_fallback = vec!["offline".to_string()])]
+async fn fetch_headlines() -> Result<Vec<String>, String> {
+    if FETCHES.fetch_add(1, Ordering::SeqCst) == 0 {
+        return Err("network unreachable".to_string());
+    }
+    Ok(vec!["Rust 2024 released".to_string()])
+}
+
+#[provider]
+async fn fetch_weather() -> Result<String, String> {
+    Err("network unreachable".to_string())
+}
+
+// The first fetch succeeds, later ones fail
+#[provider(error_fallback = 0)]
+async fn fetch_score() -> Result<u32, String> {
+    if SCORE_FETCHES.fetch_add(1, Ordering::SeqCst) == 0 {
+        return Ok(42);
+    }
+    Err("scoreboard down".to_string())
+}
+
+// The first fetch fails; without an error TTL only staleness makes it retry
+#[provider(stale_time = "1m", error_fallback = 1.0)]
+async fn fetch_rate() -> Result<f64, String> {
+    if RATE_FETCHES.fetch_add(1, Ordering::SeqCst) == 0 {
+        return Err("rates unavailable".to_string());
+    }
+    Ok(1.08)
+}
+
+#[tokio::test]
+async fn test_first_load_error_shows_fallback() {
+    let (mut dom, states) = common::record(|| {
+        let headlines = use_provider(fetch_headlines(), ());
+        let weather = use_provider(fetch_weather(), ());
+        (headlines.read().clone(), weather.read().clone())
+    });
+    let latest_headlines = || states.last().unwrap().0;
+    run_for(&mut dom, Duration::from_millis(30)).await;
+
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        latest_headlines(),
+        ProviderState::Success(vec!["offline".to_string()])
+    );
+    assert!(
+        !states
+            .all()
+            .iter()
+            .any(|(headlines, _)| headlines.is_error())
+    );
+
+    // Without a fallback the error is shown
+    assert_eq!(
+        states.last().unwrap().1,
+        ProviderState::Error("network unreachable".to_string())
+    );
+
+    // The cached error expires and the provider fetches again
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        latest_headlines(),
+        ProviderState::Success(vec!["Rust 2024 released".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn test_error_after_data_is_shown_as_error() {
+    let (mut dom, states) = common::record(|| use_provider(fetch_score(), ()).read().clone());
+    run_for(&mut dom, Duration::from_millis(30)).await;
+    assert_eq!(states.last(), Some(ProviderState::Success(42)));
+
+    // A failed refetch stored over the data is reported instead of hidden by the fallback
+    let refetched = fetch_provider(fetch_score(), ()).await.unwrap();
+    assert_eq!(refetched, Err("scoreboard down".to_string()));
+    run_for(&mut dom, Duration::from_millis(30)).await;
+    assert_eq!(
+        states.last(),
+        Some(ProviderState::Error("scoreboard down".to_string()))
+    );
+    assert!(!states.all().contains(&ProviderState::Success(0)));
+}
+
+#[tokio::test]
+async fn test_fallback_marks_the_error_stale_so_it_retries() {
+    let (mut dom, states) = common::record(|| use_provider(fetch_rate(), ()).read().clone());
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    assert!(states.all().contains(&ProviderState::Success(1.0)));
+    assert_eq!(RATE_FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(states.last(), Some(ProviderState::Success(1.08)));
+}
@@ -0,0 +1,54 @@
+// Tests for #[provider(error_ttl = ...)] expiring cached errors before successes
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+#[provider(cache_expiration = "1h", error_ttl = "100ms")]
+async fn fetch_status() -> Result<String, String> {
+    // The first attempt hits a network blip, later attempts succeed
+    match ATTEMPTS.fetch_add(1, Ordering::SeqCst) {
+        0 => Err("network unreachable".to_string()),
+        _ => Ok("online".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_cached_error_expires_and_refetches() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_status().id(&());
+
+    let mut dom = common::mount(|| {
+        let _status = use_provider(fetch_status(), ());
+        rsx! {}
+    });
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Err("network unreachable".to_string()))
+    );
+
+    // Expiration checks run at most once per second
+    run_for(&mut dom, Duration::from_millis(1300)).await;
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Ok("online".to_string()))
+    );
+
+    // The successful result keeps the full cache expiration
+    run_for(&mut dom, Duration::from_millis(1300)).await;
+    assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Ok("online".to_string()))
+    );
+}
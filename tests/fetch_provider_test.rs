@@ -0,0 +1,99 @@
+// Tests for fetch_provider running providers outside of a Dioxus runtime
+
+use dioxus_provider::{global::get_global_cache, platform::spawn_task, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(stale_time = "1m")]
+async fn fetch_report(id: u32) -> Result<String, String> {
+    FETCHES.fetch_add(1, Ordering::SeqCst);
+    if id == 0 {
+        return Err("no report 0".to_string());
+    }
+    Ok(format!("report {id}"))
+}
+
+#[provider]
+async fn fetch_summary(id: u32) -> Result<String, String> {
+    Ok(format!("summary {id}"))
+}
+
+#[tokio::test]
+async fn test_fetch_provider_from_plain_tokio_task() {
+    let result = tokio::spawn(async { fetch_provider(fetch_report(), 7).await.unwrap() })
+        .await
+        .unwrap();
+    assert_eq!(result, Ok("report 7".to_string()));
+
+    let failed = tokio::spawn(async { fetch_provider(fetch_report(), 0).await.unwrap() })
+        .await
+        .unwrap();
+    assert_eq!(failed, Err("no report 0".to_string()));
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+
+    let cache = get_global_cache().unwrap();
+    assert_eq!(
+        cache.get::<Result<String, String>>(&fetch_report().id(&7)),
+        Some(Ok("report 7".to_string()))
+    );
+    assert_eq!(
+        cache.get::<Result<String, String>>(&fetch_report().id(&0)),
+        Some(Err("no report 0".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_background_tasks_run_on_a_local_executor() {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    let handle = spawn_task(async move {
+        // Tasks spawned by a running task land on the same executor
+        spawn_task(async move {
+            fetch_provider(fetch_summary(), 3).await.unwrap().ok();
+            sender.send(()).ok();
+        });
+    });
+    assert!(!handle.is_cancelled());
+    tokio::time::timeout(Duration::from_secs(5), receiver)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        get_global_cache()
+            .unwrap()
+            .get::<Result<String, String>>(&fetch_summary().id(&3)),
+        Some(Ok("summary 3".to_string()))
+    );
+
+    let runs = std::rc::Rc::new(std::cell::Cell::new(0));
+    let ticks = runs.clone();
+    let handle = spawn_task(async move {
+        loop {
+            ticks.set(ticks.get() + 1);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    handle.cancel();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let stopped = runs.get();
+    assert!(stopped > 0);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(runs.get(), stopped);
+}
+
+#[test]
+fn test_background_tasks_are_skipped_without_an_executor() {
+    let handle = spawn_task(async { panic!("must not run without an executor") });
+    assert!(handle.is_cancelled());
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let handle = runtime.block_on(async { spawn_task(async { panic!("not Send-safe") }) });
+    assert!(handle.is_cancelled());
+}
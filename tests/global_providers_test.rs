@@ -0,0 +1,67 @@
+// Tests for init_global_providers returning handles to the global cache and registry
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{
+    global::{get_global_cache, get_global_refresh_registry},
+    prelude::*,
+};
+use std::time::Duration;
+
+#[provider]
+async fn fetch_config() -> Result<String, String> {
+    Ok("fetched".to_string())
+}
+
+/// The cached entry and provider state a component saw
+type Seen = (
+    Option<Result<String, String>>,
+    ProviderState<String, String>,
+);
+
+fn use_seen() -> Seen {
+    let cache = use_provider_cache();
+    let config = use_provider(fetch_config(), ());
+    let cached = cache.get::<Result<String, String>>(&fetch_config().id(&()));
+    (cached, config.read().clone())
+}
+
+#[tokio::test]
+async fn test_init_is_idempotent_and_shares_the_cache() {
+    let providers = init_global_providers().unwrap();
+    let key = fetch_config().id(&());
+
+    // Hydrate before any component mounts
+    providers
+        .cache
+        .set(key.clone(), Ok::<_, String>("hydrated".to_string()));
+    providers.refresh.trigger_refresh(&key);
+
+    // Initializing again keeps the existing instances and their state
+    let again = init_global_providers().unwrap();
+    assert_eq!(
+        again.cache.get::<Result<String, String>>(&key),
+        Some(Ok("hydrated".to_string()))
+    );
+    assert_eq!(
+        get_global_cache()
+            .unwrap()
+            .get::<Result<String, String>>(&key),
+        Some(Ok("hydrated".to_string()))
+    );
+    assert_eq!(
+        get_global_refresh_registry()
+            .unwrap()
+            .get_refresh_count(&key),
+        again.refresh.get_refresh_count(&key)
+    );
+
+    // Components see the hydrated entry instead of fetching
+    let (mut dom, seen) = common::record(use_seen);
+    let _ = tokio::time::timeout(Duration::from_millis(20), dom.wait_for_work()).await;
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    let (cached, state) = seen.last().unwrap();
+    assert_eq!(cached, Some(Ok("hydrated".to_string())));
+    assert_eq!(state, ProviderState::Success("hydrated".to_string()));
+}
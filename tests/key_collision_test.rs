@@ -0,0 +1,102 @@
+// Tests for detecting two provider types, or a bare value, writing to the same cache key in debug builds
+#![cfg(debug_assertions)]
+
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{panic, sync::Mutex};
+
+#[derive(Clone, PartialEq)]
+struct UserNameProvider;
+
+impl Provider<u32> for UserNameProvider {
+    type Output = String;
+    type Error = String;
+
+    async fn run(&self, id: u32) -> Result<Self::Output, Self::Error> {
+        Ok(format!("user {id}"))
+    }
+
+    fn id(&self, id: &u32) -> String {
+        format!("user_{id}")
+    }
+}
+
+// Deliberately reuses UserNameProvider's key format
+#[derive(Clone, PartialEq)]
+struct UserEmailProvider;
+
+impl Provider<u32> for UserEmailProvider {
+    type Output = String;
+    type Error = String;
+
+    async fn run(&self, id: u32) -> Result<Self::Output, Self::Error> {
+        Ok(format!("user{id}@example.com"))
+    }
+
+    fn id(&self, id: &u32) -> String {
+        format!("user_{id}")
+    }
+}
+
+#[test]
+fn test_same_provider_may_reuse_its_key() {
+    init_global_providers().unwrap();
+    assert!(seed_provider(UserNameProvider, 1, "Ada".to_string()).unwrap());
+    assert!(!seed_provider(UserNameProvider, 1, "Grace".to_string()).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "cache key 'user_7' is used by two different providers")]
+fn test_key_reuse_by_another_provider_panics() {
+    init_global_providers().unwrap();
+    seed_provider(UserNameProvider, 7, "Ada".to_string()).unwrap();
+    seed_provider(UserEmailProvider, 7, "ada@example.com".to_string()).unwrap();
+}
+
+#[test]
+fn test_removed_key_may_be_reused_by_another_provider() {
+    init_global_providers().unwrap();
+    seed_provider(UserNameProvider, 11, "Ada".to_string()).unwrap();
+    let cache = get_global_cache().unwrap();
+    assert_eq!(
+        cache.key_owner("user_11"),
+        Some(std::any::type_name::<UserNameProvider>())
+    );
+
+    assert!(cache.remove("user_11"));
+    assert_eq!(cache.key_owner("user_11"), None);
+    assert!(seed_provider(UserEmailProvider, 11, "ada@example.com".to_string()).unwrap());
+}
+
+#[allow(non_snake_case)]
+fn UserName() -> Element {
+    let _name = use_provider(UserNameProvider, 9u32);
+    rsx! {}
+}
+
+#[test]
+fn test_reading_entry_of_another_type_panics() {
+    init_global_providers().unwrap();
+    // A bare value where the provider expects a `Result<String, String>`
+    get_global_cache().unwrap().set("user_9".to_string(), 5u32);
+
+    // The render panic is caught by dioxus, so record it from the panic hook
+    static MESSAGE: Mutex<Option<String>> = Mutex::new(None);
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        if let Some(message) = info.payload().downcast_ref::<String>()
+            && message.starts_with("cache entry")
+        {
+            *MESSAGE.lock().unwrap() = Some(message.clone());
+        }
+    }));
+    let mut dom = VirtualDom::new(UserName);
+    dom.rebuild_in_place();
+    panic::set_hook(previous_hook);
+
+    let message = MESSAGE.lock().unwrap().clone().unwrap_or_default();
+    assert!(
+        message.starts_with("cache entry 'user_9' holds a `u32`"),
+        "{message}"
+    );
+}
@@ -0,0 +1,23 @@
+// Provider hooks must work even when init_global_providers() was never called
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{global::is_initialized, prelude::*};
+
+#[provider]
+async fn lazy_value() -> Result<u32, String> {
+    Ok(42)
+}
+
+#[test]
+fn use_provider_without_init_does_not_panic() {
+    assert!(!is_initialized());
+
+    let _dom = common::mount(|| {
+        let value = use_provider(lazy_value(), ());
+        rsx! { "{value.read().is_loading()}" }
+    });
+
+    assert!(is_initialized());
+}
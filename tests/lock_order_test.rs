@@ -0,0 +1,87 @@
+// Tests that reactive contexts may re-enter the cache and registry while being marked dirty
+
+mod common;
+
+use common::run_for;
+use dioxus::{dioxus_core::ReactiveContext, prelude::*};
+use dioxus_provider::{
+    global::{get_global_cache, get_global_refresh_registry},
+    prelude::*,
+};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+        mpsc,
+    },
+    time::Duration,
+};
+
+#[provider(cache_expiration = "50ms")]
+async fn fetch_quote() -> Result<String, String> {
+    Ok("stay hungry".to_string())
+}
+
+/// Expires a cached entry whose subscriber reads the cache and refreshes another key
+/// from its callback, then re-renders the provider's component
+async fn expire_with_reentrant_subscriber() -> u32 {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap().clone();
+    let registry = get_global_refresh_registry().unwrap().clone();
+    let key = fetch_quote().id(&());
+
+    let (mut dom, renders) = common::record_with(|| {
+        let renders = use_signal(|| 0);
+        let quote = use_provider(fetch_quote(), ());
+        (renders, rsx! { "{renders} {quote.read().is_loading()}" })
+    });
+    run_for(&mut dom, Duration::from_millis(20)).await;
+
+    let callbacks = Arc::new(AtomicU32::new(0));
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        let (callback_count, callback_cache, callback_registry, callback_key) = (
+            callbacks.clone(),
+            cache.clone(),
+            registry.clone(),
+            key.clone(),
+        );
+        let context = ReactiveContext::new_with_callback(
+            move || {
+                callback_count.fetch_add(1, Ordering::SeqCst);
+                let _ = callback_cache.get::<Result<String, String>>(&callback_key);
+                callback_registry.trigger_refresh("unrelated");
+            },
+            ScopeId::ROOT,
+            std::panic::Location::caller(),
+        );
+        registry.subscribe_to_refresh(&key, context);
+    });
+
+    // Let the entry expire, then render so the hook finds and removes it
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        let mut renders = renders.last().unwrap();
+        renders += 1;
+    });
+    run_for(&mut dom, Duration::from_millis(20)).await;
+
+    callbacks.load(Ordering::SeqCst)
+}
+
+#[test]
+fn test_reentrant_subscriber_does_not_deadlock_on_expiration() {
+    let (done, finished) = mpsc::channel();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let callbacks = runtime.block_on(expire_with_reentrant_subscriber());
+        let _ = done.send(callbacks);
+    });
+
+    let callbacks = finished
+        .recv_timeout(Duration::from_secs(5))
+        .expect("expiring an entry deadlocked on the cache or registry lock");
+    assert!(callbacks >= 1);
+}
@@ -0,0 +1,52 @@
+// Tests for mark_provider_stale revalidating an entry while it keeps being served
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(stale_time = "1m")]
+async fn fetch_profile(id: u32) -> Result<String, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    Ok(format!("profile {id} v{fetch}"))
+}
+
+#[provider]
+async fn fetch_settings() -> Result<String, String> {
+    Ok("dark".to_string())
+}
+
+#[tokio::test]
+async fn test_mark_provider_stale_revalidates_in_background() {
+    init_global_providers().unwrap();
+
+    let (mut dom, states) = common::record_with(|| {
+        let profile = use_provider(fetch_profile(), 1u32);
+        let settings = use_provider(fetch_settings(), ());
+        let profile = profile.read().clone();
+        (profile, rsx! { "{settings.read().is_loading()}" })
+    });
+    settle(&mut dom).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+    states.take();
+    assert!(mark_provider_stale(fetch_profile(), 1u32).unwrap());
+    settle(&mut dom).await;
+
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    let states = states.all();
+    // The stale value is served until the revalidated one replaces it
+    assert!(states.iter().all(|state| !state.is_loading()));
+    assert_eq!(
+        states.last(),
+        Some(&ProviderState::Success("profile 1 v2".to_string()))
+    );
+
+    // Missing entries and providers without a stale time are left alone
+    assert!(!mark_provider_stale(fetch_profile(), 2u32).unwrap());
+    assert!(!mark_provider_stale(fetch_settings(), ()).unwrap());
+}
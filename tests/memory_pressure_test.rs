@@ -0,0 +1,42 @@
+// Tests for handle_memory_pressure shedding unreferenced entries from every cache
+
+use dioxus_provider::{
+    cache::CacheConfig,
+    global::{get_global_cache, get_named_cache, handle_memory_pressure, init_named_cache},
+    platform::register_memory_pressure_handler,
+    prelude::*,
+};
+
+#[test]
+fn memory_pressure_drops_unreferenced_entries() {
+    init_global_providers().unwrap();
+    init_named_cache("pressure", CacheConfig::default());
+    let cache = get_global_cache().unwrap();
+    let named = get_named_cache("pressure").unwrap();
+
+    cache.set("recent".to_string(), Ok::<u32, String>(1));
+    cache.set("referenced".to_string(), Ok::<u32, String>(2));
+    named.set("named".to_string(), Ok::<u32, String>(3));
+    // Read right away, so the periodic cleanup would keep it
+    assert_eq!(cache.get::<Result<u32, String>>("recent"), Some(Ok(1)));
+    cache
+        .cache
+        .lock()
+        .unwrap()
+        .get("referenced")
+        .unwrap()
+        .add_reference();
+
+    assert_eq!(handle_memory_pressure(), 2);
+    assert!(cache.get::<Result<u32, String>>("recent").is_none());
+    assert!(named.get::<Result<u32, String>>("named").is_none());
+    assert_eq!(cache.get::<Result<u32, String>>("referenced"), Some(Ok(2)));
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos", windows))]
+fn desktop_installs_a_pressure_watcher() {
+    assert!(register_memory_pressure_handler());
+    // Later calls reuse the watcher
+    assert!(register_memory_pressure_handler());
+}
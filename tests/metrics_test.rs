@@ -0,0 +1,88 @@
+// Tests for the `metrics` feature recording fetch counts and error rates of providers
+#![cfg(feature = "metrics")]
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    metrics::{all_provider_metrics, provider_metrics, use_provider_metrics},
+    prelude::*,
+};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static CALLS: AtomicU32 = AtomicU32::new(0);
+
+// Fails every second fetch
+#[provider]
+async fn flaky_count() -> Result<u32, String> {
+    let call = CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    if call.is_multiple_of(2) {
+        Err(format!("call {call} failed"))
+    } else {
+        Ok(call)
+    }
+}
+
+#[tokio::test]
+async fn test_fetches_record_count_duration_and_error_rate() {
+    init_global_providers().unwrap();
+    assert_eq!(provider_metrics(&flaky_count()), None);
+
+    let mut dom = common::mount(|| {
+        let _count = use_provider(flaky_count(), ());
+        rsx! {}
+    });
+    settle(&mut dom).await;
+    for _ in 0..3 {
+        invalidate_provider(flaky_count(), ()).unwrap();
+        settle(&mut dom).await;
+    }
+
+    let metrics = provider_metrics(&flaky_count()).unwrap();
+    assert_eq!(metrics.fetch_count, 4);
+    assert_eq!(metrics.error_count, 2);
+    assert_eq!(metrics.error_rate(), 0.5);
+    assert!(metrics.last_duration >= Duration::from_millis(5));
+    assert!(metrics.average_duration() >= Duration::from_millis(5));
+    assert!(metrics.total_duration >= metrics.average_duration() * 4);
+    assert!(all_provider_metrics().contains(&(flaky_count().type_name(), metrics)));
+}
+
+static RENDERS: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn doubled(value: u32) -> Result<u32, String> {
+    Ok(value * 2)
+}
+
+#[tokio::test]
+async fn test_metrics_are_per_provider_and_rerender_the_dashboard() {
+    init_global_providers().unwrap();
+    let mut dom = common::mount(|| {
+        let _one = use_provider(doubled(), 1u32);
+        let _two = use_provider(doubled(), 2u32);
+        rsx! { Dashboard {} }
+    });
+    settle(&mut dom).await;
+    let renders = RENDERS.load(Ordering::SeqCst);
+
+    let metrics = provider_metrics(&doubled()).unwrap();
+    assert_eq!(metrics.fetch_count, 2);
+
+    invalidate_provider(doubled(), 1u32).unwrap();
+    settle(&mut dom).await;
+    assert_eq!(provider_metrics(&doubled()).unwrap().fetch_count, 3);
+    assert!(RENDERS.load(Ordering::SeqCst) > renders);
+}
+
+#[component]
+fn Dashboard() -> Element {
+    RENDERS.fetch_add(1, Ordering::SeqCst);
+    let _metrics = use_provider_metrics();
+    rsx! {}
+}
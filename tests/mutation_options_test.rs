@@ -0,0 +1,131 @@
+// Tests for #[mutation(retry = ..., timeout = ..., optimistic = ...)]
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static SAVE_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+// Fails twice before succeeding
+#[mutation(retry = 2)]
+async fn flaky_save(value: u32) -> Result<u32, String> {
+    if SAVE_ATTEMPTS.fetch_add(1, Ordering::SeqCst) < 2 {
+        return Err("connection reset".to_string());
+    }
+    Ok(value)
+}
+
+static UPLOAD_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+#[mutation(retry = 1)]
+async fn failing_upload(value: u32) -> Result<u32, String> {
+    UPLOAD_ATTEMPTS.fetch_add(1, Ordering::SeqCst);
+    Err(format!("upload of {value} rejected"))
+}
+
+#[provider]
+async fn fetch_title(id: u32) -> Result<String, String> {
+    Ok(format!("Title {id}"))
+}
+
+// Shows the new title immediately, then fails and rolls it back
+#[mutation(updates = [fetch_title(*id)], optimistic = title.clone())]
+async fn rename_title(id: u32, title: String) -> Result<String, String> {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    Err(format!("cannot rename {id} to {title}"))
+}
+
+#[mutation(timeout = "50ms")]
+async fn slow_save() -> Result<(), String> {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    Ok(())
+}
+
+type SaveState = MutationState<u32, String>;
+
+/// The state of each mutation, started on the first render
+fn use_saves() -> (SaveState, SaveState, MutationState<(), String>) {
+    let (save_state, save) = use_mutation(flaky_save());
+    let (upload_state, upload) = use_mutation(failing_upload());
+    let (slow_state, slow) = use_mutation(slow_save());
+    use_hook(move || {
+        save(7);
+        upload(3);
+        slow(());
+    });
+
+    let save_state = save_state.read().clone();
+    let upload_state = upload_state.read().clone();
+    let slow_state = slow_state.read().clone();
+    (save_state, upload_state, slow_state)
+}
+
+/// The shown title, renamed once it first loads
+fn use_renamed_title() -> Option<String> {
+    let title = use_provider(fetch_title(), 1u32);
+    let (_state, rename) = use_mutation(rename_title());
+    let started = use_signal(|| false);
+    let ProviderState::Success(title) = title.read().clone() else {
+        return None;
+    };
+    if !*started.peek() {
+        let mut started = started;
+        started.set(true);
+        rename((1, "Renamed".to_string()));
+    }
+    Some(title)
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    common::settle_rounds(dom, 5, Duration::from_millis(40)).await;
+}
+
+#[tokio::test]
+async fn test_mutation_retry_and_timeout() {
+    init_global_providers().unwrap();
+
+    let (mut dom, states) = common::record(use_saves);
+    settle(&mut dom).await;
+    let states = states.all();
+    let save_states: Vec<_> = states.iter().map(|(save, _, _)| save.clone()).collect();
+    let upload_states: Vec<_> = states.iter().map(|(_, upload, _)| upload.clone()).collect();
+    let slow_states: Vec<_> = states.iter().map(|(_, _, slow)| slow.clone()).collect();
+
+    // Two failures are retried, the third attempt succeeds
+    assert_eq!(SAVE_ATTEMPTS.load(Ordering::SeqCst), 3);
+    assert!(save_states.last() == Some(&MutationState::Success(7)));
+    assert!(
+        !save_states
+            .iter()
+            .any(|state| matches!(state, MutationState::Error(_)))
+    );
+
+    // Retries are exhausted and the last error is reported
+    assert_eq!(UPLOAD_ATTEMPTS.load(Ordering::SeqCst), 2);
+    assert!(
+        upload_states.last() == Some(&MutationState::Error("upload of 3 rejected".to_string()))
+    );
+
+    match slow_states.last() {
+        Some(MutationState::Error(error)) => assert!(error.contains("timed out"), "{error}"),
+        _ => panic!("slow mutation should have timed out"),
+    }
+}
+
+#[tokio::test]
+async fn test_optimistic_value_is_rolled_back_on_failure() {
+    init_global_providers().unwrap();
+
+    let (mut dom, titles) = common::record(use_renamed_title);
+    settle(&mut dom).await;
+
+    let titles: Vec<String> = titles.all().into_iter().flatten().collect();
+    assert_eq!(titles.first().map(String::as_str), Some("Title 1"));
+    assert!(titles.iter().any(|title| title == "Renamed"));
+    assert_eq!(titles.last().map(String::as_str), Some("Title 1"));
+}
@@ -0,0 +1,57 @@
+// Tests for #[mutation] invalidating provider families with params picked from its inputs
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+static POST_1_FETCHES: AtomicU32 = AtomicU32::new(0);
+static POST_2_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_comment(post_id: u32, comment_id: u32) -> Result<String, String> {
+    match post_id {
+        1 => POST_1_FETCHES.fetch_add(1, Ordering::SeqCst),
+        _ => POST_2_FETCHES.fetch_add(1, Ordering::SeqCst),
+    };
+    Ok(format!("comment {comment_id} on post {post_id}"))
+}
+
+#[mutation(invalidates = [fetch_comment(post_id, comment_id)])]
+async fn edit_comment(post_id: u32, comment_id: u32, text: String) -> Result<String, String> {
+    Ok(format!("comment {comment_id} on post {post_id}: {text}"))
+}
+
+type MutateFn = Rc<dyn Fn((u32, u32, String))>;
+
+#[tokio::test]
+async fn test_mutation_invalidates_only_the_matching_family_member() {
+    init_global_providers().unwrap();
+
+    let (mut dom, mutate) = common::record_with(|| {
+        let first = use_provider(fetch_comment(), (1u32, 5u32));
+        let second = use_provider(fetch_comment(), (2u32, 5u32));
+        let (_state, mutate) = use_mutation(edit_comment());
+        let element = rsx! {
+            "{first.read().is_loading()} {second.read().is_loading()}"
+        };
+        (Rc::new(mutate) as MutateFn, element)
+    });
+    settle(&mut dom).await;
+    assert_eq!(POST_1_FETCHES.load(Ordering::SeqCst), 1);
+    assert_eq!(POST_2_FETCHES.load(Ordering::SeqCst), 1);
+
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        (mutate.last().unwrap())((1, 5, "edited".to_string()));
+    });
+    settle(&mut dom).await;
+
+    // Only the comment on post 1 was refetched
+    assert_eq!(POST_1_FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(POST_2_FETCHES.load(Ordering::SeqCst), 1);
+}
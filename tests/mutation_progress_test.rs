@@ -0,0 +1,71 @@
+// Tests for #[mutation(with_progress)] reporting progress to use_mutation_with_progress
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    mutation::Mutation,
+    prelude::*,
+    progress::{MutationProgress, ProgressReporter},
+};
+use std::time::Duration;
+
+#[mutation(with_progress)]
+async fn upload(chunks: u32, progress: ProgressReporter) -> Result<u32, String> {
+    progress.step("Uploading");
+    for chunk in 1..=chunks {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        progress.set(f64::from(chunk) / f64::from(chunks));
+    }
+    progress.step("Done");
+    Ok(chunks)
+}
+
+/// The reported progress and state of an upload started on the first render
+fn use_upload() -> (MutationProgress, MutationState<u32, String>) {
+    let (state, progress, upload) = use_mutation_with_progress(upload());
+    use_hook(move || upload(4));
+
+    let progress = progress.read().clone();
+    let state = state.read().clone();
+    (progress, state)
+}
+
+#[tokio::test]
+async fn test_hook_shows_reported_progress() {
+    let (mut dom, renders) = common::record(use_upload);
+    run_for(&mut dom, Duration::from_millis(300)).await;
+
+    let (reports, states): (Vec<_>, Vec<_>) = renders.all().into_iter().unzip();
+    let fractions: Vec<f64> = reports
+        .iter()
+        .filter_map(|report| report.fraction)
+        .collect();
+    for fraction in [0.25, 0.5, 0.75] {
+        assert!(
+            fractions.contains(&fraction),
+            "missing {fraction} in {fractions:?}"
+        );
+    }
+    assert!(
+        reports
+            .iter()
+            .any(|report| report.step.as_deref() == Some("Uploading") && report.fraction.is_some())
+    );
+    assert_eq!(
+        reports.last(),
+        Some(&MutationProgress {
+            fraction: Some(1.0),
+            step: Some("Done".to_string()),
+        })
+    );
+    assert_eq!(reports.last().unwrap().percent(), 100.0);
+    assert!(states.last() == Some(&MutationState::Success(4)));
+}
+
+#[tokio::test]
+async fn test_progress_is_ignored_without_the_hook() {
+    assert!(!ProgressReporter::claim().is_connected());
+    assert_eq!(upload().mutate(2).await, Ok(2));
+}
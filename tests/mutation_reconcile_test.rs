@@ -0,0 +1,90 @@
+// Tests for #[mutation(optimistic = ...)] reconciling optimistic values with the mutation's result
+
+mod common;
+
+use common::{Renders, run_for};
+use dioxus::prelude::*;
+use dioxus_provider::{global::invalidate_provider, prelude::*};
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_name(id: u32) -> Result<String, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    Ok(format!("Name {id} (fetch {fetch})"))
+}
+
+// The server normalizes the name, so the confirmed value differs from the optimistic one
+#[mutation(updates = [fetch_name(*id)], optimistic = name.clone())]
+async fn rename(id: u32, name: String) -> Result<String, String> {
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    if name.trim().is_empty() {
+        return Err(format!("name of {id} is required"));
+    }
+    Ok(name.trim().to_uppercase())
+}
+
+type RenameFn = Rc<dyn Fn((u32, String))>;
+
+/// The shown name, if loaded, and the rename trigger
+fn use_name() -> (Option<String>, RenameFn) {
+    let name = use_provider(fetch_name(), 1u32);
+    let (_state, rename) = use_mutation(rename());
+    let name = match &*name.read() {
+        ProviderState::Success(name) => Some(name.clone()),
+        _ => None,
+    };
+    (name, Rc::new(rename))
+}
+
+fn rename_to(dom: &VirtualDom, renders: &Renders<(Option<String>, RenameFn)>, name: &str) {
+    let (_, rename) = renders.last().unwrap();
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || rename((1, name.to_string())));
+}
+
+/// Each distinct name shown, in order
+fn names(renders: &Renders<(Option<String>, RenameFn)>) -> Vec<String> {
+    let mut names: Vec<String> = renders
+        .all()
+        .into_iter()
+        .filter_map(|(name, _)| name)
+        .collect();
+    names.dedup();
+    names
+}
+
+#[tokio::test]
+async fn test_optimistic_value_is_reconciled_then_rolled_back() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_name);
+    let names = || names(&renders);
+    run_for(&mut dom, Duration::from_millis(60)).await;
+    assert_eq!(names(), ["Name 1 (fetch 1)"]);
+
+    // A refetch is running when the mutation starts and finishes before it
+    invalidate_provider(fetch_name(), 1u32).unwrap();
+    run_for(&mut dom, Duration::from_millis(5)).await;
+    rename_to(&dom, &renders, " ada ");
+    run_for(&mut dom, Duration::from_millis(5)).await;
+    assert_eq!(names().last().map(String::as_str), Some(" ada "));
+
+    // The refetched name never replaces the optimistic one, the confirmed name does
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(names(), ["Name 1 (fetch 1)", " ada ", "ADA"]);
+
+    // A failed mutation shows its optimistic value, then restores the confirmed one
+    rename_to(&dom, &renders, " ");
+    run_for(&mut dom, Duration::from_millis(5)).await;
+    assert_eq!(names().last().map(String::as_str), Some(" "));
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(names(), ["Name 1 (fetch 1)", " ada ", "ADA", " ", "ADA"]);
+}
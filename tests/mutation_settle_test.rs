@@ -0,0 +1,159 @@
+// Tests for #[mutation(await_invalidations)] settling once the invalidated providers refetched
+
+mod common;
+
+use common::{Renders, run_for};
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    future::Future,
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_total() -> Result<u32, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    Ok(fetch)
+}
+
+#[mutation(invalidates = [fetch_total], await_invalidations = "1s")]
+async fn add_settled(amount: u32) -> Result<u32, String> {
+    Ok(amount)
+}
+
+#[mutation(invalidates = [fetch_total])]
+async fn add(amount: u32) -> Result<u32, String> {
+    Ok(amount)
+}
+
+#[provider]
+async fn fetch_label(id: u32) -> Result<String, String> {
+    Ok(format!("label {id}"))
+}
+
+#[mutation(updates = [fetch_label(*id)], optimistic = label.clone())]
+async fn relabel(id: u32, label: String) -> Result<String, String> {
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    Ok(format!("{} #{id}", label.to_uppercase()))
+}
+
+type RelabelFn = Rc<dyn Fn(&str)>;
+
+/// The shown label of `id`, if loaded, and a relabel trigger dropping its future
+fn use_label(id: u32) -> (Option<String>, RelabelFn) {
+    let label = use_provider(fetch_label(), id);
+    let (_state, relabel) = use_mutation_async(relabel());
+    let label = match &*label.read() {
+        ProviderState::Success(label) => Some(label.clone()),
+        _ => None,
+    };
+    (
+        label,
+        Rc::new(move |label| drop(relabel((id, label.to_string())))),
+    )
+}
+
+type Total = ProviderState<u32, String>;
+type AddFn = Rc<dyn Fn(u32)>;
+
+/// Wraps a mutation trigger to record the total shown when its future resolves
+fn record_resolved<F>(
+    mutate: impl Fn(u32) -> F + 'static,
+    total: Signal<Total>,
+    resolved: &Renders<Total>,
+) -> AddFn
+where
+    F: Future<Output = Result<u32, String>> + 'static,
+{
+    let resolved = resolved.clone();
+    Rc::new(move |amount| {
+        let run = mutate(amount);
+        let resolved = resolved.clone();
+        spawn(async move {
+            run.await.unwrap();
+            resolved.push(total.peek().clone());
+        });
+    })
+}
+
+#[tokio::test]
+async fn test_mutate_async_resolves_after_invalidated_refetch() {
+    // The total shown when each mutation's future resolved
+    let resolved = Renders::default();
+    let (mut dom, adders) = common::record({
+        let resolved = resolved.clone();
+        move || {
+            let total = use_provider(fetch_total(), ());
+            let (_state, add_settled) = use_mutation_async(add_settled());
+            let (_state, add) = use_mutation_async(add());
+            (
+                record_resolved(add_settled, total, &resolved),
+                record_resolved(add, total, &resolved),
+            )
+        }
+    });
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+    // The future is pending while the invalidated provider refetches
+    let (add_settled, add) = adders.last().unwrap();
+    dom.runtime().on_scope(ScopeId::ROOT, || add_settled(5));
+    run_for(&mut dom, Duration::from_millis(40)).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    assert!(resolved.is_empty());
+
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(resolved.all(), [ProviderState::Success(2)]);
+
+    // Without await_invalidations the future resolves before the refetch finishes
+    dom.runtime().on_scope(ScopeId::ROOT, || add(5));
+    run_for(&mut dom, Duration::from_millis(40)).await;
+    assert_eq!(resolved.len(), 2);
+    assert_ne!(resolved.all()[1], ProviderState::Success(3));
+
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_dropped_future_still_settles_the_mutation() {
+    let (mut dom, renders) = common::record(|| use_label(1));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    let (label, relabel) = renders.last().unwrap();
+    assert_eq!(label.as_deref(), Some("label 1"));
+
+    dom.runtime().on_scope(ScopeId::ROOT, || relabel("draft"));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(renders.last().unwrap().0.as_deref(), Some("draft"));
+
+    // The confirmed value replaces the optimistic one although nothing awaited the mutation
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(renders.last().unwrap().0.as_deref(), Some("DRAFT #1"));
+}
+
+#[tokio::test]
+async fn test_unmounting_rolls_back_the_optimistic_value() {
+    let (mut dom, renders) = common::record(|| use_label(2));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    let (_, relabel) = renders.last().unwrap();
+    dom.runtime().on_scope(ScopeId::ROOT, || relabel("draft"));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(renders.last().unwrap().0.as_deref(), Some("draft"));
+
+    // Dropping the running mutation restores the entry it wrote its optimistic value into
+    drop(dom);
+    let (mut dom, renders) = common::record(|| use_label(2));
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(renders.last().unwrap().0.as_deref(), Some("label 2"));
+    assert!(
+        renders
+            .all()
+            .iter()
+            .all(|(label, _)| label.as_deref() != Some("draft"))
+    );
+}
@@ -0,0 +1,82 @@
+// Tests for #[mutation(invalidates_tags = [...])] invalidating every provider with a tag
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+static LIST_FETCHES: AtomicU32 = AtomicU32::new(0);
+static COUNT_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(tags = ["users"])]
+async fn fetch_user_list() -> Result<Vec<String>, String> {
+    LIST_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(vec!["Ada".to_string()])
+}
+
+#[provider(tags = ["users", "stats"])]
+async fn fetch_user_count(team: u32) -> Result<u32, String> {
+    COUNT_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(team)
+}
+
+#[provider(tags = ["settings"])]
+async fn fetch_settings() -> Result<String, String> {
+    Ok("dark".to_string())
+}
+
+#[mutation(invalidates_tags = ["users"])]
+async fn import_users(names: Vec<String>) -> Result<usize, String> {
+    Ok(names.len())
+}
+
+type MutateFn = Rc<dyn Fn(Vec<String>)>;
+
+#[tokio::test]
+async fn test_tagged_mutation_invalidates_tagged_providers() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    assert_eq!(import_users().invalidates_tags(), &["users"]);
+    assert_eq!(fetch_user_count().metadata().tags, vec!["users", "stats"]);
+
+    let (mut dom, mutate) = common::record_with(|| {
+        let list = use_provider(fetch_user_list(), ());
+        let count = use_provider(fetch_user_count(), 1u32);
+        let settings = use_provider(fetch_settings(), ());
+        let (_state, mutate) = use_mutation(import_users());
+        let element = rsx! {
+            "{list.read().is_loading()} {count.read().is_loading()} {settings.read().is_loading()}"
+        };
+        (Rc::new(mutate) as MutateFn, element)
+    });
+    settle(&mut dom).await;
+    assert_eq!(LIST_FETCHES.load(Ordering::SeqCst), 1);
+    assert_eq!(COUNT_FETCHES.load(Ordering::SeqCst), 1);
+    let settings_key = fetch_settings().id(&());
+    let settings_before = cache
+        .entries()
+        .into_iter()
+        .find(|(key, _)| *key == settings_key);
+
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        (mutate.last().unwrap())(vec!["Grace".to_string()]);
+    });
+    settle(&mut dom).await;
+
+    // Both providers tagged "users" refetched, the untagged one kept its entry
+    assert_eq!(LIST_FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(COUNT_FETCHES.load(Ordering::SeqCst), 2);
+    let settings_after = cache
+        .entries()
+        .into_iter()
+        .find(|(key, _)| *key == settings_key);
+    assert!(settings_before.unwrap().1.age <= settings_after.unwrap().1.age);
+
+    // Refetched entries are indexed again
+    assert_eq!(invalidate_tag("stats"), 1);
+}
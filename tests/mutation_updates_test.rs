@@ -0,0 +1,69 @@
+// Tests for #[mutation(updates = [...])] writing mutation output straight into provider caches
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct User {
+    id: u32,
+    name: String,
+}
+
+static FETCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<User, String> {
+    FETCH_COUNT.fetch_add(1, Ordering::SeqCst);
+    Ok(User {
+        id,
+        name: "Original".to_string(),
+    })
+}
+
+#[mutation(updates = [fetch_user(user.id)])]
+async fn rename_user(user: User) -> Result<User, String> {
+    Ok(user)
+}
+
+type MutateFn = Rc<dyn Fn(User)>;
+
+/// The shown user name, if loaded, and the rename trigger
+fn use_user_name() -> (Option<String>, MutateFn) {
+    let user = use_provider(fetch_user(), 1u32);
+    let (_state, mutate) = use_mutation(rename_user());
+    let name = match &*user.read() {
+        ProviderState::Success(user) => Some(user.name.clone()),
+        _ => None,
+    };
+    (name, Rc::new(mutate))
+}
+
+#[tokio::test]
+async fn test_updates_mutation_writes_cache_without_refetch() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_user_name);
+    settle(&mut dom).await;
+
+    assert_eq!(FETCH_COUNT.load(Ordering::SeqCst), 1);
+    let (name, mutate) = renders.last().unwrap();
+    assert_eq!(name, Some("Original".to_string()));
+
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        mutate(User {
+            id: 1,
+            name: "Renamed".to_string(),
+        })
+    });
+    settle(&mut dom).await;
+
+    assert_eq!(FETCH_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(renders.last().unwrap().0, Some("Renamed".to_string()));
+}
@@ -0,0 +1,36 @@
+// Tests for hooks using named caches that were never initialized with init_named_cache
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_named_cache, prelude::*};
+
+#[provider(cache = "uninitialized")]
+async fn fetch_profile(id: u32) -> Result<String, String> {
+    Ok(format!("profile {id}"))
+}
+
+#[tokio::test]
+async fn test_hooks_create_uninitialized_named_cache() {
+    init_global_providers().unwrap();
+    assert!(get_named_cache("uninitialized").is_err());
+
+    let (mut dom, states) = common::record(|| {
+        let _clear = use_clear_named_provider_cache("never_initialized");
+        use_provider(fetch_profile(), 1u32).read().clone()
+    });
+    settle(&mut dom).await;
+
+    // The hooks render with default caches instead of panicking
+    assert!(matches!(
+        states.last(),
+        Some(ProviderState::Success(profile)) if profile == "profile 1"
+    ));
+    let cache = get_named_cache("uninitialized").unwrap();
+    assert_eq!(
+        cache.get::<Result<String, String>>(&fetch_profile().id(&1)),
+        Some(Ok("profile 1".to_string()))
+    );
+    assert!(get_named_cache("never_initialized").is_ok());
+}
@@ -0,0 +1,68 @@
+// Tests for providers with the same name in different modules getting distinct cache keys
+
+use dioxus_provider::prelude::*;
+
+mod users {
+    use dioxus_provider::prelude::*;
+
+    #[provider(namespaced)]
+    pub async fn fetch_data(id: u32) -> Result<String, String> {
+        Ok(format!("user {id}"))
+    }
+
+    #[provider]
+    pub async fn fetch_plain() -> Result<String, String> {
+        Ok("users".to_string())
+    }
+}
+
+mod orders {
+    use dioxus_provider::prelude::*;
+
+    #[provider(namespaced)]
+    pub async fn fetch_data(id: u32) -> Result<String, String> {
+        Ok(format!("order {id}"))
+    }
+
+    #[provider]
+    pub async fn fetch_plain() -> Result<String, String> {
+        Ok("orders".to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Page {
+    number: u32,
+}
+
+impl ProviderKey for Page {
+    fn provider_key(&self) -> String {
+        format!("page-{}", self.number)
+    }
+}
+
+#[provider(namespaced)]
+async fn fetch_page(page: Page) -> Result<Vec<u32>, String> {
+    Ok((0..10).map(|i| page.number * 10 + i).collect())
+}
+
+#[test]
+fn test_same_named_providers_in_different_modules_have_distinct_keys() {
+    let user_key = users::fetch_data().id(&1);
+    let order_key = orders::fetch_data().id(&1);
+    assert_ne!(user_key, order_key);
+    assert!(user_key.starts_with("namespaced_provider_test::users::FetchData("));
+    assert!(order_key.starts_with("namespaced_provider_test::orders::FetchData("));
+    assert_ne!(users::fetch_data().id(&1), users::fetch_data().id(&2));
+
+    // Providers without `namespaced` are keyed by their type, which differs per module too
+    assert_ne!(users::fetch_plain().id(&()), orders::fetch_plain().id(&()));
+}
+
+#[test]
+fn test_namespaced_key_uses_the_param_key() {
+    assert_eq!(
+        fetch_page().id(&Page { number: 2 }),
+        "namespaced_provider_test::FetchPage(page-2)"
+    );
+}
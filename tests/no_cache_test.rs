@@ -0,0 +1,229 @@
+// Tests for providers declared with `no_cache = true` fetching per mount without caching
+
+mod common;
+
+use common::{record, run_for};
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, hooks::Freshness, prelude::*};
+use std::{
+    cell::RefCell,
+    sync::atomic::{AtomicU32, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+static NONCES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(no_cache = true)]
+async fn fetch_nonce() -> Result<u32, String> {
+    Ok(NONCES.fetch_add(1, Ordering::SeqCst))
+}
+
+static TOKENS: AtomicU32 = AtomicU32::new(0);
+
+#[provider(no_cache = true)]
+async fn fetch_token(scope: u32) -> Result<u32, String> {
+    Ok(scope * 100 + TOKENS.fetch_add(1, Ordering::SeqCst))
+}
+
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+static MAX_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+#[provider(no_cache = true, concurrency = 1)]
+async fn fetch_quote(symbol: u32) -> Result<u32, String> {
+    let running = IN_FLIGHT.fetch_add(1, Ordering::SeqCst) + 1;
+    MAX_IN_FLIGHT.fetch_max(running, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+    Ok(symbol)
+}
+
+static ROW_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(no_cache = true)]
+async fn fetch_row(id: u32) -> Result<u32, String> {
+    ROW_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(id * 10)
+}
+
+#[provider(no_cache = true)]
+async fn fetch_row_from_replica(id: u32) -> Result<u32, String> {
+    Err(format!("replica has no row {id}"))
+}
+
+/// Rows fetched in one batch that must never be cached
+#[derive(Clone, PartialEq)]
+struct OneTimeRows;
+
+impl Provider<u32> for OneTimeRows {
+    type Output = u32;
+    type Error = String;
+
+    async fn run(&self, id: u32) -> Result<u32, String> {
+        panic!("row {id} should be fetched in a batch");
+    }
+
+    fn cacheable(&self) -> bool {
+        false
+    }
+}
+
+impl BatchProvider<u32> for OneTimeRows {
+    async fn run_batch(&self, ids: &[u32]) -> Vec<Result<u32, String>> {
+        ids.iter().map(|id| Ok(id * 100)).collect()
+    }
+}
+
+thread_local! {
+    static SEEN: RefCell<Vec<ProviderState<u32, String>>> = const { RefCell::new(Vec::new()) };
+    static SUSPENDED_TOKEN: RefCell<Option<Result<u32, String>>> = const { RefCell::new(None) };
+}
+
+#[component]
+fn NonceView(slot: usize) -> Element {
+    let nonce = use_provider(fetch_nonce(), ());
+    if let ProviderState::Success(value) = &*nonce.read() {
+        SEEN.with(|seen| {
+            let mut seen = seen.borrow_mut();
+            if seen.len() <= slot {
+                seen.resize(slot + 1, ProviderState::Idle);
+            }
+            seen[slot] = ProviderState::Success(*value);
+        });
+    }
+    rsx! {}
+}
+
+#[tokio::test]
+async fn test_each_mount_fetches_without_caching() {
+    let mut dom = common::mount(|| {
+        rsx! {
+            NonceView { slot: 0 }
+            NonceView { slot: 1 }
+        }
+    });
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert_eq!(NONCES.load(Ordering::SeqCst), 2);
+    let mut seen = SEEN.with(|seen| seen.borrow().clone());
+    seen.sort_by_key(|state| match state {
+        ProviderState::Success(value) => *value,
+        _ => u32::MAX,
+    });
+    assert_eq!(
+        seen,
+        vec![ProviderState::Success(0), ProviderState::Success(1)]
+    );
+
+    let cache = get_global_cache().unwrap();
+    assert!(cache.snapshot(&fetch_nonce().id(&())).is_none());
+    assert_eq!(cache.size(), 0);
+}
+
+#[tokio::test]
+async fn test_fetch_and_prefetch_run_without_storing() {
+    let first = fetch_provider(fetch_token(), 1).await.unwrap().unwrap();
+    let second = prefetch_provider(fetch_token(), 1).await.unwrap().unwrap();
+    assert_ne!(first, second);
+    assert!(
+        get_global_cache()
+            .unwrap()
+            .snapshot(&fetch_token().id(&1))
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_uncached_fetches_keep_the_concurrency_limit() {
+    let (mut dom, renders) =
+        record(|| [1u32, 2, 3].map(|symbol| use_provider(fetch_quote(), symbol).read().clone()));
+    run_for(&mut dom, Duration::from_millis(200)).await;
+
+    assert_eq!(
+        renders.last().unwrap(),
+        [1, 2, 3].map(ProviderState::<u32, String>::Success)
+    );
+    assert_eq!(MAX_IN_FLIGHT.load(Ordering::SeqCst), 1);
+}
+
+#[component]
+fn SuspendedToken() -> Element {
+    let token = use_provider_suspense(fetch_token(), 2u32)?;
+    SUSPENDED_TOKEN.with(|shown| *shown.borrow_mut() = Some(token));
+    rsx! {}
+}
+
+#[tokio::test]
+async fn test_suspense_swr_and_freshness_hooks_bypass_the_cache() {
+    let mut dom = common::mount(|| {
+        rsx! {
+            SuspenseBoundary { fallback: |_| rsx! {}, SuspendedToken {} }
+        }
+    });
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    let shown = SUSPENDED_TOKEN.with(|shown| shown.borrow().clone());
+    assert!(matches!(shown, Some(Ok(token)) if token / 100 == 2));
+
+    let (mut dom, renders) = record(|| {
+        let swr = use_provider_swr(fetch_token(), 3u32);
+        let freshness = use_provider_freshness(fetch_token(), 3u32);
+        (swr.read().clone(), freshness())
+    });
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    let (swr, freshness) = renders.last().unwrap();
+    assert!(matches!(swr.data, Some(token) if token / 100 == 3));
+    assert!(!swr.is_stale && !swr.is_revalidating);
+    assert_eq!(freshness, Freshness::default());
+
+    let cache = get_global_cache().unwrap();
+    assert!(cache.snapshot(&fetch_token().id(&2)).is_none());
+    assert!(cache.snapshot(&fetch_token().id(&3)).is_none());
+}
+
+#[tokio::test]
+async fn test_batch_and_fallback_hooks_bypass_the_cache() {
+    let (mut dom, renders) = record(|| {
+        let rows = use_provider_batch(fetch_row(), vec![1u32, 2, 1]);
+        let batched = use_batch_provider(OneTimeRows, vec![3u32, 4]);
+        let fallback = use_provider_with_fallback(fetch_row_from_replica(), fetch_row(), 5u32);
+        (
+            rows.read().clone(),
+            batched.read().clone(),
+            fallback.read().clone(),
+        )
+    });
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let (rows, batched, fallback) = renders.last().unwrap();
+    assert_eq!(
+        rows,
+        [10, 20, 10].map(ProviderState::<u32, String>::Success)
+    );
+    assert_eq!(
+        batched,
+        [300, 400].map(ProviderState::<u32, String>::Success)
+    );
+    assert_eq!(fallback, ProviderState::Success(50));
+    // Repeated params are fetched once per batch
+    assert_eq!(ROW_FETCHES.load(Ordering::SeqCst), 3);
+
+    let cache = get_global_cache().unwrap();
+    for id in [1, 2, 5] {
+        assert!(cache.snapshot(&fetch_row().id(&id)).is_none());
+    }
+    for id in [3, 4] {
+        assert!(cache.snapshot(&OneTimeRows.id(&id)).is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_seed_provider_skips_uncached_providers() {
+    init_global_providers().unwrap();
+
+    assert!(!seed_provider(fetch_row(), 6, 60).unwrap());
+    assert!(
+        get_global_cache()
+            .unwrap()
+            .snapshot(&fetch_row().id(&6))
+            .is_none()
+    );
+}
@@ -0,0 +1,40 @@
+// Tests for #[provider(no_persist = true)] keeping entries out of persistence and snapshots
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+
+#[provider(no_persist = true)]
+async fn fetch_auth_token() -> Result<String, String> {
+    Ok("secret".to_string())
+}
+
+#[provider]
+async fn fetch_theme() -> Result<String, String> {
+    Ok("dark".to_string())
+}
+
+#[tokio::test]
+async fn test_no_persist_entries_are_excluded_from_persistable_keys() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    assert!(!fetch_auth_token().persistable());
+    assert!(fetch_theme().persistable());
+
+    let mut dom = common::mount(|| {
+        let token = use_provider(fetch_auth_token(), ());
+        let theme = use_provider(fetch_theme(), ());
+        rsx! { "{token.read().is_loading()} {theme.read().is_loading()}" }
+    });
+    settle(&mut dom).await;
+
+    let token_key = fetch_auth_token().id(&());
+    assert_eq!(
+        cache.get::<Result<String, String>>(&token_key),
+        Some(Ok("secret".to_string()))
+    );
+    assert_eq!(cache.persistable_keys(), vec![fetch_theme().id(&())]);
+    assert!(!cache.is_persistable(&token_key));
+}
@@ -0,0 +1,69 @@
+// Tests for #[provider(normalize = ...)] sharing cache entries between equivalent params
+
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static SEARCHES: AtomicU32 = AtomicU32::new(0);
+
+/// Tags match regardless of order and case
+fn canonical_tags(tags: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = tags.iter().map(|tag| tag.to_lowercase()).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+#[provider(normalize = canonical_tags)]
+async fn fetch_search(tags: Vec<String>) -> Result<String, String> {
+    SEARCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(tags.join("+"))
+}
+
+/// The page size doesn't change which items are listed
+fn ignore_page_size(params: &(String, u32)) -> &String {
+    &params.0
+}
+
+#[provider(normalize = ignore_page_size, namespaced)]
+async fn fetch_listing(query: String, page_size: u32) -> Result<String, String> {
+    Ok(format!("{query}:{page_size}"))
+}
+
+fn tags(tags: &[&str]) -> Vec<String> {
+    tags.iter().map(|tag| tag.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_equivalent_params_share_a_cache_entry() {
+    let key = fetch_search().id(&tags(&["a", "b"]));
+    assert_eq!(key, fetch_search().id(&tags(&["b", "A", "a"])));
+    assert_ne!(key, fetch_search().id(&tags(&["a", "c"])));
+
+    // The provider still receives the parameter as passed
+    assert_eq!(
+        fetch_provider(fetch_search(), tags(&["b", "a"]))
+            .await
+            .unwrap(),
+        Ok("b+a".to_string())
+    );
+    let cache = get_global_cache().unwrap();
+    assert_eq!(
+        cache.get::<Result<String, String>>(&fetch_search().id(&tags(&["a", "b"]))),
+        Some(Ok("b+a".to_string()))
+    );
+    assert_eq!(cache.size(), 1);
+    assert_eq!(SEARCHES.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_normalized_namespaced_key_and_multiple_params() {
+    assert_eq!(
+        fetch_listing().id(&("shoes".to_string(), 10)),
+        fetch_listing().id(&("shoes".to_string(), 50))
+    );
+    assert!(
+        fetch_listing()
+            .id(&("shoes".to_string(), 10))
+            .starts_with("normalize_param_test::FetchListing(")
+    );
+}
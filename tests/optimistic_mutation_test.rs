@@ -0,0 +1,164 @@
+// Tests for use_optimistic_mutation_for writing optimistic values into specific provider entries
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, mutation::CacheUpdate, prelude::*};
+use std::{rc::Rc, time::Duration};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Todo {
+    id: u32,
+    title: String,
+    done: bool,
+}
+
+#[provider]
+async fn fetch_todo(id: u32) -> Result<Todo, String> {
+    Ok(Todo {
+        id,
+        title: format!("Todo {}", id),
+        done: false,
+    })
+}
+
+#[mutation]
+async fn save_todo(todo: Todo) -> Result<Todo, String> {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    if todo.title.is_empty() {
+        Err("title is required".to_string())
+    } else {
+        Ok(todo)
+    }
+}
+
+type MutateFn = Rc<dyn Fn(Todo)>;
+
+/// Shows two todos and returns the trigger of the optimistic save
+fn use_todos() -> MutateFn {
+    let _first = use_provider(fetch_todo(), 1u32);
+    let _second = use_provider(fetch_todo(), 2u32);
+    let (_state, mutate) = use_optimistic_mutation_for(save_todo(), |todo: &Todo| {
+        vec![CacheUpdate::new(fetch_todo(), todo.id, todo.clone())]
+    });
+    Rc::new(mutate)
+}
+
+fn cached_todo(id: u32) -> Option<Todo> {
+    get_global_cache()
+        .unwrap()
+        .get::<Result<Todo, String>>(&fetch_todo().id(&id))
+        .and_then(Result::ok)
+}
+
+#[tokio::test]
+async fn test_optimistic_update_targets_one_entry_and_rolls_back() {
+    init_global_providers().unwrap();
+
+    let (mut dom, triggers) = common::record(use_todos);
+    let mutate = |dom: &VirtualDom, todo: Todo| {
+        let mutate = triggers.last().unwrap();
+        dom.runtime().on_scope(ScopeId::ROOT, || mutate(todo));
+    };
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let original = cached_todo(2).unwrap();
+
+    // The optimistic value is visible before the mutation finishes, and only for its entry
+    let done = Todo {
+        done: true,
+        ..original.clone()
+    };
+    mutate(&dom, done.clone());
+    assert_eq!(cached_todo(2), Some(done.clone()));
+    assert_eq!(cached_todo(1).map(|todo| todo.done), Some(false));
+
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(cached_todo(2), Some(done.clone()));
+
+    // A failed mutation restores the entry from its snapshot
+    let invalid = Todo {
+        title: String::new(),
+        ..done.clone()
+    };
+    mutate(&dom, invalid.clone());
+    assert_eq!(cached_todo(2), Some(invalid));
+
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(cached_todo(2), Some(done));
+}
+
+#[tokio::test]
+async fn test_earlier_trigger_applies_the_latest_closure() {
+    init_global_providers().unwrap();
+
+    // The closure captures a label that changes between renders
+    let (mut dom, renders) = common::record(|| {
+        let label = use_signal(|| "draft");
+        let _todo = use_provider(fetch_todo(), 3u32);
+        let current = label();
+        let (_state, mutate) = use_optimistic_mutation_for(save_todo(), move |todo: &Todo| {
+            let todo = Todo {
+                title: format!("{} ({current})", todo.title),
+                ..todo.clone()
+            };
+            vec![CacheUpdate::new(fetch_todo(), todo.id, todo)]
+        });
+        (label, Rc::new(mutate) as MutateFn)
+    });
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let (mut label, first_trigger) = renders.all().remove(0);
+
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || label.set("saving"));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+
+    let todo = cached_todo(3).unwrap();
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || first_trigger(todo.clone()));
+    assert_eq!(
+        cached_todo(3).map(|todo| todo.title),
+        Some("Todo 3 (saving)".to_string())
+    );
+}
+
+#[provider(tags = ["notes"])]
+async fn fetch_note(id: u32) -> Result<Todo, String> {
+    Ok(Todo {
+        id,
+        title: format!("Note {}", id),
+        done: false,
+    })
+}
+
+#[tokio::test]
+async fn test_rolled_back_entry_stays_invalidatable_by_tag() {
+    init_global_providers().unwrap();
+
+    let (mut dom, triggers) = common::record(|| {
+        let _note = use_provider(fetch_note(), 4u32);
+        let (_state, mutate) = use_optimistic_mutation_for(save_todo(), |todo: &Todo| {
+            vec![CacheUpdate::new(fetch_note(), todo.id, todo.clone())]
+        });
+        Rc::new(mutate) as MutateFn
+    });
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let cache = get_global_cache().unwrap();
+    let key = fetch_note().id(&4);
+
+    // Invalidating the tag while the failing save runs drops the entry from the tag index
+    let mutate = triggers.last().unwrap();
+    dom.runtime().on_scope(ScopeId::ROOT, || {
+        mutate(Todo {
+            id: 4,
+            title: String::new(),
+            done: true,
+        })
+    });
+    assert_eq!(cache.invalidate_tag("notes"), vec![key.clone()]);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    // The rollback restores the entry and indexes it under its tag again
+    assert!(cache.get::<Result<Todo, String>>(&key).is_some());
+    assert_eq!(cache.invalidate_tag("notes"), vec![key]);
+}
@@ -0,0 +1,100 @@
+// Tests for the `prefetch` method generated by #[provider] warming the cache
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static DASHBOARD_FETCHES: AtomicU32 = AtomicU32::new(0);
+static REPORT_FETCHES: AtomicU32 = AtomicU32::new(0);
+static PAGE_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_dashboard(user_id: u32) -> Result<String, String> {
+    DASHBOARD_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("dashboard of {user_id}"))
+}
+
+#[provider]
+async fn fetch_report(year: u32, month: u32) -> Result<String, String> {
+    REPORT_FETCHES.fetch_add(1, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    Ok(format!("report {year}-{month}"))
+}
+
+#[provider]
+async fn fetch_page(page: u32) -> Result<String, String> {
+    PAGE_FETCHES.fetch_add(1, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    Ok(format!("page {page}"))
+}
+
+#[provider(cache = "never-initialized")]
+async fn fetch_archived(page: u32) -> Result<String, String> {
+    Ok(format!("archived {page}"))
+}
+
+#[tokio::test]
+async fn test_prefetch_warms_use_provider() {
+    assert_eq!(
+        FetchDashboard::prefetch(7).await.unwrap(),
+        Ok("dashboard of 7".to_string())
+    );
+    assert_eq!(DASHBOARD_FETCHES.load(Ordering::SeqCst), 1);
+
+    let (mut dom, states) = common::record(|| use_provider(fetch_dashboard(), 7u32).read().clone());
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    assert_eq!(
+        states.last(),
+        Some(ProviderState::Success("dashboard of 7".to_string()))
+    );
+    assert_eq!(DASHBOARD_FETCHES.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_concurrent_prefetches_share_one_fetch() {
+    let (first, second) = tokio::join!(
+        FetchReport::prefetch(2024, 5),
+        FetchReport::prefetch(2024, 5)
+    );
+    assert_eq!(first.unwrap(), Ok("report 2024-5".to_string()));
+    assert_eq!(second.unwrap(), Ok("report 2024-5".to_string()));
+    assert_eq!(REPORT_FETCHES.load(Ordering::SeqCst), 1);
+
+    // Once finished, the next prefetch fetches again
+    FetchReport::prefetch(2024, 5).await.unwrap().unwrap();
+    assert_eq!(REPORT_FETCHES.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_prefetch_joins_a_fetch_started_by_a_component() {
+    let mut dom = common::mount(|| {
+        use_provider(fetch_page(), 3u32);
+        rsx! {}
+    });
+    run_for(&mut dom, Duration::from_millis(10)).await;
+    assert_eq!(PAGE_FETCHES.load(Ordering::SeqCst), 1);
+
+    let (_, prefetched) = tokio::join!(
+        run_for(&mut dom, Duration::from_millis(100)),
+        FetchPage::prefetch(3)
+    );
+    assert_eq!(prefetched.unwrap(), Ok("page 3".to_string()));
+    assert_eq!(PAGE_FETCHES.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_prefetch_reports_an_uninitialized_named_cache() {
+    let error = FetchArchived::prefetch(1).await.unwrap_err();
+    assert!(matches!(
+        error,
+        dioxus_provider::global::GlobalProviderError::NamedCacheNotFound(name)
+            if name == "never-initialized"
+    ));
+}
@@ -0,0 +1,52 @@
+// Tests for #[provider(progressive)] showing partial values before the final result
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*, progressive::Emitter};
+use std::time::Duration;
+
+#[provider(progressive)]
+async fn fetch_report(id: u32, emit: Emitter<String>) -> Result<String, String> {
+    emit.emit(format!("report {id}: header"));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    emit.emit(format!("report {id}: header, summary"));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    Ok(format!("report {id}: header, summary, details"))
+}
+
+#[tokio::test]
+async fn test_partial_values_are_shown_before_the_final_value() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_report().id(&7);
+
+    let (mut dom, states) = common::record(|| use_provider(fetch_report(), 7u32).read().clone());
+    common::settle_rounds(&mut dom, 20, Duration::from_millis(20)).await;
+
+    let mut successes: Vec<String> = states
+        .all()
+        .iter()
+        .filter_map(|state| state.data().cloned())
+        .collect();
+    successes.dedup();
+    assert_eq!(
+        successes,
+        vec![
+            "report 7: header".to_string(),
+            "report 7: header, summary".to_string(),
+            "report 7: header, summary, details".to_string(),
+        ]
+    );
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Ok("report 7: header, summary, details".to_string()))
+    );
+
+    // Running the provider directly discards partial values
+    assert_eq!(
+        fetch_report().run(8).await,
+        Ok("report 8: header, summary, details".to_string())
+    );
+    assert!(!Emitter::<String>::disconnected().is_connected());
+}
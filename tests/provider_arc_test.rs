@@ -0,0 +1,110 @@
+// Tests for sharing provider output behind an Arc
+
+use dioxus_provider::{
+    cache::{ProviderCache, SizedCacheValue},
+    hooks::ArcProvider,
+    prelude::*,
+};
+use std::sync::Arc;
+
+#[provider]
+async fn fetch_rows(count: u32) -> Result<Vec<u32>, String> {
+    Ok((0..count).collect())
+}
+
+#[provider(sized = true, dedupe = true)]
+async fn fetch_sized_rows(count: u32) -> Result<Vec<u32>, String> {
+    Ok((0..count).collect())
+}
+
+fn row_id(row: &u32) -> u32 {
+    *row
+}
+
+#[provider(accumulate = row_id)]
+async fn fetch_page(page: u32) -> Result<Vec<u32>, String> {
+    Ok(vec![page * 2, page * 2 + 1])
+}
+
+#[tokio::test]
+async fn test_consumers_share_the_same_arc() {
+    let provider = ArcProvider::new(fetch_rows());
+    let cache = ProviderCache::new();
+    let key = provider.id(&1000);
+
+    let result = provider.run(1000).await;
+    provider.cache_result(&cache, key.clone(), result);
+
+    let first = cache
+        .get::<Result<Arc<Vec<u32>>, String>>(&key)
+        .unwrap()
+        .unwrap();
+    let second = cache
+        .get::<Result<Arc<Vec<u32>>, String>>(&key)
+        .unwrap()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.len(), 1000);
+}
+
+#[tokio::test]
+async fn test_unchanged_refetch_keeps_the_cached_arc() {
+    let provider = ArcProvider::new(fetch_rows());
+    let cache = ProviderCache::new();
+    let key = provider.id(&10);
+
+    assert!(provider.cache_result(&cache, key.clone(), provider.run(10).await));
+    let before = cache
+        .get::<Result<Arc<Vec<u32>>, String>>(&key)
+        .unwrap()
+        .unwrap();
+
+    assert!(!provider.cache_result(&cache, key.clone(), provider.run(10).await));
+    let after = cache
+        .get::<Result<Arc<Vec<u32>>, String>>(&key)
+        .unwrap()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&before, &after));
+    assert_ne!(key, fetch_rows().id(&10));
+}
+
+#[tokio::test]
+async fn test_inner_provider_sizes_stored_results() {
+    let provider = ArcProvider::new(fetch_sized_rows());
+    let cache = ProviderCache::new();
+    let key = provider.id(&100);
+
+    let result = provider.run(100).await;
+    let expected = result.clone().map(Arc::unwrap_or_clone).approximate_size();
+    assert!(provider.cache_result(&cache, key.clone(), result));
+
+    assert_eq!(cache.total_size(), expected);
+    assert_eq!(
+        cache
+            .get::<Result<Arc<Vec<u32>>, String>>(&key)
+            .unwrap()
+            .unwrap()
+            .len(),
+        100
+    );
+}
+
+#[tokio::test]
+async fn test_inner_provider_merges_fetched_results() {
+    let provider = ArcProvider::new(fetch_page());
+    let cache = ProviderCache::new();
+    let key = provider.id(&0);
+
+    for page in 0..3 {
+        let merged = provider.merge_fetched(&cache, &key, provider.run(page).await);
+        provider.cache_missed_result(&cache, key.clone(), merged);
+    }
+
+    let rows = cache
+        .get::<Result<Arc<Vec<u32>>, String>>(&key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(*rows, vec![0, 1, 2, 3, 4, 5]);
+}
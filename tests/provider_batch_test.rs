@@ -0,0 +1,163 @@
+// Tests for use_provider_batch coalescing fetches and use_batch_provider dispatching one batch
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{sync::Mutex, time::Duration};
+
+static FETCHED: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+#[provider]
+async fn fetch_item(id: u32) -> Result<String, String> {
+    FETCHED.lock().unwrap().push(id);
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    Ok(format!("item {id}"))
+}
+
+static BATCHES: Mutex<Vec<Vec<u32>>> = Mutex::new(Vec::new());
+
+#[derive(Clone, PartialEq)]
+struct RowProvider;
+
+impl Provider<u32> for RowProvider {
+    type Output = String;
+    type Error = String;
+
+    async fn run(&self, id: u32) -> Result<String, String> {
+        panic!("row {id} should be fetched in a batch");
+    }
+}
+
+impl BatchProvider<u32> for RowProvider {
+    async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+        BATCHES.lock().unwrap().push(ids.to_vec());
+        ids.iter()
+            .map(|id| match id {
+                0 => Err("row 0 is reserved".to_string()),
+                id => Ok(format!("row {id}")),
+            })
+            .collect()
+    }
+}
+
+type States = Vec<ProviderState<String, String>>;
+
+/// The params signal and the states of a batch of items
+fn use_items() -> (Signal<Vec<u32>>, States) {
+    let params = use_signal(|| vec![1, 2, 2, 3, 1]);
+    let items = use_provider_batch(fetch_item(), params());
+    let items = items.read().clone();
+    (params, items)
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    common::settle_rounds(dom, 5, Duration::from_millis(40)).await;
+}
+
+fn success(values: &[&str]) -> States {
+    values
+        .iter()
+        .map(|value| ProviderState::Success(value.to_string()))
+        .collect()
+}
+
+fn set_params(dom: &VirtualDom, mut signal: Signal<Vec<u32>>, params: Vec<u32>) {
+    dom.runtime().on_scope(ScopeId::ROOT, || signal.set(params));
+}
+
+#[tokio::test]
+async fn test_batch_coalesces_duplicate_and_in_flight_keys() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_items);
+    settle(&mut dom).await;
+    let params = renders.last().unwrap().0;
+
+    let mut fetched = FETCHED.lock().unwrap().clone();
+    fetched.sort();
+    assert_eq!(fetched, vec![1, 2, 3]);
+    assert_eq!(
+        renders.last().unwrap().1,
+        success(&["item 1", "item 2", "item 2", "item 3", "item 1"])
+    );
+
+    // Item 5 is still being fetched when the params change again
+    set_params(&dom, params, vec![4, 5]);
+    let _ = tokio::time::timeout(Duration::from_millis(5), dom.wait_for_work()).await;
+    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    set_params(&dom, params, vec![5, 6, 2]);
+    settle(&mut dom).await;
+
+    let fetched = FETCHED.lock().unwrap().clone();
+    assert_eq!(fetched.iter().filter(|id| **id == 5).count(), 1);
+    assert_eq!(fetched.iter().filter(|id| **id == 2).count(), 1);
+    assert_eq!(
+        renders.last().unwrap().1,
+        success(&["item 5", "item 6", "item 2"])
+    );
+}
+
+#[tokio::test]
+async fn test_batch_provider_fetches_missing_rows_in_one_call() {
+    init_global_providers().unwrap();
+    seed_provider(RowProvider, 5, "seeded row 5".to_string()).unwrap();
+
+    let (mut dom, rows) = common::record(|| {
+        use_batch_provider(RowProvider, vec![4, 5, 0, 6, 4])
+            .read()
+            .clone()
+    });
+    settle(&mut dom).await;
+
+    assert_eq!(*BATCHES.lock().unwrap(), vec![vec![4, 0, 6]]);
+    let mut expected = success(&["row 4", "seeded row 5", "", "row 6", "row 4"]);
+    expected[2] = ProviderState::Error("row 0 is reserved".to_string());
+    assert_eq!(rows.last(), Some(expected));
+}
+
+#[derive(Clone, PartialEq)]
+struct ShortBatchProvider;
+
+impl Provider<u32> for ShortBatchProvider {
+    type Output = String;
+    type Error = String;
+
+    async fn run(&self, id: u32) -> Result<String, String> {
+        panic!("row {id} should be fetched in a batch");
+    }
+
+    fn id(&self, id: &u32) -> String {
+        format!("short_row_{id}")
+    }
+}
+
+impl BatchProvider<u32> for ShortBatchProvider {
+    async fn run_batch(&self, ids: &[u32]) -> Vec<Result<String, String>> {
+        // Drops the last row
+        ids[..ids.len() - 1]
+            .iter()
+            .map(|id| Ok(format!("row {id}")))
+            .collect()
+    }
+
+    async fn missing_result(&self, id: &u32) -> Result<String, String> {
+        Err(format!("row {id} missing from batch"))
+    }
+}
+
+#[tokio::test]
+async fn test_short_batch_reports_missing_rows() {
+    init_global_providers().unwrap();
+
+    let (mut dom, rows) = common::record(|| {
+        use_batch_provider(ShortBatchProvider, vec![1, 2, 3])
+            .read()
+            .clone()
+    });
+    settle(&mut dom).await;
+
+    let mut expected = success(&["row 1", "row 2", ""]);
+    expected[2] = ProviderState::Error("row 3 missing from batch".to_string());
+    assert_eq!(rows.last(), Some(expected));
+}
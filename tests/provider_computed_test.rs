@@ -0,0 +1,52 @@
+// Tests for use_provider_computed tracking every signal read while building the param
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::sync::{LazyLock, Mutex};
+
+static FETCHED_RANGES: LazyLock<Mutex<Vec<String>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+#[provider]
+async fn fetch_report(range: String) -> Result<String, String> {
+    FETCHED_RANGES.lock().unwrap().push(range.clone());
+    Ok(format!("Report for {}", range))
+}
+
+type Range = (Signal<u32>, Signal<u32>);
+
+/// Fetches the report for the range built from two signals, and returns the signals
+fn use_report() -> Range {
+    let from = use_signal(|| 2023);
+    let to = use_signal(|| 2024);
+    let _report = use_provider_computed(fetch_report(), move || format!("{}-{}", from(), to()));
+    (from, to)
+}
+
+fn update(dom: &VirtualDom, range: Range, f: impl FnOnce(&mut Signal<u32>, &mut Signal<u32>)) {
+    let (mut from, mut to) = range;
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || f(&mut from, &mut to));
+}
+
+#[tokio::test]
+async fn test_changing_either_signal_refetches() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_report);
+    settle(&mut dom).await;
+    let range = renders.last().unwrap();
+
+    update(&dom, range, |from, _| from.set(2020));
+    settle(&mut dom).await;
+
+    update(&dom, range, |_, to| to.set(2021));
+    settle(&mut dom).await;
+
+    assert_eq!(
+        *FETCHED_RANGES.lock().unwrap(),
+        vec!["2023-2024", "2020-2024", "2020-2021"]
+    );
+}
@@ -0,0 +1,125 @@
+// Tests for #[provider(concurrency = N)] limiting how many fetches of a provider run at once
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{hooks::ArcProvider, prelude::*};
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+static MAX_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+static COMPLETED: AtomicUsize = AtomicUsize::new(0);
+
+#[provider(concurrency = 3)]
+async fn fetch_item(id: u32) -> Result<String, String> {
+    let running = IN_FLIGHT.fetch_add(1, Ordering::SeqCst) + 1;
+    MAX_IN_FLIGHT.fetch_max(running, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+    COMPLETED.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("item {id}"))
+}
+
+#[component]
+fn Item(id: u32) -> Element {
+    let item = use_provider(fetch_item(), id);
+    rsx! { "{item.read().is_loading()}" }
+}
+
+#[tokio::test]
+async fn test_fetches_never_exceed_the_concurrency_limit() {
+    init_global_providers().unwrap();
+    assert_eq!(fetch_item().max_concurrency(), Some(3));
+
+    let mut dom = common::mount(|| {
+        rsx! {
+            for id in 0..20u32 {
+                Item { key: "{id}", id }
+            }
+        }
+    });
+    let _ = tokio::time::timeout(Duration::from_millis(500), async {
+        while COMPLETED.load(Ordering::SeqCst) < 20 {
+            let _ = tokio::time::timeout(Duration::from_millis(20), dom.wait_for_work()).await;
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+    })
+    .await;
+
+    assert_eq!(COMPLETED.load(Ordering::SeqCst), 20);
+    assert_eq!(MAX_IN_FLIGHT.load(Ordering::SeqCst), 3);
+}
+
+static STARTED: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+#[provider(concurrency = 1)]
+async fn fetch_slow(id: u32) -> Result<u32, String> {
+    STARTED.lock().unwrap().push(id);
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    Ok(id)
+}
+
+#[component]
+fn Slow(id: u32) -> Element {
+    let value = use_provider(fetch_slow(), id);
+    rsx! { "{value.read().is_loading()}" }
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    for _ in 0..3 {
+        let _ = tokio::time::timeout(Duration::from_millis(20), dom.wait_for_work()).await;
+    }
+}
+
+#[tokio::test]
+async fn test_dropped_fetches_release_their_permit() {
+    init_global_providers().unwrap();
+
+    // The first fetch holds the only permit until its app is dropped mid-fetch
+    let mut first = VirtualDom::new_with_props(Slow, SlowProps { id: 1 });
+    first.rebuild_in_place();
+    settle(&mut first).await;
+    assert_eq!(*STARTED.lock().unwrap(), vec![1]);
+    drop(first);
+
+    let mut second = VirtualDom::new_with_props(Slow, SlowProps { id: 2 });
+    second.rebuild_in_place();
+    settle(&mut second).await;
+    assert_eq!(*STARTED.lock().unwrap(), vec![1, 2]);
+}
+
+static WRAPPED_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+static WRAPPED_MAX_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+#[provider(concurrency = 1)]
+async fn fetch_wrapped(id: u32) -> Result<u32, String> {
+    let running = WRAPPED_IN_FLIGHT.fetch_add(1, Ordering::SeqCst) + 1;
+    WRAPPED_MAX_IN_FLIGHT.fetch_max(running, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    WRAPPED_IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+    Ok(id)
+}
+
+#[tokio::test]
+async fn test_wrappers_share_the_limit_of_their_provider() {
+    init_global_providers().unwrap();
+    assert_eq!(
+        ArcProvider::new(fetch_wrapped()).type_name(),
+        fetch_wrapped().type_name()
+    );
+
+    let mut dom = common::mount(|| {
+        let _plain = use_provider(fetch_wrapped(), 1u32);
+        let _shared = use_provider(ArcProvider::new(fetch_wrapped()), 2u32);
+        rsx! {}
+    });
+    common::run_for(&mut dom, Duration::from_millis(150)).await;
+
+    assert_eq!(WRAPPED_MAX_IN_FLIGHT.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,79 @@
+// Tests for telling a loaded but empty collection apart from one that hasn't loaded yet
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{cell::RefCell, time::Duration};
+
+thread_local! {
+    static RENDERED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(view: &str) -> Element {
+    RENDERED.with(|rendered| rendered.borrow_mut().push(view.to_string()));
+    rsx! {}
+}
+
+#[provider]
+async fn fetch_items(count: u32) -> Result<Vec<String>, String> {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    Ok((0..count).map(|i| format!("item {i}")).collect())
+}
+
+#[component]
+fn LoadingMarker() -> Element {
+    record("loading")
+}
+
+#[component]
+fn EmptyMarker() -> Element {
+    record("empty")
+}
+
+/// Shows the items through `ProviderView`, returning their state
+fn use_item_list(count: u32) -> (ProviderState<Vec<String>, String>, Element) {
+    let items = use_provider(fetch_items(), count);
+    let state = items.read().clone();
+    let view = rsx! {
+        ProviderView {
+            state: items,
+            loading: rsx! { LoadingMarker {} },
+            empty: rsx! { EmptyMarker {} },
+            is_empty: |items: Vec<String>| items.is_empty(),
+            success: |items: Vec<String>| record(&format!("{} items", items.len())),
+        }
+    };
+    (state, view)
+}
+
+#[tokio::test]
+async fn test_empty_success_is_distinct_from_loading() {
+    init_global_providers().unwrap();
+
+    let (mut dom, states) = common::record_with(|| use_item_list(0));
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let states = states.all();
+    let first = states.first().unwrap();
+    assert!(first.is_loading());
+    assert!(!first.is_empty_success());
+    let last = states.last().unwrap();
+    assert!(last.is_empty_success());
+    assert!(last.is_empty_success_by(|items| items.len() < 2));
+
+    let rendered = RENDERED.with(|rendered| rendered.borrow().clone());
+    assert_eq!(rendered.first().map(String::as_str), Some("loading"));
+    assert_eq!(rendered.last().map(String::as_str), Some("empty"));
+    assert!(!rendered.iter().any(|view| view.ends_with("items")));
+}
+
+#[test]
+fn test_non_empty_success_is_not_empty() {
+    let state = ProviderState::<Vec<u32>, String>::Success(vec![1]);
+    assert!(!state.is_empty_success());
+    assert!(ProviderState::<String, String>::Success(String::new()).is_empty_success());
+    assert!(!ProviderState::<Vec<u32>, String>::Error("offline".to_string()).is_empty_success());
+    assert!(!ProviderState::<Vec<u32>, String>::Idle.is_empty_success());
+}
@@ -0,0 +1,57 @@
+// Tests for use_provider_with_fallback chaining a primary and a secondary provider
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static SECONDARY_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_from_edge(id: u32) -> Result<String, String> {
+    Err(format!("edge miss for {}", id))
+}
+
+#[provider]
+async fn fetch_from_origin(id: u32) -> Result<String, String> {
+    SECONDARY_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("origin item {}", id))
+}
+
+#[provider]
+async fn fetch_from_mirror(id: u32) -> Result<String, String> {
+    Err(format!("mirror miss for {}", id))
+}
+
+type State = ProviderState<String, String>;
+
+/// The states of an item whose fallback succeeds and one whose fallback fails too
+fn use_items() -> (State, State) {
+    let item = use_provider_with_fallback(fetch_from_edge(), fetch_from_origin(), 7u32);
+    let failing = use_provider_with_fallback(fetch_from_edge(), fetch_from_mirror(), 8u32);
+    (item.read().clone(), failing.read().clone())
+}
+
+#[tokio::test]
+async fn test_secondary_value_is_returned_when_primary_fails() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_items);
+    settle(&mut dom).await;
+
+    let (item, failing) = renders.last().unwrap();
+    assert_eq!(item, ProviderState::Success("origin item 7".to_string()));
+    assert_eq!(
+        failing,
+        ProviderState::Error("mirror miss for 8".to_string())
+    );
+    assert_eq!(SECONDARY_FETCHES.load(Ordering::SeqCst), 1);
+
+    let cache = get_global_cache().unwrap();
+    assert_eq!(
+        cache.get::<Result<String, String>>(&fetch_from_origin().id(&7)),
+        Some(Ok("origin item 7".to_string()))
+    );
+}
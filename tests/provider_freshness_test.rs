@@ -0,0 +1,45 @@
+// Tests for use_provider_freshness tracking the age and staleness of a cached entry live
+
+mod common;
+
+use common::run_for;
+use dioxus_provider::{global::get_global_cache, hooks::Freshness, prelude::*};
+use std::time::Duration;
+
+#[provider(stale_time = "100ms", cache_expiration = "300ms")]
+async fn fetch_prices() -> Result<Vec<u32>, String> {
+    Ok(vec![42])
+}
+
+#[tokio::test]
+async fn test_freshness_turns_stale_then_expired() {
+    let (mut dom, freshness) = common::record(|| use_provider_freshness(fetch_prices(), ())());
+    let latest = || freshness.last().unwrap();
+    assert_eq!(latest(), Freshness::default());
+
+    // Storing the entry updates the signal right away
+    let cache = get_global_cache().unwrap();
+    cache.set(fetch_prices().id(&()), Ok::<_, String>(vec![42u32]));
+    run_for(&mut dom, Duration::from_millis(30)).await;
+    let fresh = latest();
+    assert!(
+        fresh
+            .age
+            .is_some_and(|age| age < Duration::from_millis(100))
+    );
+    assert!(!fresh.is_stale);
+    assert!(!fresh.is_expired);
+    assert!(!fresh.is_fetching);
+
+    // The stale window elapses without anything touching the cache
+    run_for(&mut dom, Duration::from_millis(120)).await;
+    let stale = latest();
+    assert!(stale.is_stale);
+    assert!(!stale.is_expired);
+
+    run_for(&mut dom, Duration::from_millis(200)).await;
+    let expired = latest();
+    assert!(expired.is_stale);
+    assert!(expired.is_expired);
+    assert!(expired.age.unwrap() > Duration::from_millis(300));
+}
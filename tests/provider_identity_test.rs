@@ -0,0 +1,112 @@
+// Tests for use_provider and use_provider_batch rerunning on cache key changes instead of provider value changes
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+/// A provider built with a formatting closure, like providers made by a builder
+#[derive(Clone)]
+struct GreetingProvider {
+    format: Arc<dyn Fn(&str) -> String + Send + Sync>,
+}
+
+/// Closures can't be compared, so two builds of the provider are never equal
+impl PartialEq for GreetingProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.format, &other.format)
+    }
+}
+
+fn greeting_provider(greeting: &'static str) -> GreetingProvider {
+    GreetingProvider {
+        format: Arc::new(move |name| format!("{greeting}, {name}!")),
+    }
+}
+
+impl Provider<String> for GreetingProvider {
+    type Output = String;
+    type Error = String;
+
+    async fn run(&self, name: String) -> Result<Self::Output, Self::Error> {
+        FETCHES.fetch_add(1, Ordering::SeqCst);
+        Ok((self.format)(&name))
+    }
+
+    fn id(&self, name: &String) -> String {
+        format!("greeting({name})")
+    }
+}
+
+/// Greets a name that changes after 100ms
+fn use_greeting() -> ProviderState<String, String> {
+    let mut name = use_signal(|| "Ada".to_string());
+    use_hook(|| {
+        spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            name.set("Grace".to_string());
+        })
+    });
+    // A new provider value every render
+    let greeting = use_provider(greeting_provider("Hello"), name());
+    greeting.read().clone()
+}
+
+#[tokio::test]
+async fn test_rebuilt_provider_does_not_rerun_every_render() {
+    let (mut dom, states) = common::record(use_greeting);
+    let last_state = || states.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(60)).await;
+
+    assert_eq!(
+        last_state(),
+        ProviderState::Success("Hello, Ada!".to_string())
+    );
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+    // Loading, then the fetched value; an unequal provider must not rerun the memo
+    let renders = states.len();
+    assert!(renders <= 3, "rendered {renders} times");
+
+    // A new param still changes the key and fetches again
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(
+        last_state(),
+        ProviderState::Success("Hello, Grace!".to_string())
+    );
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+}
+
+/// Greets a batch of names with a new provider value every render
+fn use_greetings() -> Vec<ProviderState<String, String>> {
+    let greetings = use_provider_batch(
+        greeting_provider("Welcome"),
+        vec!["Linus".to_string(), "Margaret".to_string()],
+    );
+    greetings.read().clone()
+}
+
+#[tokio::test]
+async fn test_rebuilt_provider_does_not_rerun_the_batch_every_render() {
+    let (mut dom, states) = common::record(use_greetings);
+    run_for(&mut dom, Duration::from_millis(60)).await;
+
+    assert_eq!(
+        states.last().unwrap(),
+        vec![
+            ProviderState::Success("Welcome, Linus!".to_string()),
+            ProviderState::Success("Welcome, Margaret!".to_string()),
+        ]
+    );
+    let renders = states.len();
+    assert!(renders <= 4, "rendered {renders} times");
+}
@@ -0,0 +1,121 @@
+// Tests for changing a provider's polling interval at runtime with use_provider_with_interval
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+// The declared interval is overridden by the hook
+#[provider(interval = "1h")]
+async fn fetch_live_metrics() -> Result<u32, String> {
+    Ok(FETCH_COUNT.fetch_add(1, Ordering::SeqCst))
+}
+
+/// Polls the metrics at the interval held by the returned signal
+fn use_metrics() -> (Signal<Duration>, Element) {
+    let interval = use_signal(|| Duration::from_millis(10));
+    let metrics = use_provider_with_interval(fetch_live_metrics(), (), interval.into());
+    (interval, rsx! { "{metrics.read().is_loading()}" })
+}
+
+fn set_interval(dom: &VirtualDom, mut signal: Signal<Duration>, interval: Duration) {
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || signal.set(interval));
+}
+
+/// Counts the fetches made while running the DOM for `duration`
+async fn fetches_during(dom: &mut VirtualDom, duration: Duration) -> u32 {
+    let before = FETCH_COUNT.load(Ordering::SeqCst);
+    run_for(dom, duration).await;
+    FETCH_COUNT.load(Ordering::SeqCst) - before
+}
+
+#[tokio::test]
+async fn test_changing_interval_changes_tick_rate() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record_with(use_metrics);
+    let interval = renders.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    let fast = fetches_during(&mut dom, Duration::from_millis(300)).await;
+    assert!(fast >= 10, "expected fast polling, got {fast} fetches");
+
+    set_interval(&dom, interval, Duration::from_millis(100));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    let slow = fetches_during(&mut dom, Duration::from_millis(300)).await;
+    assert!(
+        (1..=4).contains(&slow),
+        "expected slow polling, got {slow} fetches"
+    );
+
+    set_interval(&dom, interval, Duration::ZERO);
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(
+        fetches_during(&mut dom, Duration::from_millis(200)).await,
+        0
+    );
+}
+
+static SHARED_FETCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_shared_metrics() -> Result<u32, String> {
+    Ok(SHARED_FETCH_COUNT.fetch_add(1, Ordering::SeqCst))
+}
+
+#[component]
+fn SharedMetrics() -> Element {
+    let interval = use_signal(|| Duration::from_millis(15));
+    let metrics = use_provider_with_interval(fetch_shared_metrics(), (), interval.into());
+    rsx! { "{metrics.read().is_loading()}" }
+}
+
+#[tokio::test]
+async fn test_unmounting_one_hook_keeps_polling_for_the_other() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record_with(|| {
+        let shown = use_signal(|| 2);
+        let element = rsx! {
+            if shown() > 0 {
+                SharedMetrics {}
+            }
+            if shown() > 1 {
+                SharedMetrics {}
+            }
+        };
+        (shown, element)
+    });
+    let mut shown = renders.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    dom.runtime().on_scope(ScopeId::ROOT, || shown.set(1));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    let polled = shared_fetches_during(&mut dom, Duration::from_millis(200)).await;
+    assert!(
+        polled >= 5,
+        "expected polling to continue, got {polled} fetches"
+    );
+
+    // Unmounting the last hook stops the task
+    dom.runtime().on_scope(ScopeId::ROOT, || shown.set(0));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(
+        shared_fetches_during(&mut dom, Duration::from_millis(100)).await,
+        0
+    );
+}
+
+async fn shared_fetches_during(dom: &mut VirtualDom, duration: Duration) -> u32 {
+    let before = SHARED_FETCH_COUNT.load(Ordering::SeqCst);
+    run_for(dom, duration).await;
+    SHARED_FETCH_COUNT.load(Ordering::SeqCst) - before
+}
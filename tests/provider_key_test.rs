@@ -0,0 +1,69 @@
+// Tests for keying providers on parameters that implement ProviderKey instead of Hash
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PriceRange {
+    min: f64,
+    max: f64,
+}
+
+impl ProviderKey for PriceRange {
+    fn provider_key(&self) -> String {
+        format!("{}..{}", self.min, self.max)
+    }
+}
+
+#[provider]
+async fn fetch_product_count(range: PriceRange) -> Result<usize, String> {
+    Ok(((range.max - range.min) / 10.0) as usize)
+}
+
+#[test]
+fn test_equal_params_share_a_key() {
+    let cheap = PriceRange {
+        min: 0.0,
+        max: 49.5,
+    };
+    let expensive = PriceRange {
+        min: 50.0,
+        max: 99.5,
+    };
+
+    assert_eq!(
+        fetch_product_count().id(&cheap),
+        fetch_product_count().id(&cheap.clone())
+    );
+    assert_ne!(
+        fetch_product_count().id(&cheap),
+        fetch_product_count().id(&expensive)
+    );
+}
+
+#[tokio::test]
+async fn test_provider_keyed_by_provider_key_is_cached() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+
+    let mut dom = common::mount(|| {
+        let count = use_provider(
+            fetch_product_count(),
+            (PriceRange {
+                min: 0.0,
+                max: 49.5,
+            },),
+        );
+        rsx! { "{count.read().is_loading()}" }
+    });
+    settle(&mut dom).await;
+
+    let key = fetch_product_count().id(&PriceRange {
+        min: 0.0,
+        max: 49.5,
+    });
+    assert_eq!(cache.get::<Result<usize, String>>(&key), Some(Ok(4)));
+}
@@ -0,0 +1,54 @@
+// Tests for use_provider_map viewing a provider's output through a mapping
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct UserDto {
+    first: String,
+    last: String,
+}
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<UserDto, String> {
+    Ok(UserDto {
+        first: "Ada".to_string(),
+        last: format!("Lovelace #{}", id),
+    })
+}
+
+/// The user id signal and the user's full name
+fn use_user_name() -> (Signal<u32>, ProviderState<String, String>) {
+    let user_id = use_signal(|| 1u32);
+    let name = use_provider_map(fetch_user(), user_id(), |user: &UserDto| {
+        format!("{} {}", user.first, user.last)
+    });
+    let name = name.read().clone();
+    (user_id, name)
+}
+
+#[tokio::test]
+async fn test_mapped_signal_tracks_source_updates() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_user_name);
+    let last_rendered = || renders.last().map(|(_, name)| name);
+    let mut user_id = renders.last().unwrap().0;
+    assert!(last_rendered().unwrap().is_loading());
+
+    settle(&mut dom).await;
+    assert_eq!(
+        last_rendered(),
+        Some(ProviderState::Success("Ada Lovelace #1".to_string()))
+    );
+
+    dom.runtime().on_scope(ScopeId::ROOT, || user_id.set(2));
+    settle(&mut dom).await;
+    assert_eq!(
+        last_rendered(),
+        Some(ProviderState::Success("Ada Lovelace #2".to_string()))
+    );
+}
@@ -0,0 +1,45 @@
+// Tests for Provider::metadata describing providers for tooling
+
+use dioxus_provider::{
+    hooks::{ArcProvider, ProviderMetadata},
+    prelude::*,
+};
+use std::time::Duration;
+
+#[provider(interval = "30s", stale_time = "10s", tags = ["metrics", "live"])]
+async fn fetch_live_metrics(region: String) -> Result<u32, String> {
+    Ok(region.len() as u32)
+}
+
+#[provider]
+async fn fetch_settings() -> Result<String, String> {
+    Ok("dark".to_string())
+}
+
+#[test]
+fn test_configured_provider_reports_metadata() {
+    assert_eq!(
+        Provider::<String>::metadata(&fetch_live_metrics()),
+        ProviderMetadata {
+            name: Some("FetchLiveMetrics"),
+            interval: Some(Duration::from_secs(30)),
+            stale_time: Some(Duration::from_secs(10)),
+            cache_expiration: None,
+            tags: vec!["metrics", "live"],
+        }
+    );
+}
+
+#[test]
+fn test_unconfigured_provider_reports_only_its_name() {
+    let metadata = fetch_settings().metadata();
+    assert_eq!(metadata.name, Some("FetchSettings"));
+    assert_eq!(metadata.interval, None);
+    assert!(metadata.tags.is_empty());
+
+    // Adapters describe the provider they wrap
+    assert_eq!(
+        Provider::<()>::metadata(&ArcProvider::new(fetch_settings())),
+        metadata
+    );
+}
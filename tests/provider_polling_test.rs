@@ -0,0 +1,89 @@
+// Tests for pausing and resuming interval polling with use_provider_polling
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[provider(interval = "20ms")]
+async fn fetch_live_metrics() -> Result<u32, String> {
+    Ok(FETCH_COUNT.fetch_add(1, Ordering::SeqCst))
+}
+
+static SHARED_FETCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+// Another interval than fetch_live_metrics, so the tests don't share a timer
+#[provider(interval = "25ms")]
+async fn fetch_shared_metrics() -> Result<u32, String> {
+    Ok(SHARED_FETCH_COUNT.fetch_add(1, Ordering::SeqCst))
+}
+
+/// Polls the metrics while the returned signal is true
+fn use_metrics() -> Signal<bool> {
+    let enabled = use_signal(|| true);
+    let _metrics = use_provider_polling(fetch_live_metrics(), (), enabled.into());
+    enabled
+}
+
+fn set_enabled(dom: &VirtualDom, mut enabled: Signal<bool>, value: bool) {
+    dom.runtime().on_scope(ScopeId::ROOT, || enabled.set(value));
+}
+
+#[tokio::test]
+async fn test_toggling_enabled_stops_and_resumes_ticks() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(use_metrics);
+    let enabled = renders.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert!(FETCH_COUNT.load(Ordering::SeqCst) > 1);
+
+    set_enabled(&dom, enabled, false);
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let paused_at = FETCH_COUNT.load(Ordering::SeqCst);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(FETCH_COUNT.load(Ordering::SeqCst), paused_at);
+
+    set_enabled(&dom, enabled, true);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert!(FETCH_COUNT.load(Ordering::SeqCst) > paused_at);
+}
+
+#[tokio::test]
+async fn test_one_disabled_hook_does_not_pause_the_others() {
+    init_global_providers().unwrap();
+
+    let (mut dom, renders) = common::record(|| {
+        let first = use_signal(|| true);
+        let second = use_signal(|| true);
+        let _first = use_provider_polling(fetch_shared_metrics(), (), first.into());
+        let _second = use_provider_polling(fetch_shared_metrics(), (), second.into());
+        (first, second)
+    });
+    let (first, second) = renders.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    set_enabled(&dom, first, false);
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    let one_paused_at = SHARED_FETCH_COUNT.load(Ordering::SeqCst);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert!(SHARED_FETCH_COUNT.load(Ordering::SeqCst) > one_paused_at);
+
+    set_enabled(&dom, second, false);
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let both_paused_at = SHARED_FETCH_COUNT.load(Ordering::SeqCst);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(SHARED_FETCH_COUNT.load(Ordering::SeqCst), both_paused_at);
+
+    // Re-enabling one hook resumes polling while the other stays disabled
+    set_enabled(&dom, first, true);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert!(SHARED_FETCH_COUNT.load(Ordering::SeqCst) > both_paused_at);
+}
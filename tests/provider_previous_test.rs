@@ -0,0 +1,71 @@
+// Tests for use_provider_with_previous keeping the last success through a refetch
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static CALLS: AtomicU32 = AtomicU32::new(0);
+
+/// Counts 1, 2, ... with the second call failing
+#[provider]
+async fn fetch_score() -> Result<u32, String> {
+    let call = CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    if call == 2 {
+        return Err("offline".to_string());
+    }
+    Ok(call)
+}
+
+/// A previous value and the state shown with it
+type Seen = (Option<u32>, ProviderState<u32, String>);
+
+async fn settle(dom: &mut VirtualDom) {
+    common::settle_rounds(dom, 5, Duration::from_millis(30)).await;
+}
+
+#[tokio::test]
+async fn test_previous_value_is_kept_through_refetches() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_score().id(&());
+
+    let (mut dom, renders) =
+        common::record(|| -> Seen { use_provider_with_previous(fetch_score(), ()).read().clone() });
+    let take_seen = || renders.take();
+    settle(&mut dom).await;
+    let seen = take_seen();
+    assert!(matches!(
+        seen.first(),
+        Some((None, ProviderState::Loading { .. }))
+    ));
+    assert_eq!(seen.last(), Some(&(None, ProviderState::Success(1))));
+
+    // A failed refetch keeps the previous success through Loading and Error
+    cache.invalidate(&key);
+    settle(&mut dom).await;
+    let seen = take_seen();
+    assert!(
+        seen.iter()
+            .any(|(previous, state)| *previous == Some(1) && state.is_loading())
+    );
+    assert_eq!(
+        seen.last(),
+        Some(&(Some(1), ProviderState::Error("offline".to_string())))
+    );
+
+    // The next success is shown next to the one it replaces
+    cache.invalidate(&key);
+    settle(&mut dom).await;
+    let seen = take_seen();
+    assert!(
+        seen.iter()
+            .any(|(previous, state)| *previous == Some(1) && state.is_loading())
+    );
+    assert_eq!(seen.last(), Some(&(Some(1), ProviderState::Success(3))));
+}
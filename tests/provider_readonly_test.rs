@@ -0,0 +1,38 @@
+// Tests for use_provider_readonly exposing provider state through a read-only signal
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::time::Duration;
+
+#[provider]
+async fn fetch_greeting(name: String) -> Result<String, String> {
+    Ok(format!("Hello, {name}"))
+}
+
+#[tokio::test]
+async fn test_readonly_signal_follows_the_cache() {
+    let (mut dom, states) = common::record(|| {
+        use_provider_readonly(fetch_greeting(), "Ada".to_string())
+            .read()
+            .clone()
+    });
+    let last_state = || states.last();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(
+        last_state(),
+        Some(ProviderState::Success("Hello, Ada".to_string()))
+    );
+
+    let key = fetch_greeting().id(&"Ada".to_string());
+    get_global_cache()
+        .unwrap()
+        .set(key, Ok::<String, String>("Hi, Ada".to_string()));
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(
+        last_state(),
+        Some(ProviderState::Success("Hi, Ada".to_string()))
+    );
+}
@@ -0,0 +1,58 @@
+// Tests for bridging providers and Dioxus resources in both directions
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::invalidate_provider, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_count() -> Result<u32, String> {
+    Ok(FETCHES.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+/// Shows a resource through `use_provider_from_resource`
+fn use_greeting() -> ProviderState<String, String> {
+    let greeting = use_resource(|| async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok::<_, String>("Hello".to_string())
+    });
+    let state = use_provider_from_resource(greeting);
+    state.read().clone()
+}
+
+#[tokio::test]
+async fn test_resource_reflects_provider_cache_updates() {
+    let (mut dom, values) =
+        common::record(|| use_provider_as_resource(fetch_count(), ()).read().clone());
+    let last_value = || values.last().flatten();
+    assert_eq!(last_value(), None);
+
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(last_value(), Some(Ok(1)));
+
+    // Invalidating the cache key refetches the provider and re-runs the resource
+    invalidate_provider(fetch_count(), ()).unwrap();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(last_value(), Some(Ok(2)));
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_resource_shown_as_provider_state() {
+    let (mut dom, states) = common::record(use_greeting);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let states = states.all();
+    assert!(states.first().unwrap().is_loading());
+    assert_eq!(
+        states.last().unwrap(),
+        &ProviderState::Success("Hello".to_string())
+    );
+}
@@ -0,0 +1,77 @@
+// Tests for use_provider_retry clearing a failed result and refetching
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static CALLS: AtomicU32 = AtomicU32::new(0);
+
+/// Fails on the first call and succeeds afterwards
+#[provider]
+async fn fetch_report(id: u32) -> Result<String, String> {
+    let call = CALLS.fetch_add(1, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    if call == 0 {
+        return Err(format!("report {id} unavailable"));
+    }
+    Ok(format!("report {id}"))
+}
+
+/// The report's state and the function retrying it
+fn use_report() -> (ProviderState<String, String>, Rc<dyn Fn()>) {
+    let report = use_provider(fetch_report(), 7u32);
+    let retry = use_provider_retry(fetch_report(), 7u32);
+    let state = report.read().clone();
+    (state, Rc::new(retry))
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    common::settle_rounds(dom, 5, Duration::from_millis(30)).await;
+}
+
+#[tokio::test]
+async fn test_retry_refetches_failed_provider() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_report().id(&7);
+
+    let (mut dom, renders) = common::record(use_report);
+    let states = || -> Vec<_> { renders.all().into_iter().map(|(state, _)| state).collect() };
+    let retry = || (renders.last().unwrap().1)();
+    settle(&mut dom).await;
+    assert_eq!(
+        states().last(),
+        Some(&ProviderState::Error("report 7 unavailable".to_string()))
+    );
+
+    // Retrying goes back through Loading to the new result
+    let retry_failed = renders.take().pop().unwrap().1;
+    retry_failed();
+    settle(&mut dom).await;
+    let states = states();
+    let loading = states
+        .iter()
+        .position(ProviderState::is_loading)
+        .expect("the retry never showed Loading");
+    let success = states
+        .iter()
+        .position(|state| *state == ProviderState::Success("report 7".to_string()))
+        .expect("the retry never succeeded");
+    assert!(loading < success);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    // Retrying a provider showing data keeps its cached result
+    retry();
+    settle(&mut dom).await;
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Ok("report 7".to_string()))
+    );
+}
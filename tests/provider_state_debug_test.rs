@@ -0,0 +1,34 @@
+// Tests for formatting ProviderState with {:?}, as in `rsx! { "{user:?}" }`
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+}
+
+#[test]
+fn test_each_variant_formats_with_debug() {
+    let success: ProviderState<User, String> = ProviderState::Success(User {
+        name: "Ada".to_string(),
+    });
+    assert_eq!(format!("{success:?}"), r#"Success(User { name: "Ada" })"#);
+
+    let error: ProviderState<User, String> = ProviderState::Error("offline".to_string());
+    assert_eq!(format!("{error:?}"), r#"Error("offline")"#);
+
+    let idle: ProviderState<User, String> = ProviderState::Idle;
+    assert_eq!(format!("{idle:?}"), "Idle");
+
+    // Loading shows the task it waits for
+    let dom = common::mount(|| rsx! {});
+    let task = dom.runtime().on_scope(ScopeId::ROOT, || spawn(async {}));
+    let loading: ProviderState<User, String> = ProviderState::Loading { task };
+    assert_eq!(
+        format!("{loading:?}"),
+        format!("Loading {{ task: {task:?} }}")
+    );
+}
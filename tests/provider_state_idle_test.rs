@@ -0,0 +1,78 @@
+// Tests for ProviderState::Idle in the state accessors, combinators and suspend()
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{hooks::SuspenseSignalExt, prelude::*};
+use std::{cell::RefCell, time::Duration};
+
+#[test]
+fn idle_accessors() {
+    let state: ProviderState<u32, String> = ProviderState::Idle;
+    assert!(state.is_idle());
+    assert!(!state.is_loading());
+    assert!(!state.is_success());
+    assert!(!state.is_error());
+    assert_eq!(state.data(), None);
+    assert_eq!(state.error(), None);
+    assert!(!ProviderState::<u32, String>::Success(1).is_idle());
+}
+
+#[test]
+fn idle_passes_through_combinators() {
+    let idle = || ProviderState::<u32, String>::Idle;
+    assert_eq!(idle().map(|value| value * 2), ProviderState::Idle);
+    assert_eq!(
+        idle().map_err(|error| error.len()),
+        ProviderState::<u32, usize>::Idle
+    );
+    assert_eq!(
+        idle().and_then(|value| ProviderState::<u32, String>::Success(value + 1)),
+        ProviderState::Idle
+    );
+}
+
+thread_local! {
+    static RENDERED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(view: String) -> Element {
+    RENDERED.with(|rendered| rendered.borrow_mut().push(view));
+    rsx! {}
+}
+
+#[component]
+fn Value(state: Signal<ProviderState<u32, String>>) -> Element {
+    match state.suspend()? {
+        Ok(value) => record(format!("value {}", value)),
+        Err(error) => record(format!("error {}", error)),
+    }
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    common::settle_rounds(dom, 3, Duration::from_millis(20)).await;
+}
+
+#[tokio::test]
+async fn idle_suspends_until_state_changes() {
+    let (mut dom, states) = common::record_with(|| {
+        let state = use_signal(|| ProviderState::Idle);
+        let view = rsx! {
+            SuspenseBoundary { fallback: |_| record("fallback".to_string()),
+                Value { state }
+            }
+        };
+        (state, view)
+    });
+    let mut state = states.last().unwrap();
+    settle(&mut dom).await;
+    let rendered = RENDERED.with(|rendered| rendered.borrow().clone());
+    assert!(rendered.iter().all(|view| view == "fallback"));
+    assert!(!rendered.is_empty());
+
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || state.set(ProviderState::Success(7)));
+    settle(&mut dom).await;
+    let rendered = RENDERED.with(|rendered| rendered.borrow().clone());
+    assert_eq!(rendered.last().unwrap(), "value 7");
+}
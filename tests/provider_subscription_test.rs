@@ -0,0 +1,48 @@
+// Tests for subscribe_provider notifying non-component code about provider state changes
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicU32, Ordering},
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_balance(account: u32) -> Result<u64, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) as u64;
+    Ok(account as u64 * 100 + fetch)
+}
+
+#[tokio::test]
+async fn test_callback_fires_on_refetch_until_dropped() {
+    init_global_providers().unwrap();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let seen_by_callback = seen.clone();
+    let subscription = subscribe_provider(fetch_balance(), 7, move |state| {
+        seen_by_callback.lock().unwrap().push(state.data().copied());
+    })
+    .unwrap();
+
+    let mut dom = common::mount(|| {
+        let balance = use_provider(fetch_balance(), 7u32);
+        rsx! { "{balance.read().is_loading()}" }
+    });
+    settle(&mut dom).await;
+    assert_eq!(*seen.lock().unwrap(), vec![Some(700)]);
+
+    invalidate_provider(fetch_balance(), 7).unwrap();
+    settle(&mut dom).await;
+    assert_eq!(*seen.lock().unwrap(), vec![Some(700), Some(701)]);
+
+    drop(subscription);
+    invalidate_provider(fetch_balance(), 7).unwrap();
+    settle(&mut dom).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 3);
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
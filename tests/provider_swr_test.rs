@@ -0,0 +1,73 @@
+// Tests for use_provider_swr keeping data through stale revalidations and failed refetches
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::mark_provider_stale, hooks::SwrState, prelude::*};
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::Semaphore;
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+// Revalidations wait for a permit, so the test decides when they finish
+static REVALIDATIONS: Semaphore = Semaphore::const_new(0);
+
+// The first fetch succeeds, every revalidation fails
+#[provider(stale_time = "1m")]
+async fn fetch_feed() -> Result<String, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    if fetch == 1 {
+        return Ok("feed 1".to_string());
+    }
+    REVALIDATIONS.acquire().await.unwrap().forget();
+    Err(format!("fetch {fetch} failed"))
+}
+
+#[tokio::test]
+async fn test_swr_state_keeps_data_through_revalidation_and_error() {
+    let (mut dom, states) = common::record(|| use_provider_swr(fetch_feed(), ()).read().clone());
+    let feed = || states.last().unwrap();
+    assert!(feed().is_loading());
+    assert!(!feed().is_revalidating);
+
+    // Fresh
+    settle(&mut dom).await;
+    assert_eq!(
+        feed(),
+        SwrState {
+            data: Some("feed 1".to_string()),
+            error: None,
+            is_stale: false,
+            is_revalidating: false,
+        }
+    );
+
+    // Stale, and revalidating in the background while the data stays
+    assert!(mark_provider_stale(fetch_feed(), ()).unwrap());
+    settle(&mut dom).await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        feed(),
+        SwrState {
+            data: Some("feed 1".to_string()),
+            error: None,
+            is_stale: true,
+            is_revalidating: true,
+        }
+    );
+
+    // The failed revalidation shows its error next to the data
+    REVALIDATIONS.add_permits(1);
+    settle(&mut dom).await;
+    assert_eq!(
+        feed(),
+        SwrState {
+            data: Some("feed 1".to_string()),
+            error: Some("fetch 2 failed".to_string()),
+            is_stale: false,
+            is_revalidating: false,
+        }
+    );
+    assert!(!feed().is_loading());
+}
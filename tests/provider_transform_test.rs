@@ -0,0 +1,56 @@
+// Tests for #[provider(transform = fn -> Output)] post-processing
+
+use dioxus_provider::{cache::ProviderCache, prelude::*};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RawUser {
+    first: String,
+    last: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct User {
+    full_name: String,
+}
+
+fn normalize_user(raw: RawUser) -> User {
+    User {
+        full_name: format!("{} {}", raw.first, raw.last),
+    }
+}
+
+#[provider(transform = normalize_user -> User)]
+async fn fetch_user(id: u32) -> Result<RawUser, String> {
+    if id == 0 {
+        return Err("User ID cannot be zero".to_string());
+    }
+    Ok(RawUser {
+        first: "Ada".to_string(),
+        last: format!("#{id}"),
+    })
+}
+
+#[tokio::test]
+async fn test_transform_applies_to_ok_value_before_caching() {
+    let provider = fetch_user();
+    let result = provider.run(7).await;
+
+    let cache = ProviderCache::new();
+    let key = provider.id(&7);
+    cache.set(key.clone(), result);
+
+    let cached = cache.get::<Result<User, String>>(&key);
+    assert_eq!(
+        cached,
+        Some(Ok(User {
+            full_name: "Ada #7".to_string()
+        }))
+    );
+    assert!(cache.get::<Result<RawUser, String>>(&key).is_none());
+}
+
+#[tokio::test]
+async fn test_transform_passes_errors_through() {
+    let result = fetch_user().run(0).await;
+    assert_eq!(result, Err("User ID cannot be zero".to_string()));
+}
@@ -0,0 +1,80 @@
+// Tests for ProviderView rendering the view matching a provider's state
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{cell::RefCell, time::Duration};
+
+thread_local! {
+    static RENDERED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(view: String) -> Element {
+    RENDERED.with(|rendered| rendered.borrow_mut().push(view));
+    rsx! {}
+}
+
+fn rendered() -> Vec<String> {
+    RENDERED.with(|rendered| rendered.borrow().clone())
+}
+
+#[provider]
+async fn fetch_title(id: u32) -> Result<String, String> {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    if id == 0 {
+        Err("no such title".to_string())
+    } else {
+        Ok(format!("Title {}", id))
+    }
+}
+
+#[component]
+fn LoadingMarker() -> Element {
+    record("loading".to_string())
+}
+
+#[component]
+fn TitleCard(id: u32) -> Element {
+    let title = use_provider(fetch_title(), id);
+    rsx! {
+        ProviderView {
+            state: title,
+            loading: rsx! { LoadingMarker {} },
+            error: |e: String| record(format!("error: {}", e)),
+            success: |text: String| record(format!("success: {}", text)),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_renders_success_after_loading() {
+    init_global_providers().unwrap();
+
+    let mut dom = VirtualDom::new_with_props(TitleCard, TitleCardProps { id: 1 });
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let rendered = rendered();
+    assert_eq!(rendered.first().unwrap(), "loading");
+    assert!(
+        rendered
+            .iter()
+            .all(|view| view == "loading" || view == "success: Title 1")
+    );
+    assert_eq!(rendered.last().unwrap(), "success: Title 1");
+}
+
+#[tokio::test]
+async fn test_renders_error() {
+    init_global_providers().unwrap();
+
+    let mut dom = VirtualDom::new_with_props(TitleCard, TitleCardProps { id: 0 });
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let rendered = rendered();
+    assert!(!rendered.iter().any(|view| view.starts_with("success")));
+    assert_eq!(rendered.last().unwrap(), "error: no such title");
+}
@@ -0,0 +1,41 @@
+// Tests for #[provider(interval_when_visible_only = true)] pausing polling while hidden
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{platform::set_document_visible, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[provider(interval = "20ms", interval_when_visible_only = true)]
+async fn fetch_live_metrics() -> Result<u32, String> {
+    Ok(FETCH_COUNT.fetch_add(1, Ordering::SeqCst))
+}
+
+#[tokio::test]
+async fn test_hidden_document_pauses_and_resumes_ticks() {
+    init_global_providers().unwrap();
+    assert!(fetch_live_metrics().interval_when_visible_only());
+
+    let mut dom = common::mount(|| {
+        let metrics = use_provider(fetch_live_metrics(), ());
+        rsx! { "{metrics.read().is_loading()}" }
+    });
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert!(FETCH_COUNT.load(Ordering::SeqCst) > 1);
+
+    set_document_visible(false);
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let paused_at = FETCH_COUNT.load(Ordering::SeqCst);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(FETCH_COUNT.load(Ordering::SeqCst), paused_at);
+
+    set_document_visible(true);
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    assert!(FETCH_COUNT.load(Ordering::SeqCst) > paused_at);
+}
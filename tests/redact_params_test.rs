@@ -0,0 +1,77 @@
+// Tests for #[provider(redact_params)] keeping parameters out of logs, errors and cache keys
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+const SECRET: &str = "sk-live-0123456789";
+
+#[provider(redact_params, error_context = true)]
+async fn fetch_profile(token: String) -> Result<String, ProviderError> {
+    Err(ProviderError::Network(format!(
+        "profile unreachable ({} chars)",
+        token.len()
+    )))
+}
+
+/// Captures formatted tracing output in memory
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_redacted_params_stay_out_of_logs_and_keys() {
+    let logs = LogBuffer::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    init_global_providers().unwrap();
+    let mut dom = common::mount(|| {
+        let _profile = use_provider(fetch_profile(), SECRET.to_string());
+        rsx! {}
+    });
+    for _ in 0..3 {
+        let _ = tokio::time::timeout(Duration::from_millis(20), dom.wait_for_work()).await;
+    }
+
+    let provider = fetch_profile();
+    let secret = SECRET.to_string();
+    assert_eq!(provider.describe_param(&secret), "<redacted>");
+    let key = provider.id(&secret);
+    assert!(!key.contains(SECRET));
+
+    // The memo log describes the parameter instead of printing it
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("with param: <redacted>"));
+    assert!(!logs.contains(SECRET));
+
+    // So does the error context
+    let cache = get_global_cache().unwrap();
+    let error = cache
+        .get::<Result<String, ProviderError>>(&key)
+        .unwrap()
+        .unwrap_err();
+    assert!(matches!(&error, ProviderError::WithContext { param, .. } if param == "<redacted>"));
+    assert!(!error.to_string().contains(SECRET));
+}
@@ -0,0 +1,126 @@
+// Tests for use_provider discarding fetches superseded by a newer refresh of the same key
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*, refresh::RefreshRegistry};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static CALLS: AtomicU32 = AtomicU32::new(0);
+
+/// The first refresh is slow and the second one fast, so the first would resolve last
+#[provider]
+async fn fetch_feed() -> Result<String, String> {
+    let call = CALLS.fetch_add(1, Ordering::SeqCst);
+    let delay = match call {
+        1 => 200,
+        _ => 10,
+    };
+    tokio::time::sleep(Duration::from_millis(delay)).await;
+    Ok(format!("feed {call}"))
+}
+
+#[tokio::test]
+async fn test_latest_refresh_wins() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_feed().id(&());
+
+    let (mut dom, states) = common::record(|| use_provider(fetch_feed(), ()).read().clone());
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Ok("feed 0".to_string()))
+    );
+
+    // Mash the refresh button: the slow first refresh must not clobber the second
+    cache.invalidate(&key);
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    cache.invalidate(&key);
+    run_for(&mut dom, Duration::from_millis(400)).await;
+
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    assert_eq!(
+        cache.get::<Result<String, String>>(&key),
+        Some(Ok("feed 2".to_string()))
+    );
+    let states = states.all();
+    assert_eq!(
+        states.last(),
+        Some(&ProviderState::Success("feed 2".to_string()))
+    );
+    assert!(
+        !states.contains(&ProviderState::Success("feed 1".to_string())),
+        "the superseded refresh was shown"
+    );
+}
+
+#[test]
+fn test_fetch_generations_are_per_key() {
+    let registry = RefreshRegistry::new();
+    let first = registry.start_fetch("a");
+    let other = registry.start_fetch("b");
+    let second = registry.start_fetch("a");
+    assert!(second.generation() > first.generation());
+
+    assert!(second.claim_store());
+    assert!(!first.claim_store());
+    assert!(other.claim_store());
+}
+
+#[test]
+fn test_aborted_newer_fetch_does_not_discard_older_one() {
+    let registry = RefreshRegistry::new();
+    let first = registry.start_fetch("a");
+    let second = registry.start_fetch("a");
+    drop(second);
+    assert!(first.claim_store());
+
+    // Generations start over once no fetch of the key is running
+    drop(first);
+    assert_eq!(registry.start_fetch("a").generation(), 1);
+}
+
+static SHARED_CALLS: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_shared() -> Result<u32, String> {
+    let call = SHARED_CALLS.fetch_add(1, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    Ok(call)
+}
+
+#[component]
+fn SharedReader() -> Element {
+    let _shared = use_provider(fetch_shared(), ());
+    rsx! {}
+}
+
+#[tokio::test]
+async fn test_hook_outlives_aborted_fetch_of_unmounted_hook() {
+    init_global_providers().unwrap();
+
+    let (mut dom, states) = common::record_with(|| {
+        let mut show_other = use_signal(|| true);
+        let state = use_provider(fetch_shared(), ()).read().clone();
+        // The second hook starts its own fetch of the key and unmounts while it runs
+        use_future(move || async move {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            show_other.set(false);
+        });
+        let element = rsx! {
+            if show_other() {
+                SharedReader {}
+            }
+        };
+        (state, element)
+    });
+    run_for(&mut dom, Duration::from_millis(300)).await;
+
+    assert_eq!(SHARED_CALLS.load(Ordering::SeqCst), 2);
+    assert_eq!(states.last(), Some(ProviderState::Success(0)));
+}
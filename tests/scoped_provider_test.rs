@@ -0,0 +1,111 @@
+// Tests for use_scoped_provider isolating entries per scope and ScopeBoundary dropping them
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*, scope::ScopedProvider};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider]
+async fn fetch_draft(document_id: u32) -> Result<String, String> {
+    let fetch = FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    Ok(format!("draft of {document_id} (fetch {fetch})"))
+}
+
+thread_local! {
+    static DRAFTS: RefCell<HashMap<String, ProviderState<String, String>>> = RefCell::new(HashMap::new());
+}
+
+/// Records the draft it shows under `label`
+#[component]
+fn Editor(label: String) -> Element {
+    let draft = use_scoped_provider(fetch_draft(), 7u32);
+    DRAFTS.with(|drafts| drafts.borrow_mut().insert(label, draft.read().clone()));
+    rsx! {}
+}
+
+/// Two editor tabs, the first shown while the returned signal is true
+fn use_tabs() -> (Signal<bool>, Element) {
+    let show_first = use_signal(|| true);
+    let view = rsx! {
+        if show_first() {
+            ScopeBoundary { scope: "tab-1", Editor { label: "tab-1" } }
+        }
+        ScopeBoundary { scope: "tab-2", Editor { label: "tab-2" } }
+    };
+    (show_first, view)
+}
+
+fn cached_draft(scope: &str) -> Option<Result<String, String>> {
+    let key = ScopedProvider::new(fetch_draft(), scope).id(&7u32);
+    get_global_cache().unwrap().get(&key)
+}
+
+#[tokio::test]
+async fn test_scopes_cache_independently_and_clean_up_on_exit() {
+    let (mut dom, renders) = common::record_with(use_tabs);
+    let mut show_first = renders.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    // The same provider and parameter are fetched once per scope
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    let first = cached_draft("tab-1").unwrap().unwrap();
+    let second = cached_draft("tab-2").unwrap().unwrap();
+    assert_ne!(first, second);
+    DRAFTS.with(|drafts| {
+        let drafts = drafts.borrow();
+        assert_eq!(drafts["tab-1"], ProviderState::Success(first));
+        assert_eq!(drafts["tab-2"], ProviderState::Success(second.clone()));
+    });
+    assert_eq!(
+        get_global_cache()
+            .unwrap()
+            .get::<Result<String, String>>(&fetch_draft().id(&7u32)),
+        None
+    );
+
+    // Leaving the first scope drops its entry and keeps the other scope's
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || show_first.set(false));
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(cached_draft("tab-1"), None);
+    assert_eq!(cached_draft("tab-2"), Some(Ok(second)));
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_switching_scope_leaves_the_previous_one() {
+    let (mut dom, renders) = common::record_with(|| {
+        let document = use_signal(|| "doc-a".to_string());
+        let view = rsx! {
+            ScopeBoundary { scope: document(), Editor { label: "document" } }
+        };
+        (document, view)
+    });
+    let mut document = renders.last().unwrap();
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    let first = cached_draft("doc-a").unwrap();
+
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || document.set("doc-b".to_string()));
+    run_for(&mut dom, Duration::from_millis(50)).await;
+
+    // The editor follows the boundary to the new scope, and the old scope is dropped
+    assert_eq!(cached_draft("doc-a"), None);
+    let second = cached_draft("doc-b").unwrap();
+    assert_ne!(second, first);
+    DRAFTS.with(|drafts| {
+        assert_eq!(
+            drafts.borrow()["document"],
+            ProviderState::Success(second.unwrap())
+        )
+    });
+}
@@ -0,0 +1,98 @@
+// Tests for seed_provider priming a detail provider from a list provider's data
+
+mod common;
+
+use common::settle;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    cell::RefCell,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+static DETAIL_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct User {
+    id: u32,
+    name: String,
+}
+
+#[provider]
+async fn fetch_users() -> Result<Vec<User>, String> {
+    Ok(vec![
+        User {
+            id: 1,
+            name: "Ada".to_string(),
+        },
+        User {
+            id: 2,
+            name: "Grace".to_string(),
+        },
+    ])
+}
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<User, String> {
+    DETAIL_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok(User {
+        id,
+        name: "fetched".to_string(),
+    })
+}
+
+thread_local! {
+    static DETAIL_STATES: RefCell<Vec<ProviderState<User, String>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The user list, and the detail of the user held by the returned signal
+fn use_users() -> (Signal<Option<u32>>, Element) {
+    let selected = use_signal(|| None::<u32>);
+    let users = use_provider(fetch_users(), ());
+    let view = rsx! {
+        "{users.read().is_loading()}"
+        if let Some(id) = selected() {
+            UserDetail { id }
+        }
+    };
+    (selected, view)
+}
+
+#[component]
+fn UserDetail(id: u32) -> Element {
+    let user = use_provider(fetch_user(), id);
+    DETAIL_STATES.with(|states| states.borrow_mut().push(user.read().clone()));
+    rsx! {}
+}
+
+#[tokio::test]
+async fn test_detail_mounts_with_seeded_data() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+
+    let (mut dom, renders) = common::record_with(use_users);
+    let mut selected = renders.last().unwrap();
+    settle(&mut dom).await;
+
+    // Prime the detail provider from the list
+    let users = cache
+        .get::<Result<Vec<User>, String>>(&fetch_users().id(&()))
+        .unwrap()
+        .unwrap();
+    for user in &users {
+        assert!(seed_provider(fetch_user(), user.id, user.clone()).unwrap());
+    }
+    // Already cached entries are left alone
+    assert!(!seed_provider(fetch_user(), 1, users[1].clone()).unwrap());
+
+    dom.runtime()
+        .on_scope(ScopeId::ROOT, || selected.set(Some(2)));
+    settle(&mut dom).await;
+
+    assert_eq!(DETAIL_FETCHES.load(Ordering::SeqCst), 0);
+    let states = DETAIL_STATES.with(|states| states.borrow().clone());
+    assert_eq!(
+        states.last(),
+        Some(&ProviderState::Success(users[1].clone()))
+    );
+}
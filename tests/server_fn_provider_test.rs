@@ -0,0 +1,63 @@
+// Tests for #[provider(server_fn)] converting the ServerFnError of server functions
+#![cfg(feature = "fullstack")]
+
+use dioxus_provider::{
+    errors::ProviderError,
+    prelude::*,
+    server_fn::{ServerFnError, ServerFnErrorErr, into_provider_error},
+};
+
+// Stands in for the client side of a `#[server]` function, which has the same signature
+async fn load_profile(id: u32) -> Result<String, ServerFnError> {
+    match id {
+        0 => Err(ServerFnError::new("profile 0 not found")),
+        1 => Err(ServerFnError::CommunicationError(
+            ServerFnErrorErr::Deserialization("expected a string".to_string()),
+        )),
+        id => Ok(format!("profile {id}")),
+    }
+}
+
+#[provider(server_fn)]
+async fn fetch_profile(id: u32) -> Result<String, ServerFnError> {
+    let profile = load_profile(id).await?;
+    Ok(profile.to_uppercase())
+}
+
+#[tokio::test]
+async fn test_server_fn_errors_convert_to_provider_error() {
+    let provider = fetch_profile();
+    assert_eq!(provider.run(7).await, Ok("PROFILE 7".to_string()));
+    assert_eq!(
+        provider.run(0).await,
+        Err(ProviderError::ServerFn {
+            status: 500,
+            message: "profile 0 not found".to_string(),
+        })
+    );
+    assert_eq!(
+        provider.run(1).await,
+        Err(ProviderError::DataParsing("expected a string".to_string()))
+    );
+}
+
+#[test]
+fn test_into_provider_error_keeps_message() {
+    let error = into_provider_error(ServerFnError::new("database offline"));
+    assert_eq!(
+        error.to_string(),
+        "Server function failed with status 500: database offline"
+    );
+    assert_eq!(
+        ProviderError::from(ServerFnError::CommunicationError(
+            ServerFnErrorErr::Request("connection refused".to_string())
+        )),
+        ProviderError::Network("connection refused".to_string())
+    );
+    assert_eq!(
+        ProviderError::from(ServerFnError::CommunicationError(
+            ServerFnErrorErr::MissingArg("id".to_string())
+        )),
+        ProviderError::Generic("missing argument id".to_string())
+    );
+}
@@ -0,0 +1,77 @@
+// Tests for #[provider(should_refetch = ...)] forcing revalidation of fresh cached data
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{global::get_global_cache, prelude::*};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+const APP_VERSION: u32 = 2;
+
+static SETTINGS_FETCHES: AtomicU32 = AtomicU32::new(0);
+static BROKEN_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Settings {
+    version: u32,
+}
+
+fn is_outdated(_entry_age: Duration, cached: &Settings) -> bool {
+    cached.version < APP_VERSION
+}
+
+fn always(_entry_age: Duration, _cached: &Settings) -> bool {
+    true
+}
+
+// The first fetch returns settings written by an older app version
+#[provider(cache_expiration = "1h", should_refetch = is_outdated)]
+async fn fetch_settings() -> Result<Settings, String> {
+    let version = SETTINGS_FETCHES.fetch_add(1, Ordering::SeqCst) + 1;
+    Ok(Settings { version })
+}
+
+#[provider(cache_expiration = "1h", should_refetch = always)]
+async fn fetch_broken_settings() -> Result<Settings, String> {
+    BROKEN_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Err("settings unavailable".to_string())
+}
+
+#[tokio::test]
+async fn test_predicate_refetches_fresh_entry() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+    let key = fetch_settings().id(&());
+
+    let mut dom = common::mount(|| {
+        let settings = use_provider(fetch_settings(), ());
+        rsx! { "{settings.read().is_loading()}" }
+    });
+    run_for(&mut dom, Duration::from_millis(200)).await;
+
+    // The outdated entry is refetched once, then the current one is kept
+    assert_eq!(SETTINGS_FETCHES.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        cache.get::<Result<Settings, String>>(&key),
+        Some(Ok(Settings {
+            version: APP_VERSION
+        }))
+    );
+}
+
+#[tokio::test]
+async fn test_predicate_ignores_cached_errors() {
+    init_global_providers().unwrap();
+
+    let mut dom = common::mount(|| {
+        let settings = use_provider(fetch_broken_settings(), ());
+        rsx! { "{settings.read().is_loading()}" }
+    });
+    run_for(&mut dom, Duration::from_millis(200)).await;
+
+    assert_eq!(BROKEN_FETCHES.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,76 @@
+// Tests for shutdown_global_providers stopping background tasks and emptying the caches
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    cache::CacheConfig,
+    global::{GlobalProviderError, get_named_cache},
+    prelude::*,
+};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static TICKS: AtomicU32 = AtomicU32::new(0);
+
+#[provider(interval = "20ms", stale_time = "1m")]
+async fn fetch_clock() -> Result<u32, String> {
+    Ok(TICKS.fetch_add(1, Ordering::SeqCst))
+}
+
+#[provider(cache = "session")]
+async fn fetch_session() -> Result<String, String> {
+    Ok("session".to_string())
+}
+
+#[allow(non_snake_case)]
+fn App() -> Element {
+    let _clock = use_provider(fetch_clock(), ());
+    let _session = use_provider(fetch_session(), ());
+    rsx! {}
+}
+
+#[tokio::test]
+async fn test_shutdown_stops_tasks_and_allows_reinit() {
+    // Nothing to tear down yet
+    shutdown_global_providers();
+
+    let providers = init_global_providers().unwrap();
+    init_named_cache("session", CacheConfig::default());
+    let mut dom = common::mount(App);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert!(providers.refresh.stats().task_count > 0);
+    assert!(providers.cache.size() > 0);
+    assert_eq!(get_named_cache("session").unwrap().size(), 1);
+
+    shutdown_global_providers();
+    let stats = providers.refresh.stats();
+    assert_eq!(stats.task_count, 0);
+    assert_eq!(stats.context_count, 0);
+    assert_eq!(providers.cache.size(), 0);
+    assert!(matches!(
+        get_named_cache("session"),
+        Err(GlobalProviderError::NamedCacheNotFound(_))
+    ));
+
+    // The interval task no longer ticks, even though the old root keeps running
+    let ticks = TICKS.load(Ordering::SeqCst);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(TICKS.load(Ordering::SeqCst), ticks);
+    drop(dom);
+
+    // A new root starts from scratch
+    let providers = init_global_providers().unwrap();
+    init_named_cache("session", CacheConfig::default());
+    assert_eq!(providers.cache.size(), 0);
+    let mut dom = common::mount(App);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert!(TICKS.load(Ordering::SeqCst) > ticks + 1);
+    assert!(providers.refresh.stats().task_count > 0);
+    assert_eq!(get_named_cache("session").unwrap().size(), 1);
+}
@@ -0,0 +1,83 @@
+// Tests for byte-based LRU eviction with SizedCacheValue estimates
+
+use dioxus_provider::{
+    cache::{CacheConfig, ProviderCache},
+    prelude::*,
+};
+use std::{thread, time::Duration};
+
+#[derive(Clone, PartialEq)]
+pub struct Message {
+    body: String,
+}
+
+impl SizedCacheValue for Message {
+    fn approximate_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.body.capacity()
+    }
+}
+
+#[provider(sized = true)]
+async fn fetch_messages(count: usize) -> Result<Vec<Message>, String> {
+    Ok(vec![
+        Message {
+            body: "x".repeat(100)
+        };
+        count
+    ])
+}
+
+fn message_of(len: usize) -> Message {
+    Message {
+        body: "x".repeat(len),
+    }
+}
+
+#[test]
+fn test_eviction_respects_byte_budget() {
+    let cache = ProviderCache::with_config(CacheConfig {
+        max_bytes: Some(10_000),
+        default_entry_size: 500,
+        ..CacheConfig::default()
+    });
+
+    // Insert oldest first, so "small" is the least recently used entry
+    cache.set_sized("small".to_string(), message_of(100));
+    thread::sleep(Duration::from_millis(5));
+    cache.set_sized("large".to_string(), message_of(6_000));
+    thread::sleep(Duration::from_millis(5));
+    cache.set("unsized".to_string(), 42u32);
+    thread::sleep(Duration::from_millis(5));
+    cache.set_sized("medium".to_string(), message_of(3_000));
+
+    let message_size = std::mem::size_of::<Message>();
+    let expected = 9_100 + 3 * message_size + 500;
+    assert_eq!(cache.total_size(), expected);
+    assert_eq!(cache.evict_lru_entries(100), 0);
+
+    // A larger entry pushes the cache over budget: the least recently used ones go first
+    thread::sleep(Duration::from_millis(5));
+    cache.set_sized("huge".to_string(), message_of(5_000));
+    assert_eq!(cache.evict_lru_entries(100), 2);
+    assert!(cache.get::<Message>("small").is_none());
+    assert!(cache.get::<Message>("large").is_none());
+    assert_eq!(cache.get::<u32>("unsized"), Some(42));
+    assert!(cache.total_size() <= 10_000);
+
+    // The entry count limit still applies on its own
+    assert_eq!(cache.evict_lru_entries(2), 1);
+    assert_eq!(cache.size(), 2);
+
+    // Without a byte budget only the count matters
+    let unbounded = ProviderCache::new();
+    unbounded.set_sized("large".to_string(), message_of(1_000_000));
+    assert_eq!(unbounded.evict_lru_entries(100), 0);
+}
+
+#[test]
+fn test_sized_provider_reports_result_size() {
+    let result: Result<Vec<Message>, String> = Ok(vec![message_of(100); 3]);
+    let size = fetch_messages().approximate_size(&result).unwrap();
+    assert_eq!(size, result.approximate_size());
+    assert!(size >= 300 + 3 * std::mem::size_of::<Message>());
+}
@@ -0,0 +1,82 @@
+// Tests for providers declared with `ssr = false` not fetching during server-side rendering
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{
+    platform::{RenderTarget, set_render_target, set_ssr_prefetch},
+    prelude::*,
+};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static LOCATION_FETCHES: AtomicU32 = AtomicU32::new(0);
+static PAGE_FETCHES: AtomicU32 = AtomicU32::new(0);
+static FOOTER_FETCHES: AtomicU32 = AtomicU32::new(0);
+
+#[provider(ssr = false)]
+async fn fetch_location() -> Result<String, String> {
+    LOCATION_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok("Kathmandu".to_string())
+}
+
+#[provider]
+async fn fetch_page() -> Result<String, String> {
+    PAGE_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok("Home".to_string())
+}
+
+#[provider]
+async fn fetch_footer() -> Result<String, String> {
+    FOOTER_FETCHES.fetch_add(1, Ordering::SeqCst);
+    Ok("Footer".to_string())
+}
+
+/// The location and page states of each render
+type States = (ProviderState<String, String>, ProviderState<String, String>);
+
+fn use_states() -> States {
+    let location = use_provider(fetch_location(), ());
+    let page = use_provider(fetch_page(), ());
+    (location.read().clone(), page.read().clone())
+}
+
+#[tokio::test]
+async fn test_client_only_provider_waits_for_the_client() {
+    assert!(fetch_page().ssr());
+    assert!(!fetch_location().ssr());
+
+    set_render_target(RenderTarget::Server);
+    let (mut dom, states) = common::record(use_states);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let (location, page) = states.last().unwrap();
+    assert!(location.is_loading());
+    assert_eq!(page, ProviderState::Success("Home".to_string()));
+    assert_eq!(LOCATION_FETCHES.load(Ordering::SeqCst), 0);
+    assert_eq!(PAGE_FETCHES.load(Ordering::SeqCst), 1);
+    drop(dom);
+
+    // Without SSR prefetching, the server leaves every provider to the client
+    set_ssr_prefetch(false);
+    let (mut dom, footers) = common::record(|| use_provider(fetch_footer(), ()).read().clone());
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert!(footers.last().unwrap().is_loading());
+    assert_eq!(FOOTER_FETCHES.load(Ordering::SeqCst), 0);
+    set_ssr_prefetch(true);
+    drop(dom);
+
+    // After hydration the client fetches the client-only provider and reuses the page
+    set_render_target(RenderTarget::Client);
+    let (mut dom, states) = common::record(use_states);
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let (location, page) = states.last().unwrap();
+    assert_eq!(location, ProviderState::Success("Kathmandu".to_string()));
+    assert_eq!(page, ProviderState::Success("Home".to_string()));
+    assert_eq!(LOCATION_FETCHES.load(Ordering::SeqCst), 1);
+    assert_eq!(PAGE_FETCHES.load(Ordering::SeqCst), 1);
+}
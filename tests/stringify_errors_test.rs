@@ -0,0 +1,26 @@
+// Tests for #[provider(stringify_errors)] propagating foreign errors into String errors
+
+use dioxus_provider::prelude::*;
+
+#[provider(stringify_errors)]
+async fn parse_port(raw: String) -> Result<u16, String> {
+    let port: u16 = raw.trim().parse()?;
+    if port == 0 {
+        return Err("port 0 is reserved".to_string());
+    }
+    Ok(port)
+}
+
+#[tokio::test]
+async fn test_foreign_errors_become_strings() {
+    let provider = parse_port();
+    assert_eq!(provider.run(" 8080 ".to_string()).await, Ok(8080));
+    assert_eq!(
+        provider.run("http".to_string()).await,
+        Err("invalid digit found in string".to_string())
+    );
+    assert_eq!(
+        provider.run("0".to_string()).await,
+        Err("port 0 is reserved".to_string())
+    );
+}
@@ -0,0 +1,79 @@
+// Tests for use_provider aborting the in-flight fetch of a param that was replaced
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::{cancel::CancellationToken, global::get_global_cache, prelude::*};
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+static TOKENS: Mutex<Vec<(u32, CancellationToken)>> = Mutex::new(Vec::new());
+static COMPLETED: AtomicU32 = AtomicU32::new(0);
+
+#[provider(with_cancel)]
+async fn search(query: u32, cancel: CancellationToken) -> Result<String, String> {
+    TOKENS.lock().unwrap().push((query, cancel));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    COMPLETED.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("results for {query}"))
+}
+
+type SearchSignals = (Signal<u32>, Signal<ProviderState<String, String>>);
+
+/// Searches for the query held by the returned signal
+fn use_search() -> (SearchSignals, Element) {
+    let query = use_signal(|| 1u32);
+    let results = use_provider(search(), query());
+    ((query, results), rsx! { "{results.read().is_loading()}" })
+}
+
+#[tokio::test]
+async fn test_param_change_aborts_superseded_fetch() {
+    init_global_providers().unwrap();
+    let cache = get_global_cache().unwrap();
+
+    let (mut dom, renders) = common::record_with(use_search);
+    let (mut query, state) = renders.last().unwrap();
+    let mut set_query = |dom: &VirtualDom, value: u32| {
+        dom.runtime().on_scope(ScopeId::ROOT, || query.set(value));
+    };
+    run_for(&mut dom, Duration::from_millis(20)).await;
+
+    // Type faster than the search completes
+    set_query(&dom, 2);
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    set_query(&dom, 3);
+    run_for(&mut dom, Duration::from_millis(300)).await;
+
+    let tokens = TOKENS.lock().unwrap().clone();
+    let queries: Vec<u32> = tokens.iter().map(|(query, _)| *query).collect();
+    assert_eq!(queries, vec![1, 2, 3]);
+    let cancelled: Vec<bool> = tokens
+        .iter()
+        .map(|(_, token)| token.is_cancelled())
+        .collect();
+    assert_eq!(cancelled, vec![true, true, false]);
+
+    // Only the current query finished and was cached
+    assert_eq!(COMPLETED.load(Ordering::SeqCst), 1);
+    assert!(
+        cache
+            .get::<Result<String, String>>(&search().id(&1))
+            .is_none()
+    );
+    assert!(
+        cache
+            .get::<Result<String, String>>(&search().id(&2))
+            .is_none()
+    );
+    assert_eq!(
+        *state.peek(),
+        ProviderState::Success("results for 3".to_string())
+    );
+}
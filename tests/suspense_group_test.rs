@@ -0,0 +1,90 @@
+// Tests for ProviderSuspenseGroup revealing suspending providers together
+
+mod common;
+
+use common::run_for;
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+use std::{cell::RefCell, time::Duration};
+
+/// Each section takes longer than the one before it
+#[provider]
+async fn fetch_section(id: u32) -> Result<String, String> {
+    tokio::time::sleep(Duration::from_millis(u64::from(30 + id % 10 * 60))).await;
+    Ok(format!("section {id}"))
+}
+
+thread_local! {
+    static REVEALED: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn Section(id: u32) -> Element {
+    let section = use_provider_suspense(fetch_section(), id)?;
+    assert_eq!(section, Ok(format!("section {id}")));
+    REVEALED.with(|revealed| {
+        let mut revealed = revealed.borrow_mut();
+        if !revealed.contains(&id) {
+            revealed.push(id);
+        }
+    });
+    rsx! {}
+}
+
+#[component]
+fn Page(reveal_order: RevealOrder, offset: u32) -> Element {
+    rsx! {
+        ProviderSuspenseGroup { reveal_order,
+            for id in offset..offset + 3 {
+                SuspenseBoundary { key: "{id}", fallback: |_| rsx! {}, Section { id } }
+            }
+        }
+    }
+}
+
+fn revealed() -> Vec<u32> {
+    REVEALED.with(|revealed| revealed.borrow().clone())
+}
+
+#[tokio::test]
+async fn test_group_reveals_members_together() {
+    let mut dom = VirtualDom::new_with_props(
+        Page,
+        PageProps {
+            reveal_order: RevealOrder::Together,
+            offset: 0,
+        },
+    );
+    dom.rebuild_in_place();
+
+    // The first two sections have loaded, but wait for the slowest one
+    run_for(&mut dom, Duration::from_millis(120)).await;
+    assert!(revealed().is_empty());
+
+    run_for(&mut dom, Duration::from_millis(150)).await;
+    let mut all = revealed();
+    all.sort();
+    assert_eq!(all, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn test_group_reveals_members_forwards() {
+    // Fresh keys, the other test may have cached the first sections
+    let mut dom = VirtualDom::new_with_props(
+        Page,
+        PageProps {
+            reveal_order: RevealOrder::Forwards,
+            offset: 10,
+        },
+    );
+    dom.rebuild_in_place();
+
+    run_for(&mut dom, Duration::from_millis(60)).await;
+    assert_eq!(revealed(), vec![10]);
+
+    run_for(&mut dom, Duration::from_millis(60)).await;
+    assert_eq!(revealed(), vec![10, 11]);
+
+    run_for(&mut dom, Duration::from_millis(90)).await;
+    assert_eq!(revealed(), vec![10, 11, 12]);
+}
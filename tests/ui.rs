@@ -0,0 +1,24 @@
+// Compile-time checks of the macros and hooks, run as one trybuild project
+//
+// Every `tests/ui/*.rs` case with a `.stderr` file next to it must fail to compile with
+// that output; the other cases must compile.
+
+use std::fs;
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    let mut cases: Vec<_> = fs::read_dir("tests/ui")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    cases.sort();
+    for case in cases {
+        if case.with_extension("stderr").exists() {
+            t.compile_fail(&case);
+        } else {
+            t.pass(&case);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+
+pub struct Catalog;
+
+#[providers]
+impl Catalog {
+    #[provider(stale_time = "1min", with_cancel)]
+    pub async fn fetch_item(
+        id: u32,
+        cancel: dioxus_provider::cancel::CancellationToken,
+    ) -> Result<String, String> {
+        let _ = cancel.is_cancelled();
+        Ok(format!("item {id}"))
+    }
+
+    #[provider]
+    pub async fn fetch_page(page: u32, size: u32) -> Result<Vec<u32>, String> {
+        Ok((page * size..(page + 1) * size).collect())
+    }
+}
+
+fn assert_provider<P: Provider<u32, Output = String>>(_provider: P) {}
+
+#[allow(non_snake_case)]
+fn Items() -> Element {
+    let item = use_provider(Catalog::fetch_item(), 1u32);
+    let page = use_provider(Catalog::fetch_page(), (0u32, 10u32));
+    rsx! { "{item.read().is_loading()} {page.read().is_loading()}" }
+}
+
+fn main() {
+    assert_provider(Catalog::fetch_item());
+    let _ = Items;
+}
@@ -0,0 +1,15 @@
+use dioxus_provider::prelude::*;
+
+pub struct Api {
+    base_url: String,
+}
+
+#[providers]
+impl Api {
+    #[provider]
+    pub async fn fetch_user(&self, id: u32) -> Result<String, String> {
+        Ok(format!("{}/users/{id}", self.base_url))
+    }
+}
+
+fn main() {}
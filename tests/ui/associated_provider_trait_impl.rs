@@ -0,0 +1,16 @@
+use dioxus_provider::prelude::*;
+
+pub struct Api;
+
+pub trait Fetch {
+    fn fetch_user() -> u32;
+}
+
+#[providers]
+impl Fetch for Api {
+    fn fetch_user() -> u32 {
+        1
+    }
+}
+
+fn main() {}
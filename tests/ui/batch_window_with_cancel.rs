@@ -0,0 +1,12 @@
+use dioxus_provider::prelude::*;
+
+#[provider(batch_window = "10ms", with_cancel)]
+async fn fetch_item(
+    id: u32,
+    cancel: dioxus_provider::cancel::CancellationToken,
+) -> Result<String, String> {
+    let _ = cancel;
+    Ok(format!("item {id}"))
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[provider(batch_window = "10ms")]
+async fn fetch_item(id: u32) -> Result<String, String> {
+    Ok(format!("item {id}"))
+}
+
+fn main() {}
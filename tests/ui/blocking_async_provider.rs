@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[provider(blocking)]
+async fn parse_report() -> Result<u32, String> {
+    Ok(42)
+}
+
+fn main() {}
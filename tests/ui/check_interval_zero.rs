@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[provider(cache_expiration = "1s", check_interval = "0s")]
+async fn fetch_quote() -> Result<String, String> {
+    Ok("quote".to_string())
+}
+
+fn main() {}
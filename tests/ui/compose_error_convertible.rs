@@ -0,0 +1,28 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct NetworkError;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum GreetingError {
+    Network,
+}
+
+impl From<NetworkError> for GreetingError {
+    fn from(_: NetworkError) -> Self {
+        GreetingError::Network
+    }
+}
+
+#[provider]
+async fn fetch_name(id: u32) -> Result<String, NetworkError> {
+    Ok(format!("user {}", id))
+}
+
+#[provider(compose = [fetch_name])]
+async fn fetch_greeting(id: u32) -> Result<String, GreetingError> {
+    let name = __dioxus_composed_fetch_name_result?;
+    Ok(format!("Hello, {}!", name))
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct NetworkError;
+
+#[provider]
+async fn fetch_name(id: u32) -> Result<String, NetworkError> {
+    Ok(format!("user {}", id))
+}
+
+#[provider(compose = [fetch_name])]
+async fn fetch_greeting(id: u32) -> Result<String, String> {
+    Ok(format!("Hello, {}!", __dioxus_composed_fetch_name_result.unwrap_or_default()))
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct FetchError;
+
+#[provider(conditional)]
+async fn fetch_article(id: u32) -> Result<FetchOutcome<String>, FetchError> {
+    Ok(FetchOutcome::Modified(format!("article {id}")))
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+use anyhow::Context;
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settings {
+    pub theme: String,
+    pub font_size: u32,
+}
+
+async fn read_file(path: &str) -> std::io::Result<String> {
+    Ok(format!("{path}\n14"))
+}
+
+#[provider(error = String)]
+async fn fetch_settings(path: String) -> anyhow::Result<Settings> {
+    let contents = read_file(&path).await?;
+    let mut lines = contents.lines();
+    let theme = lines.next().context("missing theme")?.to_string();
+    let font_size = lines.next().context("missing font size")?.parse()?;
+    if font_size == 0 {
+        return Err(anyhow::anyhow!("font size can't be 0"));
+    }
+    Ok(Settings { theme, font_size })
+}
+
+#[provider(error = String, stale_time = "1min")]
+async fn fetch_theme() -> anyhow::Result<String> {
+    Ok(fetch_settings()
+        .run("theme".to_string())
+        .await
+        .map_err(anyhow::Error::msg)?
+        .theme)
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dioxus_provider::prelude::*;
+
+fn to_message(error: anyhow::Error) -> String {
+    error.to_string()
+}
+
+#[provider(map_err = to_message)]
+async fn fetch_name() -> Result<String, String> {
+    Ok("name".to_string())
+}
+
+fn main() {}
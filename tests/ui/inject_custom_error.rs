@@ -0,0 +1,37 @@
+use dioxus_provider::prelude::*;
+use std::sync::Arc;
+
+pub struct Client;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum AppError {
+    Injection(InjectionError),
+    NotFound,
+}
+
+impl From<InjectionError> for AppError {
+    fn from(error: InjectionError) -> Self {
+        AppError::Injection(error)
+    }
+}
+
+#[provider]
+async fn fetch_greeting(id: u32) -> Result<String, AppError> {
+    let _client = inject::<Client>()?;
+    if id == 0 {
+        return Err(AppError::NotFound);
+    }
+    Ok(format!("hello {id}"))
+}
+
+fn lookup_client() -> Result<Arc<Client>, AppError> {
+    Ok(dioxus_provider::inject!(Client))
+}
+
+fn main() {
+    let _ = fetch_greeting();
+    assert!(matches!(
+        lookup_client(),
+        Err(AppError::Injection(InjectionError::NotInitialized))
+    ));
+}
@@ -0,0 +1,14 @@
+use dioxus_provider::prelude::*;
+
+pub struct Client;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AppError;
+
+#[provider]
+async fn fetch_greeting() -> Result<String, AppError> {
+    let _client = inject::<Client>()?;
+    Ok("hello".to_string())
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[provider(max_items = 100)]
+async fn fetch_events() -> Result<Vec<u32>, String> {
+    Ok(vec![1, 2, 3])
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use dioxus_provider::prelude::*;
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<String, String> {
+    Ok(format!("user {id}"))
+}
+
+// Composed providers run with all of the mutation's parameters
+#[mutation(compose = [fetch_user])]
+async fn rename_user(id: u32, name: String) -> Result<String, String> {
+    let user = __dioxus_composed_fetch_user_result?;
+    Ok(format!("{user}: {name}"))
+}
+
+fn main() {}
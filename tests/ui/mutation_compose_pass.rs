@@ -0,0 +1,35 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Account {
+    id: u32,
+    balance: i64,
+}
+
+#[provider]
+async fn fetch_account(id: u32) -> Result<Account, String> {
+    Ok(Account { id, balance: 100 })
+}
+
+#[provider]
+async fn fetch_limit(id: u32) -> Result<i64, String> {
+    Ok(i64::from(id) * 50)
+}
+
+// Reads the account and its limit before writing the withdrawal
+#[mutation(compose = [fetch_account, fetch_limit], invalidates = [fetch_account(id)])]
+async fn withdraw(id: u32) -> Result<Account, String> {
+    let account = __dioxus_composed_fetch_account_result?;
+    let limit = __dioxus_composed_fetch_limit_result?;
+    let amount = limit.min(account.balance);
+    Ok(Account {
+        balance: account.balance - amount,
+        ..account
+    })
+}
+
+fn main() {
+    let account = futures::executor::block_on(withdraw().mutate(1)).unwrap();
+    assert_eq!(account, Account { id: 1, balance: 50 });
+    assert_eq!(withdraw().invalidates_for(&1), vec![fetch_account().id(&1)]);
+}
@@ -0,0 +1,14 @@
+use dioxus_provider::prelude::*;
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<String, String> {
+    Ok(format!("user {id}"))
+}
+
+// fetch_user takes the user's id, which has to be passed explicitly
+#[mutation(invalidates = [fetch_user])]
+async fn rename_user(id: u32, name: String) -> Result<String, String> {
+    Ok(format!("{id}: {name}"))
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use dioxus_provider::prelude::*;
+
+#[provider]
+async fn fetch_comment(post_id: u32, comment_id: u32) -> Result<String, String> {
+    Ok(format!("comment {comment_id} on post {post_id}"))
+}
+
+#[provider]
+async fn fetch_comments(post_id: u32) -> Result<Vec<String>, String> {
+    Ok(vec![format!("comments on post {post_id}")])
+}
+
+#[provider]
+async fn fetch_recent_comments() -> Result<Vec<String>, String> {
+    Ok(Vec::new())
+}
+
+// Invalidates the edited comment, its post's comment list and the global feed
+#[mutation(invalidates = [
+    fetch_comment(post_id, comment_id),
+    fetch_comments(post_id),
+    fetch_recent_comments,
+])]
+async fn edit_comment(post_id: u32, comment_id: u32, text: String) -> Result<String, String> {
+    Ok(format!("comment {comment_id} on post {post_id}: {text}"))
+}
+
+fn main() {
+    let input = (3, 7, "edited".to_string());
+    assert_eq!(
+        edit_comment().invalidates_for(&input),
+        vec![
+            fetch_recent_comments().id(&()),
+            fetch_comment().id(&(3, 7)),
+            fetch_comments().id(&3),
+        ]
+    );
+    assert_eq!(
+        edit_comment().invalidates(),
+        vec![fetch_recent_comments().id(&())]
+    );
+}
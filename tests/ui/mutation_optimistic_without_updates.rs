@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[mutation(optimistic = name.clone())]
+async fn rename(name: String) -> Result<String, String> {
+    Ok(name)
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use dioxus_provider::prelude::*;
+use std::time::Duration;
+
+#[derive(Clone, PartialEq)]
+pub struct User {
+    id: u32,
+    name: String,
+}
+
+#[provider]
+async fn fetch_user(id: u32) -> Result<User, String> {
+    Ok(User {
+        id,
+        name: "Ada".to_string(),
+    })
+}
+
+#[mutation(retry = 3)]
+async fn retried(id: u32) -> Result<u32, String> {
+    Ok(id)
+}
+
+#[mutation(timeout = "250ms")]
+async fn timed() -> Result<(), ProviderError> {
+    Ok(())
+}
+
+#[mutation(updates = [fetch_user(user.id)], optimistic = user.clone())]
+async fn save_user(user: User) -> Result<User, String> {
+    Ok(user)
+}
+
+fn main() {
+    assert_eq!(retried().retry_policy(), Some(RetryPolicy::new(3)));
+    assert_eq!(timed().timeout(), Some(Duration::from_millis(250)));
+    assert!(timed().timeout_error(Duration::from_millis(250)).is_some());
+
+    let user = User {
+        id: 1,
+        name: "Grace".to_string(),
+    };
+    assert!(save_user().optimistic_value(&user) == Some(user));
+}
@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[mutation(retry = "three")]
+async fn retried() -> Result<(), String> {
+    Ok(())
+}
+
+fn main() {}
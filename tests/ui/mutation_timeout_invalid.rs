@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[mutation(timeout = "soon")]
+async fn timed() -> Result<(), String> {
+    Ok(())
+}
+
+fn main() {}
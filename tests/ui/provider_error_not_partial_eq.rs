@@ -0,0 +1,11 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct FetchError;
+
+#[provider]
+async fn fetch_name(id: u32) -> Result<String, FetchError> {
+    Ok(format!("user {id}"))
+}
+
+fn main() {}
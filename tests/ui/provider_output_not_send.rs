@@ -0,0 +1,16 @@
+use dioxus_provider::prelude::*;
+use std::rc::Rc;
+
+#[derive(Clone, PartialEq)]
+pub struct Session {
+    pub user: Rc<String>,
+}
+
+#[provider]
+async fn fetch_session() -> Result<Session, String> {
+    Ok(Session {
+        user: Rc::new("alice".to_string()),
+    })
+}
+
+fn main() {}
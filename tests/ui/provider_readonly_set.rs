@@ -0,0 +1,16 @@
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+
+#[provider]
+async fn fetch_count() -> Result<u32, String> {
+    Ok(1)
+}
+
+#[component]
+fn Counter() -> Element {
+    let count = use_provider_readonly(fetch_count(), ());
+    count.set(ProviderState::Success(2));
+    rsx! {}
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dioxus_provider::prelude::*;
+
+#[provider(server_fn, error = String)]
+async fn fetch_profile(id: u32) -> Result<String, ServerFnError> {
+    Ok(format!("profile {id}"))
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AppError;
+
+#[provider(stringify_errors)]
+async fn fetch_count() -> Result<u32, AppError> {
+    Ok(1)
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use dioxus_provider::prelude::*;
+use std::fmt;
+
+/// Stands in for an HTTP client's error type
+#[derive(Debug)]
+pub struct HttpError {
+    pub status: u16,
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request failed with status {}", self.status)
+    }
+}
+
+async fn get(url: &str) -> Result<String, HttpError> {
+    if url.is_empty() {
+        Err(HttpError { status: 404 })
+    } else {
+        Ok(format!("body of {url}"))
+    }
+}
+
+#[provider(stringify_errors)]
+async fn fetch_page(url: String) -> Result<usize, String> {
+    let body = get(&url).await?;
+    let words = body.split_whitespace().count();
+    // Closures keep their own error types
+    let first_digit = |text: &str| -> Result<u32, std::num::ParseIntError> { text[..1].parse() };
+    let _ = first_digit("1")?;
+    Ok(words)
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dioxus_provider::prelude::*;
+
+fn double(value: u32) -> u64 {
+    value as u64 * 2
+}
+
+#[provider(transform = double)]
+async fn fetch_number() -> Result<u32, String> {
+    Ok(21)
+}
+
+fn main() {}
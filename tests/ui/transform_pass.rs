@@ -0,0 +1,19 @@
+use dioxus_provider::prelude::*;
+
+#[derive(Clone, PartialEq)]
+pub struct Raw(u32);
+
+fn double(raw: Raw) -> u64 {
+    raw.0 as u64 * 2
+}
+
+#[provider(transform = double -> u64)]
+async fn fetch_number() -> Result<Raw, String> {
+    Ok(Raw(21))
+}
+
+fn assert_output<P: Provider<(), Output = u64>>(_provider: P) {}
+
+fn main() {
+    assert_output(fetch_number());
+}
@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+use dioxus_provider::prelude::*;
+
+#[provider]
+async fn fetch_user_by_id(id: u32) -> Result<String, String> {
+    Ok(format!("User {id}"))
+}
+
+#[provider]
+async fn fetch_user_by_name(name: String) -> Result<u32, String> {
+    Ok(name.len() as u32)
+}
+
+#[provider]
+async fn fetch_comment(post_id: u32, comment_id: u32) -> Result<String, String> {
+    Ok(format!("Comment {comment_id} of {post_id}"))
+}
+
+#[component]
+fn Profile() -> Element {
+    let _by_id = use_provider(fetch_user_by_id(), "123");
+    let _by_name = use_provider(fetch_user_by_name(), 123u32);
+    let _comment = use_provider(fetch_comment(), (1u32, 5u32, 9u32));
+    rsx! {}
+}
+
+fn main() {}